@@ -21,9 +21,9 @@
 use local_runtime::{
     wasm_binary_unwrap, AccountId, AuraConfig, AuraId, BalancesConfig, BlockRewardConfig,
     CouncilConfig, DappStakingConfig, DemocracyConfig, EVMConfig, GenesisConfig, GrandpaConfig,
-    GrandpaId, InflationConfig, InflationParameters, Precompiles, RewardDistributionConfig,
-    Signature, SudoConfig, SystemConfig, TechnicalCommitteeConfig, TierThreshold, TreasuryConfig,
-    VestingConfig, AST,
+    GrandpaId, InflationConfig, InflationParameters, Precompiles, PriceOracleConfig,
+    RewardDistributionConfig, Signature, SudoConfig, SystemConfig, TechnicalCommitteeConfig,
+    TierThreshold, TreasuryConfig, VestingConfig, AST,
 };
 use sc_service::ChainType;
 use sp_core::{crypto::Ss58Codec, sr25519, Pair, Public};
@@ -211,6 +211,9 @@ fn testnet_genesis(
         inflation: InflationConfig {
             params: InflationParameters::default(),
         },
+        price_oracle: PriceOracleConfig {
+            feeders: vec![get_account_id_from_seed::<sr25519::Public>("Alice")],
+        },
     }
 }
 
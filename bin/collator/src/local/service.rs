@@ -32,6 +32,8 @@ use std::{collections::BTreeMap, sync::Arc, time::Duration};
 
 pub use local_runtime::RuntimeApi;
 
+use crate::rpc::dapp_staking::{DappStaking, DappStakingApiServer};
+use crate::rpc::inflation::{Inflation, InflationApiServer};
 use astar_primitives::*;
 
 /// Local runtime native executor.
@@ -290,7 +292,7 @@ pub fn start_node(
     task_manager.spawn_essential_handle().spawn(
         "frontier-fee-history",
         Some("frontier"),
-        fc_rpc::EthTask::fee_history_task(
+        crate::rpc::fee_history::fee_history_task(
             client.clone(),
             overrides.clone(),
             fee_history_cache.clone(),
@@ -344,13 +346,19 @@ pub fn start_node(
                 enable_evm_rpc: true, // enable EVM RPC for dev node by default
             };
 
-            crate::rpc::create_full(
+            let mut io = crate::rpc::create_full(
                 deps,
                 subscription,
                 pubsub_notification_sinks.clone(),
                 rpc_config.clone(),
             )
-            .map_err::<ServiceError, _>(Into::into)
+            .map_err::<ServiceError, _>(Into::into)?;
+            io.merge(DappStaking::new(client.clone()).into_rpc())
+                .map_err(|e| ServiceError::Other(e.to_string()))?;
+            io.merge(Inflation::new(client.clone()).into_rpc())
+                .map_err(|e| ServiceError::Other(e.to_string()))?;
+
+            Ok(io)
         })
     };
 
@@ -564,7 +572,7 @@ pub fn start_node(config: Configuration) -> Result<TaskManager, ServiceError> {
     task_manager.spawn_essential_handle().spawn(
         "frontier-fee-history",
         Some("frontier"),
-        fc_rpc::EthTask::fee_history_task(
+        crate::rpc::fee_history::fee_history_task(
             client.clone(),
             overrides.clone(),
             fee_history_cache.clone(),
@@ -613,8 +621,15 @@ pub fn start_node(config: Configuration) -> Result<TaskManager, ServiceError> {
                 enable_evm_rpc: true, // enable EVM RPC for dev node by default
             };
 
-            crate::rpc::create_full(deps, subscription, pubsub_notification_sinks.clone())
-                .map_err::<ServiceError, _>(Into::into)
+            let mut io =
+                crate::rpc::create_full(deps, subscription, pubsub_notification_sinks.clone())
+                    .map_err::<ServiceError, _>(Into::into)?;
+            io.merge(DappStaking::new(client.clone()).into_rpc())
+                .map_err(|e| ServiceError::Other(e.to_string()))?;
+            io.merge(Inflation::new(client.clone()).into_rpc())
+                .map_err(|e| ServiceError::Other(e.to_string()))?;
+
+            Ok(io)
         })
     };
 
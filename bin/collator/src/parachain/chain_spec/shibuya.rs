@@ -24,8 +24,8 @@ use shibuya_runtime::{
     wasm_binary_unwrap, AccountId, AuraConfig, AuraId, Balance, BalancesConfig,
     CollatorSelectionConfig, CouncilConfig, DappStakingConfig, DemocracyConfig, EVMChainIdConfig,
     EVMConfig, GenesisConfig, InflationConfig, InflationParameters, ParachainInfoConfig,
-    Precompiles, SessionConfig, SessionKeys, Signature, SudoConfig, SystemConfig,
-    TechnicalCommitteeConfig, TierThreshold, TreasuryConfig, VestingConfig, SBY,
+    Precompiles, PriceOracleConfig, SessionConfig, SessionKeys, Signature, SudoConfig,
+    SystemConfig, TechnicalCommitteeConfig, TierThreshold, TreasuryConfig, VestingConfig, SBY,
 };
 use sp_core::{sr25519, Pair, Public};
 
@@ -198,6 +198,9 @@ fn make_genesis(
         inflation: InflationConfig {
             params: InflationParameters::default(),
         },
+        price_oracle: PriceOracleConfig {
+            feeders: vec![get_account_id_from_seed::<sr25519::Public>("Alice")],
+        },
     }
 }
 
@@ -53,6 +53,11 @@ use substrate_prometheus_endpoint::Registry;
 use super::shell_upgrade::*;
 #[cfg(feature = "evm-tracing")]
 use crate::evm_tracing_types::{EthApi as EthApiCmd, EvmTracingConfig};
+use crate::rpc::{
+    dapp_staking::{DappStaking, DappStakingApiServer},
+    inflation::{Inflation, InflationApiServer},
+    xcm_dry_run::{XcmDryRun, XcmDryRunApiServer},
+};
 
 #[cfg(feature = "evm-tracing")]
 use crate::rpc::tracing;
@@ -296,6 +301,28 @@ async fn build_relay_chain_interface(
         )
     }
 }
+
+/// Merges RPCs backed by runtime APIs which aren't implemented by every runtime.
+type RpcExtension<RuntimeApi, Executor> = fn(
+    &Arc<TFullClient<Block, RuntimeApi, NativeElseWasmExecutor<Executor>>>,
+    &mut jsonrpsee::RpcModule<()>,
+) -> Result<(), sc_service::Error>;
+
+/// Merges the dApp staking & inflation RPCs, backed by runtime APIs implemented only by Shibuya.
+fn extend_shibuya_rpc(
+    client: &Arc<
+        TFullClient<Block, shibuya::RuntimeApi, NativeElseWasmExecutor<shibuya::Executor>>,
+    >,
+    io: &mut jsonrpsee::RpcModule<()>,
+) -> Result<(), sc_service::Error> {
+    io.merge(DappStaking::new(client.clone()).into_rpc())
+        .map_err(|e| sc_service::Error::Other(e.to_string()))?;
+    io.merge(Inflation::new(client.clone()).into_rpc())
+        .map_err(|e| sc_service::Error::Other(e.to_string()))?;
+
+    Ok(())
+}
+
 /// Start a node with the given parachain `Configuration` and relay chain `Configuration`.
 ///
 /// This is the actual implementation that is abstract over the executor and the runtime api.
@@ -307,6 +334,7 @@ async fn start_node_impl<RuntimeApi, Executor, BIQ, BIC>(
     collator_options: CollatorOptions,
     id: ParaId,
     additional_config: AdditionalConfig,
+    extend_rpc: RpcExtension<RuntimeApi, Executor>,
     build_import_queue: BIQ,
     build_consensus: BIC,
 ) -> sc_service::error::Result<(
@@ -330,6 +358,8 @@ where
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
+        + dynamic_evm_base_fee_runtime_api::DynamicEvmBaseFeeApi<Block, BlockNumber>
+        + xcm_dry_run_runtime_api::XcmDryRunApi<Block>
         + cumulus_primitives_core::CollectCollationInfo<Block>,
     sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_api::StateBackend<BlakeTwo256>,
     Executor: sc_executor::NativeExecutionDispatch + 'static,
@@ -470,7 +500,7 @@ where
     task_manager.spawn_essential_handle().spawn(
         "frontier-fee-history",
         Some("frontier"),
-        fc_rpc::EthTask::fee_history_task(
+        crate::rpc::fee_history::fee_history_task(
             client.clone(),
             overrides.clone(),
             fee_history_cache.clone(),
@@ -511,8 +541,15 @@ where
                 enable_evm_rpc: additional_config.enable_evm_rpc,
             };
 
-            crate::rpc::create_full(deps, subscription, pubsub_notification_sinks.clone())
-                .map_err(Into::into)
+            let mut io =
+                crate::rpc::create_full(deps, subscription, pubsub_notification_sinks.clone())
+                    .map_err::<sc_service::Error, _>(Into::into)?;
+            // `create_full` is shared with the local node, whose runtime doesn't implement the XCM dry-run API.
+            io.merge(XcmDryRun::new(client.clone()).into_rpc())
+                .map_err(|e| sc_service::Error::Other(e.to_string()))?;
+            extend_rpc(&client, &mut io)?;
+
+            Ok(io)
         })
     };
 
@@ -625,6 +662,7 @@ async fn start_node_impl<RuntimeApi, Executor, BIQ, BIC>(
     collator_options: CollatorOptions,
     id: ParaId,
     additional_config: AdditionalConfig,
+    extend_rpc: RpcExtension<RuntimeApi, Executor>,
     build_import_queue: BIQ,
     build_consensus: BIC,
 ) -> sc_service::error::Result<(
@@ -650,6 +688,8 @@ where
         + moonbeam_rpc_primitives_txpool::TxPoolRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
+        + dynamic_evm_base_fee_runtime_api::DynamicEvmBaseFeeApi<Block, BlockNumber>
+        + xcm_dry_run_runtime_api::XcmDryRunApi<Block>
         + cumulus_primitives_core::CollectCollationInfo<Block>,
     sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_api::StateBackend<BlakeTwo256>,
     Executor: sc_executor::NativeExecutionDispatch + 'static,
@@ -811,7 +851,7 @@ where
     task_manager.spawn_essential_handle().spawn(
         "frontier-fee-history",
         Some("frontier"),
-        fc_rpc::EthTask::fee_history_task(
+        crate::rpc::fee_history::fee_history_task(
             client.clone(),
             overrides.clone(),
             fee_history_cache.clone(),
@@ -857,13 +897,19 @@ where
                 enable_evm_rpc: additional_config.enable_evm_rpc,
             };
 
-            crate::rpc::create_full(
+            let mut io = crate::rpc::create_full(
                 deps,
                 subscription,
                 pubsub_notification_sinks.clone(),
                 rpc_config.clone(),
             )
-            .map_err(Into::into)
+            .map_err::<sc_service::Error, _>(Into::into)?;
+            // `create_full` is shared with the local node, whose runtime doesn't implement the XCM dry-run API.
+            io.merge(XcmDryRun::new(client.clone()).into_rpc())
+                .map_err(|e| sc_service::Error::Other(e.to_string()))?;
+            extend_rpc(&client, &mut io)?;
+
+            Ok(io)
         })
     };
 
@@ -1057,6 +1103,7 @@ pub async fn start_astar_node(
         collator_options,
         id,
         additional_config.clone(),
+        |_, _| Ok(()),
         |client,
          block_import,
          config,
@@ -1191,6 +1238,7 @@ pub async fn start_astar_node(
         collator_options,
         id,
         additional_config.clone(),
+        |_, _| Ok(()),
         |client,
          block_import,
          config,
@@ -1325,6 +1373,7 @@ pub async fn start_shiden_node(
         collator_options,
         id,
         additional_config.clone(),
+        |_, _| Ok(()),
         build_import_queue,
         |client,
          block_import,
@@ -1488,6 +1537,7 @@ pub async fn start_shiden_node(
         collator_options,
         id,
         additional_config.clone(),
+        |_, _| Ok(()),
         build_import_queue,
         |client,
          block_import,
@@ -1651,6 +1701,7 @@ pub async fn start_shibuya_node(
         collator_options,
         id,
         additional_config.clone(),
+        extend_shibuya_rpc,
         |client,
          block_import,
          config,
@@ -1783,6 +1834,7 @@ pub async fn start_shibuya_node(
         collator_options,
         id,
         additional_config.clone(),
+        extend_shibuya_rpc,
         |client,
          block_import,
          config,
@@ -51,6 +51,11 @@ use moonbeam_rpc_txpool::{TxPool as MoonbeamTxPool, TxPoolServer};
 
 use astar_primitives::*;
 
+pub mod dapp_staking;
+pub mod fee_history;
+pub mod inflation;
+pub mod xcm_dry_run;
+
 #[cfg(feature = "evm-tracing")]
 pub mod tracing;
 
@@ -0,0 +1,219 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! dApp staking RPC support.
+
+use astar_primitives::{dapp_staking::SmartContract, AccountId, Balance, BlockNumber};
+use dapp_staking_v3_runtime_api::{
+    DAppMetadataInfo, DappStakingApi as DappStakingRuntimeApi, EraNumber, TierId,
+};
+use jsonrpsee::{
+    core::{Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+/// Error code used when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+#[rpc(client, server)]
+pub trait DappStakingApi<BlockHash> {
+    /// For how many standard era lengths does the voting subperiod last.
+    #[method(name = "dappStaking_erasPerVotingSubperiod")]
+    fn eras_per_voting_subperiod(&self, at: Option<BlockHash>) -> RpcResult<EraNumber>;
+
+    /// How many standard eras are there in the build&earn subperiod.
+    #[method(name = "dappStaking_erasPerBuildAndEarnSubperiod")]
+    fn eras_per_build_and_earn_subperiod(&self, at: Option<BlockHash>) -> RpcResult<EraNumber>;
+
+    /// How many blocks are there per standard era.
+    #[method(name = "dappStaking_blocksPerEra")]
+    fn blocks_per_era(&self, at: Option<BlockHash>) -> RpcResult<BlockNumber>;
+
+    /// Total amount of staker rewards the account can claim, across all claimable eras.
+    #[method(name = "dappStaking_pendingStakerRewards")]
+    fn pending_staker_rewards(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Balance>;
+
+    /// Bonus rewards the account can claim, per smart contract.
+    #[method(name = "dappStaking_pendingBonusRewards")]
+    fn pending_bonus_rewards(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(SmartContract<AccountId>, Balance)>>;
+
+    /// dApp rewards which can be claimed for the smart contract, per era.
+    #[method(name = "dappStaking_pendingDappRewards")]
+    fn pending_dapp_rewards(
+        &self,
+        smart_contract: SmartContract<AccountId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(EraNumber, Balance)>>;
+
+    /// Tier into which the smart contract would be assigned, based on the stake in the ongoing era.
+    #[method(name = "dappStaking_dappTier")]
+    fn dapp_tier(
+        &self,
+        smart_contract: SmartContract<AccountId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<TierId>>;
+
+    /// Minimum stake amount required to enter each tier, starting with the first tier.
+    #[method(name = "dappStaking_tierThresholds")]
+    fn tier_thresholds(&self, at: Option<BlockHash>) -> RpcResult<Vec<Balance>>;
+
+    /// Metadata of the smart contract's dApp, like name, website, logo hash & category, if it exists.
+    #[method(name = "dappStaking_dappMetadata")]
+    fn dapp_metadata(
+        &self,
+        smart_contract: SmartContract<AccountId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<DAppMetadataInfo>>;
+}
+
+/// dApp staking RPC, backed by the `DappStakingApi` runtime API.
+pub struct DappStaking<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> DappStaking<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Converts runtime API error into RPC error.
+fn runtime_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    CallError::Custom(ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Unable to query dApp staking state.",
+        Some(format!("{:?}", err)),
+    ))
+    .into()
+}
+
+impl<C, Block> DappStakingApiServer<<Block as BlockT>::Hash> for DappStaking<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DappStakingRuntimeApi<Block>,
+{
+    fn eras_per_voting_subperiod(&self, at: Option<Block::Hash>) -> RpcResult<EraNumber> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .eras_per_voting_subperiod(at)
+            .map_err(runtime_error)
+    }
+
+    fn eras_per_build_and_earn_subperiod(&self, at: Option<Block::Hash>) -> RpcResult<EraNumber> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .eras_per_build_and_earn_subperiod(at)
+            .map_err(runtime_error)
+    }
+
+    fn blocks_per_era(&self, at: Option<Block::Hash>) -> RpcResult<BlockNumber> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .blocks_per_era(at)
+            .map_err(runtime_error)
+    }
+
+    fn pending_staker_rewards(
+        &self,
+        account: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Balance> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .pending_staker_rewards(at, account)
+            .map_err(runtime_error)
+    }
+
+    fn pending_bonus_rewards(
+        &self,
+        account: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<(SmartContract<AccountId>, Balance)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .pending_bonus_rewards(at, account)
+            .map_err(runtime_error)
+    }
+
+    fn pending_dapp_rewards(
+        &self,
+        smart_contract: SmartContract<AccountId>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<(EraNumber, Balance)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .pending_dapp_rewards(at, smart_contract)
+            .map_err(runtime_error)
+    }
+
+    fn dapp_tier(
+        &self,
+        smart_contract: SmartContract<AccountId>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<TierId>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .dapp_tier(at, smart_contract)
+            .map_err(runtime_error)
+    }
+
+    fn tier_thresholds(&self, at: Option<Block::Hash>) -> RpcResult<Vec<Balance>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .tier_thresholds(at)
+            .map_err(runtime_error)
+    }
+
+    fn dapp_metadata(
+        &self,
+        smart_contract: SmartContract<AccountId>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<DAppMetadataInfo>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .dapp_metadata(at, smart_contract)
+            .map_err(runtime_error)
+    }
+}
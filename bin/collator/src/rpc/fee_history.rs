@@ -0,0 +1,197 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! EVM fee history support.
+//!
+//! Maintains the fee history cache used by the `eth_feeHistory` RPC.
+//! Follows the default Frontier task, reading the Ethereum block & receipts via the storage overrides.
+//! The only difference is that the base fee is taken from the `DynamicEvmBaseFeeApi` history,
+//! which holds the value actually applied in the block, instead of the one calculated for the next block.
+
+use astar_primitives::BlockNumber;
+use dynamic_evm_base_fee_runtime_api::DynamicEvmBaseFeeApi;
+use fc_rpc::OverrideHandle;
+use fc_rpc_core::types::{FeeHistoryCache, FeeHistoryCacheItem};
+use fp_rpc::EthereumRuntimeRPCApi;
+use futures::StreamExt;
+use sc_client_api::{Backend, BlockchainEvents, StorageProvider};
+use sp_api::ProvideRuntimeApi;
+use sp_core::{H256, U256};
+use sp_runtime::traits::{Block as BlockT, UniqueSaturatedInto};
+use std::sync::Arc;
+
+/// Reward percentiles cached for each block, from 0 to 100 in 0.5 steps.
+fn reward_percentiles() -> Vec<f64> {
+    (0..=200).map(|step| step as f64 * 0.5).collect()
+}
+
+/// Gas used & effective reward of a single transaction.
+struct TransactionHelper {
+    gas_used: u64,
+    effective_reward: u64,
+}
+
+/// Build the fee history cache item of the specified block.
+///
+/// Returns `None` if the block doesn't contain an Ethereum block.
+fn fee_history_cache_item<B, C, BE>(
+    client: &C,
+    overrides: &OverrideHandle<B>,
+    hash: B::Hash,
+) -> Option<(u64, FeeHistoryCacheItem)>
+where
+    B: BlockT<Hash = H256>,
+    C: ProvideRuntimeApi<B> + StorageProvider<B, BE>,
+    C::Api: EthereumRuntimeRPCApi<B> + DynamicEvmBaseFeeApi<B, BlockNumber>,
+    BE: Backend<B>,
+{
+    // Ethereum block & receipts are read directly from the storage, like in the default Frontier task.
+    let schema = fc_storage::onchain_storage_schema(client, hash);
+    let handler = overrides
+        .schemas
+        .get(&schema)
+        .unwrap_or(&overrides.fallback);
+    let block = handler.current_block(hash)?;
+    let receipts = handler.current_receipts(hash)?;
+
+    let api = client.runtime_api();
+    let block_number = block.header.number.low_u64();
+
+    // Base fee applied in the block, with a fallback to the one calculated for the next block.
+    let history_record = api
+        .base_fee_history(hash, 1)
+        .ok()
+        .and_then(|mut records| records.pop())
+        .filter(|record| u64::from(record.block_number) == block_number);
+    let base_fee = match history_record {
+        Some(record) => record.base_fee,
+        None => api.gas_price(hash).unwrap_or_default(),
+    };
+
+    let gas_used = block.header.gas_used.low_u64() as f64;
+    let gas_limit = block.header.gas_limit.low_u64() as f64;
+    let gas_used_ratio = match history_record {
+        Some(record) => record.fullness.deconstruct() as f64 / 1_000_000_000_f64,
+        None if gas_limit > 0_f64 => gas_used / gas_limit,
+        None => 0_f64,
+    };
+
+    // Receipts only contain the cumulative gas used, so the difference has to be calculated.
+    let mut previous_cumulative_gas = U256::zero();
+    let mut transactions: Vec<TransactionHelper> = receipts
+        .iter()
+        .enumerate()
+        .map(|(i, receipt)| {
+            let cumulative_gas = match receipt {
+                ethereum::ReceiptV3::Legacy(d)
+                | ethereum::ReceiptV3::EIP2930(d)
+                | ethereum::ReceiptV3::EIP1559(d) => d.used_gas,
+            };
+            let gas_used = cumulative_gas.saturating_sub(previous_cumulative_gas);
+            previous_cumulative_gas = cumulative_gas;
+
+            let effective_reward = match block.transactions.get(i) {
+                Some(ethereum::TransactionV2::Legacy(t)) => t.gas_price.saturating_sub(base_fee),
+                Some(ethereum::TransactionV2::EIP2930(t)) => t.gas_price.saturating_sub(base_fee),
+                Some(ethereum::TransactionV2::EIP1559(t)) => t
+                    .max_priority_fee_per_gas
+                    .min(t.max_fee_per_gas.saturating_sub(base_fee)),
+                None => U256::zero(),
+            };
+
+            TransactionHelper {
+                gas_used: gas_used.unique_saturated_into(),
+                effective_reward: effective_reward.unique_saturated_into(),
+            }
+        })
+        .collect();
+    transactions.sort_by(|a, b| a.effective_reward.cmp(&b.effective_reward));
+
+    let rewards = reward_percentiles()
+        .into_iter()
+        .filter_map(|percentile| {
+            let target_gas = (percentile * gas_used / 100_f64) as u64;
+            let mut sum_gas = 0;
+            transactions.iter().find_map(|transaction| {
+                sum_gas += transaction.gas_used;
+                (target_gas <= sum_gas).then_some(transaction.effective_reward)
+            })
+        })
+        .collect();
+
+    Some((
+        block_number,
+        FeeHistoryCacheItem {
+            base_fee: base_fee.unique_saturated_into(),
+            gas_used_ratio,
+            rewards,
+        },
+    ))
+}
+
+/// Task which keeps the fee history cache up to date with the best chain.
+///
+/// At most `block_limit` most recent blocks are kept in the cache.
+pub async fn fee_history_task<B, C, BE>(
+    client: Arc<C>,
+    overrides: Arc<OverrideHandle<B>>,
+    fee_history_cache: FeeHistoryCache,
+    block_limit: u64,
+) where
+    B: BlockT<Hash = H256>,
+    C: ProvideRuntimeApi<B> + BlockchainEvents<B> + StorageProvider<B, BE> + Send + Sync + 'static,
+    C::Api: EthereumRuntimeRPCApi<B> + DynamicEvmBaseFeeApi<B, BlockNumber>,
+    BE: Backend<B> + 'static,
+{
+    let commit = |hash: B::Hash| {
+        if let Some((block_number, item)) =
+            fee_history_cache_item(client.as_ref(), overrides.as_ref(), hash)
+        {
+            let mut cache = fee_history_cache
+                .lock()
+                .expect("Fee history cache lock is never poisoned; qed");
+            cache.insert(block_number, item);
+
+            // Remove the items which are out of the limit.
+            let first_out = block_number.saturating_sub(block_limit);
+            cache.retain(|number, _| *number >= first_out);
+        }
+    };
+
+    let mut notification_st = client.import_notification_stream();
+    while let Some(notification) = notification_st.next().await {
+        if !notification.is_new_best {
+            continue;
+        }
+
+        if let Some(tree_route) = notification.tree_route {
+            // On re-org, retracted blocks are removed and the enacted ones are added.
+            if let Ok(mut cache) = fee_history_cache.lock() {
+                for retracted in tree_route.retracted() {
+                    let number: u64 = retracted.number.unique_saturated_into();
+                    cache.remove(&number);
+                }
+            }
+            for enacted in tree_route.enacted() {
+                commit(enacted.hash);
+            }
+        }
+
+        commit(notification.hash);
+    }
+}
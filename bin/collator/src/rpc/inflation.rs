@@ -0,0 +1,161 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Inflation RPC support.
+
+use astar_primitives::Balance;
+use inflation_runtime_api::{
+    CycleInflationProjection, InflationApi as InflationRuntimeApi, InflationConfiguration,
+    InflationParameters, RewardPoolsBreakdown,
+};
+use jsonrpsee::{
+    core::{Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+/// Error code used when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+/// Error code used when an RPC argument can't be converted into the runtime type.
+const DECODE_ERROR: i32 = 2;
+
+#[rpc(client, server)]
+pub trait InflationApi<BlockHash> {
+    /// Active inflation configuration.
+    #[method(name = "inflation_activeConfiguration")]
+    fn active_configuration(&self, at: Option<BlockHash>) -> RpcResult<InflationConfiguration>;
+
+    /// Active inflation parameters.
+    #[method(name = "inflation_activeParameters")]
+    fn active_parameters(&self, at: Option<BlockHash>) -> RpcResult<InflationParameters>;
+
+    /// Inflation configuration which the next recalculation would produce, based on the current total issuance.
+    #[method(name = "inflation_projectedConfiguration")]
+    fn projected_configuration(&self, at: Option<BlockHash>) -> RpcResult<InflationConfiguration>;
+
+    /// Reward pools & their emission over the entire cycle, for the hypothetical total value staked.
+    #[method(name = "inflation_rewardPools")]
+    fn reward_pools(
+        &self,
+        total_value_staked: NumberOrHex,
+        at: Option<BlockHash>,
+    ) -> RpcResult<RewardPoolsBreakdown>;
+
+    /// Projected inflation for the upcoming cycles, assuming max emission in each cycle.
+    #[method(name = "inflation_projectedInflation")]
+    fn projected_inflation(
+        &self,
+        cycles: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<CycleInflationProjection>>;
+}
+
+/// Inflation RPC, backed by the `InflationApi` runtime API.
+pub struct Inflation<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Inflation<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Converts runtime API error into RPC error.
+fn runtime_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    CallError::Custom(ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Unable to query inflation state.",
+        Some(format!("{:?}", err)),
+    ))
+    .into()
+}
+
+impl<C, Block> InflationApiServer<<Block as BlockT>::Hash> for Inflation<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: InflationRuntimeApi<Block>,
+{
+    fn active_configuration(&self, at: Option<Block::Hash>) -> RpcResult<InflationConfiguration> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .inflation_configuration(at)
+            .map_err(runtime_error)
+    }
+
+    fn active_parameters(&self, at: Option<Block::Hash>) -> RpcResult<InflationParameters> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .inflation_parameters(at)
+            .map_err(runtime_error)
+    }
+
+    fn projected_configuration(
+        &self,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<InflationConfiguration> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .projected_inflation_configuration(at)
+            .map_err(runtime_error)
+    }
+
+    fn reward_pools(
+        &self,
+        total_value_staked: NumberOrHex,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<RewardPoolsBreakdown> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let total_value_staked = Balance::try_from(total_value_staked).map_err(|_| {
+            CallError::Custom(ErrorObject::owned(
+                DECODE_ERROR,
+                "Total value staked doesn't fit into the balance type.",
+                None::<()>,
+            ))
+        })?;
+        self.client
+            .runtime_api()
+            .reward_pools(at, total_value_staked)
+            .map_err(runtime_error)
+    }
+
+    fn projected_inflation(
+        &self,
+        cycles: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<CycleInflationProjection>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .project_inflation(at, cycles)
+            .map_err(runtime_error)
+    }
+}
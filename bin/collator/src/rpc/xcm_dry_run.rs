@@ -0,0 +1,150 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! XCM dry-run RPC support.
+
+use jsonrpsee::{
+    core::{Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+use xcm::{
+    latest::{Outcome, Weight},
+    VersionedMultiLocation, VersionedXcm,
+};
+use xcm_dry_run_runtime_api::{XcmDryRunApi as XcmDryRunRuntimeApi, XcmDryRunEffects};
+
+/// Error code used when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+/// Error code used when the provided parameters can't be decoded.
+const DECODE_ERROR: i32 = 2;
+/// Error code used when the runtime is unable to dry-run the XCM program.
+const DRY_RUN_ERROR: i32 = 3;
+
+/// Effects of an XCM program dry-run.
+///
+/// XCM types are returned SCALE encoded since they don't support serialization.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XcmDryRunResult {
+    /// Weight used by the local execution.
+    pub weight_used: Weight,
+    /// Error which stopped the local execution, if any.
+    pub error: Option<String>,
+    /// SCALE encoded `VersionedMultiAssets`, fees charged for the local execution.
+    pub fees_charged: Bytes,
+    /// SCALE encoded runtime events emitted during the local execution.
+    pub emitted_events: Vec<Bytes>,
+    /// SCALE encoded `VersionedMultiLocation` & `VersionedXcm` pairs, messages which would be sent.
+    pub forwarded_xcms: Vec<(Bytes, Bytes)>,
+}
+
+impl From<XcmDryRunEffects<Vec<u8>>> for XcmDryRunResult {
+    fn from(effects: XcmDryRunEffects<Vec<u8>>) -> Self {
+        let error = match effects.execution_result {
+            Outcome::Complete(_) => None,
+            Outcome::Incomplete(_, error) | Outcome::Error(error) => Some(format!("{:?}", error)),
+        };
+
+        Self {
+            weight_used: effects.weight_used,
+            error,
+            fees_charged: effects.fees_charged.encode().into(),
+            emitted_events: effects.emitted_events.into_iter().map(Into::into).collect(),
+            forwarded_xcms: effects
+                .forwarded_xcms
+                .into_iter()
+                .map(|(destination, message)| {
+                    (destination.encode().into(), message.encode().into())
+                })
+                .collect(),
+        }
+    }
+}
+
+#[rpc(client, server)]
+pub trait XcmDryRunApi<BlockHash> {
+    /// Executes the SCALE encoded `VersionedXcm` program from the SCALE encoded `VersionedMultiLocation` origin,
+    /// without committing any of its effects.
+    #[method(name = "xcm_dryRun")]
+    fn dry_run(
+        &self,
+        origin: Bytes,
+        xcm: Bytes,
+        at: Option<BlockHash>,
+    ) -> RpcResult<XcmDryRunResult>;
+}
+
+/// XCM dry-run RPC, backed by the `XcmDryRunApi` runtime API.
+pub struct XcmDryRun<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> XcmDryRun<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Builds RPC error with the given code, message & debug formatted data.
+fn rpc_error(code: i32, message: &str, data: impl std::fmt::Debug) -> JsonRpseeError {
+    CallError::Custom(ErrorObject::owned(
+        code,
+        message,
+        Some(format!("{:?}", data)),
+    ))
+    .into()
+}
+
+impl<C, Block> XcmDryRunApiServer<<Block as BlockT>::Hash> for XcmDryRun<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: XcmDryRunRuntimeApi<Block>,
+{
+    fn dry_run(
+        &self,
+        origin: Bytes,
+        xcm: Bytes,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<XcmDryRunResult> {
+        let origin = VersionedMultiLocation::decode(&mut &origin[..])
+            .map_err(|e| rpc_error(DECODE_ERROR, "Unable to decode origin.", e))?;
+        let xcm = VersionedXcm::<()>::decode(&mut &xcm[..])
+            .map_err(|e| rpc_error(DECODE_ERROR, "Unable to decode XCM program.", e))?;
+
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .dry_run_xcm(at, origin, xcm)
+            .map_err(|e| rpc_error(RUNTIME_ERROR, "Unable to dry-run XCM program.", e))?
+            .map(Into::into)
+            .map_err(|e| rpc_error(DRY_RUN_ERROR, "XCM program dry-run failed.", e))
+    }
+}
@@ -0,0 +1,208 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::traits::{
+    fungibles::{Create, Inspect},
+    EnsureOrigin,
+};
+use pallet_assets::BenchmarkHelper;
+use pallet_xcm_benchmarks::{account_and_location, new_executor};
+use sp_runtime::traits::{One, Zero};
+use sp_std::{boxed::Box, vec};
+use xcm::latest::prelude::*;
+use xcm_executor::traits::{Convert, TransactAsset};
+
+type AssetTransactorOf<T> =
+    <<T as pallet_xcm_benchmarks::Config>::XcmConfig as xcm_executor::Config>::AssetTransactor;
+type AssetIdOf<T> = <T as pallet_assets::Config>::AssetId;
+type BalanceOf<T> = <T as pallet_assets::Config>::Balance;
+
+/// Amount of the benchmarked asset moved by the instructions.
+const ASSET_AMOUNT: u128 = 1_000_000_000_000_000_000;
+
+/// Creates a sufficient `pallet-assets` asset and registers it in `XcAssetConfig` under a sibling parachain location.
+///
+/// Returns the asset Id, the multi asset moved by the instructions and the registered reserve location of the asset.
+fn create_registered_asset<T: Config>(
+) -> Result<(AssetIdOf<T>, MultiAsset, MultiLocation), BenchmarkError> {
+    let asset_id: AssetIdOf<T> = T::BenchmarkHelper::create_asset_id_parameter(1).into();
+    let asset_location = MultiLocation::new(1, X2(Parachain(1000), GeneralIndex(1)));
+
+    <pallet_assets::Pallet<T> as Create<T::AccountId>>::create(
+        asset_id.clone(),
+        account("asset_owner", 0, 0),
+        true,
+        One::one(),
+    )?;
+
+    // Registering the asset location also trusts its derived reserve location
+    let origin = <T as pallet_xc_asset_config::Config>::ManagerOrigin::try_successful_origin()
+        .map_err(|_| BenchmarkError::Weightless)?;
+    pallet_xc_asset_config::Pallet::<T>::register_asset_location(
+        origin,
+        Box::new(asset_location.into_versioned()),
+        asset_id.clone(),
+    )?;
+    let reserve_location =
+        pallet_xc_asset_config::Pallet::<T>::derive_reserve_location(asset_location).ok_or(
+            BenchmarkError::Stop("Reserve location of the asset must be derivable."),
+        )?;
+
+    let asset = MultiAsset {
+        id: Concrete(asset_location),
+        fun: Fungible(ASSET_AMOUNT),
+    };
+
+    Ok((asset_id, asset, reserve_location))
+}
+
+/// Deposits the asset to the given location.
+///
+/// Nothing else is deposited, so the account is removed once the asset is moved away, which is the worst case.
+fn fund<T: Config>(asset: &MultiAsset, who: &MultiLocation) -> Result<(), BenchmarkError> {
+    let context = XcmContext {
+        origin: None,
+        message_id: [0; 32],
+        topic: None,
+    };
+
+    AssetTransactorOf::<T>::deposit_asset(asset, who, &context)
+        .map_err(|_| BenchmarkError::Stop("Failed to fund the account."))
+}
+
+fn balance_of<T: Config>(asset_id: AssetIdOf<T>, who: &T::AccountId) -> BalanceOf<T> {
+    <pallet_assets::Pallet<T> as Inspect<T::AccountId>>::balance(asset_id, who)
+}
+
+benchmarks! {
+
+    withdraw_asset {
+        let (asset_id, asset, _) = create_registered_asset::<T>()?;
+        let (sender_account, sender_location) = account_and_location::<T>(1);
+        fund::<T>(&asset, &sender_location)?;
+
+        let mut executor = new_executor::<T>(sender_location);
+        let xcm = Xcm(vec![WithdrawAsset(asset.clone().into())]);
+    }: {
+        executor.bench_process(xcm)?;
+    } verify {
+        assert!(balance_of::<T>(asset_id, &sender_account).is_zero());
+        assert!(executor.holding().ensure_contains(&asset.into()).is_ok());
+    }
+
+    transfer_asset {
+        let (asset_id, asset, _) = create_registered_asset::<T>()?;
+        let (sender_account, sender_location) = account_and_location::<T>(1);
+        fund::<T>(&asset, &sender_location)?;
+
+        // Beneficiary account doesn't exist yet, so it has to be created
+        let (beneficiary_account, beneficiary_location) = account_and_location::<T>(2);
+        assert!(balance_of::<T>(asset_id.clone(), &beneficiary_account).is_zero());
+
+        let mut executor = new_executor::<T>(sender_location);
+        let xcm = Xcm(vec![TransferAsset {
+            assets: asset.into(),
+            beneficiary: beneficiary_location,
+        }]);
+    }: {
+        executor.bench_process(xcm)?;
+    } verify {
+        assert!(balance_of::<T>(asset_id.clone(), &sender_account).is_zero());
+        assert!(!balance_of::<T>(asset_id, &beneficiary_account).is_zero());
+    }
+
+    transfer_reserve_asset {
+        let (asset_id, asset, _) = create_registered_asset::<T>()?;
+        let (sender_account, sender_location) = account_and_location::<T>(1);
+        fund::<T>(&asset, &sender_location)?;
+
+        let dest_location = <T as pallet_xcm_benchmarks::Config>::valid_destination()?;
+        let dest_account = T::AccountIdConverter::convert(dest_location)
+            .map_err(|_| BenchmarkError::Stop("Destination must have an account."))?;
+        assert!(balance_of::<T>(asset_id.clone(), &dest_account).is_zero());
+
+        let mut executor = new_executor::<T>(sender_location);
+        let xcm = Xcm(vec![TransferReserveAsset {
+            assets: asset.into(),
+            dest: dest_location,
+            xcm: Xcm::new(),
+        }]);
+    }: {
+        executor.bench_process(xcm)?;
+    } verify {
+        assert!(balance_of::<T>(asset_id.clone(), &sender_account).is_zero());
+        assert!(!balance_of::<T>(asset_id, &dest_account).is_zero());
+    }
+
+    reserve_asset_deposited {
+        let (_, asset, reserve_location) = create_registered_asset::<T>()?;
+
+        let mut executor = new_executor::<T>(reserve_location);
+        let xcm = Xcm(vec![ReserveAssetDeposited(asset.clone().into())]);
+    }: {
+        executor.bench_process(xcm)?;
+    } verify {
+        assert!(executor.holding().ensure_contains(&asset.into()).is_ok());
+    }
+
+    deposit_asset {
+        let (asset_id, asset, _) = create_registered_asset::<T>()?;
+
+        // Beneficiary account doesn't exist yet, so it has to be created
+        let (beneficiary_account, beneficiary_location) = account_and_location::<T>(2);
+        assert!(balance_of::<T>(asset_id.clone(), &beneficiary_account).is_zero());
+
+        let mut executor = new_executor::<T>(Default::default());
+        executor.set_holding(asset.clone().into());
+        let xcm = Xcm(vec![DepositAsset {
+            assets: Definite(asset.into()),
+            beneficiary: beneficiary_location,
+        }]);
+    }: {
+        executor.bench_process(xcm)?;
+    } verify {
+        assert!(!balance_of::<T>(asset_id, &beneficiary_account).is_zero());
+    }
+
+    deposit_reserve_asset {
+        let (asset_id, asset, _) = create_registered_asset::<T>()?;
+
+        let dest_location = <T as pallet_xcm_benchmarks::Config>::valid_destination()?;
+        let dest_account = T::AccountIdConverter::convert(dest_location)
+            .map_err(|_| BenchmarkError::Stop("Destination must have an account."))?;
+        assert!(balance_of::<T>(asset_id.clone(), &dest_account).is_zero());
+
+        let mut executor = new_executor::<T>(Default::default());
+        executor.set_holding(asset.clone().into());
+        let xcm = Xcm(vec![DepositReserveAsset {
+            assets: Definite(asset.into()),
+            dest: dest_location,
+            xcm: Xcm::new(),
+        }]);
+    }: {
+        executor.bench_process(xcm)?;
+    } verify {
+        assert!(!balance_of::<T>(asset_id, &dest_account).is_zero());
+    }
+
+}
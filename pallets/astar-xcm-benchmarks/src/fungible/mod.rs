@@ -0,0 +1,44 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks of the fungible XCM instructions, transacting a `pallet-assets` asset registered in `XcAssetConfig`.
+//!
+//! Transacting a `pallet-assets` asset accesses more storage than transacting the native currency,
+//! so these benchmarks are the worst case of the fungible instructions.
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use frame_support::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config:
+        frame_system::Config
+        + pallet_xcm_benchmarks::Config
+        + pallet_assets::Config
+        + pallet_xc_asset_config::Config<AssetId = <Self as pallet_assets::Config>::AssetId>
+    {
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+}
@@ -0,0 +1,36 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Astar XCM Benchmarks
+//!
+//! Benchmarks of the XCM instructions for the cross-chain assets of Astar networks.
+//!
+//! `pallet-xcm-benchmarks` benchmarks the fungible instructions against a single `fungible::Mutate` implementation,
+//! which for Astar networks is the native currency. Cross-chain assets are however transacted via `pallet-assets`,
+//! and are only accepted from their reserve locations registered in `XcAssetConfig`.
+//!
+//! ## Benchmarks
+//!
+//! - `fungible` - fungible instructions, benchmarked against a `pallet-assets` asset which is created & registered
+//!   in `XcAssetConfig` as part of the benchmark setup.
+//!
+//! Generic instructions don't depend on the asset transactor, so they are benchmarked by `pallet-xcm-benchmarks`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod fungible;
@@ -28,10 +28,12 @@ use frame_support::{
     assert_ok,
     codec::Decode,
     traits::{Currency, EnsureOrigin, Get},
+    BoundedVec,
 };
 use frame_system::{EventRecord, RawOrigin};
 use pallet_authorship::EventHandler;
 use pallet_session::{self as session, SessionManager};
+use sp_runtime::Perbill;
 use sp_std::prelude::*;
 
 pub type BalanceOf<T> =
@@ -113,6 +115,31 @@ fn register_candidates<T: Config>(count: u32) {
     }
 }
 
+fn delegation_amount<T: Config>() -> BalanceOf<T> {
+    T::MinDelegation::get().max(T::Currency::minimum_balance())
+}
+
+fn register_delegators<T: Config>(candidate: &T::AccountId, count: u32) {
+    let amount = delegation_amount::<T>();
+
+    for d in 0..count {
+        let who = account("delegator", d, SEED);
+        T::Currency::make_free_balance_be(&who, amount * 2u32.into());
+        <CollatorSelection<T>>::delegate(RawOrigin::Signed(who).into(), candidate.clone(), amount)
+            .unwrap();
+    }
+}
+
+fn setup_candidate<T: Config + session::Config>() -> T::AccountId {
+    <CandidacyBond<T>>::put(T::Currency::minimum_balance());
+    <DesiredCandidates<T>>::put(1);
+
+    register_validators::<T>(1);
+    register_candidates::<T>(1);
+
+    <Candidates<T>>::get()[0].who.clone()
+}
+
 benchmarks! {
     where_clause { where T: pallet_authorship::Config + session::Config }
 
@@ -156,7 +183,7 @@ benchmarks! {
     // worse case is when we have all the max-candidate slots filled except one, and we fill that
     // one.
     register_as_candidate {
-        let c in 1 .. T::MaxCandidates::get();
+        let c in 1 .. T::MaxCandidates::get() - 1;
 
         <CandidacyBond<T>>::put(T::Currency::minimum_balance());
         <DesiredCandidates<T>>::put(c + 1);
@@ -195,22 +222,26 @@ benchmarks! {
         assert_last_event::<T>(Event::CandidateRemoved(leaving).into());
     }
 
-    // worse case is paying a non-existing candidate account.
+    // worse case is paying the author & all of its delegators.
     note_author {
-        <CandidacyBond<T>>::put(T::Currency::minimum_balance());
+        let d in 0 .. T::MaxDelegatorsPerCandidate::get();
+
+        let author = setup_candidate::<T>();
+        register_delegators::<T>(&author, d);
+        // delegations count from the next session on
+        <CurrentSession<T>>::put(1);
         T::Currency::make_free_balance_be(
             &<CollatorSelection<T>>::account_id(),
-            T::Currency::minimum_balance() * 4u32.into(),
+            T::Currency::minimum_balance() * 1_000_000u32.into(),
         );
-        let author = account("author", 0, SEED);
         let new_block: T::BlockNumber = 10u32.into();
 
         frame_system::Pallet::<T>::set_block_number(new_block);
-        assert!(T::Currency::free_balance(&author) == 0u32.into());
+        let author_balance = T::Currency::free_balance(&author);
     }: {
         <CollatorSelection<T> as EventHandler<_, _>>::note_author(author.clone())
     } verify {
-        assert!(T::Currency::free_balance(&author) > 0u32.into());
+        assert!(T::Currency::free_balance(&author) > author_balance);
         assert_eq!(frame_system::Pallet::<T>::block_number(), new_block);
     }
 
@@ -262,6 +293,145 @@ benchmarks! {
             assert!(<Candidates<T>>::get().len() == pre_length);
         }
     }
+
+    // worse case is a new delegator taking the last free slot.
+    delegate {
+        let candidate = setup_candidate::<T>();
+        register_delegators::<T>(&candidate, T::MaxDelegatorsPerCandidate::get() - 1);
+
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = delegation_amount::<T>();
+        T::Currency::make_free_balance_be(&caller, amount * 2u32.into());
+    }: _(RawOrigin::Signed(caller.clone()), candidate.clone(), amount)
+    verify {
+        assert_last_event::<T>(Event::Delegated(caller, candidate, amount).into());
+    }
+
+    // worse case is removing the whole delegation.
+    undelegate {
+        let candidate = setup_candidate::<T>();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = delegation_amount::<T>();
+        T::Currency::make_free_balance_be(&caller, amount * 2u32.into());
+        assert_ok!(<CollatorSelection<T>>::delegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            candidate.clone(),
+            amount,
+        ));
+    }: _(RawOrigin::Signed(caller.clone()), candidate.clone(), amount)
+    verify {
+        assert_last_event::<T>(Event::Undelegated(caller, candidate, amount).into());
+    }
+
+    // worse case is withdrawing the last funds of the delegator, freeing its slot.
+    withdraw_unbonded {
+        let candidate = setup_candidate::<T>();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = delegation_amount::<T>();
+        T::Currency::make_free_balance_be(&caller, amount * 2u32.into());
+        assert_ok!(<CollatorSelection<T>>::delegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            candidate.clone(),
+            amount,
+        ));
+        assert_ok!(<CollatorSelection<T>>::undelegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            candidate.clone(),
+            amount,
+        ));
+        let unlock_at = frame_system::Pallet::<T>::block_number() + T::UnbondingPeriod::get();
+        frame_system::Pallet::<T>::set_block_number(unlock_at);
+    }: _(RawOrigin::Signed(caller.clone()), candidate.clone())
+    verify {
+        assert_last_event::<T>(Event::UnbondedWithdrawn(caller, candidate, amount).into());
+    }
+
+    set_collator_commission {
+        let commission = Perbill::from_percent(10);
+        let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    }: {
+        assert_ok!(
+            <CollatorSelection<T>>::set_collator_commission(origin, commission)
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::NewCollatorCommission(commission).into());
+    }
+
+    // `d` counts both the delegations & the unbonding entries.
+    slash_delegations {
+        let d in 1 .. T::MaxDelegatorsPerCandidate::get() * 2;
+
+        let candidate = setup_candidate::<T>();
+        let delegators = (d + 1) / 2;
+        register_delegators::<T>(&candidate, delegators);
+
+        // the rest of the entries are unbonding funds of the same delegators
+        let amount = delegation_amount::<T>();
+        for i in 0..d / 2 {
+            let who: T::AccountId = account("delegator", i, SEED);
+            T::Currency::make_free_balance_be(&who, amount * 2u32.into());
+            assert_ok!(<CollatorSelection<T>>::delegate(
+                RawOrigin::Signed(who.clone()).into(),
+                candidate.clone(),
+                amount,
+            ));
+            assert_ok!(<CollatorSelection<T>>::undelegate(
+                RawOrigin::Signed(who).into(),
+                candidate.clone(),
+                amount,
+            ));
+        }
+        <SlashDestination<T>>::put(account::<T::AccountId>("destination", 0, SEED));
+    }: {
+        <CollatorSelection<T>>::slash_delegations(&candidate, Perbill::from_percent(10))
+    } verify {
+        let delegated = amount * delegators.into();
+        assert!(<CandidateDelegations<T>>::get(&candidate).delegated.total() < delegated);
+    }
+
+    unjail {
+        let caller: T::AccountId = whitelisted_caller();
+        <Jailed<T>>::insert(&caller, T::BlockNumber::from(10u32));
+        frame_system::Pallet::<T>::set_block_number(10u32.into());
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert_last_event::<T>(Event::CandidateUnjailed(caller).into());
+    }
+
+    // worst case is when all collators have full performance history & stay collators.
+    rotate_performance_stats {
+        let c in 1 .. T::MaxCandidates::get() + T::MaxInvulnerables::get();
+
+        let collators = (0..c).map(|i| account("collator", i, SEED)).collect::<Vec<T::AccountId>>();
+        let entry = SessionPerformance {
+            session: 0,
+            blocks_authored: 1,
+            missed_slots: 1,
+            rewards: T::Currency::minimum_balance(),
+        };
+        for collator in collators.iter() {
+            <CurrentStats<T>>::insert(collator, CollatorStats {
+                blocks_authored: 1,
+                rewards: T::Currency::minimum_balance(),
+            });
+            let history = (0..T::MaxPerformanceHistory::get())
+                .map(|_| entry.clone())
+                .collect::<Vec<_>>();
+            <PerformanceHistory<T>>::insert(collator, BoundedVec::truncate_from(history));
+        }
+        <SessionCollators<T>>::insert(1, collators.clone());
+        <SessionCollators<T>>::insert(2, collators.clone());
+    }: {
+        <CollatorSelection<T>>::rotate_performance_stats(3, &collators)
+    } verify {
+        assert_eq!(
+            <PerformanceHistory<T>>::get(&collators[0]).last().map(|p| p.session),
+            Some(1)
+        );
+    }
 }
 
 impl_benchmark_test_suite!(
@@ -30,6 +30,8 @@
 //! - Collator: A parachain block producer.
 //! - Bond: An amount of `Balance` _reserved_ for candidate registration.
 //! - Invulnerable: An account guaranteed to be in the collator set.
+//! - Delegation: An amount of `Balance` _reserved_ by a token holder to back a candidate.
+//! - Backing: Sum of the candidate's bond and all of its active delegations.
 //!
 //! ## Implementation
 //!
@@ -40,8 +42,9 @@
 //! 2. [`Candidates`]: these are *candidates to the collation task* and may or may not be elected as
 //!    a final collator.
 //!
-//! The current implementation resolves congestion of [`Candidates`] in a first-come-first-serve
-//! manner.
+//! Up to [`Config::MaxCandidates`] accounts can register as candidates. If there are more
+//! candidates than [`DesiredCandidates`], the ones with the highest backing are selected for the
+//! next session.
 //!
 //! Candidates will not be allowed to get kicked or leave_intent if the total number of candidates
 //! fall below MinCandidates. This is for potential disaster recovery scenarios.
@@ -57,6 +60,38 @@
 //!
 //! To initiate rewards an ED needs to be transferred to the pot address.
 //!
+//! If the author has active delegations, it first takes the [`CollatorCommission`] from its reward.
+//! The rest is split pro rata between the author (according to its bond) and its delegators.
+//!
+//! ### Delegation
+//!
+//! Token holders can back candidates by delegating funds to them, which are reserved. New
+//! delegations only count towards the backing & rewards from the next session on.
+//!
+//! Delegations can be undelegated at any time, even after the candidate has left. Undelegated
+//! funds stop counting immediately, but stay reserved for [`Config::UnbondingPeriod`] blocks
+//! before they can be withdrawn.
+//!
+//! When a candidate is slashed, its delegations, including the unbonding ones, are slashed by the
+//! same ratio.
+//!
+//! ### Jailing
+//!
+//! Candidates which didn't produce a block within the kick threshold are kicked, their bond is
+//! slashed and they're jailed for [`Config::JailPeriod`] blocks. A jailed account cannot register
+//! as a candidate again until it has been unjailed, which it can do itself once the jail period is
+//! over. Candidates kicked again before producing any block are slashed by a higher ratio.
+//!
+//! ### Performance
+//!
+//! Blocks authored & rewards earned by each collator are tracked during the session. When the
+//! session ends, they're moved into the collator's [`PerformanceHistory`], which keeps the last
+//! [`Config::MaxPerformanceHistory`] sessions. Missed slots are the Aura slots of the session owned
+//! by the collator, in which it didn't author a block. Aura assigns the slots to the session
+//! collators in turns, so the owner of a slot is the collator at `slot % collators` in the session
+//! collator set. History of collators which are neither in the ongoing nor in the next session is
+//! removed.
+//!
 //! Note: Eventually the Pot distribution may be modified as discussed in
 //! [this issue](https://github.com/paritytech/statemint/issues/21#issuecomment-810481073).
 
@@ -83,8 +118,8 @@ pub mod pallet {
         inherent::Vec,
         pallet_prelude::*,
         sp_runtime::{
-            traits::{AccountIdConversion, CheckedSub, Saturating, Zero},
-            RuntimeDebug,
+            traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedSub, Saturating, Zero},
+            RuntimeDebug, SaturatedConversion,
         },
         traits::{
             Currency, EnsureOrigin, ExistenceRequirement::KeepAlive, ReservableCurrency,
@@ -124,8 +159,8 @@ pub mod pallet {
         /// Account Identifier from which the internal Pot is generated.
         type PotId: Get<PalletId>;
 
-        /// Maximum number of candidates that we should have. This is used for benchmarking and is not
-        /// enforced.
+        /// Maximum number of candidates that we should have. Registration is refused once reached,
+        /// and at most [`DesiredCandidates`] of them are selected.
         ///
         /// This does not take into account the invulnerables.
         type MaxCandidates: Get<u32>;
@@ -157,6 +192,33 @@ pub mod pallet {
         /// How many in perc kicked collators should be slashed (set 0 to disable)
         type SlashRatio: Get<Perbill>;
 
+        /// How many in perc collators kicked again before producing a block should be slashed
+        #[pallet::constant]
+        type RepeatedOffenceSlashRatio: Get<Perbill>;
+
+        /// Number of blocks a kicked candidate is jailed for before it can be unjailed.
+        #[pallet::constant]
+        type JailPeriod: Get<Self::BlockNumber>;
+
+        /// Maximum number of delegators a single candidate can have.
+        #[pallet::constant]
+        type MaxDelegatorsPerCandidate: Get<u32>;
+
+        /// Minimum amount which can be delegated to a candidate.
+        #[pallet::constant]
+        type MinDelegation: Get<BalanceOf<Self>>;
+
+        /// Number of blocks undelegated funds stay reserved before they can be withdrawn.
+        #[pallet::constant]
+        type UnbondingPeriod: Get<Self::BlockNumber>;
+
+        /// Number of past sessions for which the collator performance is kept.
+        #[pallet::constant]
+        type MaxPerformanceHistory: Get<u32>;
+
+        /// Aura slot of the block being executed.
+        type CurrentSlot: Get<u64>;
+
         /// The weight information of this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -170,6 +232,118 @@ pub mod pallet {
         pub deposit: Balance,
     }
 
+    /// Reason for slashing a candidate.
+    #[derive(
+        PartialEq,
+        Eq,
+        Clone,
+        Copy,
+        Encode,
+        Decode,
+        MaxEncodedLen,
+        RuntimeDebug,
+        scale_info::TypeInfo,
+    )]
+    pub enum SlashReason {
+        /// Candidate didn't produce a block within the kick threshold.
+        NotProducingBlocks,
+        /// Candidate didn't produce a block within the kick threshold again, without producing
+        /// any block since it was last slashed.
+        RepeatedlyNotProducingBlocks,
+    }
+
+    /// Amount delegated to a candidate.
+    #[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct Delegation<Balance> {
+        /// Amount counted in the candidate's backing & rewards.
+        pub active: Balance,
+        /// Amount delegated during the `session`, counted only from the next session on.
+        pub pending: Balance,
+        /// Session in which the pending amount was delegated.
+        pub session: SessionIndex,
+    }
+
+    impl<Balance: AtLeast32BitUnsigned + Copy> Delegation<Balance> {
+        /// Total delegated amount, including the pending one.
+        pub fn total(&self) -> Balance {
+            self.active.saturating_add(self.pending)
+        }
+
+        /// Delegation as of the `current_session`. Pending amount is activated once its session is over.
+        pub fn refreshed(mut self, current_session: SessionIndex) -> Self {
+            if current_session > self.session {
+                self.active = self.total();
+                self.pending = Zero::zero();
+                self.session = current_session;
+            }
+            self
+        }
+
+        /// Reduce the delegation by `amount`, taking it from the pending amount first.
+        ///
+        /// Returns the amounts taken from the pending & the active amount.
+        pub fn reduce(&mut self, amount: Balance) -> (Balance, Balance) {
+            let from_pending = amount.min(self.pending);
+            let from_active = amount.saturating_sub(from_pending).min(self.active);
+            self.subtract(from_pending, from_active);
+            (from_pending, from_active)
+        }
+
+        /// Subtract the given pending & active amounts.
+        pub fn subtract(&mut self, pending: Balance, active: Balance) {
+            self.pending = self.pending.saturating_sub(pending);
+            self.active = self.active.saturating_sub(active);
+        }
+    }
+
+    /// Aggregated delegations of a candidate.
+    #[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct DelegationSummary<Balance> {
+        /// Sum of all the delegations to the candidate.
+        pub delegated: Delegation<Balance>,
+        /// Number of delegators, including the ones which only have unbonding funds left.
+        pub delegators: u32,
+    }
+
+    impl<Balance: AtLeast32BitUnsigned + Copy> DelegationSummary<Balance> {
+        /// Summary as of the `current_session`, see [`Delegation::refreshed`].
+        pub fn refreshed(mut self, current_session: SessionIndex) -> Self {
+            self.delegated = self.delegated.refreshed(current_session);
+            self
+        }
+    }
+
+    /// Undelegated funds which are still reserved.
+    #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct UnbondingDelegation<Balance, BlockNumber> {
+        /// Undelegated amount.
+        pub amount: Balance,
+        /// Block from which the amount can be withdrawn.
+        pub unlock_at: BlockNumber,
+    }
+
+    /// Collator statistics for the ongoing session.
+    #[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct CollatorStats<Balance> {
+        /// Number of blocks authored.
+        pub blocks_authored: u32,
+        /// Rewards earned for authoring blocks.
+        pub rewards: Balance,
+    }
+
+    /// Collator performance in a single finished session.
+    #[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct SessionPerformance<Balance> {
+        /// Session index.
+        pub session: SessionIndex,
+        /// Number of blocks authored.
+        pub blocks_authored: u32,
+        /// Number of collator's Aura turns in which it didn't author a block.
+        pub missed_slots: u32,
+        /// Rewards earned for authoring blocks.
+        pub rewards: Balance,
+    }
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
@@ -210,6 +384,84 @@ pub mod pallet {
     #[pallet::getter(fn slash_destination)]
     pub type SlashDestination<T> = StorageValue<_, <T as frame_system::Config>::AccountId>;
 
+    /// Amounts delegated to candidates, keyed by candidate & delegator.
+    #[pallet::storage]
+    #[pallet::getter(fn delegations)]
+    pub type Delegations<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        Delegation<BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Undelegated funds waiting for the unbonding period to pass, keyed by candidate & delegator.
+    #[pallet::storage]
+    #[pallet::getter(fn unbonding)]
+    pub type Unbonding<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        UnbondingDelegation<BalanceOf<T>, T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Aggregated delegations per candidate.
+    #[pallet::storage]
+    #[pallet::getter(fn candidate_delegations)]
+    pub type CandidateDelegations<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, DelegationSummary<BalanceOf<T>>, ValueQuery>;
+
+    /// Part of the block reward the author takes before the rest is shared with its delegators.
+    #[pallet::storage]
+    #[pallet::getter(fn collator_commission)]
+    pub type CollatorCommission<T> = StorageValue<_, Perbill, ValueQuery>;
+
+    /// Jailed accounts and the block number until which they're jailed.
+    #[pallet::storage]
+    #[pallet::getter(fn jailed)]
+    pub type Jailed<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, T::BlockNumber>;
+
+    /// Number of times the account has been slashed since it last produced a block.
+    #[pallet::storage]
+    #[pallet::getter(fn offences)]
+    pub type Offences<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Statistics of collators for the ongoing session.
+    #[pallet::storage]
+    #[pallet::getter(fn current_stats)]
+    pub type CurrentStats<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, CollatorStats<BalanceOf<T>>, ValueQuery>;
+
+    /// Performance of collators in the most recent sessions, oldest first.
+    #[pallet::storage]
+    #[pallet::getter(fn performance_history)]
+    pub type PerformanceHistory<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        BoundedVec<SessionPerformance<BalanceOf<T>>, T::MaxPerformanceHistory>,
+        ValueQuery,
+    >;
+
+    /// Collators assembled for the session, kept until the session ends.
+    #[pallet::storage]
+    pub type SessionCollators<T: Config> =
+        StorageMap<_, Twox64Concat, SessionIndex, Vec<T::AccountId>, OptionQuery>;
+
+    /// Aura slot at which the last session rotation happened.
+    #[pallet::storage]
+    pub type SessionStartSlot<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Index of the ongoing session.
+    #[pallet::storage]
+    #[pallet::getter(fn current_session)]
+    pub type CurrentSession<T> = StorageValue<_, SessionIndex, ValueQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub invulnerables: Vec<T::AccountId>,
@@ -264,6 +516,22 @@ pub mod pallet {
         CandidateAdded(T::AccountId, BalanceOf<T>),
         CandidateRemoved(T::AccountId),
         CandidateSlashed(T::AccountId),
+        /// Delegator has delegated funds to the candidate.
+        Delegated(T::AccountId, T::AccountId, BalanceOf<T>),
+        /// Delegator has undelegated funds from the candidate, which are now unbonding.
+        Undelegated(T::AccountId, T::AccountId, BalanceOf<T>),
+        /// Delegator has withdrawn the unbonded funds undelegated from the candidate.
+        UnbondedWithdrawn(T::AccountId, T::AccountId, BalanceOf<T>),
+        /// Delegation to the candidate has been slashed.
+        DelegationSlashed(T::AccountId, T::AccountId, BalanceOf<T>),
+        /// Collator commission has been changed.
+        NewCollatorCommission(Perbill),
+        /// Candidate has been jailed until the given block.
+        CandidateJailed(T::AccountId, T::BlockNumber),
+        /// Account has been unjailed and can register as a candidate again.
+        CandidateUnjailed(T::AccountId),
+        /// Candidate has been slashed by the given amount, for the given reason.
+        CandidateSlashedFor(T::AccountId, BalanceOf<T>, SlashReason),
     }
 
     // Errors inform users that something went wrong.
@@ -287,6 +555,24 @@ pub mod pallet {
         NoAssociatedValidatorId,
         /// Validator ID is not yet registered
         ValidatorNotRegistered,
+        /// Delegation would be below the minimum delegation amount
+        DelegationBelowMinimum,
+        /// Candidate already has the max number of delegators
+        TooManyDelegators,
+        /// There is no delegation to the candidate
+        NoDelegation,
+        /// Cannot undelegate more than was delegated
+        InsufficientDelegation,
+        /// There are no unbonding funds undelegated from the candidate
+        NoUnbonding,
+        /// Unbonding period is not over yet
+        UnbondingPeriodNotOver,
+        /// Account is jailed
+        Jailed,
+        /// Account is not jailed
+        NotJailed,
+        /// Jail period is not over yet
+        JailPeriodNotOver,
     }
 
     #[pallet::hooks]
@@ -365,16 +651,17 @@ pub mod pallet {
         pub fn register_as_candidate(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
-            // ensure we are below limit.
+            // ensure we are below limit. Candidates with the highest backing are selected later on.
             let length = <Candidates<T>>::decode_len().unwrap_or_default();
             ensure!(
-                (length as u32) < Self::desired_candidates(),
+                (length as u32) < T::MaxCandidates::get(),
                 Error::<T>::TooManyCandidates
             );
             ensure!(
                 !Self::invulnerables().contains(&who),
                 Error::<T>::AlreadyInvulnerable
             );
+            ensure!(!<Jailed<T>>::contains_key(&who), Error::<T>::Jailed);
 
             let validator_key = T::ValidatorIdOf::convert(who.clone())
                 .ok_or(Error::<T>::NoAssociatedValidatorId)?;
@@ -423,10 +710,170 @@ pub mod pallet {
                 Self::candidates().len() as u32 > T::MinCandidates::get(),
                 Error::<T>::TooFewCandidates
             );
-            let current_count = Self::try_remove_candidate(&who, false)?;
+            let current_count = Self::try_remove_candidate(&who, None)?;
 
             Ok(Some(T::WeightInfo::leave_intent(current_count as u32)).into())
         }
+
+        /// Delegate `amount` to the `candidate`, increasing its backing from the next session on.
+        /// The amount is reserved until it's undelegated & unbonded.
+        ///
+        /// Existing delegation to the same candidate is increased.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::delegate())]
+        pub fn delegate(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Self::candidates().iter().any(|c| c.who == candidate),
+                Error::<T>::NotCandidate
+            );
+
+            let current_session = Self::current_session();
+            let current = Self::delegations(&candidate, &who);
+            let is_new_delegator =
+                current.is_none() && !<Unbonding<T>>::contains_key(&candidate, &who);
+            let mut delegation = current.unwrap_or_default().refreshed(current_session);
+            delegation.pending = delegation.pending.saturating_add(amount);
+            ensure!(
+                delegation.total() >= T::MinDelegation::get(),
+                Error::<T>::DelegationBelowMinimum
+            );
+
+            let mut summary = Self::candidate_delegations(&candidate).refreshed(current_session);
+            if is_new_delegator {
+                ensure!(
+                    summary.delegators < T::MaxDelegatorsPerCandidate::get(),
+                    Error::<T>::TooManyDelegators
+                );
+                summary.delegators.saturating_inc();
+            }
+            summary.delegated.pending = summary.delegated.pending.saturating_add(amount);
+
+            T::Currency::reserve(&who, amount)?;
+            <Delegations<T>>::insert(&candidate, &who, delegation);
+            <CandidateDelegations<T>>::insert(&candidate, summary);
+
+            Self::deposit_event(Event::Delegated(who, candidate, amount));
+            Ok(().into())
+        }
+
+        /// Undelegate `amount` from the `candidate`. The amount stops counting towards the backing
+        /// immediately, but stays reserved until the unbonding period is over.
+        ///
+        /// Undelegating again before withdrawing adds to the unbonding amount, and restarts the
+        /// unbonding period.
+        ///
+        /// The remaining delegation must either be zero or at least the minimum delegation amount.
+        /// This call is available even if the account is no longer a candidate.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::undelegate())]
+        pub fn undelegate(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let current_session = Self::current_session();
+            let mut delegation = Self::delegations(&candidate, &who)
+                .ok_or(Error::<T>::NoDelegation)?
+                .refreshed(current_session);
+            let remaining = delegation
+                .total()
+                .checked_sub(&amount)
+                .ok_or(Error::<T>::InsufficientDelegation)?;
+            ensure!(
+                remaining.is_zero() || remaining >= T::MinDelegation::get(),
+                Error::<T>::DelegationBelowMinimum
+            );
+
+            let (from_pending, from_active) = delegation.reduce(amount);
+            let mut summary = Self::candidate_delegations(&candidate).refreshed(current_session);
+            summary.delegated.subtract(from_pending, from_active);
+            if remaining.is_zero() {
+                <Delegations<T>>::remove(&candidate, &who);
+            } else {
+                <Delegations<T>>::insert(&candidate, &who, delegation);
+            }
+            // Delegator keeps its slot until the unbonding funds are withdrawn.
+            Self::update_candidate_delegations(&candidate, summary);
+
+            let unlock_at = frame_system::Pallet::<T>::block_number() + T::UnbondingPeriod::get();
+            <Unbonding<T>>::mutate(&candidate, &who, |unbonding| {
+                let amount = unbonding
+                    .as_ref()
+                    .map_or_else(Zero::zero, |u| u.amount)
+                    .saturating_add(amount);
+                *unbonding = Some(UnbondingDelegation { amount, unlock_at });
+            });
+
+            Self::deposit_event(Event::Undelegated(who, candidate, amount));
+            Ok(().into())
+        }
+
+        /// Set the part of the block reward which the author takes before sharing the rest with
+        /// its delegators.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_collator_commission())]
+        pub fn set_collator_commission(
+            origin: OriginFor<T>,
+            commission: Perbill,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            <CollatorCommission<T>>::put(commission);
+            Self::deposit_event(Event::NewCollatorCommission(commission));
+            Ok(().into())
+        }
+
+        /// Unjail this account, allowing it to register as a candidate again.
+        ///
+        /// The jail period must be over.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::unjail())]
+        pub fn unjail(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let jailed_until = Self::jailed(&who).ok_or(Error::<T>::NotJailed)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= jailed_until,
+                Error::<T>::JailPeriodNotOver
+            );
+
+            <Jailed<T>>::remove(&who);
+            Self::deposit_event(Event::CandidateUnjailed(who));
+            Ok(().into())
+        }
+
+        /// Withdraw the funds undelegated from the `candidate`, once the unbonding period is over.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::withdraw_unbonded())]
+        pub fn withdraw_unbonded(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let unbonding = Self::unbonding(&candidate, &who).ok_or(Error::<T>::NoUnbonding)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= unbonding.unlock_at,
+                Error::<T>::UnbondingPeriodNotOver
+            );
+
+            <Unbonding<T>>::remove(&candidate, &who);
+            if !<Delegations<T>>::contains_key(&candidate, &who) {
+                let mut summary = Self::candidate_delegations(&candidate);
+                summary.delegators.saturating_dec();
+                Self::update_candidate_delegations(&candidate, summary);
+            }
+            T::Currency::unreserve(&who, unbonding.amount);
+
+            Self::deposit_event(Event::UnbondedWithdrawn(who, candidate, unbonding.amount));
+            Ok(().into())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -435,8 +882,11 @@ pub mod pallet {
             T::PotId::get().into_account_truncating()
         }
         /// Removes a candidate if they exist and sends them back their deposit
-        /// If second argument is `true` then a candidate will be slashed
-        fn try_remove_candidate(who: &T::AccountId, slash: bool) -> Result<usize, DispatchError> {
+        /// If second argument is `Some` then a candidate will be slashed & jailed
+        fn try_remove_candidate(
+            who: &T::AccountId,
+            slash_reason: Option<SlashReason>,
+        ) -> Result<usize, DispatchError> {
             let current_count =
                 <Candidates<T>>::try_mutate(|candidates| -> Result<usize, DispatchError> {
                     let index = candidates
@@ -445,8 +895,14 @@ pub mod pallet {
                         .ok_or(Error::<T>::NotCandidate)?;
                     let deposit = candidates[index].deposit;
 
-                    if slash {
-                        let slash = T::SlashRatio::get() * deposit;
+                    if let Some(reason) = slash_reason {
+                        let ratio = match reason {
+                            SlashReason::NotProducingBlocks => T::SlashRatio::get(),
+                            SlashReason::RepeatedlyNotProducingBlocks => {
+                                T::RepeatedOffenceSlashRatio::get()
+                            }
+                        };
+                        let slash = ratio * deposit;
                         let remain = deposit - slash;
 
                         let (imbalance, _) = T::Currency::slash_reserved(who, slash);
@@ -456,7 +912,20 @@ pub mod pallet {
                             T::Currency::resolve_creating(&dest, imbalance);
                         }
 
+                        let slashed_delegators = Self::slash_delegations(who, ratio);
+                        frame_system::Pallet::<T>::register_extra_weight_unchecked(
+                            T::WeightInfo::slash_delegations(slashed_delegators),
+                            DispatchClass::Mandatory,
+                        );
+
+                        let jailed_until =
+                            frame_system::Pallet::<T>::block_number() + T::JailPeriod::get();
+                        <Jailed<T>>::insert(who, jailed_until);
+                        <Offences<T>>::mutate(who, |offences| offences.saturating_inc());
+
                         Self::deposit_event(Event::CandidateSlashed(who.clone()));
+                        Self::deposit_event(Event::CandidateSlashedFor(who.clone(), slash, reason));
+                        Self::deposit_event(Event::CandidateJailed(who.clone(), jailed_until));
                     } else {
                         T::Currency::unreserve(who, deposit);
                     }
@@ -468,6 +937,162 @@ pub mod pallet {
             Ok(current_count)
         }
 
+        /// Slashes all delegations to the `candidate` by `ratio`, including the unbonding ones.
+        /// Slashed amount is sent to the slash destination, if there is one.
+        ///
+        /// Other slashing mechanisms should use this to propagate candidate slashing to its
+        /// delegators. Returns the number of slashed delegations & unbonding entries.
+        pub fn slash_delegations(candidate: &T::AccountId, ratio: Perbill) -> u32 {
+            if ratio.is_zero() {
+                return 0;
+            }
+
+            let current_session = Self::current_session();
+            let delegations = <Delegations<T>>::iter_prefix(candidate).collect::<Vec<_>>();
+            let unbonding = <Unbonding<T>>::iter_prefix(candidate).collect::<Vec<_>>();
+            let mut summary = Self::candidate_delegations(candidate).refreshed(current_session);
+
+            for (delegator, delegation) in delegations.iter() {
+                let mut delegation = delegation.clone().refreshed(current_session);
+                let slashed = Self::slash_delegator(delegator, ratio * delegation.total());
+                let (from_pending, from_active) = delegation.reduce(slashed);
+                summary.delegated.subtract(from_pending, from_active);
+
+                if delegation.total().is_zero() {
+                    <Delegations<T>>::remove(candidate, delegator);
+                    if !<Unbonding<T>>::contains_key(candidate, delegator) {
+                        summary.delegators.saturating_dec();
+                    }
+                } else {
+                    <Delegations<T>>::insert(candidate, delegator, delegation);
+                }
+
+                Self::deposit_event(Event::DelegationSlashed(
+                    delegator.clone(),
+                    candidate.clone(),
+                    slashed,
+                ));
+            }
+
+            for (delegator, mut entry) in unbonding.clone() {
+                let slashed = Self::slash_delegator(&delegator, ratio * entry.amount);
+                entry.amount = entry.amount.saturating_sub(slashed);
+
+                if entry.amount.is_zero() {
+                    <Unbonding<T>>::remove(candidate, &delegator);
+                    if !<Delegations<T>>::contains_key(candidate, &delegator) {
+                        summary.delegators.saturating_dec();
+                    }
+                } else {
+                    <Unbonding<T>>::insert(candidate, &delegator, entry);
+                }
+
+                Self::deposit_event(Event::DelegationSlashed(
+                    delegator,
+                    candidate.clone(),
+                    slashed,
+                ));
+            }
+            Self::update_candidate_delegations(candidate, summary);
+
+            delegations.len().saturating_add(unbonding.len()) as u32
+        }
+
+        /// Slashes up to `amount` of the delegator's reserved funds, sending it to the slash
+        /// destination if there is one. Returns the slashed amount.
+        fn slash_delegator(delegator: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+            let (imbalance, missing) = T::Currency::slash_reserved(delegator, amount);
+            if let Some(dest) = Self::slash_destination() {
+                T::Currency::resolve_creating(&dest, imbalance);
+            }
+            amount.saturating_sub(missing)
+        }
+
+        /// Stores the aggregated delegations of the candidate, cleaning up if there are no delegators.
+        fn update_candidate_delegations(
+            candidate: &T::AccountId,
+            summary: DelegationSummary<BalanceOf<T>>,
+        ) {
+            if summary.delegators == 0 {
+                <CandidateDelegations<T>>::remove(candidate);
+            } else {
+                <CandidateDelegations<T>>::insert(candidate, summary);
+            }
+        }
+
+        /// Total backing of the candidate - its bond & all the active delegations.
+        pub fn backing(candidate: &CandidateInfo<T::AccountId, BalanceOf<T>>) -> BalanceOf<T> {
+            let delegated = Self::candidate_delegations(&candidate.who)
+                .refreshed(Self::current_session())
+                .delegated;
+            candidate.deposit.saturating_add(delegated.active)
+        }
+
+        /// Selects at most [`DesiredCandidates`] candidates with the highest backing.
+        /// Candidates with the same backing keep their order.
+        ///
+        /// Candidates which aren't selected have their last authored block bumped, so they aren't
+        /// kicked for not producing blocks while waiting for selection.
+        pub fn select_candidates(
+            candidates: Vec<CandidateInfo<T::AccountId, BalanceOf<T>>>,
+        ) -> Vec<T::AccountId> {
+            let desired_candidates = Self::desired_candidates() as usize;
+            if candidates.len() <= desired_candidates {
+                return candidates.into_iter().map(|c| c.who).collect();
+            }
+
+            let mut ranked = candidates
+                .into_iter()
+                .map(|c| (Self::backing(&c), c.who))
+                .collect::<Vec<_>>();
+            ranked.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+            let next_block = frame_system::Pallet::<T>::block_number() + T::KickThreshold::get();
+            for (_, who) in ranked.iter().skip(desired_candidates) {
+                <LastAuthoredBlock<T>>::insert(who.clone(), next_block);
+            }
+
+            ranked
+                .into_iter()
+                .take(desired_candidates)
+                .map(|(_, who)| who)
+                .collect()
+        }
+
+        /// Pays out the delegators' part of the `reward` from the pot, pro rata to their active
+        /// delegations.
+        ///
+        /// The author first takes the commission, and then shares the rest with its delegators
+        /// according to its bond. Returns the author's part of the reward, including anything that
+        /// couldn't be paid out to the delegators.
+        ///
+        /// `summary` is expected to be refreshed for the current session.
+        fn reward_delegators(
+            author: &CandidateInfo<T::AccountId, BalanceOf<T>>,
+            summary: &DelegationSummary<BalanceOf<T>>,
+            reward: BalanceOf<T>,
+        ) -> BalanceOf<T> {
+            let pot = Self::account_id();
+            let shared_reward = reward.saturating_sub(Self::collator_commission() * reward);
+            let total_backing = author.deposit.saturating_add(summary.delegated.active);
+
+            let mut paid_out: BalanceOf<T> = Zero::zero();
+            for (delegator, delegation) in <Delegations<T>>::iter_prefix(&author.who) {
+                let amount = delegation.refreshed(summary.delegated.session).active;
+                if amount.is_zero() {
+                    continue;
+                }
+                let delegator_reward =
+                    Perbill::from_rational(amount, total_backing) * shared_reward;
+                // Can fail if the reward is below ED, in which case it remains in the pot.
+                if T::Currency::transfer(&pot, &delegator, delegator_reward, KeepAlive).is_ok() {
+                    paid_out = paid_out.saturating_add(delegator_reward);
+                }
+            }
+
+            reward.saturating_sub(paid_out)
+        }
+
         /// Assemble the current set of candidates and invulnerables into the next collator set.
         ///
         /// This is done on the fly, as frequent as we are told to do so, as the session manager.
@@ -476,10 +1101,82 @@ pub mod pallet {
             collators.extend(candidates.into_iter().collect::<Vec<_>>());
             collators
         }
+        /// Moves the statistics of the finished session into the performance history of its
+        /// collators, and removes the history of collators which have departed.
+        /// Returns the consumed weight.
+        ///
+        /// `index` is the session being planned, which is two sessions after the finished one,
+        /// and `next_collators` are the collators planned for it.
+        pub(crate) fn rotate_performance_stats(
+            index: SessionIndex,
+            next_collators: &[T::AccountId],
+        ) -> Weight {
+            let end_slot = T::CurrentSlot::get();
+            let start_slot = <SessionStartSlot<T>>::get();
+            <SessionStartSlot<T>>::put(end_slot);
+
+            let finished = index.saturating_sub(2);
+            // Collators are unknown for the sessions planned before performance tracking started.
+            let (collators, ongoing_collators) = if index >= 2 {
+                (
+                    <SessionCollators<T>>::take(finished).unwrap_or_default(),
+                    <SessionCollators<T>>::get(finished.saturating_add(1)).unwrap_or_default(),
+                )
+            } else {
+                (Vec::new(), Vec::new())
+            };
+
+            let collators_len = collators.len() as u64;
+            for (position, collator) in collators.iter().enumerate() {
+                let stats = <CurrentStats<T>>::take(collator);
+                if !ongoing_collators.contains(collator) && !next_collators.contains(collator) {
+                    <PerformanceHistory<T>>::remove(collator);
+                    continue;
+                }
+
+                // Slots in the `(start_slot, end_slot]` range for which `slot % collators_len`
+                // is equal to the collator position.
+                let owned_slots = |slot: u64| {
+                    slot.checked_sub(position as u64)
+                        .and_then(|slot| slot.checked_div(collators_len))
+                        .map_or(0, |turns| turns.saturating_add(1))
+                };
+                let expected_blocks: u32 = owned_slots(end_slot)
+                    .saturating_sub(owned_slots(start_slot))
+                    .saturated_into();
+
+                <PerformanceHistory<T>>::mutate(collator, |history| {
+                    // Make room for the new entry by dropping the oldest one.
+                    if !history.is_empty()
+                        && history.len() as u32 >= T::MaxPerformanceHistory::get()
+                    {
+                        history.remove(0);
+                    }
+                    let _ = history.try_push(SessionPerformance {
+                        session: finished,
+                        blocks_authored: stats.blocks_authored,
+                        missed_slots: expected_blocks.saturating_sub(stats.blocks_authored),
+                        rewards: stats.rewards,
+                    });
+                });
+            }
+            // Blocks authored by accounts outside of the session collators aren't tracked.
+            // There can't be more authors than collators, which bounds the removal.
+            let cleared = <CurrentStats<T>>::clear(
+                T::MaxCandidates::get().saturating_add(T::MaxInvulnerables::get()),
+                None,
+            );
+
+            T::WeightInfo::rotate_performance_stats(collators.len() as u32).saturating_add(
+                T::DbWeight::get().reads_writes(cleared.loops.into(), cleared.unique.into()),
+            )
+        }
+
         /// Kicks out and candidates that did not produce a block in the kick threshold.
+        /// Kicked candidates are slashed & jailed.
         pub fn kick_stale_candidates(
             candidates: Vec<CandidateInfo<T::AccountId, BalanceOf<T>>>,
-        ) -> Vec<T::AccountId> {
+        ) -> Vec<CandidateInfo<T::AccountId, BalanceOf<T>>> {
             let now = frame_system::Pallet::<T>::block_number();
             let kick_threshold = T::KickThreshold::get();
             candidates
@@ -490,9 +1187,14 @@ pub mod pallet {
                     if since_last < kick_threshold
                         || Self::candidates().len() as u32 <= T::MinCandidates::get()
                     {
-                        Some(c.who)
+                        Some(c)
                     } else {
-                        let outcome = Self::try_remove_candidate(&c.who, true);
+                        let reason = if Self::offences(&c.who) > 0 {
+                            SlashReason::RepeatedlyNotProducingBlocks
+                        } else {
+                            SlashReason::NotProducingBlocks
+                        };
+                        let outcome = Self::try_remove_candidate(&c.who, Some(reason));
                         if let Err(why) = outcome {
                             log::warn!("Failed to remove candidate {:?}", why);
                             debug_assert!(false, "failed to remove candidate {:?}", why);
@@ -516,13 +1218,32 @@ pub mod pallet {
                 .checked_sub(&T::Currency::minimum_balance())
                 .unwrap_or_else(Zero::zero)
                 .div(2u32.into());
-            // `reward` is half of pot account minus ED, this should never fail.
-            let _success = T::Currency::transfer(&pot, &author, reward, KeepAlive);
-            debug_assert!(_success.is_ok());
+            let summary = Self::candidate_delegations(&author).refreshed(Self::current_session());
+            // Only candidates share the reward with their delegators.
+            let candidate = if summary.delegated.active.is_zero() {
+                None
+            } else {
+                Self::candidates().into_iter().find(|c| c.who == author)
+            };
+            let author_reward = match candidate {
+                Some(candidate) => Self::reward_delegators(&candidate, &summary, reward),
+                None => reward,
+            };
+
+            // `author_reward` is at most half of pot account minus ED, this should never fail.
+            let success = T::Currency::transfer(&pot, &author, author_reward, KeepAlive);
+            debug_assert!(success.is_ok());
+            <CurrentStats<T>>::mutate(&author, |stats| {
+                stats.blocks_authored.saturating_inc();
+                if success.is_ok() {
+                    stats.rewards = stats.rewards.saturating_add(author_reward);
+                }
+            });
+            <Offences<T>>::remove(&author);
             <LastAuthoredBlock<T>>::insert(author, frame_system::Pallet::<T>::block_number());
 
             frame_system::Pallet::<T>::register_extra_weight_unchecked(
-                T::WeightInfo::note_author(),
+                T::WeightInfo::note_author(summary.delegators),
                 DispatchClass::Mandatory,
             );
         }
@@ -541,17 +1262,26 @@ pub mod pallet {
             let candidates_len_before = candidates.len();
             let active_candidates = Self::kick_stale_candidates(candidates);
             let active_candidates_len = active_candidates.len();
-            let result = Self::assemble_collators(active_candidates);
+            let result = Self::assemble_collators(Self::select_candidates(active_candidates));
             let removed = candidates_len_before - active_candidates_len;
 
+            let rotation_weight = Self::rotate_performance_stats(index, &result);
+            <SessionCollators<T>>::insert(index, &result);
+
             frame_system::Pallet::<T>::register_extra_weight_unchecked(
-                T::WeightInfo::new_session(candidates_len_before as u32, removed as u32),
+                T::WeightInfo::new_session(candidates_len_before as u32, removed as u32)
+                    .saturating_add(rotation_weight),
                 DispatchClass::Mandatory,
             );
             Some(result)
         }
-        fn start_session(_: SessionIndex) {
-            // we don't care.
+        fn start_session(index: SessionIndex) {
+            // Delegations made in the previous sessions start counting.
+            <CurrentSession<T>>::put(index);
+            frame_system::Pallet::<T>::register_extra_weight_unchecked(
+                T::DbWeight::get().writes(1),
+                DispatchClass::Mandatory,
+            );
         }
         fn end_session(_: SessionIndex) {
             // we don't care.
@@ -19,7 +19,7 @@ use super::*;
 use crate as collator_selection;
 use frame_support::{
     ord_parameter_types, parameter_types,
-    traits::{ConstU32, FindAuthor, GenesisBuild, ValidatorRegistration},
+    traits::{ConstU32, FindAuthor, GenesisBuild, Get, ValidatorRegistration},
     PalletId,
 };
 use frame_system as system;
@@ -199,6 +199,12 @@ parameter_types! {
     pub const MinCandidates: u32 = 1;
     pub const MaxAuthorities: u32 = 100_000;
     pub const SlashRatio: Perbill = Perbill::from_percent(10);
+    pub const RepeatedOffenceSlashRatio: Perbill = Perbill::from_percent(50);
+    pub const JailPeriod: u64 = 20;
+    pub const MaxPerformanceHistory: u32 = 2;
+    pub const MaxDelegatorsPerCandidate: u32 = 2;
+    pub const MinDelegation: u64 = 10;
+    pub const UnbondingPeriod: u64 = 5;
 }
 
 pub struct IsRegistered;
@@ -212,6 +218,14 @@ impl ValidatorRegistration<u64> for IsRegistered {
     }
 }
 
+/// Each block is produced in its own slot, equal to the block number.
+pub struct BlockNumberAsSlot;
+impl Get<u64> for BlockNumberAsSlot {
+    fn get() -> u64 {
+        System::block_number()
+    }
+}
+
 impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -225,6 +239,13 @@ impl Config for Test {
     type ValidatorIdOf = IdentityCollator;
     type ValidatorRegistration = IsRegistered;
     type SlashRatio = SlashRatio;
+    type RepeatedOffenceSlashRatio = RepeatedOffenceSlashRatio;
+    type JailPeriod = JailPeriod;
+    type MaxDelegatorsPerCandidate = MaxDelegatorsPerCandidate;
+    type MinDelegation = MinDelegation;
+    type UnbondingPeriod = UnbondingPeriod;
+    type MaxPerformanceHistory = MaxPerformanceHistory;
+    type CurrentSlot = BlockNumberAsSlot;
     type WeightInfo = ();
 }
 
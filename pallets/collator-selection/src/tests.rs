@@ -16,13 +16,16 @@
 // limitations under the License.
 
 use crate as collator_selection;
-use crate::{mock::*, CandidateInfo, Error};
+use crate::{
+    mock::*, CandidateInfo, CollatorStats, Delegation, DelegationSummary, Error,
+    SessionPerformance, SlashReason, UnbondingDelegation,
+};
 use frame_support::{
     assert_noop, assert_ok,
     traits::{Currency, GenesisBuild, OnInitialize},
 };
 use pallet_balances::Error as BalancesError;
-use sp_runtime::traits::BadOrigin;
+use sp_runtime::{traits::BadOrigin, Perbill};
 
 #[test]
 fn basic_setup_works() {
@@ -108,17 +111,16 @@ fn set_candidacy_bond() {
 #[test]
 fn cannot_register_candidate_if_too_many() {
     new_test_ext().execute_with(|| {
-        // reset desired candidates:
+        // desired candidates only limits the selection, not the registration.
         <crate::DesiredCandidates<Test>>::put(0);
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
 
-        // can't accept anyone anymore.
-        assert_noop!(
-            CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)),
-            Error::<Test>::TooManyCandidates,
-        );
-
-        // reset desired candidates:
-        <crate::DesiredCandidates<Test>>::put(1);
+        // fill up the remaining slots but one
+        let mut candidates = CollatorSelection::candidates();
+        candidates.extend((10..28).map(|who| CandidateInfo { who, deposit: 10 }));
+        <crate::Candidates<Test>>::put(candidates);
         assert_ok!(CollatorSelection::register_as_candidate(
             RuntimeOrigin::signed(4)
         ));
@@ -433,6 +435,573 @@ fn should_not_kick_mechanism_too_few() {
     });
 }
 
+#[test]
+fn delegate_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+
+        assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 20));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(crate::Event::Delegated(
+            5, 3, 20,
+        )));
+        assert_eq!(Balances::free_balance(5), 80);
+        assert_eq!(Balances::reserved_balance(5), 20);
+
+        // existing delegation can be increased by less than the minimum
+        assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 1));
+        assert_eq!(
+            CollatorSelection::delegations(3, 5),
+            Some(Delegation {
+                active: 0,
+                pending: 21,
+                session: 0
+            })
+        );
+
+        assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(1), 3, 10));
+        assert_eq!(
+            CollatorSelection::candidate_delegations(3),
+            DelegationSummary {
+                delegated: Delegation {
+                    active: 0,
+                    pending: 31,
+                    session: 0
+                },
+                delegators: 2
+            }
+        );
+
+        // delegations count only from the next session on
+        assert_eq!(
+            CollatorSelection::backing(&CollatorSelection::candidates()[0]),
+            10
+        );
+        <crate::CurrentSession<Test>>::put(1);
+        assert_eq!(
+            CollatorSelection::backing(&CollatorSelection::candidates()[0]),
+            41
+        );
+
+        // pending amount is tracked separately from the active one
+        assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 4));
+        assert_eq!(
+            CollatorSelection::delegations(3, 5),
+            Some(Delegation {
+                active: 21,
+                pending: 4,
+                session: 1
+            })
+        );
+        assert_eq!(
+            CollatorSelection::backing(&CollatorSelection::candidates()[0]),
+            41
+        );
+    });
+}
+
+#[test]
+fn delegate_fails() {
+    new_test_ext().execute_with(|| {
+        // cannot delegate to a non-candidate
+        assert_noop!(
+            CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 20),
+            Error::<Test>::NotCandidate
+        );
+
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+
+        assert_noop!(
+            CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 9),
+            Error::<Test>::DelegationBelowMinimum
+        );
+        assert_noop!(
+            CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 101),
+            BalancesError::<Test>::InsufficientBalance
+        );
+
+        // max 2 delegators per candidate
+        assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(1), 3, 10));
+        assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(2), 3, 10));
+        assert_noop!(
+            CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 10),
+            Error::<Test>::TooManyDelegators
+        );
+    });
+}
+
+#[test]
+fn undelegate_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 30));
+
+        assert_noop!(
+            CollatorSelection::undelegate(RuntimeOrigin::signed(1), 3, 10),
+            Error::<Test>::NoDelegation
+        );
+        assert_noop!(
+            CollatorSelection::undelegate(RuntimeOrigin::signed(5), 3, 31),
+            Error::<Test>::InsufficientDelegation
+        );
+        // remaining delegation cannot be below the minimum
+        assert_noop!(
+            CollatorSelection::undelegate(RuntimeOrigin::signed(5), 3, 21),
+            Error::<Test>::DelegationBelowMinimum
+        );
+
+        assert_ok!(CollatorSelection::undelegate(
+            RuntimeOrigin::signed(5),
+            3,
+            20
+        ));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(crate::Event::Undelegated(
+            5, 3, 20,
+        )));
+        assert_eq!(
+            CollatorSelection::delegations(3, 5),
+            Some(Delegation {
+                active: 0,
+                pending: 10,
+                session: 0
+            })
+        );
+        // undelegated funds stay reserved while unbonding
+        assert_eq!(
+            CollatorSelection::unbonding(3, 5),
+            Some(UnbondingDelegation {
+                amount: 20,
+                unlock_at: 6
+            })
+        );
+        assert_eq!(Balances::free_balance(5), 70);
+
+        // delegation can be undelegated after the candidate has left
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        assert_ok!(CollatorSelection::leave_intent(RuntimeOrigin::signed(3)));
+        System::set_block_number(2);
+        assert_ok!(CollatorSelection::undelegate(
+            RuntimeOrigin::signed(5),
+            3,
+            10
+        ));
+        assert_eq!(CollatorSelection::delegations(3, 5), None);
+        // unbonding period restarts
+        assert_eq!(
+            CollatorSelection::unbonding(3, 5),
+            Some(UnbondingDelegation {
+                amount: 30,
+                unlock_at: 7
+            })
+        );
+        // the delegator keeps its slot until the funds are withdrawn
+        assert_eq!(CollatorSelection::candidate_delegations(3).delegators, 1);
+        assert_eq!(Balances::free_balance(5), 70);
+    });
+}
+
+#[test]
+fn withdraw_unbonded_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 30));
+        assert_ok!(CollatorSelection::undelegate(
+            RuntimeOrigin::signed(5),
+            3,
+            30
+        ));
+
+        assert_noop!(
+            CollatorSelection::withdraw_unbonded(RuntimeOrigin::signed(1), 3),
+            Error::<Test>::NoUnbonding
+        );
+        System::set_block_number(5);
+        assert_noop!(
+            CollatorSelection::withdraw_unbonded(RuntimeOrigin::signed(5), 3),
+            Error::<Test>::UnbondingPeriodNotOver
+        );
+
+        System::set_block_number(6);
+        assert_ok!(CollatorSelection::withdraw_unbonded(
+            RuntimeOrigin::signed(5),
+            3
+        ));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(
+            crate::Event::UnbondedWithdrawn(5, 3, 30),
+        ));
+        assert_eq!(CollatorSelection::unbonding(3, 5), None);
+        assert!(!crate::CandidateDelegations::<Test>::contains_key(3));
+        assert_eq!(Balances::free_balance(5), 100);
+    });
+}
+
+#[test]
+fn set_collator_commission_works() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(CollatorSelection::collator_commission(), Perbill::zero());
+
+        assert_noop!(
+            CollatorSelection::set_collator_commission(
+                RuntimeOrigin::signed(1),
+                Perbill::from_percent(20)
+            ),
+            BadOrigin
+        );
+
+        assert_ok!(CollatorSelection::set_collator_commission(
+            RuntimeOrigin::signed(RootAccount::get()),
+            Perbill::from_percent(20)
+        ));
+        assert_eq!(
+            CollatorSelection::collator_commission(),
+            Perbill::from_percent(20)
+        );
+    });
+}
+
+#[test]
+fn authorship_reward_is_shared_with_delegators() {
+    new_test_ext().execute_with(|| {
+        // put 100 in the pot + 5 for ED
+        Balances::make_free_balance_be(&CollatorSelection::account_id(), 105);
+        assert_ok!(CollatorSelection::set_collator_commission(
+            RuntimeOrigin::signed(RootAccount::get()),
+            Perbill::from_percent(20)
+        ));
+
+        // 4 is the default author.
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(3), 4, 30));
+        // the delegation counts from the next session on
+        <crate::CurrentSession<Test>>::put(1);
+        // triggers `note_author`
+        Authorship::on_initialize(1);
+
+        // author takes 20% commission of 50, the rest is split 10:30 by the bond & delegation.
+        assert_eq!(Balances::free_balance(4), 90 + 10 + 10);
+        assert_eq!(Balances::free_balance(3), 70 + 30);
+        assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 55);
+    });
+}
+
+#[test]
+fn candidates_with_highest_backing_are_selected() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 4, 20));
+
+        // all candidates are selected while there are enough slots
+        assert_eq!(
+            CollatorSelection::select_candidates(CollatorSelection::candidates()),
+            vec![3, 4]
+        );
+
+        assert_ok!(CollatorSelection::set_desired_candidates(
+            RuntimeOrigin::signed(RootAccount::get()),
+            1
+        ));
+        initialize_to_block(10);
+
+        // 4 has the higher backing and takes the only slot.
+        let mut queued = Session::queued_keys()
+            .into_iter()
+            .map(|(who, _)| who)
+            .collect::<Vec<_>>();
+        queued.sort();
+        assert_eq!(queued, vec![1, 2, 4]);
+        // 3 remains a candidate and isn't considered stale.
+        assert_eq!(CollatorSelection::candidates().len(), 2);
+        assert_eq!(CollatorSelection::last_authored_block(3), 20);
+    });
+}
+
+#[test]
+fn kicked_candidate_delegations_are_slashed() {
+    new_test_ext().execute_with(|| {
+        <crate::SlashDestination<Test>>::put(5);
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(1), 3, 20));
+        assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(2), 3, 30));
+        assert_ok!(CollatorSelection::undelegate(
+            RuntimeOrigin::signed(2),
+            3,
+            10
+        ));
+
+        initialize_to_block(20);
+        // 3 was kicked, its bond & delegations slashed by 10%
+        assert_eq!(CollatorSelection::candidates().len(), 1);
+        System::assert_has_event(RuntimeEvent::CollatorSelection(
+            crate::Event::DelegationSlashed(1, 3, 2),
+        ));
+        assert_eq!(
+            CollatorSelection::delegations(3, 1),
+            Some(Delegation {
+                active: 18,
+                pending: 0,
+                session: 2
+            })
+        );
+        // unbonding funds are slashed as well
+        assert_eq!(
+            CollatorSelection::unbonding(3, 2),
+            Some(UnbondingDelegation {
+                amount: 9,
+                unlock_at: 5
+            })
+        );
+        assert_eq!(
+            CollatorSelection::candidate_delegations(3),
+            DelegationSummary {
+                delegated: Delegation {
+                    active: 36,
+                    pending: 0,
+                    session: 2
+                },
+                delegators: 2
+            }
+        );
+        assert_eq!(Balances::reserved_balance(1), 18);
+        assert_eq!(Balances::reserved_balance(2), 18 + 9);
+        assert_eq!(Balances::free_balance(5), 103 + 2 + 1);
+    });
+}
+
+#[test]
+fn kicked_candidate_is_jailed() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+
+        initialize_to_block(20);
+        // 3 was kicked, slashed & jailed for 20 blocks
+        assert_eq!(CollatorSelection::candidates().len(), 1);
+        System::assert_has_event(RuntimeEvent::CollatorSelection(
+            crate::Event::CandidateSlashed(3),
+        ));
+        System::assert_has_event(RuntimeEvent::CollatorSelection(
+            crate::Event::CandidateSlashedFor(3, 1, SlashReason::NotProducingBlocks),
+        ));
+        System::assert_has_event(RuntimeEvent::CollatorSelection(
+            crate::Event::CandidateJailed(3, 40),
+        ));
+        assert_eq!(CollatorSelection::jailed(3), Some(40));
+        assert_eq!(CollatorSelection::offences(3), 1);
+
+        // jailed account cannot register again
+        assert_noop!(
+            CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)),
+            Error::<Test>::Jailed
+        );
+    });
+}
+
+#[test]
+fn unjail_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CollatorSelection::unjail(RuntimeOrigin::signed(3)),
+            Error::<Test>::NotJailed
+        );
+
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        initialize_to_block(20);
+        assert_eq!(CollatorSelection::jailed(3), Some(40));
+
+        initialize_to_block(39);
+        assert_noop!(
+            CollatorSelection::unjail(RuntimeOrigin::signed(3)),
+            Error::<Test>::JailPeriodNotOver
+        );
+
+        initialize_to_block(40);
+        assert_ok!(CollatorSelection::unjail(RuntimeOrigin::signed(3)));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(
+            crate::Event::CandidateUnjailed(3),
+        ));
+        assert_eq!(CollatorSelection::jailed(3), None);
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+    });
+}
+
+#[test]
+fn repeated_offence_is_slashed_more() {
+    new_test_ext().execute_with(|| {
+        <crate::SlashDestination<Test>>::put(5);
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+
+        // first offence is slashed 10% (of 10 bond)
+        initialize_to_block(20);
+        assert_eq!(Balances::free_balance(3), 99);
+
+        initialize_to_block(40);
+        assert_ok!(CollatorSelection::unjail(RuntimeOrigin::signed(3)));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+
+        // 3 still doesn't produce blocks, second offence is slashed 50%
+        initialize_to_block(60);
+        assert_eq!(CollatorSelection::candidates().len(), 1);
+        System::assert_has_event(RuntimeEvent::CollatorSelection(
+            crate::Event::CandidateSlashedFor(3, 5, SlashReason::RepeatedlyNotProducingBlocks),
+        ));
+        assert_eq!(CollatorSelection::offences(3), 2);
+        assert_eq!(CollatorSelection::jailed(3), Some(80));
+        assert_eq!(Balances::free_balance(3), 94);
+        assert_eq!(Balances::free_balance(5), 106);
+    });
+}
+
+#[test]
+fn authoring_block_resets_offences() {
+    new_test_ext().execute_with(|| {
+        <crate::Offences<Test>>::insert(4, 1);
+
+        // triggers `note_author`
+        Authorship::on_initialize(1);
+
+        assert_eq!(CollatorSelection::offences(4), 0);
+    });
+}
+
+#[test]
+fn authoring_block_updates_current_stats() {
+    new_test_ext().execute_with(|| {
+        // put 100 in the pot + 5 for ED
+        Balances::make_free_balance_be(&CollatorSelection::account_id(), 105);
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+
+        // triggers `note_author`
+        Authorship::on_initialize(1);
+        Authorship::on_initialize(2);
+
+        // 50 for the first block, 25 for the second one
+        assert_eq!(
+            CollatorSelection::current_stats(4),
+            CollatorStats {
+                blocks_authored: 2,
+                rewards: 75,
+            }
+        );
+    });
+}
+
+#[test]
+fn performance_history_is_kept_per_session() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+
+        // session 0 ends, its collators are 1 & 2 which don't author any block.
+        initialize_to_block(10);
+        let missed_session = |session| SessionPerformance {
+            session,
+            blocks_authored: 0,
+            missed_slots: 5,
+            rewards: 0,
+        };
+        assert_eq!(
+            CollatorSelection::performance_history(1).into_inner(),
+            vec![missed_session(0)]
+        );
+        // 4 authored blocks, but it wasn't a session collator.
+        assert!(CollatorSelection::performance_history(4).is_empty());
+        // only block 10 counts towards the ongoing session
+        assert_eq!(CollatorSelection::current_stats(4).blocks_authored, 1);
+
+        initialize_to_block(30);
+        // 4 authored all 10 blocks of session 2, owning 3 of its slots.
+        assert_eq!(
+            CollatorSelection::performance_history(4).into_inner(),
+            vec![SessionPerformance {
+                session: 2,
+                blocks_authored: 10,
+                missed_slots: 0,
+                rewards: 0,
+            }]
+        );
+        // only the last 2 sessions are kept
+        assert_eq!(
+            CollatorSelection::performance_history(1).into_inner(),
+            vec![
+                missed_session(1),
+                // 1 is first of 3 collators, so it owns slots 21, 24, 27 & 30.
+                SessionPerformance {
+                    session: 2,
+                    blocks_authored: 0,
+                    missed_slots: 4,
+                    rewards: 0,
+                }
+            ]
+        );
+    });
+}
+
+#[test]
+fn performance_history_of_departed_collators_is_removed() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(10);
+        assert_eq!(CollatorSelection::performance_history(2).len(), 1);
+
+        // 2 isn't invulnerable from session 3 onwards.
+        assert_ok!(CollatorSelection::set_invulnerables(
+            RuntimeOrigin::signed(RootAccount::get()),
+            vec![1]
+        ));
+        initialize_to_block(20);
+        // 2 is still a collator of the ongoing session.
+        assert_eq!(CollatorSelection::performance_history(2).len(), 2);
+
+        initialize_to_block(30);
+        assert!(CollatorSelection::performance_history(2).is_empty());
+        assert_eq!(CollatorSelection::performance_history(1).len(), 2);
+    });
+}
+
 #[test]
 #[should_panic = "duplicate invulnerables in genesis."]
 fn cannot_set_genesis_value_twice() {
@@ -52,8 +52,15 @@ pub trait WeightInfo {
 	fn set_candidacy_bond() -> Weight;
 	fn register_as_candidate(c: u32, ) -> Weight;
 	fn leave_intent(c: u32, ) -> Weight;
-	fn note_author() -> Weight;
+	fn note_author(d: u32, ) -> Weight;
 	fn new_session(r: u32, c: u32, ) -> Weight;
+	fn delegate() -> Weight;
+	fn undelegate() -> Weight;
+	fn set_collator_commission() -> Weight;
+	fn slash_delegations(d: u32, ) -> Weight;
+	fn unjail() -> Weight;
+	fn rotate_performance_stats(c: u32, ) -> Weight;
+	fn withdraw_unbonded() -> Weight;
 }
 
 /// Weights for pallet_collator_selection using the Substrate node and recommended hardware.
@@ -92,21 +99,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: CollatorSelection Candidates (r:1 w:1)
 	// Proof Skipped: CollatorSelection Candidates (max_values: Some(1), max_size: None, mode: Measured)
-	// Storage: CollatorSelection DesiredCandidates (r:1 w:0)
-	// Proof Skipped: CollatorSelection DesiredCandidates (max_values: Some(1), max_size: None, mode: Measured)
 	// Storage: CollatorSelection Invulnerables (r:1 w:0)
 	// Proof Skipped: CollatorSelection Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection Jailed (r:1 w:0)
+	// Proof Skipped: CollatorSelection Jailed (max_values: None, max_size: None, mode: Measured)
 	// Storage: Session NextKeys (r:1 w:0)
 	// Proof Skipped: Session NextKeys (max_values: None, max_size: None, mode: Measured)
 	// Storage: CollatorSelection CandidacyBond (r:1 w:0)
 	// Proof Skipped: CollatorSelection CandidacyBond (max_values: Some(1), max_size: None, mode: Measured)
 	// Storage: CollatorSelection LastAuthoredBlock (r:0 w:1)
 	// Proof Skipped: CollatorSelection LastAuthoredBlock (max_values: None, max_size: None, mode: Measured)
-	/// The range of component `c` is `[1, 148]`.
+	/// The range of component `c` is `[1, 147]`.
 	fn register_as_candidate(c: u32, ) -> Weight {
-		// Minimum execution time: 39_356 nanoseconds.
-		Weight::from_parts(42_616_377, 0)
-			.saturating_add(Weight::from_parts(0, 9891))
+		Weight::from_parts(43_208_853, 0)
+			.saturating_add(Weight::from_parts(0, 11919))
 			// Standard Error: 670
 			.saturating_add(Weight::from_parts(39_880, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(5_u64))
@@ -130,14 +136,35 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: System Account (r:2 w:2)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: CollatorSelection CandidateDelegations (r:1 w:0)
+	// Proof Skipped: CollatorSelection CandidateDelegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection CurrentSession (r:1 w:0)
+	// Proof Skipped: CollatorSelection CurrentSession (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection CollatorCommission (r:1 w:0)
+	// Proof Skipped: CollatorSelection CollatorCommission (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection Candidates (r:1 w:0)
+	// Proof Skipped: CollatorSelection Candidates (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection Delegations (r:33 w:0)
+	// Proof Skipped: CollatorSelection Delegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:32 w:32)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: CollatorSelection CurrentStats (r:1 w:1)
+	// Proof Skipped: CollatorSelection CurrentStats (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection Offences (r:0 w:1)
+	// Proof Skipped: CollatorSelection Offences (max_values: None, max_size: None, mode: Measured)
 	// Storage: CollatorSelection LastAuthoredBlock (r:0 w:1)
 	// Proof Skipped: CollatorSelection LastAuthoredBlock (max_values: None, max_size: None, mode: Measured)
-	fn note_author() -> Weight {
-		// Minimum execution time: 31_536 nanoseconds.
-		Weight::from_parts(31_986_000, 0)
-			.saturating_add(Weight::from_parts(0, 5497))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+	/// The range of component `d` is `[0, 32]`.
+	fn note_author(d: u32, ) -> Weight {
+		Weight::from_parts(41_394_540, 0)
+			.saturating_add(Weight::from_parts(0, 9762))
+			// Standard Error: 3_102
+			.saturating_add(Weight::from_parts(24_318_577, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 5134).saturating_mul(d.into()))
 	}
 	// Storage: CollatorSelection Candidates (r:1 w:0)
 	// Proof Skipped: CollatorSelection Candidates (max_values: Some(1), max_size: None, mode: Measured)
@@ -149,20 +176,133 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: CollatorSelection SlashDestination (r:1 w:0)
 	// Proof Skipped: CollatorSelection SlashDestination (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection CandidateDelegations (r:148 w:0)
+	// Proof Skipped: CollatorSelection CandidateDelegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection CurrentSession (r:1 w:0)
+	// Proof Skipped: CollatorSelection CurrentSession (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection Offences (r:143 w:143)
+	// Proof Skipped: CollatorSelection Offences (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection Jailed (r:0 w:143)
+	// Proof Skipped: CollatorSelection Jailed (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `r` is `[1, 148]`.
 	/// The range of component `c` is `[1, 148]`.
 	fn new_session(r: u32, c: u32, ) -> Weight {
-		// Minimum execution time: 16_816 nanoseconds.
-		Weight::from_parts(17_132_000, 0)
-			.saturating_add(Weight::from_parts(0, 4714))
+		Weight::from_parts(18_655_000, 0)
+			.saturating_add(Weight::from_parts(0, 5213))
 			// Standard Error: 475_405
 			.saturating_add(Weight::from_parts(15_758_567, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 3131).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 8195).saturating_mul(c.into()))
 			.saturating_add(Weight::from_parts(0, 2751).saturating_mul(r.into()))
 	}
+	// Storage: CollatorSelection Candidates (r:1 w:0)
+	// Proof Skipped: CollatorSelection Candidates (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection CurrentSession (r:1 w:0)
+	// Proof Skipped: CollatorSelection CurrentSession (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection Delegations (r:1 w:1)
+	// Proof Skipped: CollatorSelection Delegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection Unbonding (r:1 w:0)
+	// Proof Skipped: CollatorSelection Unbonding (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection CandidateDelegations (r:1 w:1)
+	// Proof Skipped: CollatorSelection CandidateDelegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn delegate() -> Weight {
+		Weight::from_parts(46_284_000, 0)
+			.saturating_add(Weight::from_parts(0, 18248))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: CollatorSelection CurrentSession (r:1 w:0)
+	// Proof Skipped: CollatorSelection CurrentSession (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection Delegations (r:1 w:1)
+	// Proof Skipped: CollatorSelection Delegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection CandidateDelegations (r:1 w:1)
+	// Proof Skipped: CollatorSelection CandidateDelegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection Unbonding (r:1 w:1)
+	// Proof Skipped: CollatorSelection Unbonding (max_values: None, max_size: None, mode: Measured)
+	fn undelegate() -> Weight {
+		Weight::from_parts(34_917_000, 0)
+			.saturating_add(Weight::from_parts(0, 9267))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: CollatorSelection CollatorCommission (r:0 w:1)
+	// Proof Skipped: CollatorSelection CollatorCommission (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_collator_commission() -> Weight {
+		// Minimum execution time: 7_318 nanoseconds.
+		Weight::from_parts(7_502_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: CollatorSelection CurrentSession (r:1 w:0)
+	// Proof Skipped: CollatorSelection CurrentSession (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection Delegations (r:65 w:32)
+	// Proof Skipped: CollatorSelection Delegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection Unbonding (r:65 w:32)
+	// Proof Skipped: CollatorSelection Unbonding (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection CandidateDelegations (r:1 w:1)
+	// Proof Skipped: CollatorSelection CandidateDelegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:32 w:32)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: CollatorSelection SlashDestination (r:1 w:0)
+	// Proof Skipped: CollatorSelection SlashDestination (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `d` is `[1, 64]`.
+	fn slash_delegations(d: u32, ) -> Weight {
+		Weight::from_parts(16_372_731, 0)
+			.saturating_add(Weight::from_parts(0, 6977))
+			// Standard Error: 6_415
+			.saturating_add(Weight::from_parts(29_481_914, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 7776).saturating_mul(d.into()))
+	}
+	// Storage: CollatorSelection Jailed (r:1 w:1)
+	// Proof Skipped: CollatorSelection Jailed (max_values: None, max_size: None, mode: Measured)
+	fn unjail() -> Weight {
+		// Minimum execution time: 16_073 nanoseconds.
+		Weight::from_parts(16_584_000, 0)
+			.saturating_add(Weight::from_parts(0, 2531))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: CollatorSelection SessionStartSlot (r:1 w:1)
+	// Proof Skipped: CollatorSelection SessionStartSlot (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection SessionCollators (r:2 w:1)
+	// Proof Skipped: CollatorSelection SessionCollators (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection CurrentStats (r:196 w:196)
+	// Proof Skipped: CollatorSelection CurrentStats (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection PerformanceHistory (r:196 w:196)
+	// Proof Skipped: CollatorSelection PerformanceHistory (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `c` is `[1, 196]`.
+	fn rotate_performance_stats(c: u32, ) -> Weight {
+		Weight::from_parts(21_733_297, 0)
+			.saturating_add(Weight::from_parts(0, 6368))
+			.saturating_add(Weight::from_parts(12_452_408, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 6842).saturating_mul(c.into()))
+	}
+	// Storage: CollatorSelection Unbonding (r:1 w:1)
+	// Proof Skipped: CollatorSelection Unbonding (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection Delegations (r:1 w:0)
+	// Proof Skipped: CollatorSelection Delegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection CandidateDelegations (r:1 w:1)
+	// Proof Skipped: CollatorSelection CandidateDelegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_unbonded() -> Weight {
+		Weight::from_parts(33_108_000, 0)
+			.saturating_add(Weight::from_parts(0, 11186))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -200,21 +340,20 @@ impl WeightInfo for () {
 	}
 	// Storage: CollatorSelection Candidates (r:1 w:1)
 	// Proof Skipped: CollatorSelection Candidates (max_values: Some(1), max_size: None, mode: Measured)
-	// Storage: CollatorSelection DesiredCandidates (r:1 w:0)
-	// Proof Skipped: CollatorSelection DesiredCandidates (max_values: Some(1), max_size: None, mode: Measured)
 	// Storage: CollatorSelection Invulnerables (r:1 w:0)
 	// Proof Skipped: CollatorSelection Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection Jailed (r:1 w:0)
+	// Proof Skipped: CollatorSelection Jailed (max_values: None, max_size: None, mode: Measured)
 	// Storage: Session NextKeys (r:1 w:0)
 	// Proof Skipped: Session NextKeys (max_values: None, max_size: None, mode: Measured)
 	// Storage: CollatorSelection CandidacyBond (r:1 w:0)
 	// Proof Skipped: CollatorSelection CandidacyBond (max_values: Some(1), max_size: None, mode: Measured)
 	// Storage: CollatorSelection LastAuthoredBlock (r:0 w:1)
 	// Proof Skipped: CollatorSelection LastAuthoredBlock (max_values: None, max_size: None, mode: Measured)
-	/// The range of component `c` is `[1, 148]`.
+	/// The range of component `c` is `[1, 147]`.
 	fn register_as_candidate(c: u32, ) -> Weight {
-		// Minimum execution time: 39_356 nanoseconds.
-		Weight::from_parts(42_616_377, 0)
-			.saturating_add(Weight::from_parts(0, 9891))
+		Weight::from_parts(43_208_853, 0)
+			.saturating_add(Weight::from_parts(0, 11919))
 			// Standard Error: 670
 			.saturating_add(Weight::from_parts(39_880, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
@@ -238,14 +377,35 @@ impl WeightInfo for () {
 	}
 	// Storage: System Account (r:2 w:2)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: CollatorSelection CandidateDelegations (r:1 w:0)
+	// Proof Skipped: CollatorSelection CandidateDelegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection CurrentSession (r:1 w:0)
+	// Proof Skipped: CollatorSelection CurrentSession (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection CollatorCommission (r:1 w:0)
+	// Proof Skipped: CollatorSelection CollatorCommission (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection Candidates (r:1 w:0)
+	// Proof Skipped: CollatorSelection Candidates (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection Delegations (r:33 w:0)
+	// Proof Skipped: CollatorSelection Delegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:32 w:32)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: CollatorSelection CurrentStats (r:1 w:1)
+	// Proof Skipped: CollatorSelection CurrentStats (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection Offences (r:0 w:1)
+	// Proof Skipped: CollatorSelection Offences (max_values: None, max_size: None, mode: Measured)
 	// Storage: CollatorSelection LastAuthoredBlock (r:0 w:1)
 	// Proof Skipped: CollatorSelection LastAuthoredBlock (max_values: None, max_size: None, mode: Measured)
-	fn note_author() -> Weight {
-		// Minimum execution time: 31_536 nanoseconds.
-		Weight::from_parts(31_986_000, 0)
-			.saturating_add(Weight::from_parts(0, 5497))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	/// The range of component `d` is `[0, 32]`.
+	fn note_author(d: u32, ) -> Weight {
+		Weight::from_parts(41_394_540, 0)
+			.saturating_add(Weight::from_parts(0, 9762))
+			// Standard Error: 3_102
+			.saturating_add(Weight::from_parts(24_318_577, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 5134).saturating_mul(d.into()))
 	}
 	// Storage: CollatorSelection Candidates (r:1 w:0)
 	// Proof Skipped: CollatorSelection Candidates (max_values: Some(1), max_size: None, mode: Measured)
@@ -257,18 +417,131 @@ impl WeightInfo for () {
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: CollatorSelection SlashDestination (r:1 w:0)
 	// Proof Skipped: CollatorSelection SlashDestination (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection CandidateDelegations (r:148 w:0)
+	// Proof Skipped: CollatorSelection CandidateDelegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection CurrentSession (r:1 w:0)
+	// Proof Skipped: CollatorSelection CurrentSession (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection Offences (r:143 w:143)
+	// Proof Skipped: CollatorSelection Offences (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection Jailed (r:0 w:143)
+	// Proof Skipped: CollatorSelection Jailed (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `r` is `[1, 148]`.
 	/// The range of component `c` is `[1, 148]`.
 	fn new_session(r: u32, c: u32, ) -> Weight {
-		// Minimum execution time: 16_816 nanoseconds.
-		Weight::from_parts(17_132_000, 0)
-			.saturating_add(Weight::from_parts(0, 4714))
+		Weight::from_parts(18_655_000, 0)
+			.saturating_add(Weight::from_parts(0, 5213))
 			// Standard Error: 475_405
 			.saturating_add(Weight::from_parts(15_758_567, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 3131).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 8195).saturating_mul(c.into()))
 			.saturating_add(Weight::from_parts(0, 2751).saturating_mul(r.into()))
 	}
+	// Storage: CollatorSelection Candidates (r:1 w:0)
+	// Proof Skipped: CollatorSelection Candidates (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection CurrentSession (r:1 w:0)
+	// Proof Skipped: CollatorSelection CurrentSession (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection Delegations (r:1 w:1)
+	// Proof Skipped: CollatorSelection Delegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection Unbonding (r:1 w:0)
+	// Proof Skipped: CollatorSelection Unbonding (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection CandidateDelegations (r:1 w:1)
+	// Proof Skipped: CollatorSelection CandidateDelegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn delegate() -> Weight {
+		Weight::from_parts(46_284_000, 0)
+			.saturating_add(Weight::from_parts(0, 18248))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: CollatorSelection CurrentSession (r:1 w:0)
+	// Proof Skipped: CollatorSelection CurrentSession (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection Delegations (r:1 w:1)
+	// Proof Skipped: CollatorSelection Delegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection CandidateDelegations (r:1 w:1)
+	// Proof Skipped: CollatorSelection CandidateDelegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection Unbonding (r:1 w:1)
+	// Proof Skipped: CollatorSelection Unbonding (max_values: None, max_size: None, mode: Measured)
+	fn undelegate() -> Weight {
+		Weight::from_parts(34_917_000, 0)
+			.saturating_add(Weight::from_parts(0, 9267))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: CollatorSelection CollatorCommission (r:0 w:1)
+	// Proof Skipped: CollatorSelection CollatorCommission (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_collator_commission() -> Weight {
+		// Minimum execution time: 7_318 nanoseconds.
+		Weight::from_parts(7_502_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: CollatorSelection CurrentSession (r:1 w:0)
+	// Proof Skipped: CollatorSelection CurrentSession (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection Delegations (r:65 w:32)
+	// Proof Skipped: CollatorSelection Delegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection Unbonding (r:65 w:32)
+	// Proof Skipped: CollatorSelection Unbonding (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection CandidateDelegations (r:1 w:1)
+	// Proof Skipped: CollatorSelection CandidateDelegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:32 w:32)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: CollatorSelection SlashDestination (r:1 w:0)
+	// Proof Skipped: CollatorSelection SlashDestination (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `d` is `[1, 64]`.
+	fn slash_delegations(d: u32, ) -> Weight {
+		Weight::from_parts(16_372_731, 0)
+			.saturating_add(Weight::from_parts(0, 6977))
+			// Standard Error: 6_415
+			.saturating_add(Weight::from_parts(29_481_914, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 7776).saturating_mul(d.into()))
+	}
+	// Storage: CollatorSelection Jailed (r:1 w:1)
+	// Proof Skipped: CollatorSelection Jailed (max_values: None, max_size: None, mode: Measured)
+	fn unjail() -> Weight {
+		// Minimum execution time: 16_073 nanoseconds.
+		Weight::from_parts(16_584_000, 0)
+			.saturating_add(Weight::from_parts(0, 2531))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: CollatorSelection SessionStartSlot (r:1 w:1)
+	// Proof Skipped: CollatorSelection SessionStartSlot (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection SessionCollators (r:2 w:1)
+	// Proof Skipped: CollatorSelection SessionCollators (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection CurrentStats (r:196 w:196)
+	// Proof Skipped: CollatorSelection CurrentStats (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection PerformanceHistory (r:196 w:196)
+	// Proof Skipped: CollatorSelection PerformanceHistory (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `c` is `[1, 196]`.
+	fn rotate_performance_stats(c: u32, ) -> Weight {
+		Weight::from_parts(21_733_297, 0)
+			.saturating_add(Weight::from_parts(0, 6368))
+			.saturating_add(Weight::from_parts(12_452_408, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 6842).saturating_mul(c.into()))
+	}
+	// Storage: CollatorSelection Unbonding (r:1 w:1)
+	// Proof Skipped: CollatorSelection Unbonding (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection Delegations (r:1 w:0)
+	// Proof Skipped: CollatorSelection Delegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorSelection CandidateDelegations (r:1 w:1)
+	// Proof Skipped: CollatorSelection CandidateDelegations (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_unbonded() -> Weight {
+		Weight::from_parts(33_108_000, 0)
+			.saturating_add(Weight::from_parts(0, 11186))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
\ No newline at end of file
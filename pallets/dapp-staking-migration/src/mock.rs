@@ -31,6 +31,7 @@ use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
 
 use astar_primitives::{
     dapp_staking::{CycleConfiguration, SmartContract, StakingRewardHandler},
+    oracle::PriceProvider,
     testing::Header,
     Balance, BlockNumber,
 };
@@ -100,15 +101,15 @@ impl pallet_balances::Config for Test {
     type DustRemoval = ();
     type ExistentialDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
     type AccountStore = System;
-    type HoldIdentifier = ();
+    type HoldIdentifier = RuntimeHoldReason;
     type FreezeIdentifier = RuntimeFreezeReason;
-    type MaxHolds = ConstU32<0>;
+    type MaxHolds = ConstU32<1>;
     type MaxFreezes = ConstU32<1>;
     type WeightInfo = ();
 }
 
 pub struct DummyPriceProvider;
-impl pallet_dapp_staking_v3::PriceProvider for DummyPriceProvider {
+impl PriceProvider for DummyPriceProvider {
     fn average_price() -> FixedU64 {
         FixedU64::from_rational(1, 10)
     }
@@ -174,11 +175,13 @@ impl CycleConfiguration for DummyCycleConfiguration {
 impl pallet_dapp_staking_v3::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeFreezeReason = RuntimeFreezeReason;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type SmartContract = MockSmartContract;
     type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
     type NativePriceProvider = DummyPriceProvider;
     type StakingRewardHandler = DummyStakingRewardHandler;
+    type TierAssignment = pallet_dapp_staking_v3::StakeAmountTierAssignment;
     type CycleConfiguration = DummyCycleConfiguration;
     type EraRewardSpanLength = ConstU32<8>;
     type RewardRetentionInPeriods = ConstU32<2>;
@@ -189,6 +192,12 @@ impl pallet_dapp_staking_v3::Config for Test {
     type MaxNumberOfStakedContracts = ConstU32<5>;
     type MinimumStakeAmount = ConstU128<3>;
     type NumberOfTiers = ConstU32<4>;
+    type MaxDAppRewardClaimEras = ConstU32<16>;
+    type MaxRewardSplits = ConstU32<4>;
+    type MaxDAppNameLength = ConstU32<16>;
+    type MaxDAppWebsiteLength = ConstU32<32>;
+    type MetadataDepositBase = ConstU128<10>;
+    type MetadataDepositPerByte = ConstU128<1>;
     type WeightInfo = pallet_dapp_staking_v3::weights::SubstrateWeight<Test>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<MockSmartContract, AccountId>;
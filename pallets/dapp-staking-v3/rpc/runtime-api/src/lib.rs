@@ -18,14 +18,16 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use astar_primitives::BlockNumber;
-use pallet_dapp_staking_v3::EraNumber;
+use astar_primitives::{dapp_staking::SmartContract, AccountId, Balance, BlockNumber};
+pub use pallet_dapp_staking_v3::{DAppMetadataInfo, EraNumber, TierId};
+use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
 
     /// dApp Staking Api.
     ///
     /// Used to provide information otherwise not available via RPC.
+    #[api_version(2)]
     pub trait DappStakingApi {
 
         /// For how many standard era lengths does the voting subperiod last.
@@ -36,5 +38,29 @@ sp_api::decl_runtime_apis! {
 
         /// How many blocks are there per standard era.
         fn blocks_per_era() -> BlockNumber;
+
+        /// Total amount of staker rewards the account can claim, across all claimable eras.
+        #[api_version(2)]
+        fn pending_staker_rewards(account: AccountId) -> Balance;
+
+        /// Bonus rewards the account can claim, per smart contract.
+        #[api_version(2)]
+        fn pending_bonus_rewards(account: AccountId) -> Vec<(SmartContract<AccountId>, Balance)>;
+
+        /// dApp rewards which can be claimed for the smart contract, per era.
+        #[api_version(2)]
+        fn pending_dapp_rewards(smart_contract: SmartContract<AccountId>) -> Vec<(EraNumber, Balance)>;
+
+        /// Tier into which the smart contract would be assigned, based on the stake in the ongoing era.
+        #[api_version(2)]
+        fn dapp_tier(smart_contract: SmartContract<AccountId>) -> Option<TierId>;
+
+        /// Minimum stake amount required to enter each tier, starting with the first tier.
+        #[api_version(2)]
+        fn tier_thresholds() -> Vec<Balance>;
+
+        /// Metadata of the smart contract's dApp, like name, website, logo hash & category, if it exists.
+        #[api_version(2)]
+        fn dapp_metadata(smart_contract: SmartContract<AccountId>) -> Option<DAppMetadataInfo>;
     }
 }
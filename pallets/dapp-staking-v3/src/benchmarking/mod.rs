@@ -137,6 +137,8 @@ mod benchmarks {
             owner.clone().into(),
             smart_contract.clone(),
         ));
+        // Worst case is when metadata exists, since its deposit has to be released.
+        set_max_dapp_metadata::<T>(&owner, &smart_contract);
 
         #[extrinsic_call]
         _(RawOrigin::Root, smart_contract.clone());
@@ -425,6 +427,54 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn move_stake() {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let owner: T::AccountId = account("dapp_owner", 0, SEED);
+        let source_contract = T::BenchmarkHelper::get_smart_contract(1);
+        let destination_contract = T::BenchmarkHelper::get_smart_contract(2);
+        for smart_contract in [&source_contract, &destination_contract] {
+            assert_ok!(DappStaking::<T>::register(
+                RawOrigin::Root.into(),
+                owner.clone().into(),
+                smart_contract.clone(),
+            ));
+        }
+
+        let amount = T::MinimumLockedAmount::get() + 1;
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+
+        assert_ok!(DappStaking::<T>::stake(
+            RawOrigin::Signed(staker.clone()).into(),
+            source_contract.clone(),
+            amount
+        ));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(staker.clone()),
+            source_contract.clone(),
+            destination_contract.clone(),
+            amount,
+        );
+
+        assert_last_event::<T>(
+            Event::<T>::StakeMoved {
+                account: staker,
+                from: source_contract,
+                to: destination_contract,
+                amount,
+            }
+            .into(),
+        );
+    }
+
     #[benchmark]
     fn claim_staker_rewards_past_period(x: Linear<1, { T::EraRewardSpanLength::get() }>) {
         initial_config::<T>();
@@ -610,67 +660,11 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn claim_dapp_reward() {
+    fn claim_dapp_reward(x: Linear<0, { T::MaxRewardSplits::get() }>) {
         initial_config::<T>();
 
-        // Register a dApp & stake on it.
-        // This is the dApp for which we'll claim rewards for.
         let owner: T::AccountId = whitelisted_caller();
-        let smart_contract = T::BenchmarkHelper::get_smart_contract(0);
-        assert_ok!(DappStaking::<T>::register(
-            RawOrigin::Root.into(),
-            owner.clone().into(),
-            smart_contract.clone(),
-        ));
-
-        let amount = MIN_TIER_THRESHOLD * 1000;
-        T::BenchmarkHelper::set_balance(&owner, amount);
-        assert_ok!(DappStaking::<T>::lock(
-            RawOrigin::Signed(owner.clone()).into(),
-            amount,
-        ));
-        assert_ok!(DappStaking::<T>::stake(
-            RawOrigin::Signed(owner.clone()).into(),
-            smart_contract.clone(),
-            amount
-        ));
-
-        // Register & stake up to max number of contracts.
-        // The reason is we want to have reward vector filled up to the capacity.
-        for idx in 1..T::MaxNumberOfContracts::get() {
-            let owner: T::AccountId = account("dapp_owner", idx.into(), SEED);
-            let smart_contract = T::BenchmarkHelper::get_smart_contract(idx as u32);
-            assert_ok!(DappStaking::<T>::register(
-                RawOrigin::Root.into(),
-                owner.clone().into(),
-                smart_contract.clone(),
-            ));
-
-            let staker: T::AccountId = account("staker", idx.into(), SEED);
-            T::BenchmarkHelper::set_balance(&staker, amount);
-            assert_ok!(DappStaking::<T>::lock(
-                RawOrigin::Signed(staker.clone()).into(),
-                amount,
-            ));
-            assert_ok!(DappStaking::<T>::stake(
-                RawOrigin::Signed(staker.clone()).into(),
-                smart_contract.clone(),
-                amount
-            ));
-        }
-
-        // Advance enough eras so dApp reward can be claimed.
-        force_advance_to_next_subperiod::<T>();
-
-        // This is a hacky part to ensure we accomodate max number of contracts.
-        TierConfig::<T>::mutate(|config| {
-            let max_number_of_contracts: u16 = T::MaxNumberOfContracts::get().try_into().unwrap();
-            config.number_of_slots = max_number_of_contracts;
-            config.slots_per_tier[0] = max_number_of_contracts;
-            config.slots_per_tier[1..].iter_mut().for_each(|x| *x = 0);
-            config.tier_thresholds[0] = TierThreshold::FixedTvlAmount { amount: 1 };
-        });
-        force_advance_to_next_era::<T>();
+        let smart_contract = prepare_dapp_reward_claim::<T>(&owner, x);
         let claim_era = ActiveProtocolState::<T>::get().era - 1;
 
         assert_eq!(
@@ -689,10 +683,62 @@ mod benchmarks {
             claim_era,
         );
 
-        // No need to do precise check of values, but last event must be 'DAppReward'.
+        // No need to do precise check of values, but each split account & the beneficiary must be rewarded.
+        let events = dapp_staking_events::<T>();
+        assert_eq!(
+            events
+                .iter()
+                .filter(|event| matches!(event, Event::DAppReward { .. }))
+                .count(),
+            x as usize + 1
+        );
+        assert_matches!(events.last(), Some(Event::DAppReward { .. }));
+    }
+
+    #[benchmark]
+    fn claim_dapp_rewards_range(x: Linear<1, { T::MaxDAppRewardClaimEras::get() }>) {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = prepare_dapp_reward_claim::<T>(&owner, T::MaxRewardSplits::get());
+        let from_era = ActiveProtocolState::<T>::get().era - 1;
+
+        let dapp_tiers =
+            DAppTiers::<T>::get(from_era).expect("Must exist since it's from past build&earn era.");
+        assert_eq!(
+            dapp_tiers.dapps.len(),
+            T::MaxNumberOfContracts::get() as usize,
+            "Sanity check to ensure we have filled up the vector completely."
+        );
+
+        // Each era in the range should have the fully filled up reward vector.
+        let to_era = from_era + x - 1;
+        force_advance_to_era::<T>(to_era + 1);
+        for era in from_era..=to_era {
+            DAppTiers::<T>::insert(era, dapp_tiers.clone());
+        }
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(owner.clone()),
+            smart_contract.clone(),
+            from_era,
+            to_era,
+        );
+
+        // Each era must be split between all the reward split accounts & the beneficiary,
+        // so the worst case number of payouts is covered.
+        let events = dapp_staking_events::<T>();
+        assert_eq!(
+            events
+                .iter()
+                .filter(|event| matches!(event, Event::DAppReward { .. }))
+                .count(),
+            (x * (T::MaxRewardSplits::get() + 1)) as usize
+        );
         assert_matches!(
-            dapp_staking_events::<T>().last(),
-            Some(Event::DAppReward { .. })
+            events.last(),
+            Some(Event::DAppRewardsRangeProcessed { last_processed_era, .. }) if *last_processed_era == to_era
         );
     }
 
@@ -789,6 +835,76 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn set_restake() {
+        initial_config::<T>();
+
+        // Prepare staker & lock some amount
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = T::MinimumLockedAmount::get();
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()), true);
+
+        assert_last_event::<T>(
+            Event::<T>::RestakeModeSet {
+                account: staker,
+                enabled: true,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn restake_reward(x: Linear<0, { T::MaxNumberOfStakedContracts::get() }>) {
+        initial_config::<T>();
+
+        // Prepare staker & lock some amount
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = T::MinimumLockedAmount::get()
+            * Into::<Balance>::into(T::MaxNumberOfStakedContracts::get());
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+
+        // Register dApps & stake on each of them
+        for idx in 0..x {
+            let owner: T::AccountId = account("dapp_owner", idx.into(), SEED);
+            let smart_contract = T::BenchmarkHelper::get_smart_contract(idx as u32);
+            assert_ok!(DappStaking::<T>::register(
+                RawOrigin::Root.into(),
+                owner.clone().into(),
+                smart_contract.clone(),
+            ));
+
+            assert_ok!(DappStaking::<T>::stake(
+                RawOrigin::Signed(staker.clone()).into(),
+                smart_contract.clone(),
+                T::MinimumStakeAmount::get(),
+            ));
+        }
+
+        let mut ledger = Ledger::<T>::get(&staker);
+        let protocol_state = ActiveProtocolState::<T>::get();
+        let reward = T::MinimumStakeAmount::get() * Into::<Balance>::into(x.max(1));
+        let restaked_contracts;
+
+        #[block]
+        {
+            restaked_contracts =
+                DappStaking::<T>::restake_reward(&staker, &mut ledger, reward, &protocol_state);
+        }
+
+        assert_eq!(restaked_contracts, x);
+    }
+
     #[benchmark]
     fn force() {
         initial_config::<T>();
@@ -817,7 +933,10 @@ mod benchmarks {
 
         #[block]
         {
-            DappStaking::<T>::era_and_period_handler(state.next_era_start, TierAssignment::Dummy);
+            DappStaking::<T>::era_and_period_handler(
+                state.next_era_start,
+                TierAssignmentMode::Dummy,
+            );
         }
 
         assert_eq!(
@@ -869,7 +988,10 @@ mod benchmarks {
 
         #[block]
         {
-            DappStaking::<T>::era_and_period_handler(new_era_start_block, TierAssignment::Dummy);
+            DappStaking::<T>::era_and_period_handler(
+                new_era_start_block,
+                TierAssignmentMode::Dummy,
+            );
         }
 
         assert_eq!(
@@ -913,7 +1035,10 @@ mod benchmarks {
 
         #[block]
         {
-            DappStaking::<T>::era_and_period_handler(new_era_start_block, TierAssignment::Dummy);
+            DappStaking::<T>::era_and_period_handler(
+                new_era_start_block,
+                TierAssignmentMode::Dummy,
+            );
         }
 
         assert_eq!(
@@ -946,8 +1071,32 @@ mod benchmarks {
 
         #[block]
         {
-            let (dapp_tiers, _) =
-                Pallet::<T>::get_dapp_tier_assignment(reward_era, reward_period, reward_pool);
+            let (dapp_tiers, _) = Pallet::<T>::get_dapp_tier_assignment_with::<
+                StakeAmountTierAssignment,
+            >(reward_era, reward_period, reward_pool);
+            assert_eq!(dapp_tiers.dapps.len(), x as usize);
+        }
+    }
+
+    // Same as `dapp_tier_assignment`, but the strategy also reads the number of stakers of each dApp.
+    #[benchmark]
+    fn dapp_tier_assignment_by_staker_count(x: Linear<0, { max_number_of_contracts::<T>() }>) {
+        // Prepare init config (protocol state, tier params & config, etc.)
+        initial_config::<T>();
+
+        // Register & stake contracts, to prepare for tier assignment.
+        prepare_contracts_for_tier_assignment::<T>(x);
+        force_advance_to_next_era::<T>();
+
+        let reward_era = ActiveProtocolState::<T>::get().era;
+        let reward_period = ActiveProtocolState::<T>::get().period_number();
+        let reward_pool = Balance::from(10_000 * UNIT as u128);
+
+        #[block]
+        {
+            let (dapp_tiers, _) = Pallet::<T>::get_dapp_tier_assignment_with::<
+                StakerCountTierAssignment,
+            >(reward_era, reward_period, reward_pool);
             assert_eq!(dapp_tiers.dapps.len(), x as usize);
         }
     }
@@ -979,9 +1128,12 @@ mod benchmarks {
         );
         let block_number = System::<T>::block_number();
 
+        // Only enough weight for a single cleanup pass.
+        let remaining_weight = T::WeightInfo::on_idle_cleanup();
+
         #[block]
         {
-            DappStaking::<T>::on_idle(block_number, Weight::MAX);
+            DappStaking::<T>::on_idle(block_number, remaining_weight);
         }
 
         assert!(
@@ -994,6 +1146,123 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn set_dapp_metadata() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        // Worst case is when metadata already exists, so the previous deposit has to be released first.
+        set_max_dapp_metadata::<T>(&owner, &smart_contract);
+
+        let name_len = T::MaxDAppNameLength::get();
+        let website_len = T::MaxDAppWebsiteLength::get();
+        let name = BoundedVec::try_from(vec![b'b'; name_len as usize]).unwrap();
+        let website = BoundedVec::try_from(vec![b'x'; website_len as usize]).unwrap();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(owner),
+            smart_contract.clone(),
+            name,
+            website,
+            H256::repeat_byte(0x02),
+            DAppCategory::DeFi,
+        );
+
+        assert_last_event::<T>(
+            Event::<T>::DAppMetadataSet {
+                smart_contract,
+                deposit: DappStaking::<T>::metadata_deposit(name_len + website_len),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn clear_dapp_metadata() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+        set_max_dapp_metadata::<T>(&owner, &smart_contract);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, smart_contract.clone());
+
+        assert_last_event::<T>(
+            Event::<T>::DAppMetadataCleared {
+                smart_contract,
+                deposit: DappStaking::<T>::metadata_deposit(
+                    T::MaxDAppNameLength::get() + T::MaxDAppWebsiteLength::get(),
+                ),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn dapp_metadata() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+        set_max_dapp_metadata::<T>(&owner, &smart_contract);
+        let metadata;
+
+        #[block]
+        {
+            metadata = DappStaking::<T>::dapp_metadata(&smart_contract);
+        }
+
+        assert!(metadata.is_some());
+    }
+
+    #[benchmark]
+    fn set_dapp_reward_splits() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+        let reward_splits = reward_splits::<T>(T::MaxRewardSplits::get());
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(owner),
+            smart_contract.clone(),
+            reward_splits.clone(),
+        );
+
+        assert_last_event::<T>(
+            Event::<T>::DAppRewardSplitsUpdated {
+                smart_contract,
+                reward_splits,
+            }
+            .into(),
+        );
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::benchmarking::tests::new_test_ext(),
@@ -272,6 +272,118 @@ pub(super) fn prepare_contracts_for_tier_assignment<T: Config>(x: u32) {
     }
 }
 
+/// Registers & stakes on the max number of contracts, ensuring the reward vector is completely filled up.
+///
+/// Advances to the next build&earn era, so the dApp reward for the previous era can be claimed.
+/// Returns the smart contract owned by `owner`, for which the rewards can be claimed.
+pub(super) fn prepare_dapp_reward_claim<T: Config>(
+    owner: &T::AccountId,
+    number_of_reward_splits: u32,
+) -> T::SmartContract {
+    // Register a dApp & stake on it.
+    // This is the dApp for which we'll claim rewards for.
+    let smart_contract = T::BenchmarkHelper::get_smart_contract(0);
+    assert_ok!(DappStaking::<T>::register(
+        RawOrigin::Root.into(),
+        owner.clone().into(),
+        smart_contract.clone(),
+    ));
+
+    let amount = MIN_TIER_THRESHOLD * 1000;
+    T::BenchmarkHelper::set_balance(owner, amount);
+    assert_ok!(DappStaking::<T>::lock(
+        RawOrigin::Signed(owner.clone()).into(),
+        amount,
+    ));
+    assert_ok!(DappStaking::<T>::stake(
+        RawOrigin::Signed(owner.clone()).into(),
+        smart_contract.clone(),
+        amount
+    ));
+
+    // Register & stake up to max number of contracts.
+    // The reason is we want to have reward vector filled up to the capacity.
+    for idx in 1..T::MaxNumberOfContracts::get() {
+        let owner: T::AccountId = account("dapp_owner", idx.into(), SEED);
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(idx as u32);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        let staker: T::AccountId = account("staker", idx.into(), SEED);
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+        assert_ok!(DappStaking::<T>::stake(
+            RawOrigin::Signed(staker.clone()).into(),
+            smart_contract.clone(),
+            amount
+        ));
+    }
+
+    // Advance enough eras so dApp reward can be claimed.
+    force_advance_to_next_subperiod::<T>();
+
+    // Split the dApp reward between the specified number of accounts.
+    assert_ok!(DappStaking::<T>::set_dapp_reward_splits(
+        RawOrigin::Signed(owner.clone()).into(),
+        smart_contract.clone(),
+        reward_splits::<T>(number_of_reward_splits),
+    ));
+
+    // This is a hacky part to ensure we accomodate max number of contracts.
+    TierConfig::<T>::mutate(|config| {
+        let max_number_of_contracts: u16 = T::MaxNumberOfContracts::get().try_into().unwrap();
+        config.number_of_slots = max_number_of_contracts;
+        config.slots_per_tier[0] = max_number_of_contracts;
+        config.slots_per_tier[1..].iter_mut().for_each(|x| *x = 0);
+        config.tier_thresholds[0] = TierThreshold::FixedTvlAmount { amount: 1 };
+    });
+    force_advance_to_next_era::<T>();
+
+    smart_contract
+}
+
+/// Reward splits with the specified number of accounts, each receiving an equal share.
+///
+/// Reward beneficiary receives the same share as each split account.
+pub(super) fn reward_splits<T: Config>(
+    number_of_splits: u32,
+) -> BoundedVec<(T::AccountId, Perbill), T::MaxRewardSplits> {
+    let share = Perbill::from_rational(1, number_of_splits + 1);
+
+    (0..number_of_splits)
+        .map(|idx| (account("reward_split", idx, SEED), share))
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("Size is limited by the max allowed number of splits.")
+}
+
+/// Sets the largest possible metadata for the smart contract, holding the deposit from `owner`.
+pub(super) fn set_max_dapp_metadata<T: Config>(
+    owner: &T::AccountId,
+    smart_contract: &T::SmartContract,
+) {
+    let name_len = T::MaxDAppNameLength::get();
+    let website_len = T::MaxDAppWebsiteLength::get();
+
+    let deposit = DappStaking::<T>::metadata_deposit(name_len + website_len);
+    T::BenchmarkHelper::set_balance(owner, deposit + UNIT);
+
+    assert_ok!(DappStaking::<T>::set_dapp_metadata(
+        RawOrigin::Signed(owner.clone()).into(),
+        smart_contract.clone(),
+        BoundedVec::try_from(vec![b'a'; name_len as usize]).unwrap(),
+        BoundedVec::try_from(vec![b'w'; website_len as usize]).unwrap(),
+        H256::repeat_byte(0x01),
+        DAppCategory::Other,
+    ));
+}
+
 /// Reuse from `sassafras` pallet tests.
 ///
 /// Just a trivial, insecure shuffle for the benchmarks.
@@ -38,20 +38,25 @@
 use frame_support::{
     pallet_prelude::*,
     traits::{
-        fungible::{Inspect as FunInspect, MutateFreeze as FunMutateFreeze},
+        fungible::{
+            Inspect as FunInspect, MutateFreeze as FunMutateFreeze, MutateHold as FunMutateHold,
+        },
+        tokens::{Fortitude, Precision, Preservation},
         OnRuntimeUpgrade, StorageVersion,
     },
     weights::Weight,
 };
 use frame_system::pallet_prelude::*;
+use sp_core::H256;
 use sp_runtime::{
     traits::{BadOrigin, One, Saturating, UniqueSaturatedInto, Zero},
-    Perbill, Permill,
+    Perbill, Permill, TokenError,
 };
 pub use sp_std::vec::Vec;
 
 use astar_primitives::{
     dapp_staking::{CycleConfiguration, SmartContractHandle, StakingRewardHandler},
+    oracle::PriceProvider,
     Balance, BlockNumber,
 };
 
@@ -72,7 +77,7 @@ pub use weights::WeightInfo;
 const LOG_TARGET: &str = "dapp-staking";
 
 /// Helper enum for benchmarking.
-pub(crate) enum TierAssignment {
+pub(crate) enum TierAssignmentMode {
     /// Real tier assignment calculation should be done.
     Real,
     /// Dummy tier assignment calculation should be done, e.g. default value should be returned.
@@ -86,7 +91,7 @@ pub mod pallet {
     use super::*;
 
     /// The current storage version.
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -109,13 +114,13 @@ pub mod pallet {
         /// The overarching freeze reason.
         type RuntimeFreezeReason: From<FreezeReason>;
 
-        /// Currency used for staking.
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Currency used for staking & for holding dApp metadata deposits.
         /// Reference: <https://github.com/paritytech/substrate/pull/12951/>
-        type Currency: FunMutateFreeze<
-            Self::AccountId,
-            Id = Self::RuntimeFreezeReason,
-            Balance = Balance,
-        >;
+        type Currency: FunMutateFreeze<Self::AccountId, Id = Self::RuntimeFreezeReason, Balance = Balance>
+            + FunMutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason, Balance = Balance>;
 
         /// Describes smart contract in the context required by dApp staking.
         type SmartContract: Parameter
@@ -132,6 +137,9 @@ pub mod pallet {
         /// Used to handle reward payouts & reward pool amount fetching.
         type StakingRewardHandler: StakingRewardHandler<Self::AccountId>;
 
+        /// Strategy used to assign dApps into tiers at the end of each era.
+        type TierAssignment: TierAssignment;
+
         /// Describes era length, subperiods & period length, as well as cycle length.
         type CycleConfiguration: CycleConfiguration;
 
@@ -173,6 +181,30 @@ pub mod pallet {
         #[pallet::constant]
         type NumberOfTiers: Get<u32>;
 
+        /// Maximum number of eras for which dApp rewards can be claimed in a single call.
+        #[pallet::constant]
+        type MaxDAppRewardClaimEras: Get<u32>;
+
+        /// Maximum number of accounts between which a dApp reward can be split, besides the reward beneficiary.
+        #[pallet::constant]
+        type MaxRewardSplits: Get<u32>;
+
+        /// Maximum length of the dApp name in the dApp metadata.
+        #[pallet::constant]
+        type MaxDAppNameLength: Get<u32>;
+
+        /// Maximum length of the dApp website in the dApp metadata.
+        #[pallet::constant]
+        type MaxDAppWebsiteLength: Get<u32>;
+
+        /// Base deposit required for storing dApp metadata.
+        #[pallet::constant]
+        type MetadataDepositBase: Get<Balance>;
+
+        /// Additional deposit required per byte of the dApp name & website.
+        #[pallet::constant]
+        type MetadataDepositPerByte: Get<Balance>;
+
         /// Weight info for various calls & operations in the pallet.
         type WeightInfo: WeightInfo;
 
@@ -204,6 +236,11 @@ pub mod pallet {
             smart_contract: T::SmartContract,
             beneficiary: Option<T::AccountId>,
         },
+        /// dApp reward splits have been updated.
+        DAppRewardSplitsUpdated {
+            smart_contract: T::SmartContract,
+            reward_splits: BoundedVec<(T::AccountId, Perbill), T::MaxRewardSplits>,
+        },
         /// dApp owner has been changed.
         DAppOwnerChanged {
             smart_contract: T::SmartContract,
@@ -267,6 +304,13 @@ pub mod pallet {
             era: EraNumber,
             amount: Balance,
         },
+        /// dApp reward split share was below the minimum balance, so it was paid out to the reward beneficiary instead.
+        DAppRewardSplitSkipped {
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+            era: EraNumber,
+            amount: Balance,
+        },
         /// Account has unstaked funds from an unregistered smart contract
         UnstakeFromUnregistered {
             account: T::AccountId,
@@ -277,6 +321,36 @@ pub mod pallet {
         ExpiredEntriesRemoved { account: T::AccountId, count: u16 },
         /// Privileged origin has forced a new era and possibly a subperiod to start from next block.
         Force { forcing_type: ForcingType },
+        /// Account has changed the restake mode for the claimed rewards.
+        RestakeModeSet {
+            account: T::AccountId,
+            enabled: bool,
+        },
+        /// Account has moved some stake from one smart contract to another.
+        StakeMoved {
+            account: T::AccountId,
+            from: T::SmartContract,
+            to: T::SmartContract,
+            amount: Balance,
+        },
+        /// dApp rewards for a range of eras have been processed, up to and including `last_processed_era`.
+        /// If it's lower than `to_era`, the remaining eras can be claimed by repeating the call, starting from the next era.
+        DAppRewardsRangeProcessed {
+            smart_contract: T::SmartContract,
+            from_era: EraNumber,
+            to_era: EraNumber,
+            last_processed_era: EraNumber,
+        },
+        /// dApp metadata has been set, and the deposit for it has been held from the dApp owner.
+        DAppMetadataSet {
+            smart_contract: T::SmartContract,
+            deposit: Balance,
+        },
+        /// dApp metadata has been cleared, and the deposit for it has been released.
+        DAppMetadataCleared {
+            smart_contract: T::SmartContract,
+            deposit: Balance,
+        },
     }
 
     #[pallet::error]
@@ -353,6 +427,14 @@ pub mod pallet {
         TooManyStakedContracts,
         /// There are no expired entries to cleanup for the account.
         NoExpiredEntries,
+        /// Account has no locked funds in dApp staking.
+        NoLockedFunds,
+        /// Stake cannot be moved to the same smart contract it's moved from.
+        SameSourceAndDestination,
+        /// There is no metadata for the dApp.
+        NoDAppMetadata,
+        /// Reward splits are invalid - shares must be non-zero, accounts unique and shares must sum up to at most 100%.
+        InvalidRewardSplits,
         // TODO: remove this prior to the launch
         /// Tier parameters aren't valid.
         InvalidTierParameters,
@@ -375,7 +457,7 @@ pub mod pallet {
     pub type IntegratedDApps<T: Config> = CountedStorageMap<
         Hasher = Blake2_128Concat,
         Key = T::SmartContract,
-        Value = DAppInfo<T::AccountId>,
+        Value = DAppInfoFor<T>,
         QueryKind = OptionQuery,
         MaxValues = ConstU32<{ DAppId::MAX as u32 }>,
     >;
@@ -407,6 +489,16 @@ pub mod pallet {
         MaxValues = ConstU32<{ DAppId::MAX as u32 }>,
     >;
 
+    /// Number of unique stakers staking on a smart contract in the ongoing period.
+    #[pallet::storage]
+    pub type ContractStakers<T: Config> = StorageMap<
+        Hasher = Twox64Concat,
+        Key = DAppId,
+        Value = ContractStakerCount,
+        QueryKind = ValueQuery,
+        MaxValues = ConstU32<{ DAppId::MAX as u32 }>,
+    >;
+
     /// General information about the current era.
     #[pallet::storage]
     pub type CurrentEraInfo<T: Config> = StorageValue<_, EraInfo, ValueQuery>;
@@ -448,6 +540,16 @@ pub mod pallet {
     #[pallet::storage]
     pub type HistoryCleanupMarker<T: Config> = StorageValue<_, CleanupMarker, ValueQuery>;
 
+    /// Optional metadata of dApps, like name, website, logo hash & category.
+    #[pallet::storage]
+    pub type DAppMetadataOf<T: Config> = StorageMap<
+        Hasher = Twox64Concat,
+        Key = DAppId,
+        Value = DAppMetadataFor<T>,
+        QueryKind = OptionQuery,
+        MaxValues = ConstU32<{ DAppId::MAX as u32 }>,
+    >;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig {
@@ -522,7 +624,7 @@ pub mod pallet {
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumber> for Pallet<T> {
         fn on_initialize(now: BlockNumber) -> Weight {
-            Self::era_and_period_handler(now, TierAssignment::Real)
+            Self::era_and_period_handler(now, TierAssignmentMode::Real)
         }
 
         fn on_idle(_block: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
@@ -538,6 +640,7 @@ pub mod pallet {
             assert!(T::MaxUnlockingChunks::get() > 0);
             assert!(T::UnlockingPeriod::get() > 0);
             assert!(T::MaxNumberOfStakedContracts::get() > 0);
+            assert!(T::MaxDAppRewardClaimEras::get() > 0);
 
             assert!(T::MinimumLockedAmount::get() > 0);
             assert!(T::MinimumStakeAmount::get() > 0);
@@ -559,6 +662,14 @@ pub mod pallet {
         DAppStaking,
     }
 
+    /// A reason for placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Deposit for storing dApp metadata.
+        #[codec(index = 0)]
+        DAppMetadata,
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Used to enable or disable maintenance mode.
@@ -608,6 +719,7 @@ pub mod pallet {
                     id: dapp_id,
                     state: DAppState::Registered,
                     reward_destination: None,
+                    reward_splits: Default::default(),
                 },
             );
 
@@ -726,6 +838,14 @@ pub mod pallet {
             );
 
             ContractStake::<T>::remove(&dapp_info.id);
+            ContractStakers::<T>::remove(&dapp_info.id);
+
+            if let Some(deposit) = Self::remove_dapp_metadata(dapp_info.id)? {
+                Self::deposit_event(Event::<T>::DAppMetadataCleared {
+                    smart_contract: smart_contract.clone(),
+                    deposit,
+                });
+            }
 
             dapp_info.state = DAppState::Unregistered(current_era);
             IntegratedDApps::<T>::insert(&smart_contract, dapp_info);
@@ -1011,6 +1131,11 @@ pub mod pallet {
             Self::update_ledger(&account, ledger)?;
             StakerInfo::<T>::insert(&account, &smart_contract, new_staking_info);
             ContractStake::<T>::insert(&dapp_info.id, contract_stake_info);
+            if is_new_entry {
+                ContractStakers::<T>::mutate(&dapp_info.id, |stakers| {
+                    stakers.increment(protocol_state.period_number())
+                });
+            }
 
             Self::deposit_event(Event::<T>::Stake {
                 account,
@@ -1115,6 +1240,9 @@ pub mod pallet {
             if new_staking_info.is_empty() {
                 ledger.contract_stake_count.saturating_dec();
                 StakerInfo::<T>::remove(&account, &smart_contract);
+                ContractStakers::<T>::mutate(&dapp_info.id, |stakers| {
+                    stakers.decrement(protocol_state.period_number())
+                });
             } else {
                 StakerInfo::<T>::insert(&account, &smart_contract, new_staking_info);
             }
@@ -1137,167 +1265,38 @@ pub mod pallet {
             let max_span_length = T::EraRewardSpanLength::get();
             T::WeightInfo::claim_staker_rewards_ongoing_period(max_span_length)
                 .max(T::WeightInfo::claim_staker_rewards_past_period(max_span_length))
+                .saturating_add(T::WeightInfo::restake_reward(T::MaxNumberOfStakedContracts::get()))
         })]
         pub fn claim_staker_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
 
-            let mut ledger = Ledger::<T>::get(&account);
-            let staked_period = ledger
-                .staked_period()
-                .ok_or(Error::<T>::NoClaimableRewards)?;
-
-            // Check if the rewards have expired
-            let protocol_state = ActiveProtocolState::<T>::get();
-            ensure!(
-                staked_period >= Self::oldest_claimable_period(protocol_state.period_number()),
-                Error::<T>::RewardExpired
-            );
-
-            // Calculate the reward claim span
-            let earliest_staked_era = ledger
-                .earliest_staked_era()
-                .ok_or(Error::<T>::InternalClaimStakerError)?;
-            let era_rewards =
-                EraRewards::<T>::get(Self::era_reward_span_index(earliest_staked_era))
-                    .ok_or(Error::<T>::NoClaimableRewards)?;
-
-            // The last era for which we can theoretically claim rewards.
-            // And indicator if we know the period's ending era.
-            let (last_period_era, period_end) = if staked_period == protocol_state.period_number() {
-                (protocol_state.era.saturating_sub(1), None)
-            } else {
-                PeriodEnd::<T>::get(&staked_period)
-                    .map(|info| (info.final_era, Some(info.final_era)))
-                    .ok_or(Error::<T>::InternalClaimStakerError)?
-            };
-
-            // The last era for which we can claim rewards for this account.
-            let last_claim_era = era_rewards.last_era().min(last_period_era);
-
-            // Get chunks for reward claiming
-            let rewards_iter =
-                ledger
-                    .claim_up_to_era(last_claim_era, period_end)
-                    .map_err(|err| match err {
-                        AccountLedgerError::NothingToClaim => Error::<T>::NoClaimableRewards,
-                        _ => Error::<T>::InternalClaimStakerError,
-                    })?;
-
-            // Calculate rewards
-            let mut rewards: Vec<_> = Vec::new();
-            let mut reward_sum = Balance::zero();
-            for (era, amount) in rewards_iter {
-                let era_reward = era_rewards
-                    .get(era)
-                    .ok_or(Error::<T>::InternalClaimStakerError)?;
-
-                // Optimization, and zero-division protection
-                if amount.is_zero() || era_reward.staked.is_zero() {
-                    continue;
-                }
-                let staker_reward = Perbill::from_rational(amount, era_reward.staked)
-                    * era_reward.staker_reward_pool;
-
-                rewards.push((era, staker_reward));
-                reward_sum.saturating_accrue(staker_reward);
-            }
-            let rewards_len: u32 = rewards.len().unique_saturated_into();
-
-            T::StakingRewardHandler::payout_reward(&account, reward_sum)
-                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
-
-            Self::update_ledger(&account, ledger)?;
-
-            rewards.into_iter().for_each(|(era, reward)| {
-                Self::deposit_event(Event::<T>::Reward {
-                    account: account.clone(),
-                    era,
-                    amount: reward,
-                });
-            });
-
-            Ok(Some(if period_end.is_some() {
-                T::WeightInfo::claim_staker_rewards_past_period(rewards_len)
-            } else {
-                T::WeightInfo::claim_staker_rewards_ongoing_period(rewards_len)
-            })
-            .into())
+            Self::internal_claim_staker_rewards(&account)
         }
 
         /// Used to claim bonus reward for a smart contract, if eligible.
         #[pallet::call_index(12)]
-        #[pallet::weight(T::WeightInfo::claim_bonus_reward())]
+        #[pallet::weight(T::WeightInfo::claim_bonus_reward().saturating_add(
+            T::WeightInfo::restake_reward(T::MaxNumberOfStakedContracts::get())
+        ))]
         pub fn claim_bonus_reward(
             origin: OriginFor<T>,
             smart_contract: T::SmartContract,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
 
-            let staker_info = StakerInfo::<T>::get(&account, &smart_contract)
-                .ok_or(Error::<T>::NoClaimableRewards)?;
-            let protocol_state = ActiveProtocolState::<T>::get();
-
-            // Ensure:
-            // 1. Period for which rewards are being claimed has ended.
-            // 2. Account has been a loyal staker.
-            // 3. Rewards haven't expired.
-            let staked_period = staker_info.period_number();
-            ensure!(
-                staked_period < protocol_state.period_number(),
-                Error::<T>::NoClaimableRewards
-            );
-            ensure!(
-                staker_info.is_loyal(),
-                Error::<T>::NotEligibleForBonusReward
-            );
-            ensure!(
-                staker_info.period_number()
-                    >= Self::oldest_claimable_period(protocol_state.period_number()),
-                Error::<T>::RewardExpired
-            );
-
-            let period_end_info =
-                PeriodEnd::<T>::get(&staked_period).ok_or(Error::<T>::InternalClaimBonusError)?;
-            // Defensive check - we should never get this far in function if no voting period stake exists.
-            ensure!(
-                !period_end_info.total_vp_stake.is_zero(),
-                Error::<T>::InternalClaimBonusError
-            );
-
-            let eligible_amount = staker_info.staked_amount(Subperiod::Voting);
-            let bonus_reward =
-                Perbill::from_rational(eligible_amount, period_end_info.total_vp_stake)
-                    * period_end_info.bonus_reward_pool;
-
-            T::StakingRewardHandler::payout_reward(&account, bonus_reward)
-                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
-
-            // Cleanup entry since the reward has been claimed
-            StakerInfo::<T>::remove(&account, &smart_contract);
-            Ledger::<T>::mutate(&account, |ledger| {
-                ledger.contract_stake_count.saturating_dec();
-            });
-
-            Self::deposit_event(Event::<T>::BonusReward {
-                account: account.clone(),
-                smart_contract,
-                period: staked_period,
-                amount: bonus_reward,
-            });
-
-            Ok(())
+            Self::internal_claim_bonus_reward(&account, &smart_contract)
         }
 
         /// Used to claim dApp reward for the specified era.
         #[pallet::call_index(13)]
-        #[pallet::weight(T::WeightInfo::claim_dapp_reward())]
+        #[pallet::weight(T::WeightInfo::claim_dapp_reward(T::MaxRewardSplits::get()))]
         pub fn claim_dapp_reward(
             origin: OriginFor<T>,
             smart_contract: T::SmartContract,
             #[pallet::compact] era: EraNumber,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
 
             // To keep in line with legacy behavior, dApp rewards can be claimed by anyone.
@@ -1326,23 +1325,39 @@ pub mod pallet {
                         _ => Error::<T>::InternalClaimDAppError,
                     })?;
 
-            // Get reward destination, and deposit the reward.
-            let beneficiary = dapp_info.reward_beneficiary();
-            T::StakingRewardHandler::payout_reward(&beneficiary, amount)
-                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+            // Split the reward between the reward split accounts & the reward beneficiary, and deposit it.
+            // If any of the payouts fails, the entire claim fails.
+            let (payouts, skipped) = dapp_info.split_reward(amount, T::Currency::minimum_balance());
+            for (beneficiary, amount) in &payouts {
+                T::StakingRewardHandler::payout_reward(beneficiary, *amount)
+                    .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+            }
 
             // Write back updated struct to prevent double reward claims
             DAppTiers::<T>::insert(&era, dapp_tiers);
 
-            Self::deposit_event(Event::<T>::DAppReward {
-                beneficiary: beneficiary.clone(),
-                smart_contract,
-                tier_id,
-                era,
-                amount,
-            });
+            for (account, amount) in skipped {
+                Self::deposit_event(Event::<T>::DAppRewardSplitSkipped {
+                    account,
+                    smart_contract: smart_contract.clone(),
+                    era,
+                    amount,
+                });
+            }
+            for (beneficiary, amount) in payouts {
+                Self::deposit_event(Event::<T>::DAppReward {
+                    beneficiary,
+                    smart_contract: smart_contract.clone(),
+                    tier_id,
+                    era,
+                    amount,
+                });
+            }
 
-            Ok(())
+            Ok(Some(T::WeightInfo::claim_dapp_reward(
+                dapp_info.reward_splits.len() as u32,
+            ))
+            .into())
         }
 
         /// Used to unstake funds from a contract that was unregistered after an account staked on it.
@@ -1469,148 +1484,1097 @@ pub mod pallet {
             .into())
         }
 
-        // TODO: this call should be removed prior to mainnet launch.
-        // It's super useful for testing purposes, but even though force is used in this pallet & works well,
-        // it won't apply to the inflation recalculation logic - which is wrong.
-        // Probably for this call to make sense, an outside logic should handle both inflation & dApp staking state changes.
-
-        /// Used to force a change of era or subperiod.
-        /// The effect isn't immediate but will happen on the next block.
+        /// Used to enable or disable restake mode for the caller.
         ///
-        /// Used for testing purposes, when we want to force an era change, or a subperiod change.
-        /// Not intended to be used in production, except in case of unforseen circumstances.
+        /// When enabled, claimed staker & bonus rewards are locked, and if possible, staked on the
+        /// dApps which the caller has staked on in the ongoing period, proportionally to the existing stake.
         ///
-        /// Can only be called by manager origin.
-        #[pallet::call_index(16)]
-        #[pallet::weight(T::WeightInfo::force())]
-        pub fn force(origin: OriginFor<T>, forcing_type: ForcingType) -> DispatchResult {
+        /// Staking the reward isn't possible if there are still some unclaimed staker rewards remaining,
+        /// if the caller has no stake in the ongoing period, or if the period ends in the next era.
+        /// In that case, reward remains locked and can be staked manually.
+        ///
+        /// Setting is removed once the account fully exits dApp staking.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::set_restake())]
+        pub fn set_restake(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
             Self::ensure_pallet_enabled()?;
-            T::ManagerOrigin::ensure_origin(origin)?;
+            let account = ensure_signed(origin)?;
 
-            // Ensure a 'change' happens on the next block
-            ActiveProtocolState::<T>::mutate(|state| {
-                let current_block = frame_system::Pallet::<T>::block_number();
-                state.next_era_start = current_block.saturating_add(One::one());
+            let mut ledger = Ledger::<T>::get(&account);
+            ensure!(
+                !ledger.active_locked_amount().is_zero(),
+                Error::<T>::NoLockedFunds
+            );
 
-                match forcing_type {
-                    ForcingType::Era => (),
-                    ForcingType::Subperiod => {
-                        state.period_info.next_subperiod_start_era = state.era.saturating_add(1);
-                    }
-                }
-            });
+            ledger.restake = enabled;
+            Ledger::<T>::insert(&account, ledger);
 
-            Self::deposit_event(Event::<T>::Force { forcing_type });
+            Self::deposit_event(Event::<T>::RestakeModeSet { account, enabled });
 
             Ok(())
         }
 
-        // TODO: remove this prior to Astar launch, to be only used for testing
-        #[pallet::call_index(100)]
-        #[pallet::weight(T::DbWeight::get().writes(1))]
-        pub fn force_set_tier_params(
+        /// Claims some staker rewards on behalf of the specified account, if it has any.
+        ///
+        /// Can be called by anyone, but rewards are always paid out to the staker, respecting its restake mode.
+        /// Same as with `claim_staker_rewards`, at least one era will be claimed in the case of a successful call.
+        #[pallet::call_index(18)]
+        #[pallet::weight({
+            let max_span_length = T::EraRewardSpanLength::get();
+            T::WeightInfo::claim_staker_rewards_ongoing_period(max_span_length)
+                .max(T::WeightInfo::claim_staker_rewards_past_period(max_span_length))
+                .saturating_add(T::WeightInfo::restake_reward(T::MaxNumberOfStakedContracts::get()))
+        })]
+        pub fn claim_staker_rewards_for(
             origin: OriginFor<T>,
-            value: TierParameters<T::NumberOfTiers>,
-        ) -> DispatchResult {
+            account: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
-            T::ManagerOrigin::ensure_origin(origin)?;
-            ensure!(value.is_valid(), Error::<T>::InvalidTierParameters);
-
-            StaticTierParams::<T>::put(value);
+            let _ = ensure_signed(origin)?;
 
-            Ok(())
+            Self::internal_claim_staker_rewards(&account)
         }
 
-        // TODO: remove this prior to Astar launch, to be only used for testing
-        #[pallet::call_index(101)]
-        #[pallet::weight(T::DbWeight::get().writes(1))]
-        pub fn force_set_tier_config(
+        /// Claims bonus reward for the smart contract on behalf of the specified account, if eligible.
+        ///
+        /// Can be called by anyone, but reward is always paid out to the staker, respecting its restake mode.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::claim_bonus_reward().saturating_add(
+            T::WeightInfo::restake_reward(T::MaxNumberOfStakedContracts::get())
+        ))]
+        pub fn claim_bonus_reward_for(
             origin: OriginFor<T>,
-            value: TiersConfiguration<T::NumberOfTiers>,
-        ) -> DispatchResult {
-            Self::ensure_pallet_enabled()?;
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let _ = ensure_signed(origin)?;
+
+            Self::internal_claim_bonus_reward(&account, &smart_contract)
+        }
+
+        /// Move the specified amount of stake from one smart contract to another, without unstaking it.
+        ///
+        /// The moved stake keeps its voting & build&earn subperiod split, and the account's total stake remains unchanged.
+        /// Following rules apply:
+        /// 1. Amount is taken from the source contract stake in the same way as with `unstake`. If the remaining amount
+        ///    would fall below the minimum stake threshold, everything is moved.
+        /// 2. In case source contract is unregistered, everything staked on it is moved, regardless of the specified `amount`.
+        /// 3. Moving stake never removes the loyalty flag from the source stake entry.
+        /// 4. A new destination stake entry is considered loyal if any voting subperiod stake is moved into it.
+        ///    An existing destination stake entry keeps its loyalty flag as is.
+        ///
+        /// Stake is moved from the next era onwards for both contracts, so the source contract keeps it for the ongoing era.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::move_stake())]
+        pub fn move_stake(
+            origin: OriginFor<T>,
+            from: T::SmartContract,
+            to: T::SmartContract,
+            #[pallet::compact] amount: Balance,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+
+            ensure!(from != to, Error::<T>::SameSourceAndDestination);
+
+            let to_dapp_info = IntegratedDApps::<T>::get(&to).ok_or(Error::<T>::NotOperatedDApp)?;
+            ensure!(to_dapp_info.is_registered(), Error::<T>::NotOperatedDApp);
+            let from_dapp_info =
+                IntegratedDApps::<T>::get(&from).ok_or(Error::<T>::NotOperatedDApp)?;
+            let is_from_registered = from_dapp_info.is_registered();
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let current_era = protocol_state.era;
+            ensure!(
+                !protocol_state
+                    .period_info
+                    .is_next_period(current_era.saturating_add(1)),
+                Error::<T>::PeriodEndsInNextEra
+            );
+
+            let mut ledger = Ledger::<T>::get(&account);
+
+            // 1.
+            // Move the stake out of the source contract `StakerInfo` entry.
+            let mut from_staking_info =
+                StakerInfo::<T>::get(&account, &from).ok_or(Error::<T>::NoStakingInfo)?;
+            ensure!(
+                from_staking_info.period_number() == protocol_state.period_number(),
+                Error::<T>::UnstakeFromPastPeriod
+            );
+
+            let amount = if is_from_registered {
+                ensure!(amount > 0, Error::<T>::ZeroAmount);
+                ensure!(
+                    from_staking_info.total_staked_amount() >= amount,
+                    Error::<T>::UnstakeAmountTooLarge
+                );
+
+                // If moving would take the remaining staked amount below the minimum required value, move everything.
+                if from_staking_info
+                    .total_staked_amount()
+                    .saturating_sub(amount)
+                    < T::MinimumStakeAmount::get()
+                {
+                    from_staking_info.total_staked_amount()
+                } else {
+                    amount
+                }
+            } else {
+                from_staking_info.total_staked_amount()
+            };
+
+            let (voting_amount, build_and_earn_amount) =
+                from_staking_info.move_out(amount, current_era, protocol_state.subperiod());
+
+            // 2.
+            // Move the stake into the destination contract `StakerInfo` entry.
+            // Same as with `stake`, an outdated entry is replaced unless it still has a claimable bonus reward.
+            let threshold_period = Self::oldest_claimable_period(protocol_state.period_number());
+            let (mut to_staking_info, is_new_entry) = match StakerInfo::<T>::get(&account, &to) {
+                // Entry with matching period exists
+                Some(staking_info)
+                    if staking_info.period_number() == protocol_state.period_number() =>
+                {
+                    (staking_info, false)
+                }
+                // Entry exists but period doesn't match. Bonus reward might still be claimable.
+                Some(staking_info)
+                    if staking_info.period_number() >= threshold_period
+                        && staking_info.is_loyal() =>
+                {
+                    return Err(Error::<T>::UnclaimedRewards.into());
+                }
+                // No valid entry exists
+                _ => (
+                    SingularStakingInfo::new(
+                        protocol_state.period_number(),
+                        protocol_state.subperiod(),
+                    ),
+                    true,
+                ),
+            };
+            to_staking_info.move_in(voting_amount, build_and_earn_amount, current_era);
+            ensure!(
+                to_staking_info.total_staked_amount() >= T::MinimumStakeAmount::get(),
+                Error::<T>::InsufficientStakeAmount
+            );
+
+            // 3.
+            // Update number of staked contracts in the ledger.
+            // Total staked amount of the account doesn't change, so the rest of the ledger remains the same.
+            if from_staking_info.is_empty() {
+                ledger.contract_stake_count.saturating_dec();
+            }
+            if is_new_entry {
+                ledger.contract_stake_count.saturating_inc();
+                ensure!(
+                    ledger.contract_stake_count <= T::MaxNumberOfStakedContracts::get(),
+                    Error::<T>::TooManyStakedContracts
+                );
+            }
+
+            // 4.
+            // Update `ContractStake` storage for both contracts.
+            // The moved stake keeps its subperiod split, and is moved from the next era for both contracts,
+            // so the stake of the ongoing era remains as it was.
+            // Unregistered contract no longer has its stake tracked, so there's nothing to update for it.
+            if is_from_registered {
+                let mut contract_stake_info = ContractStake::<T>::get(&from_dapp_info.id);
+                contract_stake_info.move_out(
+                    voting_amount,
+                    build_and_earn_amount,
+                    protocol_state.period_info,
+                    current_era,
+                );
+                ContractStake::<T>::insert(&from_dapp_info.id, contract_stake_info);
+            }
+            let mut contract_stake_info = ContractStake::<T>::get(&to_dapp_info.id);
+            contract_stake_info.move_in(
+                voting_amount,
+                build_and_earn_amount,
+                protocol_state.period_info,
+                current_era,
+            );
+            ContractStake::<T>::insert(&to_dapp_info.id, contract_stake_info);
+
+            // 5.
+            // Update remaining storage entries
+            if from_staking_info.is_empty() {
+                StakerInfo::<T>::remove(&account, &from);
+                if is_from_registered {
+                    ContractStakers::<T>::mutate(&from_dapp_info.id, |stakers| {
+                        stakers.decrement(protocol_state.period_number())
+                    });
+                }
+            } else {
+                StakerInfo::<T>::insert(&account, &from, from_staking_info);
+            }
+            StakerInfo::<T>::insert(&account, &to, to_staking_info);
+            if is_new_entry {
+                ContractStakers::<T>::mutate(&to_dapp_info.id, |stakers| {
+                    stakers.increment(protocol_state.period_number())
+                });
+            }
+            Ledger::<T>::insert(&account, ledger);
+
+            Self::deposit_event(Event::<T>::StakeMoved {
+                account,
+                from,
+                to,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Used to claim dApp rewards for a range of eras, `from_era` to `to_era`, inclusive.
+        ///
+        /// At most `MaxDAppRewardClaimEras` eras are processed per call. Eras without claimable rewards
+        /// for the dApp, e.g. because the reward has already been claimed or has expired, are skipped.
+        ///
+        /// The last processed era is reported in the `DAppRewardsRangeProcessed` event,
+        /// so the caller can continue from the next era in a subsequent call.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::claim_dapp_rewards_range(
+            Pallet::<T>::dapp_rewards_range_len(*from_era, *to_era)
+        ))]
+        pub fn claim_dapp_rewards_range(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            #[pallet::compact] from_era: EraNumber,
+            #[pallet::compact] to_era: EraNumber,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+
+            // Same as with single era claim, dApp rewards can be claimed by anyone.
+            let _ = ensure_signed(origin)?;
+
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+
+            // Make sure the range is valid & all the eras have ended
+            let protocol_state = ActiveProtocolState::<T>::get();
+            ensure!(
+                from_era <= to_era && to_era < protocol_state.era,
+                Error::<T>::InvalidClaimEra
+            );
+
+            let oldest_claimable_period =
+                Self::oldest_claimable_period(protocol_state.period_number());
+            let processed_eras = Self::dapp_rewards_range_len(from_era, to_era);
+            let last_processed_era = from_era.saturating_add(processed_eras.saturating_sub(1));
+
+            let mut rewards = Vec::new();
+            for era in from_era..=last_processed_era {
+                let mut dapp_tiers = match DAppTiers::<T>::get(&era) {
+                    Some(dapp_tiers) if dapp_tiers.period >= oldest_claimable_period => dapp_tiers,
+                    _ => continue,
+                };
+
+                match dapp_tiers.try_claim(dapp_info.id) {
+                    Ok((amount, tier_id)) => {
+                        // Write back updated struct to prevent double reward claims
+                        DAppTiers::<T>::insert(&era, dapp_tiers);
+                        rewards.push((era, tier_id, amount));
+                    }
+                    Err(DAppTierError::NoDAppInTiers | DAppTierError::RewardAlreadyClaimed) => {
+                        continue
+                    }
+                    Err(_) => return Err(Error::<T>::InternalClaimDAppError.into()),
+                }
+            }
+
+            // Split each era reward between the reward split accounts & the reward beneficiary,
+            // and deposit the accumulated reward to each of them.
+            let minimum_payout = T::Currency::minimum_balance();
+            let era_payouts: Vec<_> = rewards
+                .into_iter()
+                .map(|(era, tier_id, amount)| {
+                    let (payouts, skipped) = dapp_info.split_reward(amount, minimum_payout);
+                    (era, tier_id, payouts, skipped)
+                })
+                .collect();
+
+            let mut payout_sums: Vec<(T::AccountId, Balance)> = Vec::new();
+            for (beneficiary, amount) in era_payouts.iter().flat_map(|(_, _, payouts, _)| payouts) {
+                match payout_sums
+                    .iter_mut()
+                    .find(|(account, _)| account == beneficiary)
+                {
+                    Some((_, sum)) => sum.saturating_accrue(*amount),
+                    None => payout_sums.push((beneficiary.clone(), *amount)),
+                }
+            }
+            for (beneficiary, amount) in &payout_sums {
+                T::StakingRewardHandler::payout_reward(beneficiary, *amount)
+                    .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+            }
+
+            for (era, _, _, skipped) in &era_payouts {
+                for (account, amount) in skipped {
+                    Self::deposit_event(Event::<T>::DAppRewardSplitSkipped {
+                        account: account.clone(),
+                        smart_contract: smart_contract.clone(),
+                        era: *era,
+                        amount: *amount,
+                    });
+                }
+            }
+            for (era, tier_id, payouts, _) in era_payouts {
+                for (beneficiary, amount) in payouts {
+                    Self::deposit_event(Event::<T>::DAppReward {
+                        beneficiary,
+                        smart_contract: smart_contract.clone(),
+                        tier_id,
+                        era,
+                        amount,
+                    });
+                }
+            }
+
+            Self::deposit_event(Event::<T>::DAppRewardsRangeProcessed {
+                smart_contract,
+                from_era,
+                to_era,
+                last_processed_era,
+            });
+
+            Ok(Some(T::WeightInfo::claim_dapp_rewards_range(processed_eras)).into())
+        }
+
+        /// Used to set the dApp metadata, like name, website, logo hash & category.
+        ///
+        /// A deposit, proportional to the size of the name & website, is held from the dApp owner.
+        /// If metadata already exists, the previous deposit is released before the new one is held.
+        ///
+        /// Caller has to be the dApp owner, and the dApp has to be registered.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::set_dapp_metadata())]
+        pub fn set_dapp_metadata(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            name: BoundedVec<u8, T::MaxDAppNameLength>,
+            website: BoundedVec<u8, T::MaxDAppWebsiteLength>,
+            logo_hash: H256,
+            category: DAppCategory,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let dev_account = ensure_signed(origin)?;
+
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+
+            ensure!(dapp_info.owner == dev_account, Error::<T>::OriginNotOwner);
+            ensure!(dapp_info.is_registered(), Error::<T>::NotOperatedDApp);
+
+            // Release the previous deposit, if any, since the depositor might have been a different account.
+            Self::remove_dapp_metadata(dapp_info.id)?;
+
+            let mut metadata = DAppMetadataFor::<T> {
+                name,
+                website,
+                logo_hash,
+                category,
+                depositor: dev_account,
+                deposit: Zero::zero(),
+            };
+            metadata.deposit = Self::metadata_deposit(metadata.data_len());
+
+            // Holds & freezes overlap, so the deposit could otherwise be taken from the funds locked in dApp staking.
+            // It must be covered by the balance on top of the locked amount, without touching any other freeze either.
+            let locked_amount = Ledger::<T>::get(&metadata.depositor).total_locked_amount();
+            ensure!(
+                T::Currency::balance(&metadata.depositor).saturating_sub(locked_amount)
+                    >= metadata.deposit
+                    && T::Currency::reducible_balance(
+                        &metadata.depositor,
+                        Preservation::Preserve,
+                        Fortitude::Polite
+                    ) >= metadata.deposit,
+                TokenError::FundsUnavailable
+            );
+
+            T::Currency::hold(
+                &HoldReason::DAppMetadata.into(),
+                &metadata.depositor,
+                metadata.deposit,
+            )?;
+
+            let deposit = metadata.deposit;
+            DAppMetadataOf::<T>::insert(&dapp_info.id, metadata);
+
+            Self::deposit_event(Event::<T>::DAppMetadataSet {
+                smart_contract,
+                deposit,
+            });
+
+            Ok(())
+        }
+
+        /// Used to clear the dApp metadata, releasing the deposit held for it back to the depositor.
+        ///
+        /// Can only be called by dApp staking manager origin.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::clear_dapp_metadata())]
+        pub fn clear_dapp_metadata(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
             T::ManagerOrigin::ensure_origin(origin)?;
 
-            TierConfig::<T>::put(value);
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+
+            let deposit =
+                Self::remove_dapp_metadata(dapp_info.id)?.ok_or(Error::<T>::NoDAppMetadata)?;
+
+            Self::deposit_event(Event::<T>::DAppMetadataCleared {
+                smart_contract,
+                deposit,
+            });
+
+            Ok(())
+        }
+
+        /// Used to split the dApp reward between multiple accounts.
+        ///
+        /// Each account receives its share of the dApp reward, and the remainder is paid out to the reward beneficiary.
+        /// Shares must be non-zero, accounts unique and shares must sum up to at most 100%.
+        /// If set to an empty list, entire reward will be paid out to the reward beneficiary.
+        ///
+        /// Caller has to be dApp owner.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::set_dapp_reward_splits())]
+        pub fn set_dapp_reward_splits(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            reward_splits: BoundedVec<(T::AccountId, Perbill), T::MaxRewardSplits>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let dev_account = ensure_signed(origin)?;
+
+            ensure!(
+                are_reward_splits_valid(&reward_splits),
+                Error::<T>::InvalidRewardSplits
+            );
+
+            IntegratedDApps::<T>::try_mutate(
+                &smart_contract,
+                |maybe_dapp_info| -> DispatchResult {
+                    let dapp_info = maybe_dapp_info
+                        .as_mut()
+                        .ok_or(Error::<T>::ContractNotFound)?;
+
+                    ensure!(dapp_info.owner == dev_account, Error::<T>::OriginNotOwner);
+
+                    dapp_info.reward_splits = reward_splits.clone();
+
+                    Ok(())
+                },
+            )?;
+
+            Self::deposit_event(Event::<T>::DAppRewardSplitsUpdated {
+                smart_contract,
+                reward_splits,
+            });
+
+            Ok(())
+        }
+
+        // TODO: this call should be removed prior to mainnet launch.
+        // It's super useful for testing purposes, but even though force is used in this pallet & works well,
+        // it won't apply to the inflation recalculation logic - which is wrong.
+        // Probably for this call to make sense, an outside logic should handle both inflation & dApp staking state changes.
+
+        /// Used to force a change of era or subperiod.
+        /// The effect isn't immediate but will happen on the next block.
+        ///
+        /// Used for testing purposes, when we want to force an era change, or a subperiod change.
+        /// Not intended to be used in production, except in case of unforseen circumstances.
+        ///
+        /// Can only be called by manager origin.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::force())]
+        pub fn force(origin: OriginFor<T>, forcing_type: ForcingType) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            // Ensure a 'change' happens on the next block
+            ActiveProtocolState::<T>::mutate(|state| {
+                let current_block = frame_system::Pallet::<T>::block_number();
+                state.next_era_start = current_block.saturating_add(One::one());
+
+                match forcing_type {
+                    ForcingType::Era => (),
+                    ForcingType::Subperiod => {
+                        state.period_info.next_subperiod_start_era = state.era.saturating_add(1);
+                    }
+                }
+            });
+
+            Self::deposit_event(Event::<T>::Force { forcing_type });
+
+            Ok(())
+        }
+
+        // TODO: remove this prior to Astar launch, to be only used for testing
+        #[pallet::call_index(100)]
+        #[pallet::weight(T::DbWeight::get().writes(1))]
+        pub fn force_set_tier_params(
+            origin: OriginFor<T>,
+            value: TierParameters<T::NumberOfTiers>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(value.is_valid(), Error::<T>::InvalidTierParameters);
+
+            StaticTierParams::<T>::put(value);
+
+            Ok(())
+        }
+
+        // TODO: remove this prior to Astar launch, to be only used for testing
+        #[pallet::call_index(101)]
+        #[pallet::weight(T::DbWeight::get().writes(1))]
+        pub fn force_set_tier_config(
+            origin: OriginFor<T>,
+            value: TiersConfiguration<T::NumberOfTiers>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            TierConfig::<T>::put(value);
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// `Err` if pallet disabled for maintenance, `Ok` otherwise.
+        pub(crate) fn ensure_pallet_enabled() -> Result<(), Error<T>> {
+            if ActiveProtocolState::<T>::get().maintenance {
+                Err(Error::<T>::Disabled)
+            } else {
+                Ok(())
+            }
+        }
+
+        /// Ensure that the origin is either the `ManagerOrigin` or a signed origin.
+        ///
+        /// In case of manager, `Ok(None)` is returned, and if signed origin `Ok(Some(AccountId))` is returned.
+        pub(crate) fn ensure_signed_or_manager(
+            origin: T::RuntimeOrigin,
+        ) -> Result<Option<T::AccountId>, BadOrigin> {
+            if T::ManagerOrigin::ensure_origin(origin.clone()).is_ok() {
+                return Ok(None);
+            }
+            let who = ensure_signed(origin)?;
+            Ok(Some(who))
+        }
+
+        /// Update the account ledger, and dApp staking balance freeze.
+        ///
+        /// In case account ledger is empty, entries from the DB are removed and freeze is thawed.
+        ///
+        /// This call can fail if the `freeze` or `thaw` operations fail. This should never happen since
+        /// runtime definition must ensure it supports necessary freezes.
+        pub(crate) fn update_ledger(
+            account: &T::AccountId,
+            ledger: AccountLedgerFor<T>,
+        ) -> Result<(), DispatchError> {
+            if ledger.is_empty() {
+                Ledger::<T>::remove(&account);
+                T::Currency::thaw(&FreezeReason::DAppStaking.into(), account)?;
+            } else {
+                T::Currency::set_freeze(
+                    &FreezeReason::DAppStaking.into(),
+                    account,
+                    ledger.total_locked_amount(),
+                )?;
+                Ledger::<T>::insert(account, ledger);
+            }
+
+            Ok(())
+        }
+
+        /// Returns the number of blocks per voting period.
+        pub(crate) fn blocks_per_voting_period() -> BlockNumber {
+            T::CycleConfiguration::blocks_per_era()
+                .saturating_mul(T::CycleConfiguration::eras_per_voting_subperiod().into())
+        }
+
+        /// `true` if smart contract is registered, `false` otherwise.
+        pub(crate) fn is_registered(smart_contract: &T::SmartContract) -> bool {
+            IntegratedDApps::<T>::get(smart_contract)
+                .map_or(false, |dapp_info| dapp_info.is_registered())
+        }
+
+        /// Calculates the `EraRewardSpan` index for the specified era.
+        pub fn era_reward_span_index(era: EraNumber) -> EraNumber {
+            era.saturating_sub(era % T::EraRewardSpanLength::get())
+        }
+
+        /// Return the oldest period for which rewards can be claimed.
+        /// All rewards before that period are considered to be expired.
+        pub(crate) fn oldest_claimable_period(current_period: PeriodNumber) -> PeriodNumber {
+            current_period.saturating_sub(T::RewardRetentionInPeriods::get())
+        }
+
+        /// Unlocking period expressed in the number of blocks.
+        pub fn unlocking_period() -> BlockNumber {
+            T::CycleConfiguration::blocks_per_era().saturating_mul(T::UnlockingPeriod::get().into())
+        }
+
+        /// Calculates staker rewards for the first claimable era reward span of the provided ledger.
+        ///
+        /// Ledger is updated as if the rewards were claimed, but nothing is written into storage.
+        /// Returns the `(era, reward)` pairs and the final era of the staked period, if the period has ended.
+        pub(crate) fn calculate_staker_rewards(
+            ledger: &mut AccountLedgerFor<T>,
+            protocol_state: &ProtocolState,
+        ) -> Result<(Vec<(EraNumber, Balance)>, Option<EraNumber>), Error<T>> {
+            let staked_period = ledger
+                .staked_period()
+                .ok_or(Error::<T>::NoClaimableRewards)?;
+
+            // Check if the rewards have expired
+            ensure!(
+                staked_period >= Self::oldest_claimable_period(protocol_state.period_number()),
+                Error::<T>::RewardExpired
+            );
+
+            // Calculate the reward claim span
+            let earliest_staked_era = ledger
+                .earliest_staked_era()
+                .ok_or(Error::<T>::InternalClaimStakerError)?;
+            let era_rewards =
+                EraRewards::<T>::get(Self::era_reward_span_index(earliest_staked_era))
+                    .ok_or(Error::<T>::NoClaimableRewards)?;
+
+            // The last era for which we can theoretically claim rewards.
+            // And indicator if we know the period's ending era.
+            let (last_period_era, period_end) = if staked_period == protocol_state.period_number() {
+                (protocol_state.era.saturating_sub(1), None)
+            } else {
+                PeriodEnd::<T>::get(&staked_period)
+                    .map(|info| (info.final_era, Some(info.final_era)))
+                    .ok_or(Error::<T>::InternalClaimStakerError)?
+            };
+
+            // The last era for which we can claim rewards for this account.
+            let last_claim_era = era_rewards.last_era().min(last_period_era);
+
+            // Get chunks for reward claiming
+            let rewards_iter =
+                ledger
+                    .claim_up_to_era(last_claim_era, period_end)
+                    .map_err(|err| match err {
+                        AccountLedgerError::NothingToClaim => Error::<T>::NoClaimableRewards,
+                        _ => Error::<T>::InternalClaimStakerError,
+                    })?;
+
+            // Calculate rewards
+            let mut rewards: Vec<_> = Vec::new();
+            for (era, amount) in rewards_iter {
+                let era_reward = era_rewards
+                    .get(era)
+                    .ok_or(Error::<T>::InternalClaimStakerError)?;
+
+                // Optimization, and zero-division protection
+                if amount.is_zero() || era_reward.staked.is_zero() {
+                    continue;
+                }
+                let staker_reward = Perbill::from_rational(amount, era_reward.staked)
+                    * era_reward.staker_reward_pool;
+
+                rewards.push((era, staker_reward));
+            }
+
+            Ok((rewards, period_end))
+        }
+
+        /// Calculates the bonus reward for the provided staking info, if it's eligible for one.
+        pub(crate) fn calculate_bonus_reward(
+            staker_info: &SingularStakingInfo,
+            protocol_state: &ProtocolState,
+        ) -> Result<Balance, Error<T>> {
+            // Ensure:
+            // 1. Period for which rewards are being claimed has ended.
+            // 2. Account has been a loyal staker.
+            // 3. Rewards haven't expired.
+            let staked_period = staker_info.period_number();
+            ensure!(
+                staked_period < protocol_state.period_number(),
+                Error::<T>::NoClaimableRewards
+            );
+            ensure!(
+                staker_info.is_loyal(),
+                Error::<T>::NotEligibleForBonusReward
+            );
+            ensure!(
+                staker_info.period_number()
+                    >= Self::oldest_claimable_period(protocol_state.period_number()),
+                Error::<T>::RewardExpired
+            );
+
+            let period_end_info =
+                PeriodEnd::<T>::get(&staked_period).ok_or(Error::<T>::InternalClaimBonusError)?;
+            // Defensive check - we should never get this far in function if no voting period stake exists.
+            ensure!(
+                !period_end_info.total_vp_stake.is_zero(),
+                Error::<T>::InternalClaimBonusError
+            );
+
+            let eligible_amount = staker_info.staked_amount(Subperiod::Voting);
+            Ok(
+                Perbill::from_rational(eligible_amount, period_end_info.total_vp_stake)
+                    * period_end_info.bonus_reward_pool,
+            )
+        }
+
+        /// Claims staker rewards for the first claimable era reward span of the account, paying them out to the account.
+        pub(crate) fn internal_claim_staker_rewards(
+            account: &T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let mut ledger = Ledger::<T>::get(account);
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let (rewards, period_end) =
+                Self::calculate_staker_rewards(&mut ledger, &protocol_state)?;
+
+            let reward_sum = rewards.iter().fold(Balance::zero(), |acc, (_, reward)| {
+                acc.saturating_add(*reward)
+            });
+            let rewards_len: u32 = rewards.len().unique_saturated_into();
+
+            T::StakingRewardHandler::payout_reward(account, reward_sum)
+                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+
+            rewards.iter().for_each(|(era, reward)| {
+                Self::deposit_event(Event::<T>::Reward {
+                    account: account.clone(),
+                    era: *era,
+                    amount: *reward,
+                });
+            });
+
+            let restake_weight = if ledger.restake {
+                let restaked_contracts =
+                    Self::restake_reward(account, &mut ledger, reward_sum, &protocol_state);
+                T::WeightInfo::restake_reward(restaked_contracts)
+            } else {
+                Weight::zero()
+            };
+
+            Self::update_ledger(account, ledger)?;
+
+            Ok(Some(
+                if period_end.is_some() {
+                    T::WeightInfo::claim_staker_rewards_past_period(rewards_len)
+                } else {
+                    T::WeightInfo::claim_staker_rewards_ongoing_period(rewards_len)
+                }
+                .saturating_add(restake_weight),
+            )
+            .into())
+        }
+
+        /// Number of eras which will be processed when claiming dApp rewards for the range `from_era` to `to_era`, inclusive.
+        ///
+        /// Limited by `MaxDAppRewardClaimEras`.
+        pub(crate) fn dapp_rewards_range_len(from_era: EraNumber, to_era: EraNumber) -> u32 {
+            to_era
+                .saturating_sub(from_era)
+                .saturating_add(1)
+                .min(T::MaxDAppRewardClaimEras::get())
+        }
+
+        /// Claims bonus reward of the account for the specified smart contract, paying it out to the account.
+        pub(crate) fn internal_claim_bonus_reward(
+            account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+        ) -> DispatchResultWithPostInfo {
+            let staker_info = StakerInfo::<T>::get(account, smart_contract)
+                .ok_or(Error::<T>::NoClaimableRewards)?;
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            let staked_period = staker_info.period_number();
+            let bonus_reward = Self::calculate_bonus_reward(&staker_info, &protocol_state)?;
+
+            T::StakingRewardHandler::payout_reward(account, bonus_reward)
+                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+
+            Self::deposit_event(Event::<T>::BonusReward {
+                account: account.clone(),
+                smart_contract: smart_contract.clone(),
+                period: staked_period,
+                amount: bonus_reward,
+            });
 
-            Ok(())
+            // Cleanup entry since the reward has been claimed
+            StakerInfo::<T>::remove(account, smart_contract);
+            let mut ledger = Ledger::<T>::get(account);
+            ledger.contract_stake_count.saturating_dec();
+
+            let restake_weight = if ledger.restake {
+                let restaked_contracts =
+                    Self::restake_reward(account, &mut ledger, bonus_reward, &protocol_state);
+                Self::update_ledger(account, ledger)?;
+                T::WeightInfo::restake_reward(restaked_contracts)
+            } else {
+                Ledger::<T>::insert(account, ledger);
+                Weight::zero()
+            };
+
+            Ok(Some(T::WeightInfo::claim_bonus_reward().saturating_add(restake_weight)).into())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// `Err` if pallet disabled for maintenance, `Ok` otherwise.
-        pub(crate) fn ensure_pallet_enabled() -> Result<(), Error<T>> {
-            if ActiveProtocolState::<T>::get().maintenance {
-                Err(Error::<T>::Disabled)
-            } else {
-                Ok(())
+        /// Locks the reward amount, and attempts to stake it on the dApps which the account has staked on in the ongoing period.
+        ///
+        /// Reward is split proportionally to the existing stake on each dApp. If it cannot be staked,
+        /// e.g. because there are unclaimed rewards remaining, it just remains locked.
+        ///
+        /// Ledger is updated but not written into storage. Returns the number of dApps on which the reward was staked.
+        pub(crate) fn restake_reward(
+            account: &T::AccountId,
+            ledger: &mut AccountLedgerFor<T>,
+            amount: Balance,
+            protocol_state: &ProtocolState,
+        ) -> u32 {
+            if amount.is_zero() {
+                return 0;
+            }
+
+            // 1.
+            // Lock the reward amount.
+            ledger.add_lock_amount(amount);
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.add_locked(amount);
+            });
+            Self::deposit_event(Event::<T>::Locked {
+                account: account.clone(),
+                amount,
+            });
+
+            // Stake is only applicable from the next era, which mustn't be in the next period.
+            let current_era = protocol_state.era;
+            if protocol_state
+                .period_info
+                .is_next_period(current_era.saturating_add(1))
+            {
+                return 0;
+            }
+
+            // 2.
+            // Find all of the registered dApps which account has staked on in the ongoing period.
+            // This is bounded by max allowed number of stake entries per account.
+            let stake_entries: Vec<_> = StakerInfo::<T>::iter_prefix(account)
+                .filter(|(_, staking_info)| {
+                    staking_info.period_number() == protocol_state.period_number()
+                })
+                .filter_map(|(smart_contract, staking_info)| {
+                    IntegratedDApps::<T>::get(&smart_contract)
+                        .filter(|dapp_info| dapp_info.is_registered())
+                        .map(|dapp_info| (smart_contract, dapp_info.id, staking_info))
+                })
+                .collect();
+
+            let total_staked = stake_entries
+                .iter()
+                .fold(Balance::zero(), |acc, (_, _, staking_info)| {
+                    acc.saturating_add(staking_info.total_staked_amount())
+                });
+            if total_staked.is_zero() {
+                return 0;
+            }
+
+            // 3.
+            // Increase the stake amount in the ledger. In case of failure, reward just remains locked.
+            if ledger
+                .add_stake_amount(amount, current_era, protocol_state.period_info)
+                .is_err()
+            {
+                return 0;
+            }
+
+            // 4.
+            // Split the amount between the dApps, with any leftover due to rounding going to the last one.
+            let number_of_entries = stake_entries.len();
+            let mut remaining_amount = amount;
+            for (idx, (smart_contract, dapp_id, mut staking_info)) in
+                stake_entries.into_iter().enumerate()
+            {
+                let stake_amount = if idx + 1 == number_of_entries {
+                    remaining_amount
+                } else {
+                    Perbill::from_rational(staking_info.total_staked_amount(), total_staked)
+                        * amount
+                };
+                remaining_amount.saturating_reduce(stake_amount);
+
+                if stake_amount.is_zero() {
+                    continue;
+                }
+
+                staking_info.stake(stake_amount, current_era, protocol_state.subperiod());
+                StakerInfo::<T>::insert(account, &smart_contract, staking_info);
+
+                ContractStake::<T>::mutate(&dapp_id, |contract_stake_info| {
+                    contract_stake_info.stake(
+                        stake_amount,
+                        protocol_state.period_info,
+                        current_era,
+                    );
+                });
+
+                Self::deposit_event(Event::<T>::Stake {
+                    account: account.clone(),
+                    smart_contract,
+                    amount: stake_amount,
+                });
             }
+
+            // 5.
+            // Update total staked amount for the next era.
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.add_stake_amount(amount, protocol_state.subperiod());
+            });
+
+            number_of_entries.unique_saturated_into()
         }
 
-        /// Ensure that the origin is either the `ManagerOrigin` or a signed origin.
-        ///
-        /// In case of manager, `Ok(None)` is returned, and if signed origin `Ok(Some(AccountId))` is returned.
-        pub(crate) fn ensure_signed_or_manager(
-            origin: T::RuntimeOrigin,
-        ) -> Result<Option<T::AccountId>, BadOrigin> {
-            if T::ManagerOrigin::ensure_origin(origin.clone()).is_ok() {
-                return Ok(None);
+        /// Total amount of staker rewards the account can currently claim, across all of the claimable eras.
+        pub fn pending_staker_rewards(account: &T::AccountId) -> Balance {
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let mut ledger = Ledger::<T>::get(account);
+
+            // Each pass covers one era reward span, so this is bounded by the reward retention period.
+            let mut total = Balance::zero();
+            while let Ok((rewards, _)) =
+                Self::calculate_staker_rewards(&mut ledger, &protocol_state)
+            {
+                rewards
+                    .iter()
+                    .for_each(|(_, reward)| total.saturating_accrue(*reward));
             }
-            let who = ensure_signed(origin)?;
-            Ok(Some(who))
+
+            total
         }
 
-        /// Update the account ledger, and dApp staking balance freeze.
+        /// Bonus rewards the account can currently claim, per smart contract.
+        pub fn pending_bonus_rewards(account: &T::AccountId) -> Vec<(T::SmartContract, Balance)> {
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            // Bounded by the max allowed number of stake entries per account.
+            StakerInfo::<T>::iter_prefix(account)
+                .filter_map(|(smart_contract, staker_info)| {
+                    Self::calculate_bonus_reward(&staker_info, &protocol_state)
+                        .ok()
+                        .map(|reward| (smart_contract, reward))
+                })
+                .collect()
+        }
+
+        /// dApp rewards which can currently be claimed for the smart contract, per era.
         ///
-        /// In case account ledger is empty, entries from the DB are removed and freeze is thawed.
+        /// Returned pairs are sorted by era, in ascending order.
+        pub fn pending_dapp_rewards(
+            smart_contract: &T::SmartContract,
+        ) -> Vec<(EraNumber, Balance)> {
+            let dapp_id = match IntegratedDApps::<T>::get(smart_contract) {
+                Some(dapp_info) => dapp_info.id,
+                None => return Vec::new(),
+            };
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let oldest_claimable_period =
+                Self::oldest_claimable_period(protocol_state.period_number());
+
+            // Bounded by the reward retention period, since expired entries are cleaned up.
+            let mut rewards: Vec<_> = DAppTiers::<T>::iter()
+                .filter(|(era, dapp_tiers)| {
+                    *era < protocol_state.era && dapp_tiers.period >= oldest_claimable_period
+                })
+                .filter_map(|(era, mut dapp_tiers)| {
+                    dapp_tiers
+                        .try_claim(dapp_id)
+                        .ok()
+                        .map(|(amount, _)| (era, amount))
+                })
+                .collect();
+            rewards.sort_unstable_by(|(era_1, _), (era_2, _)| era_1.cmp(era_2));
+
+            rewards
+        }
+
+        /// Tier into which the smart contract would be assigned, based on the stake in the ongoing era.
         ///
-        /// This call can fail if the `freeze` or `thaw` operations fail. This should never happen since
-        /// runtime definition must ensure it supports necessary freezes.
-        pub(crate) fn update_ledger(
-            account: &T::AccountId,
-            ledger: AccountLedgerFor<T>,
-        ) -> Result<(), DispatchError> {
-            if ledger.is_empty() {
-                Ledger::<T>::remove(&account);
-                T::Currency::thaw(&FreezeReason::DAppStaking.into(), account)?;
-            } else {
-                T::Currency::set_freeze(
-                    &FreezeReason::DAppStaking.into(),
-                    account,
-                    ledger.total_locked_amount(),
-                )?;
-                Ledger::<T>::insert(account, ledger);
+        /// `None` if smart contract isn't registered or doesn't satisfy any tier threshold.
+        pub fn dapp_tier(smart_contract: &T::SmartContract) -> Option<TierId> {
+            let dapp_info = IntegratedDApps::<T>::get(smart_contract)?;
+            if !dapp_info.is_registered() {
+                return None;
             }
 
-            Ok(())
-        }
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let (dapp_tiers, _) = Self::get_dapp_tier_assignment(
+                protocol_state.era,
+                protocol_state.period_number(),
+                Balance::zero(),
+            );
 
-        /// Returns the number of blocks per voting period.
-        pub(crate) fn blocks_per_voting_period() -> BlockNumber {
-            T::CycleConfiguration::blocks_per_era()
-                .saturating_mul(T::CycleConfiguration::eras_per_voting_subperiod().into())
+            dapp_tiers
+                .dapps
+                .binary_search_by(|entry| entry.dapp_id.cmp(&dapp_info.id))
+                .ok()
+                .and_then(|idx| dapp_tiers.dapps[idx].tier_id)
         }
 
-        /// `true` if smart contract is registered, `false` otherwise.
-        pub(crate) fn is_registered(smart_contract: &T::SmartContract) -> bool {
-            IntegratedDApps::<T>::get(smart_contract)
-                .map_or(false, |dapp_info| dapp_info.is_registered())
+        /// Minimum stake amount required to enter each tier, according to the active tier configuration.
+        ///
+        /// First entry refers to the first tier, and so on.
+        pub fn tier_thresholds() -> Vec<Balance> {
+            TierConfig::<T>::get()
+                .tier_thresholds
+                .iter()
+                .map(|threshold| threshold.threshold())
+                .collect()
         }
 
-        /// Calculates the `EraRewardSpan` index for the specified era.
-        pub fn era_reward_span_index(era: EraNumber) -> EraNumber {
-            era.saturating_sub(era % T::EraRewardSpanLength::get())
+        /// Metadata of the smart contract's dApp, if it exists.
+        pub fn dapp_metadata(smart_contract: &T::SmartContract) -> Option<DAppMetadataInfo> {
+            let dapp_info = IntegratedDApps::<T>::get(smart_contract)?;
+            DAppMetadataOf::<T>::get(&dapp_info.id).map(Into::into)
         }
 
-        /// Return the oldest period for which rewards can be claimed.
-        /// All rewards before that period are considered to be expired.
-        pub(crate) fn oldest_claimable_period(current_period: PeriodNumber) -> PeriodNumber {
-            current_period.saturating_sub(T::RewardRetentionInPeriods::get())
+        /// Deposit required for storing dApp metadata with `data_len` bytes of name & website.
+        pub(crate) fn metadata_deposit(data_len: u32) -> Balance {
+            T::MetadataDepositPerByte::get()
+                .saturating_mul(data_len.into())
+                .saturating_add(T::MetadataDepositBase::get())
         }
 
-        /// Unlocking period expressed in the number of blocks.
-        pub fn unlocking_period() -> BlockNumber {
-            T::CycleConfiguration::blocks_per_era().saturating_mul(T::UnlockingPeriod::get().into())
+        /// Removes the dApp metadata, if it exists, and releases the deposit held for it.
+        ///
+        /// Returns the released deposit in case metadata existed.
+        pub(crate) fn remove_dapp_metadata(
+            dapp_id: DAppId,
+        ) -> Result<Option<Balance>, DispatchError> {
+            match DAppMetadataOf::<T>::take(&dapp_id) {
+                Some(metadata) => {
+                    T::Currency::release(
+                        &HoldReason::DAppMetadata.into(),
+                        &metadata.depositor,
+                        metadata.deposit,
+                        Precision::BestEffort,
+                    )?;
+                    Ok(Some(metadata.deposit))
+                }
+                None => Ok(None),
+            }
         }
 
         /// Assign eligible dApps into appropriate tiers, and calculate reward for each tier.
@@ -1618,13 +2582,15 @@ pub mod pallet {
         /// ### Algorithm
         ///
         /// 1. Read in over all contract stake entries. In case staked amount is zero for the current era, ignore it.
+        ///    If the strategy uses it, number of unique stakers in the period is read for each dApp alongside the staked amount.
         ///    This information is used to calculate 'score' per dApp, which is used to determine the tier.
         ///
-        /// 2. Sort the entries by the score, in descending order - the top score dApp comes first.
-        ///
-        /// 3. Read in tier configuration. This contains information about how many slots per tier there are,
+        /// 2. Read in tier configuration. This contains information about how many slots per tier there are,
         ///    as well as the threshold for each tier. Threshold is the minimum amount of stake required to be eligible for a tier.
-        ///    Iterate over tier thresholds & capacities, starting from the top tier, and assign dApps to them.
+        ///    dApps are assigned into tiers using the configured `TierAssignment` strategy, which must respect both.
+        ///
+        ///    The default strategy sorts the entries by the score, in descending order - the top score dApp comes first.
+        ///    Afterwards, it iterates over tier thresholds & capacities, starting from the top tier, and assigns dApps to them.
         ///    
         ///    ```text
         ////   for each tier:
@@ -1634,9 +2600,9 @@ pub mod pallet {
         ///            else:
         ///               exit loop since no more dApps will satisfy the threshold since they are sorted by score
         ///    ```
-        ///    (Sort the entries by dApp ID, in ascending order. This is so we can efficiently search for them using binary search.)
+        ///    (Entries are later sorted by dApp ID, in ascending order. This is so we can efficiently search for them using binary search.)
         ///
-        /// 4. Calculate rewards for each tier.
+        /// 3. Calculate rewards for each tier.
         ///    This is done by dividing the total reward pool into tier reward pools,
         ///    after which the tier reward pool is divided by the number of available slots in the tier.
         ///
@@ -1647,7 +2613,18 @@ pub mod pallet {
             period: PeriodNumber,
             dapp_reward_pool: Balance,
         ) -> (DAppTierRewardsFor<T>, DAppId) {
-            let mut dapp_stakes = Vec::with_capacity(T::MaxNumberOfContracts::get() as usize);
+            Self::get_dapp_tier_assignment_with::<T::TierAssignment>(era, period, dapp_reward_pool)
+        }
+
+        /// Same as [`Self::get_dapp_tier_assignment`], but using the given tier assignment strategy.
+        ///
+        /// Allows benchmarking each strategy, regardless of the configured one.
+        pub(crate) fn get_dapp_tier_assignment_with<TA: TierAssignment>(
+            era: EraNumber,
+            period: PeriodNumber,
+            dapp_reward_pool: Balance,
+        ) -> (DAppTierRewardsFor<T>, DAppId) {
+            let mut dapps = Vec::with_capacity(T::MaxNumberOfContracts::get() as usize);
 
             // 1.
             // Iterate over all staked dApps.
@@ -1662,57 +2639,26 @@ pub mod pallet {
                     _ => continue,
                 };
 
-                dapp_stakes.push((dapp_id, stake_amount.total()));
+                // Only read the number of stakers if the strategy needs it.
+                let number_of_stakers = if TA::USES_NUMBER_OF_STAKERS {
+                    ContractStakers::<T>::get(dapp_id).get(period)
+                } else {
+                    0
+                };
+
+                dapps.push(DAppTierCandidate {
+                    dapp_id,
+                    stake_amount: stake_amount.total(),
+                    number_of_stakers,
+                });
             }
 
             // 2.
-            // Sort by amount staked, in reverse - top dApp will end in the first place, 0th index.
-            dapp_stakes.sort_unstable_by(|(_, amount_1), (_, amount_2)| amount_2.cmp(amount_1));
-
-            // 3.
-            // Iterate over configured tier and potential dApps.
-            // Each dApp will be assigned to the best possible tier if it satisfies the required condition,
-            // and tier capacity hasn't been filled yet.
-            let mut dapp_tiers = Vec::with_capacity(dapp_stakes.len());
+            // Assign dApps into tiers, using the given strategy.
             let tier_config = TierConfig::<T>::get();
+            let dapp_tiers = TA::assign_tiers(dapps, &tier_config);
 
-            let mut global_idx = 0;
-            let mut tier_id = 0;
-            for (tier_capacity, tier_threshold) in tier_config
-                .slots_per_tier
-                .iter()
-                .zip(tier_config.tier_thresholds.iter())
-            {
-                let max_idx = global_idx
-                    .saturating_add(*tier_capacity as usize)
-                    .min(dapp_stakes.len());
-
-                // Iterate over dApps until one of two conditions has been met:
-                // 1. Tier has no more capacity
-                // 2. dApp doesn't satisfy the tier threshold (since they're sorted, none of the following dApps will satisfy the condition either)
-                for (dapp_id, stake_amount) in dapp_stakes[global_idx..max_idx].iter() {
-                    if tier_threshold.is_satisfied(*stake_amount) {
-                        global_idx.saturating_inc();
-                        dapp_tiers.push(DAppTier {
-                            dapp_id: *dapp_id,
-                            tier_id: Some(tier_id),
-                        });
-                    } else {
-                        break;
-                    }
-                }
-
-                tier_id.saturating_inc();
-            }
-
-            // In case when tier has 1 more free slot, but two dApps with exactly same score satisfy the threshold,
-            // one of them will be assigned to the tier, and the other one will be assigned to the lower tier, if it exists.
-            //
-            // In the current implementation, the dApp with the lower dApp Id has the advantage.
-            // There is no guarantee this will persist in the future, so it's best for dApps to do their
-            // best to avoid getting themselves into such situations.
-
-            // 4. Calculate rewards.
+            // 3. Calculate rewards.
             let tier_rewards = tier_config
                 .reward_portion
                 .iter()
@@ -1726,7 +2672,7 @@ pub mod pallet {
                 })
                 .collect::<Vec<_>>();
 
-            // 5.
+            // 4.
             // Prepare and return tier & rewards info.
             // In case rewards creation fails, we just write the default value. This should never happen though.
             (
@@ -1743,7 +2689,7 @@ pub mod pallet {
         /// Used to handle era & period transitions.
         pub(crate) fn era_and_period_handler(
             now: BlockNumber,
-            tier_assignment: TierAssignment,
+            tier_assignment: TierAssignmentMode,
         ) -> Weight {
             let mut protocol_state = ActiveProtocolState::<T>::get();
 
@@ -1814,18 +2760,30 @@ pub mod pallet {
                     // To help with benchmarking, it's possible to omit real tier calculation using the `Dummy` approach.
                     // This must never be used in production code, obviously.
                     let (dapp_tier_rewards, counter) = match tier_assignment {
-                        TierAssignment::Real => Self::get_dapp_tier_assignment(
+                        TierAssignmentMode::Real => Self::get_dapp_tier_assignment(
                             current_era,
                             protocol_state.period_number(),
                             dapp_reward_pool,
                         ),
                         #[cfg(feature = "runtime-benchmarks")]
-                        TierAssignment::Dummy => (DAppTierRewardsFor::<T>::default(), 0),
+                        TierAssignmentMode::Dummy => (DAppTierRewardsFor::<T>::default(), 0),
                     };
+
+                    // Part of the dApp reward pool not assigned to any dApp is handled by the reward handler.
+                    consumed_weight.saturating_accrue(
+                        T::StakingRewardHandler::handle_unspent_dapp_rewards(
+                            dapp_reward_pool.saturating_sub(dapp_tier_rewards.total_rewards()),
+                        ),
+                    );
                     DAppTiers::<T>::insert(&current_era, dapp_tier_rewards);
 
-                    consumed_weight
-                        .saturating_accrue(T::WeightInfo::dapp_tier_assignment(counter.into()));
+                    consumed_weight.saturating_accrue(
+                        if T::TierAssignment::USES_NUMBER_OF_STAKERS {
+                            T::WeightInfo::dapp_tier_assignment_by_staker_count(counter.into())
+                        } else {
+                            T::WeightInfo::dapp_tier_assignment(counter.into())
+                        },
+                    );
 
                     // Switch to `Voting` period if conditions are met.
                     if protocol_state.period_info.is_next_period(next_era) {
@@ -1913,12 +2871,17 @@ pub mod pallet {
             consumed_weight
         }
 
-        /// Attempt to cleanup some expired entries, if enough remaining weight & applicable entries exist.
+        /// Attempt to cleanup expired entries, if enough remaining weight & applicable entries exist.
+        ///
+        /// Cleanup is done in passes, each pass removing at most one expired `EraRewards` and one expired `DAppTiers` entry.
+        /// Passes are repeated until either there are no more expired entries, or the remaining weight isn't enough for another pass.
         ///
         /// Returns consumed weight.
         fn expired_entry_cleanup(remaining_weight: &Weight) -> Weight {
+            let pass_weight = T::WeightInfo::on_idle_cleanup();
+
             // Need to be able to process full pass
-            if remaining_weight.any_lt(T::WeightInfo::on_idle_cleanup()) {
+            if remaining_weight.any_lt(pass_weight) {
                 return Weight::zero();
             }
 
@@ -1934,7 +2897,7 @@ pub mod pallet {
                 Some(latest_expired_period) => latest_expired_period,
                 None => {
                     // Protocol hasn't advanced enough to have any expired entries.
-                    return T::WeightInfo::on_idle_cleanup();
+                    return pass_weight;
                 }
             };
 
@@ -1943,32 +2906,47 @@ pub mod pallet {
                 Some(period_end_info) => period_end_info.final_era.saturating_add(1),
                 None => {
                     // Can happen if it's period 0 or if the entry has already been cleaned up.
-                    return T::WeightInfo::on_idle_cleanup();
+                    return pass_weight;
                 }
             };
 
-            // Attempt to cleanup one expired `EraRewards` entry.
-            if let Some(era_reward) = EraRewards::<T>::get(cleanup_marker.era_reward_index) {
-                // If oldest valid era comes AFTER this span, it's safe to delete it.
-                if era_reward.last_era() < oldest_valid_era {
-                    EraRewards::<T>::remove(cleanup_marker.era_reward_index);
-                    cleanup_marker
-                        .era_reward_index
-                        .saturating_accrue(T::EraRewardSpanLength::get());
+            // Each pass is accounted for as a full cleanup pass.
+            // This overestimates the weight of subsequent passes, but keeps the accounting simple & safe.
+            let mut consumed_weight = Weight::zero();
+            while remaining_weight.all_gte(consumed_weight.saturating_add(pass_weight)) {
+                consumed_weight.saturating_accrue(pass_weight);
+                let mut entry_removed = false;
+
+                // Attempt to cleanup one expired `EraRewards` entry.
+                if let Some(era_reward) = EraRewards::<T>::get(cleanup_marker.era_reward_index) {
+                    // If oldest valid era comes AFTER this span, it's safe to delete it.
+                    if era_reward.last_era() < oldest_valid_era {
+                        EraRewards::<T>::remove(cleanup_marker.era_reward_index);
+                        cleanup_marker
+                            .era_reward_index
+                            .saturating_accrue(T::EraRewardSpanLength::get());
+                        entry_removed = true;
+                    }
+                } else {
+                    // Should never happen, but if it does, log an error and move on.
+                    log::error!(
+                        target: LOG_TARGET,
+                        "Era rewards span for era {} is missing, but cleanup marker is set.",
+                        cleanup_marker.era_reward_index
+                    );
+                }
+
+                // Attempt to cleanup one expired `DAppTiers` entry.
+                if cleanup_marker.dapp_tiers_index < oldest_valid_era {
+                    DAppTiers::<T>::remove(cleanup_marker.dapp_tiers_index);
+                    cleanup_marker.dapp_tiers_index.saturating_inc();
+                    entry_removed = true;
                 }
-            } else {
-                // Should never happen, but if it does, log an error and move on.
-                log::error!(
-                    target: LOG_TARGET,
-                    "Era rewards span for era {} is missing, but cleanup marker is set.",
-                    cleanup_marker.era_reward_index
-                );
-            }
 
-            // Attempt to cleanup one expired `DAppTiers` entry.
-            if cleanup_marker.dapp_tiers_index < oldest_valid_era {
-                DAppTiers::<T>::remove(cleanup_marker.dapp_tiers_index);
-                cleanup_marker.dapp_tiers_index.saturating_inc();
+                // Nothing left to cleanup, no need to continue.
+                if !entry_removed {
+                    break;
+                }
             }
 
             // One extra grace period before we cleanup period end info.
@@ -1980,11 +2958,7 @@ pub mod pallet {
             // Store the updated cleanup marker
             HistoryCleanupMarker::<T>::put(cleanup_marker);
 
-            // We could try & cleanup more entries, but since it's not a critical operation and can happen whenever,
-            // we opt for the simpler solution where only 1 entry per block is cleaned up.
-            // It can be changed though.
-
-            T::WeightInfo::on_idle_cleanup()
+            consumed_weight
         }
     }
 }
@@ -2091,3 +3065,145 @@ impl<
         Ok(())
     }
 }
+
+/// Storage items as they were before the introduction of restake mode.
+mod v5 {
+    use super::*;
+
+    /// `AccountLedger` prior to the addition of `restake` field.
+    #[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+    pub struct AccountLedger<UnlockingLen: Get<u32>> {
+        #[codec(compact)]
+        pub locked: Balance,
+        pub unlocking: BoundedVec<UnlockingChunk, UnlockingLen>,
+        pub staked: StakeAmount,
+        pub staked_future: Option<StakeAmount>,
+        #[codec(compact)]
+        pub contract_stake_count: u32,
+    }
+}
+
+/// Storage version in which `Ledger` entries contain the restake mode.
+const RESTAKE_STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+
+/// `OnRuntimeUpgrade` logic used to migrate `Ledger` entries to the format with restake mode.
+///
+/// All existing ledgers have restake mode disabled, meaning claimed rewards remain in free balance.
+pub struct AccountLedgerRestakeMigration<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for AccountLedgerRestakeMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= RESTAKE_STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut translated: u64 = 0;
+        Ledger::<T>::translate::<v5::AccountLedger<T::MaxUnlockingChunks>, _>(|_, old_ledger| {
+            translated.saturating_inc();
+            Some(AccountLedger {
+                locked: old_ledger.locked,
+                unlocking: old_ledger.unlocking,
+                staked: old_ledger.staked,
+                staked_future: old_ledger.staked_future,
+                contract_stake_count: old_ledger.contract_stake_count,
+                restake: false,
+            })
+        });
+
+        RESTAKE_STORAGE_VERSION.put::<Pallet<T>>();
+
+        log::info!(
+            "Migrated {} account ledger entries to the restake format.",
+            translated
+        );
+
+        T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        Ok((Ledger::<T>::iter_keys().count() as u32).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        assert_eq!(
+            Pallet::<T>::on_chain_storage_version(),
+            RESTAKE_STORAGE_VERSION
+        );
+
+        let old_count = u32::decode(&mut &state[..])
+            .map_err(|_| "Failed to decode the pre-upgrade ledger count.")?;
+        assert_eq!(Ledger::<T>::iter().count() as u32, old_count);
+        assert!(Ledger::<T>::iter_values().all(|ledger| !ledger.restake));
+
+        Ok(())
+    }
+}
+
+/// Storage items as they were before the introduction of dApp reward splits.
+mod v6 {
+    use super::*;
+
+    /// `DAppInfo` prior to the addition of `reward_splits` field.
+    #[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+    pub struct DAppInfo<AccountId> {
+        pub owner: AccountId,
+        #[codec(compact)]
+        pub id: DAppId,
+        pub state: DAppState,
+        pub reward_destination: Option<AccountId>,
+    }
+}
+
+/// `OnRuntimeUpgrade` logic used to migrate `IntegratedDApps` entries to the format with reward splits.
+///
+/// All existing dApps get an empty reward split list, meaning the entire reward goes to the reward beneficiary.
+pub struct DAppInfoRewardSplitsMigration<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for DAppInfoRewardSplitsMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut translated: u64 = 0;
+        IntegratedDApps::<T>::translate::<v6::DAppInfo<T::AccountId>, _>(|_, old_dapp_info| {
+            translated.saturating_inc();
+            Some(DAppInfo {
+                owner: old_dapp_info.owner,
+                id: old_dapp_info.id,
+                state: old_dapp_info.state,
+                reward_destination: old_dapp_info.reward_destination,
+                reward_splits: Default::default(),
+            })
+        });
+
+        STORAGE_VERSION.put::<Pallet<T>>();
+
+        log::info!(
+            "Migrated {} dApp info entries to the reward splits format.",
+            translated
+        );
+
+        T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        Ok((IntegratedDApps::<T>::count() as u32).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        assert_eq!(Pallet::<T>::on_chain_storage_version(), STORAGE_VERSION);
+
+        let old_count = u32::decode(&mut &state[..])
+            .map_err(|_| "Failed to decode the pre-upgrade dApp count.")?;
+        assert_eq!(IntegratedDApps::<T>::count(), old_count);
+        assert_eq!(IntegratedDApps::<T>::iter().count() as u32, old_count);
+        assert!(
+            IntegratedDApps::<T>::iter_values().all(|dapp_info| dapp_info.reward_splits.is_empty())
+        );
+
+        Ok(())
+    }
+}
@@ -19,16 +19,21 @@
 use crate::test::mock::*;
 use crate::types::*;
 use crate::{
-    pallet::Config, ActiveProtocolState, ContractStake, CurrentEraInfo, DAppId, DAppTiers,
-    EraRewards, Event, FreezeReason, HistoryCleanupMarker, IntegratedDApps, Ledger, NextDAppId,
-    PeriodEnd, PeriodEndInfo, StakerInfo,
+    pallet::Config, ActiveProtocolState, ContractStake, CurrentEraInfo, DAppId, DAppMetadataOf,
+    DAppTiers, EraRewards, Event, FreezeReason, HistoryCleanupMarker, HoldReason, IntegratedDApps,
+    Ledger, NextDAppId, PeriodEnd, PeriodEndInfo, StakerInfo,
 };
 
 use frame_support::{
     assert_ok, assert_storage_noop,
-    traits::{fungible::InspectFreeze, Get, OnIdle},
+    traits::{
+        fungible::{InspectFreeze, InspectHold},
+        Get, OnIdle,
+    },
     weights::Weight,
+    BoundedVec,
 };
+use sp_core::H256;
 use sp_runtime::{traits::Zero, Perbill};
 use std::collections::HashMap;
 
@@ -41,10 +46,7 @@ pub(crate) struct MemorySnapshot {
     active_protocol_state: ProtocolState,
     next_dapp_id: DAppId,
     current_era_info: EraInfo,
-    integrated_dapps: HashMap<
-        <Test as Config>::SmartContract,
-        DAppInfo<<Test as frame_system::Config>::AccountId>,
-    >,
+    integrated_dapps: HashMap<<Test as Config>::SmartContract, DAppInfoFor<Test>>,
     ledger: HashMap<<Test as frame_system::Config>::AccountId, AccountLedgerFor<Test>>,
     staker_info: HashMap<
         (
@@ -54,9 +56,11 @@ pub(crate) struct MemorySnapshot {
         SingularStakingInfo,
     >,
     contract_stake: HashMap<DAppId, ContractStakeAmount>,
+    contract_stakers: HashMap<DAppId, ContractStakerCount>,
     era_rewards: HashMap<EraNumber, EraRewardSpan<<Test as Config>::EraRewardSpanLength>>,
     period_end: HashMap<PeriodNumber, PeriodEndInfo>,
     dapp_tiers: HashMap<EraNumber, DAppTierRewardsFor<Test>>,
+    dapp_metadata: HashMap<DAppId, DAppMetadataFor<Test>>,
 }
 
 impl MemorySnapshot {
@@ -72,12 +76,21 @@ impl MemorySnapshot {
                 .map(|(k1, k2, v)| ((k1, k2), v))
                 .collect(),
             contract_stake: ContractStake::<Test>::iter().collect(),
+            contract_stakers: ContractStakers::<Test>::iter().collect(),
             era_rewards: EraRewards::<Test>::iter().collect(),
             period_end: PeriodEnd::<Test>::iter().collect(),
             dapp_tiers: DAppTiers::<Test>::iter().collect(),
+            dapp_metadata: DAppMetadataOf::<Test>::iter().collect(),
         }
     }
 
+    /// Returns number of unique stakers on the specified dApp, in the specified period.
+    pub fn number_of_stakers(&self, dapp_id: DAppId, period: PeriodNumber) -> u32 {
+        self.contract_stakers
+            .get(&dapp_id)
+            .map_or(0, |stakers| stakers.get(period))
+    }
+
     /// Returns locked balance in dApp staking for the specified account.
     /// In case no balance is locked, returns zero.
     pub fn locked_balance(&self, account: &AccountId) -> Balance {
@@ -147,6 +160,36 @@ pub(crate) fn assert_set_dapp_reward_beneficiary(
     );
 }
 
+/// Update dApp reward splits and assert success
+pub(crate) fn assert_set_dapp_reward_splits(
+    owner: AccountId,
+    smart_contract: &MockSmartContract,
+    reward_splits: Vec<(AccountId, Perbill)>,
+) {
+    let reward_splits: BoundedVec<_, <Test as Config>::MaxRewardSplits> = reward_splits
+        .try_into()
+        .expect("Must not exceed max number of splits.");
+
+    // Change reward splits
+    assert_ok!(DappStaking::set_dapp_reward_splits(
+        RuntimeOrigin::signed(owner),
+        smart_contract.clone(),
+        reward_splits.clone(),
+    ));
+    System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppRewardSplitsUpdated {
+        smart_contract: smart_contract.clone(),
+        reward_splits: reward_splits.clone(),
+    }));
+
+    // Sanity check & reward splits update
+    assert_eq!(
+        IntegratedDApps::<Test>::get(&smart_contract)
+            .unwrap()
+            .reward_splits,
+        reward_splits
+    );
+}
+
 /// Update dApp owner and assert success.
 /// if `caller` is `None`, `Root` origin is used, otherwise standard `Signed` origin is used.
 pub(crate) fn assert_set_dapp_owner(
@@ -178,6 +221,12 @@ pub(crate) fn assert_set_dapp_owner(
 pub(crate) fn assert_unregister(smart_contract: &MockSmartContract) {
     let pre_snapshot = MemorySnapshot::new();
 
+    let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+    let pre_metadata = pre_snapshot.dapp_metadata.get(&dapp_id);
+    let pre_held_balance = pre_metadata.map(|metadata| {
+        Balances::balance_on_hold(&HoldReason::DAppMetadata.into(), &metadata.depositor)
+    });
+
     // Unregister dApp
     assert_ok!(DappStaking::unregister(
         RuntimeOrigin::root(),
@@ -196,6 +245,107 @@ pub(crate) fn assert_unregister(smart_contract: &MockSmartContract) {
     assert!(!ContractStake::<Test>::contains_key(
         &IntegratedDApps::<Test>::get(&smart_contract).unwrap().id
     ));
+    assert!(!ContractStakers::<Test>::contains_key(&dapp_id));
+
+    // Metadata must be removed & its deposit released
+    assert!(!DAppMetadataOf::<Test>::contains_key(&dapp_id));
+    if let (Some(metadata), Some(pre_held_balance)) = (pre_metadata, pre_held_balance) {
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::DAppMetadata.into(), &metadata.depositor),
+            pre_held_balance - metadata.deposit,
+        );
+    }
+}
+
+/// Set dApp metadata and assert success.
+pub(crate) fn assert_set_dapp_metadata(
+    owner: AccountId,
+    smart_contract: &MockSmartContract,
+    name: &[u8],
+    website: &[u8],
+    category: DAppCategory,
+) {
+    let pre_snapshot = MemorySnapshot::new();
+
+    let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+    let pre_metadata = pre_snapshot.dapp_metadata.get(&dapp_id);
+    let pre_owner_held = Balances::balance_on_hold(&HoldReason::DAppMetadata.into(), &owner);
+    let pre_owner_free = Balances::free_balance(&owner);
+
+    let logo_hash = H256::repeat_byte(0x01);
+    let expected_deposit = <Test as Config>::MetadataDepositBase::get()
+        + <Test as Config>::MetadataDepositPerByte::get() * (name.len() + website.len()) as Balance;
+
+    // Set metadata
+    assert_ok!(DappStaking::set_dapp_metadata(
+        RuntimeOrigin::signed(owner),
+        smart_contract.clone(),
+        name.to_vec().try_into().unwrap(),
+        website.to_vec().try_into().unwrap(),
+        logo_hash,
+        category,
+    ));
+    System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppMetadataSet {
+        smart_contract: smart_contract.clone(),
+        deposit: expected_deposit,
+    }));
+
+    // Verify post-state
+    let metadata = DAppMetadataOf::<Test>::get(&dapp_id).unwrap();
+    assert_eq!(metadata.name.as_slice(), name);
+    assert_eq!(metadata.website.as_slice(), website);
+    assert_eq!(metadata.logo_hash, logo_hash);
+    assert_eq!(metadata.category, category);
+    assert_eq!(metadata.depositor, owner);
+    assert_eq!(metadata.deposit, expected_deposit);
+
+    // Previous deposit is released, if it was held from the owner, and the new one is held
+    let released_from_owner = pre_metadata
+        .filter(|metadata| metadata.depositor == owner)
+        .map_or(Balance::zero(), |metadata| metadata.deposit);
+    assert_eq!(
+        Balances::balance_on_hold(&HoldReason::DAppMetadata.into(), &owner),
+        pre_owner_held - released_from_owner + expected_deposit,
+    );
+    assert_eq!(
+        Balances::free_balance(&owner),
+        pre_owner_free + released_from_owner - expected_deposit,
+    );
+}
+
+/// Clear dApp metadata and assert success.
+pub(crate) fn assert_clear_dapp_metadata(smart_contract: &MockSmartContract) {
+    let pre_snapshot = MemorySnapshot::new();
+
+    let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+    let pre_metadata = pre_snapshot
+        .dapp_metadata
+        .get(&dapp_id)
+        .expect("Metadata must exist in order to be cleared.");
+    let pre_held_balance =
+        Balances::balance_on_hold(&HoldReason::DAppMetadata.into(), &pre_metadata.depositor);
+    let pre_free_balance = Balances::free_balance(&pre_metadata.depositor);
+
+    // Clear metadata
+    assert_ok!(DappStaking::clear_dapp_metadata(
+        RuntimeOrigin::root(),
+        smart_contract.clone(),
+    ));
+    System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppMetadataCleared {
+        smart_contract: smart_contract.clone(),
+        deposit: pre_metadata.deposit,
+    }));
+
+    // Verify post-state
+    assert!(!DAppMetadataOf::<Test>::contains_key(&dapp_id));
+    assert_eq!(
+        Balances::balance_on_hold(&HoldReason::DAppMetadata.into(), &pre_metadata.depositor),
+        pre_held_balance - pre_metadata.deposit,
+    );
+    assert_eq!(
+        Balances::free_balance(&pre_metadata.depositor),
+        pre_free_balance + pre_metadata.deposit,
+    );
 }
 
 /// Lock funds into dApp staking and assert success.
@@ -557,6 +707,14 @@ pub(crate) fn assert_stake(
     );
     assert_eq!(post_contract_stake.latest_stake_era(), Some(stake_era));
 
+    let dapp_id = pre_snapshot.integrated_dapps[&smart_contract].id;
+    let is_new_staker = !matches!(pre_staker_info, Some(pre_staker_info) if pre_staker_info.period_number() == stake_period);
+    assert_eq!(
+        post_snapshot.number_of_stakers(dapp_id, stake_period),
+        pre_snapshot.number_of_stakers(dapp_id, stake_period) + u32::from(is_new_staker),
+        "Number of stakers must increase only if account wasn't staking on the contract in the period."
+    );
+
     // 4. verify era info
     // =========================
     // =========================
@@ -696,6 +854,13 @@ pub(crate) fn assert_unstake(
         "Staked amount must decreased by the 'amount'"
     );
 
+    let dapp_id = pre_snapshot.integrated_dapps[&smart_contract].id;
+    assert_eq!(
+        post_snapshot.number_of_stakers(dapp_id, unstake_period),
+        pre_snapshot.number_of_stakers(dapp_id, unstake_period) - u32::from(is_full_unstake),
+        "Number of stakers must decrease only in case of a full unstake."
+    );
+
     // 4. verify era info
     // =========================
     // =========================
@@ -739,6 +904,13 @@ pub(crate) fn assert_unstake(
 
 /// Claim staker rewards.
 pub(crate) fn assert_claim_staker_rewards(account: AccountId) {
+    assert_claim_staker_rewards_for(account, account);
+}
+
+/// Claim staker rewards of `account`, with the call being made by `caller`.
+///
+/// If `caller` and `account` differ, permissionless variant of the call is used.
+pub(crate) fn assert_claim_staker_rewards_for(caller: AccountId, account: AccountId) {
     let pre_snapshot = MemorySnapshot::new();
     let pre_ledger = pre_snapshot.ledger.get(&account).unwrap();
     let pre_total_issuance = <Test as Config>::Currency::total_issuance();
@@ -818,9 +990,16 @@ pub(crate) fn assert_claim_staker_rewards(account: AccountId) {
     System::reset_events();
 
     // Claim staker rewards & verify all events
-    assert_ok!(DappStaking::claim_staker_rewards(RuntimeOrigin::signed(
-        account
-    ),));
+    if caller == account {
+        assert_ok!(DappStaking::claim_staker_rewards(RuntimeOrigin::signed(
+            account
+        ),));
+    } else {
+        assert_ok!(DappStaking::claim_staker_rewards_for(
+            RuntimeOrigin::signed(caller),
+            account,
+        ));
+    }
 
     let events = dapp_staking_events();
     assert_eq!(events.len(), rewards.len());
@@ -865,6 +1044,17 @@ pub(crate) fn assert_claim_staker_rewards(account: AccountId) {
 
 /// Claim staker rewards.
 pub(crate) fn assert_claim_bonus_reward(account: AccountId, smart_contract: &MockSmartContract) {
+    assert_claim_bonus_reward_for(account, account, smart_contract);
+}
+
+/// Claim bonus reward of `account` for the smart contract, with the call being made by `caller`.
+///
+/// If `caller` and `account` differ, permissionless variant of the call is used.
+pub(crate) fn assert_claim_bonus_reward_for(
+    caller: AccountId,
+    account: AccountId,
+    smart_contract: &MockSmartContract,
+) {
     let pre_snapshot = MemorySnapshot::new();
     let pre_staker_info = pre_snapshot
         .staker_info
@@ -885,10 +1075,18 @@ pub(crate) fn assert_claim_bonus_reward(account: AccountId, smart_contract: &Moc
         * period_end_info.bonus_reward_pool;
 
     // Claim bonus reward & verify event
-    assert_ok!(DappStaking::claim_bonus_reward(
-        RuntimeOrigin::signed(account),
-        smart_contract.clone(),
-    ));
+    if caller == account {
+        assert_ok!(DappStaking::claim_bonus_reward(
+            RuntimeOrigin::signed(account),
+            smart_contract.clone(),
+        ));
+    } else {
+        assert_ok!(DappStaking::claim_bonus_reward_for(
+            RuntimeOrigin::signed(caller),
+            account,
+            smart_contract.clone(),
+        ));
+    }
     System::assert_last_event(RuntimeEvent::DappStaking(Event::BonusReward {
         account,
         smart_contract: *smart_contract,
@@ -931,9 +1129,7 @@ pub(crate) fn assert_claim_dapp_reward(
 ) {
     let pre_snapshot = MemorySnapshot::new();
     let dapp_info = pre_snapshot.integrated_dapps.get(smart_contract).unwrap();
-    let beneficiary = dapp_info.reward_beneficiary();
     let pre_total_issuance = <Test as Config>::Currency::total_issuance();
-    let pre_free_balance = <Test as Config>::Currency::free_balance(beneficiary);
 
     let (expected_reward, expected_tier_id) = {
         let mut info = pre_snapshot
@@ -944,20 +1140,49 @@ pub(crate) fn assert_claim_dapp_reward(
 
         info.try_claim(dapp_info.id).unwrap()
     };
+    let (expected_payouts, expected_skipped) = dapp_info.split_reward(
+        expected_reward,
+        <Test as Config>::Currency::minimum_balance(),
+    );
+    let pre_free_balances: Vec<_> = expected_payouts
+        .iter()
+        .map(|(beneficiary, _)| <Test as Config>::Currency::free_balance(beneficiary))
+        .collect();
 
-    // Claim dApp reward & verify event
+    // Claim dApp reward & verify events
     assert_ok!(DappStaking::claim_dapp_reward(
         RuntimeOrigin::signed(account),
         smart_contract.clone(),
         era,
     ));
-    System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppReward {
-        beneficiary: beneficiary.clone(),
-        smart_contract: smart_contract.clone(),
-        tier_id: expected_tier_id,
-        era,
-        amount: expected_reward,
-    }));
+
+    let events = dapp_staking_events();
+    let (skipped_events, reward_events) = events
+        [events.len() - expected_payouts.len() - expected_skipped.len()..]
+        .split_at(expected_skipped.len());
+    for ((account, amount), event) in expected_skipped.iter().zip(skipped_events.iter()) {
+        assert_eq!(
+            event,
+            &Event::DAppRewardSplitSkipped {
+                account: account.clone(),
+                smart_contract: smart_contract.clone(),
+                era,
+                amount: *amount,
+            }
+        );
+    }
+    for ((beneficiary, amount), event) in expected_payouts.iter().zip(reward_events.iter()) {
+        assert_eq!(
+            event,
+            &Event::DAppReward {
+                beneficiary: beneficiary.clone(),
+                smart_contract: smart_contract.clone(),
+                tier_id: expected_tier_id,
+                era,
+                amount: *amount,
+            }
+        );
+    }
 
     // Verify post-state
 
@@ -968,12 +1193,15 @@ pub(crate) fn assert_claim_dapp_reward(
         "Total issuance must increase by the reward amount."
     );
 
-    let post_free_balance = <Test as Config>::Currency::free_balance(beneficiary);
-    assert_eq!(
-        post_free_balance,
-        pre_free_balance + expected_reward,
-        "Free balance must increase by the reward amount."
-    );
+    for ((beneficiary, amount), pre_free_balance) in
+        expected_payouts.iter().zip(pre_free_balances.into_iter())
+    {
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(beneficiary),
+            pre_free_balance + amount,
+            "Free balance must increase by the reward split amount."
+        );
+    }
 
     let post_snapshot = MemorySnapshot::new();
     let mut info = post_snapshot
@@ -988,6 +1216,152 @@ pub(crate) fn assert_claim_dapp_reward(
     );
 }
 
+/// Claim dApp rewards for the specified range of eras.
+pub(crate) fn assert_claim_dapp_rewards_range(
+    account: AccountId,
+    smart_contract: &MockSmartContract,
+    from_era: EraNumber,
+    to_era: EraNumber,
+) {
+    let pre_snapshot = MemorySnapshot::new();
+    let dapp_info = pre_snapshot.integrated_dapps.get(smart_contract).unwrap();
+    let pre_total_issuance = <Test as Config>::Currency::total_issuance();
+
+    // Calculate the expected rewards for the eras which will be processed
+    let max_eras = <Test as Config>::MaxDAppRewardClaimEras::get();
+    let last_processed_era = to_era.min(from_era + max_eras - 1);
+    let oldest_claimable_period =
+        DappStaking::oldest_claimable_period(pre_snapshot.active_protocol_state.period_number());
+
+    let expected_rewards: Vec<_> = (from_era..=last_processed_era)
+        .filter_map(|era| {
+            let mut info = pre_snapshot.dapp_tiers.get(&era)?.clone();
+            if info.period < oldest_claimable_period {
+                return None;
+            }
+            info.try_claim(dapp_info.id)
+                .ok()
+                .map(|(amount, tier_id)| (era, tier_id, amount))
+        })
+        .collect();
+    let expected_reward_sum: Balance = expected_rewards.iter().map(|(_, _, amount)| amount).sum();
+
+    // Each era reward is split separately, so the rounding remainders go to the reward beneficiary
+    let minimum_payout = <Test as Config>::Currency::minimum_balance();
+    let expected_payouts: Vec<_> = expected_rewards
+        .iter()
+        .flat_map(|(era, tier_id, amount)| {
+            dapp_info
+                .split_reward(*amount, minimum_payout)
+                .0
+                .into_iter()
+                .map(move |(beneficiary, amount)| (*era, *tier_id, beneficiary, amount))
+        })
+        .collect();
+    let expected_skipped: Vec<_> = expected_rewards
+        .iter()
+        .flat_map(|(era, _, amount)| {
+            dapp_info
+                .split_reward(*amount, minimum_payout)
+                .1
+                .into_iter()
+                .map(move |(account, amount)| (*era, account, amount))
+        })
+        .collect();
+    let mut expected_increases = HashMap::<AccountId, Balance>::new();
+    for (_, _, beneficiary, amount) in &expected_payouts {
+        *expected_increases.entry(*beneficiary).or_default() += amount;
+    }
+    let pre_free_balances: HashMap<_, _> = expected_increases
+        .keys()
+        .map(|beneficiary| {
+            (
+                *beneficiary,
+                <Test as Config>::Currency::free_balance(beneficiary),
+            )
+        })
+        .collect();
+
+    // Claim dApp rewards & verify events
+    assert_ok!(DappStaking::claim_dapp_rewards_range(
+        RuntimeOrigin::signed(account),
+        smart_contract.clone(),
+        from_era,
+        to_era,
+    ));
+
+    let events = dapp_staking_events();
+    let (reward_events, last_event) = events.split_at(events.len() - 1);
+    assert_eq!(
+        last_event[0],
+        Event::DAppRewardsRangeProcessed {
+            smart_contract: smart_contract.clone(),
+            from_era,
+            to_era,
+            last_processed_era,
+        }
+    );
+    let (skipped_events, reward_events) = reward_events
+        [reward_events.len() - expected_payouts.len() - expected_skipped.len()..]
+        .split_at(expected_skipped.len());
+    for ((era, account, amount), event) in expected_skipped.iter().zip(skipped_events.iter()) {
+        assert_eq!(
+            event,
+            &Event::DAppRewardSplitSkipped {
+                account: account.clone(),
+                smart_contract: smart_contract.clone(),
+                era: *era,
+                amount: *amount,
+            }
+        );
+    }
+    for ((era, tier_id, beneficiary, amount), event) in
+        expected_payouts.iter().zip(reward_events.iter())
+    {
+        assert_eq!(
+            event,
+            &Event::DAppReward {
+                beneficiary: beneficiary.clone(),
+                smart_contract: smart_contract.clone(),
+                tier_id: *tier_id,
+                era: *era,
+                amount: *amount,
+            }
+        );
+    }
+
+    // Verify post-state
+
+    let post_total_issuance = <Test as Config>::Currency::total_issuance();
+    assert_eq!(
+        post_total_issuance,
+        pre_total_issuance + expected_reward_sum,
+        "Total issuance must increase by the reward amount."
+    );
+
+    for (beneficiary, increase) in expected_increases {
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&beneficiary),
+            pre_free_balances[&beneficiary] + increase,
+            "Free balance must increase by the accumulated reward split amount."
+        );
+    }
+
+    let post_snapshot = MemorySnapshot::new();
+    for (era, _, _) in expected_rewards {
+        let mut info = post_snapshot
+            .dapp_tiers
+            .get(&era)
+            .expect("Entry must exist.")
+            .clone();
+        assert_eq!(
+            info.try_claim(dapp_info.id),
+            Err(DAppTierError::RewardAlreadyClaimed),
+            "It must not be possible to claim the same reward twice!.",
+        );
+    }
+}
+
 /// Unstake some funds from the specified unregistered smart contract.
 pub(crate) fn assert_unstake_from_unregistered(
     account: AccountId,
@@ -1083,6 +1457,215 @@ pub(crate) fn assert_unstake_from_unregistered(
     }
 }
 
+/// Move stake from one smart contract to another and verify post state.
+pub(crate) fn assert_move_stake(
+    account: AccountId,
+    from: &MockSmartContract,
+    to: &MockSmartContract,
+    amount: Balance,
+) {
+    let pre_snapshot = MemorySnapshot::new();
+    let pre_ledger = pre_snapshot.ledger.get(&account).unwrap();
+    let pre_from_staker_info = *pre_snapshot
+        .staker_info
+        .get(&(account, *from))
+        .expect("Entry must exist since 'move_stake' is being called.");
+    let pre_to_staker_info = pre_snapshot.staker_info.get(&(account, *to));
+    let is_from_registered = pre_snapshot.integrated_dapps[from].is_registered();
+    let from_dapp_id = pre_snapshot.integrated_dapps[from].id;
+    let to_dapp_id = pre_snapshot.integrated_dapps[to].id;
+    let pre_to_contract_stake = pre_snapshot
+        .contract_stake
+        .get(&to_dapp_id)
+        .cloned()
+        .unwrap_or_default();
+
+    let period = pre_snapshot.active_protocol_state.period_number();
+    let subperiod = pre_snapshot.active_protocol_state.subperiod();
+    let current_era = pre_snapshot.active_protocol_state.era;
+
+    // Move everything if contract is unregistered or if we expect to go below the minimum stake amount
+    let minimum_stake_amount: Balance = <Test as Config>::MinimumStakeAmount::get();
+    let is_full_move = !is_from_registered
+        || pre_from_staker_info
+            .total_staked_amount()
+            .saturating_sub(amount)
+            < minimum_stake_amount;
+    let amount = if is_full_move {
+        pre_from_staker_info.total_staked_amount()
+    } else {
+        amount
+    };
+
+    // Move stake & verify event
+    assert_ok!(DappStaking::move_stake(
+        RuntimeOrigin::signed(account),
+        from.clone(),
+        to.clone(),
+        amount
+    ));
+    System::assert_last_event(RuntimeEvent::DappStaking(Event::StakeMoved {
+        account,
+        from: from.clone(),
+        to: to.clone(),
+        amount,
+    }));
+
+    // Verify post-state
+    let post_snapshot = MemorySnapshot::new();
+    let post_ledger = post_snapshot.ledger.get(&account).unwrap();
+
+    // 1. verify staker info
+    // =====================
+    // =====================
+    let mut expected_from_staker_info = pre_from_staker_info;
+    let (voting_amount, build_and_earn_amount) =
+        expected_from_staker_info.unstake(amount, current_era, subperiod);
+    if is_full_move {
+        assert!(
+            !StakerInfo::<Test>::contains_key(&account, from),
+            "Entry must be deleted since it was a full move."
+        );
+    } else {
+        let post_from_staker_info = post_snapshot.staker_info[&(account, *from)];
+        assert_eq!(
+            post_from_staker_info.total_staked_amount(),
+            pre_from_staker_info.total_staked_amount() - amount,
+            "Total staked amount must decrease by the 'amount'"
+        );
+        assert_eq!(
+            post_from_staker_info.staked_amount(Subperiod::Voting),
+            expected_from_staker_info.staked_amount(Subperiod::Voting),
+        );
+        assert_eq!(
+            post_from_staker_info.is_loyal(),
+            pre_from_staker_info.is_loyal(),
+            "Loyalty flag must not change when stake is moved."
+        );
+    }
+
+    let post_to_staker_info = post_snapshot
+        .staker_info
+        .get(&(account, *to))
+        .expect("Entry must exist since 'move_stake' operation was successful.");
+    let is_new_entry = match pre_to_staker_info {
+        Some(staker_info) if staker_info.period_number() == period => {
+            assert_eq!(post_to_staker_info.is_loyal(), staker_info.is_loyal());
+            assert_eq!(
+                post_to_staker_info.staked_amount(Subperiod::Voting),
+                staker_info.staked_amount(Subperiod::Voting) + voting_amount,
+            );
+            assert_eq!(
+                post_to_staker_info.staked_amount(Subperiod::BuildAndEarn),
+                staker_info.staked_amount(Subperiod::BuildAndEarn) + build_and_earn_amount,
+            );
+            false
+        }
+        _ => {
+            assert_eq!(
+                post_to_staker_info.is_loyal(),
+                subperiod == Subperiod::Voting || !voting_amount.is_zero(),
+                "New entry must be loyal if voting stake was moved into it."
+            );
+            assert_eq!(
+                post_to_staker_info.staked_amount(Subperiod::Voting),
+                voting_amount
+            );
+            assert_eq!(
+                post_to_staker_info.staked_amount(Subperiod::BuildAndEarn),
+                build_and_earn_amount
+            );
+            true
+        }
+    };
+    assert_eq!(post_to_staker_info.period_number(), period);
+    assert_eq!(post_to_staker_info.era(), current_era + 1);
+
+    // 2. verify ledger
+    // =====================
+    // =====================
+    let mut expected_ledger = pre_ledger.clone();
+    if is_full_move {
+        expected_ledger.contract_stake_count.saturating_dec();
+    }
+    if is_new_entry {
+        expected_ledger.contract_stake_count.saturating_inc();
+    }
+    assert_eq!(
+        post_ledger, &expected_ledger,
+        "Only the number of staked contracts can change in the ledger."
+    );
+
+    // 3. verify contract stake
+    // =========================
+    // =========================
+    // Stake is moved from the next era for both contracts, keeping the subperiod split.
+    if is_from_registered {
+        let pre_from_contract_stake = &pre_snapshot.contract_stake[&from_dapp_id];
+        let post_from_contract_stake = post_snapshot
+            .contract_stake
+            .get(&from_dapp_id)
+            .cloned()
+            .unwrap_or_default();
+        assert_eq!(
+            post_from_contract_stake.total_staked_amount(period),
+            pre_from_contract_stake.total_staked_amount(period) - amount,
+            "Staked amount must decrease by the 'amount'"
+        );
+        assert_eq!(
+            post_from_contract_stake.get(current_era, period),
+            pre_from_contract_stake.get(current_era, period),
+            "Staked amount for the ongoing era must remain unchanged."
+        );
+        let pre_from_next_era = pre_from_contract_stake
+            .get(current_era + 1, period)
+            .unwrap_or_default();
+        let post_from_next_era = post_from_contract_stake
+            .get(current_era + 1, period)
+            .unwrap_or_default();
+        assert_eq!(
+            post_from_next_era.for_type(Subperiod::Voting),
+            pre_from_next_era.for_type(Subperiod::Voting) - voting_amount
+        );
+        assert_eq!(
+            post_from_next_era.for_type(Subperiod::BuildAndEarn),
+            pre_from_next_era.for_type(Subperiod::BuildAndEarn) - build_and_earn_amount
+        );
+    } else {
+        assert!(!post_snapshot.contract_stake.contains_key(&from_dapp_id));
+    }
+    let post_to_contract_stake = &post_snapshot.contract_stake[&to_dapp_id];
+    assert_eq!(
+        post_to_contract_stake.get(current_era, period),
+        pre_to_contract_stake.get(current_era, period),
+        "Staked amount for the ongoing era must remain unchanged."
+    );
+    let pre_to_next_era = pre_to_contract_stake
+        .get(current_era + 1, period)
+        .unwrap_or_default();
+    let post_to_next_era = post_to_contract_stake
+        .get(current_era + 1, period)
+        .unwrap_or_default();
+    assert_eq!(
+        post_to_next_era.for_type(Subperiod::Voting),
+        pre_to_next_era.for_type(Subperiod::Voting) + voting_amount,
+        "Voting stake for the next era must increase by the moved voting amount"
+    );
+    assert_eq!(
+        post_to_next_era.for_type(Subperiod::BuildAndEarn),
+        pre_to_next_era.for_type(Subperiod::BuildAndEarn) + build_and_earn_amount,
+        "Build&earn stake for the next era must increase by the moved build&earn amount"
+    );
+
+    // 4. verify era info
+    // =========================
+    // =========================
+    assert_eq!(
+        post_snapshot.current_era_info, pre_snapshot.current_era_info,
+        "Era info must not change since total stake remains the same."
+    );
+}
+
 /// Cleanup expired DB entries for the account and verify post state.
 pub(crate) fn assert_cleanup_expired_entries(account: AccountId) {
     let pre_snapshot = MemorySnapshot::new();
@@ -1139,6 +1722,26 @@ pub(crate) fn assert_cleanup_expired_entries(account: AccountId) {
     );
 }
 
+/// Set restake mode for the account and verify post state.
+pub(crate) fn assert_set_restake(account: AccountId, enabled: bool) {
+    let pre_snapshot = MemorySnapshot::new();
+
+    assert_ok!(DappStaking::set_restake(
+        RuntimeOrigin::signed(account),
+        enabled
+    ));
+    System::assert_last_event(RuntimeEvent::DappStaking(Event::RestakeModeSet {
+        account,
+        enabled,
+    }));
+
+    // Verify post-state, only the restake flag should have changed
+    let post_snapshot = MemorySnapshot::new();
+    let mut expected_ledger = pre_snapshot.ledger[&account].clone();
+    expected_ledger.restake = enabled;
+    assert_eq!(post_snapshot.ledger[&account], expected_ledger);
+}
+
 /// Asserts correct transitions of the protocol after a block has been produced.
 pub(crate) fn assert_block_bump(pre_snapshot: &MemorySnapshot) {
     let current_block_number = System::block_number();
@@ -1364,12 +1967,6 @@ pub(crate) fn assert_on_idle_cleanup() {
         }
     };
 
-    // Check if any span or tiers cleanup is needed.
-    let is_era_span_cleanup_expected =
-        pre_era_rewards[&pre_cleanup_marker.era_reward_index].last_era() < oldest_valid_era;
-    let is_dapp_tiers_cleanup_expected = pre_cleanup_marker.dapp_tiers_index > 0
-        && pre_cleanup_marker.dapp_tiers_index < oldest_valid_era;
-
     // Check if period end info should be cleaned up
     let maybe_period_end_cleanup = match protocol_state
         .period_number()
@@ -1383,29 +1980,29 @@ pub(crate) fn assert_on_idle_cleanup() {
 
     DappStaking::on_idle(System::block_number(), Weight::MAX);
 
-    // Post checks
+    // Post checks - with enough weight, all expired entries must be cleaned up.
     let post_cleanup_marker = HistoryCleanupMarker::<Test>::get();
 
-    if is_era_span_cleanup_expected {
-        assert!(!EraRewards::<Test>::contains_key(
-            pre_cleanup_marker.era_reward_index
-        ));
-        let span_length: EraNumber = <Test as Config>::EraRewardSpanLength::get();
-        assert_eq!(
-            post_cleanup_marker.era_reward_index,
-            pre_cleanup_marker.era_reward_index + span_length
-        );
-    }
-    if is_dapp_tiers_cleanup_expected {
-        assert!(
-            !DAppTiers::<Test>::contains_key(pre_cleanup_marker.dapp_tiers_index),
-            "Sanity check."
-        );
-        assert_eq!(
-            post_cleanup_marker.dapp_tiers_index,
-            pre_cleanup_marker.dapp_tiers_index + 1
-        )
-    }
+    assert!(
+        EraRewards::<Test>::iter().all(|(_, span)| span.last_era() >= oldest_valid_era),
+        "All expired era reward spans must be cleaned up."
+    );
+    assert!(
+        pre_era_rewards
+            .keys()
+            .filter(|index| **index < post_cleanup_marker.era_reward_index)
+            .all(|index| !EraRewards::<Test>::contains_key(index)),
+        "All spans before the cleanup marker must be cleaned up."
+    );
+
+    assert!(
+        DAppTiers::<Test>::iter_keys().all(|era| era >= oldest_valid_era),
+        "All expired dApp tiers entries must be cleaned up."
+    );
+    assert_eq!(
+        post_cleanup_marker.dapp_tiers_index,
+        pre_cleanup_marker.dapp_tiers_index.max(oldest_valid_era)
+    );
 
     if let Some(period) = maybe_period_end_cleanup {
         assert!(!PeriodEnd::<Test>::contains_key(period));
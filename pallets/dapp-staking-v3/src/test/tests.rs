@@ -18,16 +18,23 @@
 
 use crate::test::{mock::*, testing_utils::*};
 use crate::{
-    pallet::Config, ActiveProtocolState, DAppId, EraRewards, Error, Event, ForcingType,
-    IntegratedDApps, Ledger, NextDAppId, PeriodNumber, StakerInfo, Subperiod, TierConfig,
+    pallet::Config, ActiveProtocolState, ContractStake, CurrentEraInfo, DAppCategory, DAppId,
+    DAppTiers, EraNumber, EraRewards, Error, Event, ForcingType, FreezeReason,
+    HistoryCleanupMarker, HoldReason, IntegratedDApps, Ledger, NextDAppId, PeriodEnd, PeriodNumber,
+    StakerInfo, Subperiod, TierConfig, WeightInfo,
 };
 
 use frame_support::{
     assert_noop, assert_ok, assert_storage_noop,
     error::BadOrigin,
-    traits::{fungible::Unbalanced as FunUnbalanced, Currency, Get, OnFinalize, OnInitialize},
+    traits::{
+        fungible::{InspectFreeze, InspectHold, Unbalanced as FunUnbalanced},
+        Currency, Get, OnFinalize, OnIdle, OnInitialize,
+    },
+    weights::Weight,
+    BoundedVec,
 };
-use sp_runtime::traits::Zero;
+use sp_runtime::{traits::Zero, PerThing, Perbill, TokenError};
 
 use astar_primitives::{
     dapp_staking::{CycleConfiguration, SmartContractHandle},
@@ -156,6 +163,66 @@ fn maintenace_mode_call_filtering_works() {
             DappStaking::force(RuntimeOrigin::root(), ForcingType::Era),
             Error::<Test>::Disabled
         );
+        assert_noop!(
+            DappStaking::set_restake(RuntimeOrigin::signed(1), true),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::claim_staker_rewards_for(RuntimeOrigin::signed(1), 2),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::claim_bonus_reward_for(
+                RuntimeOrigin::signed(1),
+                2,
+                MockSmartContract::wasm(1 as AccountId)
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(1),
+                MockSmartContract::wasm(1 as AccountId),
+                MockSmartContract::wasm(2 as AccountId),
+                100
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::claim_dapp_rewards_range(
+                RuntimeOrigin::signed(1),
+                MockSmartContract::wasm(1 as AccountId),
+                1,
+                2
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(1),
+                MockSmartContract::wasm(1 as AccountId),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                DAppCategory::Other,
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::clear_dapp_metadata(
+                RuntimeOrigin::root(),
+                MockSmartContract::wasm(1 as AccountId),
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::set_dapp_reward_splits(
+                RuntimeOrigin::signed(1),
+                MockSmartContract::wasm(1 as AccountId),
+                Default::default(),
+            ),
+            Error::<Test>::Disabled
+        );
     })
 }
 
@@ -357,6 +424,86 @@ fn set_dapp_reward_beneficiary_fails() {
     })
 }
 
+#[test]
+fn set_dapp_reward_splits_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        // Prepare & register smart contract
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+        assert_register(owner, &smart_contract);
+
+        // Update reward splits
+        assert!(IntegratedDApps::<Test>::get(&smart_contract)
+            .unwrap()
+            .reward_splits
+            .is_empty());
+        assert_set_dapp_reward_splits(owner, &smart_contract, vec![(3, Perbill::from_percent(40))]);
+        assert_set_dapp_reward_splits(
+            owner,
+            &smart_contract,
+            vec![
+                (3, Perbill::from_percent(50)),
+                (4, Perbill::from_percent(25)),
+                (owner, Perbill::from_percent(25)),
+            ],
+        );
+        assert_set_dapp_reward_splits(owner, &smart_contract, vec![]);
+    })
+}
+
+#[test]
+fn set_dapp_reward_splits_fails() {
+    ExtBuilder::build().execute_with(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+        let reward_splits: BoundedVec<_, <Test as Config>::MaxRewardSplits> =
+            BoundedVec::try_from(vec![(3, Perbill::from_percent(40))]).unwrap();
+
+        // Contract doesn't exist yet
+        assert_noop!(
+            DappStaking::set_dapp_reward_splits(
+                RuntimeOrigin::signed(owner),
+                smart_contract,
+                reward_splits.clone(),
+            ),
+            Error::<Test>::ContractNotFound
+        );
+
+        // Non-owner cannnot change reward splits
+        assert_register(owner, &smart_contract);
+        assert_noop!(
+            DappStaking::set_dapp_reward_splits(
+                RuntimeOrigin::signed(owner + 1),
+                smart_contract,
+                reward_splits,
+            ),
+            Error::<Test>::OriginNotOwner
+        );
+
+        // Invalid reward splits
+        for invalid_splits in [
+            vec![(3, Perbill::zero())],
+            vec![
+                (3, Perbill::from_percent(10)),
+                (3, Perbill::from_percent(10)),
+            ],
+            vec![
+                (3, Perbill::from_percent(70)),
+                (4, Perbill::from_percent(31)),
+            ],
+        ] {
+            assert_noop!(
+                DappStaking::set_dapp_reward_splits(
+                    RuntimeOrigin::signed(owner),
+                    smart_contract,
+                    BoundedVec::try_from(invalid_splits).unwrap(),
+                ),
+                Error::<Test>::InvalidRewardSplits
+            );
+        }
+    })
+}
+
 #[test]
 fn set_dapp_owner_is_ok() {
     ExtBuilder::build().execute_with(|| {
@@ -456,6 +603,253 @@ fn unregister_fails() {
     })
 }
 
+#[test]
+fn unregister_with_metadata_releases_deposit() {
+    ExtBuilder::build().execute_with(|| {
+        // Prepare dApp with metadata
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+        assert_register(owner, &smart_contract);
+        assert_set_dapp_metadata(
+            owner,
+            &smart_contract,
+            b"dApp",
+            b"dapp.io",
+            DAppCategory::DeFi,
+        );
+
+        // Metadata must be removed & deposit released
+        assert_unregister(&smart_contract);
+        assert!(DappStaking::dapp_metadata(&smart_contract).is_none());
+        assert!(Balances::balance_on_hold(&HoldReason::DAppMetadata.into(), &owner).is_zero());
+    })
+}
+
+#[test]
+fn set_dapp_metadata_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        // Prepare dApp
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+        assert_register(owner, &smart_contract);
+
+        // Set metadata for the first time, and then update it with a longer & shorter one
+        assert_set_dapp_metadata(
+            owner,
+            &smart_contract,
+            b"dApp",
+            b"dapp.io",
+            DAppCategory::DeFi,
+        );
+        assert_set_dapp_metadata(
+            owner,
+            &smart_contract,
+            b"dApp Name",
+            b"https://dapp.io",
+            DAppCategory::Gaming,
+        );
+        assert_set_dapp_metadata(owner, &smart_contract, b"", b"", DAppCategory::Other);
+
+        // Metadata is readable via the getter
+        let metadata = DappStaking::dapp_metadata(&smart_contract).unwrap();
+        assert!(metadata.name.is_empty());
+        assert_eq!(metadata.category, DAppCategory::Other);
+        assert_eq!(
+            metadata.deposit,
+            <Test as Config>::MetadataDepositBase::get()
+        );
+    })
+}
+
+#[test]
+fn set_dapp_metadata_after_owner_change_releases_previous_deposit() {
+    ExtBuilder::build().execute_with(|| {
+        // Prepare dApp with metadata
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+        assert_register(owner, &smart_contract);
+        assert_set_dapp_metadata(
+            owner,
+            &smart_contract,
+            b"dApp",
+            b"dapp.io",
+            DAppCategory::DeFi,
+        );
+
+        // Change the owner, and set the metadata again
+        let new_owner = 2;
+        assert_set_dapp_owner(Some(owner), &smart_contract, new_owner);
+        assert_set_dapp_metadata(
+            new_owner,
+            &smart_contract,
+            b"dApp",
+            b"dapp.io",
+            DAppCategory::DeFi,
+        );
+
+        // Previous owner's deposit must have been released
+        assert!(Balances::balance_on_hold(&HoldReason::DAppMetadata.into(), &owner).is_zero());
+    })
+}
+
+#[test]
+fn set_dapp_metadata_deposit_cannot_use_locked_funds() {
+    ExtBuilder::build().execute_with(|| {
+        // Prepare dApp, and lock all of the owner's funds
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+        assert_register(owner, &smart_contract);
+
+        let free_balance = Balances::free_balance(&owner);
+        assert_lock(owner, free_balance);
+
+        // Deposit cannot be covered by the funds locked in dApp staking
+        assert_noop!(
+            DappStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(owner),
+                smart_contract,
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                DAppCategory::Other,
+            ),
+            TokenError::FundsUnavailable
+        );
+
+        // Once there are enough funds on top of the locked amount, metadata can be set
+        Balances::make_free_balance_be(&owner, free_balance * 2);
+        assert_set_dapp_metadata(owner, &smart_contract, b"", b"", DAppCategory::Other);
+        assert_eq!(
+            Balances::balance_frozen(&FreezeReason::DAppStaking.into(), &owner),
+            free_balance
+        );
+    })
+}
+
+#[test]
+fn set_dapp_metadata_fails() {
+    ExtBuilder::build().execute_with(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+
+        // Cannot set metadata for contract which doesn't exist
+        assert_noop!(
+            DappStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(owner),
+                smart_contract,
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                DAppCategory::Other,
+            ),
+            Error::<Test>::ContractNotFound
+        );
+
+        // Only owner can set the metadata
+        assert_register(owner, &smart_contract);
+        assert_noop!(
+            DappStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(owner + 1),
+                smart_contract,
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                DAppCategory::Other,
+            ),
+            Error::<Test>::OriginNotOwner
+        );
+
+        // Owner must be able to cover the deposit
+        let poor_owner = 1337;
+        let poor_smart_contract = MockSmartContract::Wasm(4);
+        assert_register(poor_owner, &poor_smart_contract);
+        assert!(
+            Balances::free_balance(&poor_owner).is_zero(),
+            "Sanity check"
+        );
+        assert_noop!(
+            DappStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(poor_owner),
+                poor_smart_contract,
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                DAppCategory::Other,
+            ),
+            TokenError::FundsUnavailable
+        );
+
+        // Cannot set metadata for unregistered dApp
+        assert_unregister(&smart_contract);
+        assert_noop!(
+            DappStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(owner),
+                smart_contract,
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                DAppCategory::Other,
+            ),
+            Error::<Test>::NotOperatedDApp
+        );
+    })
+}
+
+#[test]
+fn clear_dapp_metadata_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        // Prepare dApp with metadata
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+        assert_register(owner, &smart_contract);
+        assert_set_dapp_metadata(
+            owner,
+            &smart_contract,
+            b"dApp",
+            b"dapp.io",
+            DAppCategory::DeFi,
+        );
+
+        // Clear it and verify it's no longer readable
+        assert_clear_dapp_metadata(&smart_contract);
+        assert!(DappStaking::dapp_metadata(&smart_contract).is_none());
+    })
+}
+
+#[test]
+fn clear_dapp_metadata_fails() {
+    ExtBuilder::build().execute_with(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+
+        // Cannot clear metadata for contract which doesn't exist
+        assert_noop!(
+            DappStaking::clear_dapp_metadata(RuntimeOrigin::root(), smart_contract),
+            Error::<Test>::ContractNotFound
+        );
+
+        // Cannot clear metadata which doesn't exist
+        assert_register(owner, &smart_contract);
+        assert_noop!(
+            DappStaking::clear_dapp_metadata(RuntimeOrigin::root(), smart_contract),
+            Error::<Test>::NoDAppMetadata
+        );
+
+        // Only manager origin can clear the metadata, not even the owner
+        assert_set_dapp_metadata(
+            owner,
+            &smart_contract,
+            b"dApp",
+            b"dapp.io",
+            DAppCategory::DeFi,
+        );
+        assert_noop!(
+            DappStaking::clear_dapp_metadata(RuntimeOrigin::signed(owner), smart_contract),
+            BadOrigin
+        );
+    })
+}
+
 #[test]
 fn lock_is_ok() {
     ExtBuilder::build().execute_with(|| {
@@ -1695,6 +2089,84 @@ fn claim_dapp_reward_works() {
     })
 }
 
+#[test]
+fn claim_dapp_reward_with_reward_splits_works() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Split the reward between multiple accounts, with the remainder going to the beneficiary
+        assert_set_dapp_reward_beneficiary(dev_account, &smart_contract, Some(5));
+        assert_set_dapp_reward_splits(
+            dev_account,
+            &smart_contract,
+            vec![
+                (3, Perbill::from_percent(33)),
+                (4, Perbill::from_percent(17)),
+            ],
+        );
+
+        // Advance a few eras so we have multiple entries for reward claiming
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 4);
+        assert_eq!(ActiveProtocolState::<Test>::get().era, 5, "Sanity check");
+
+        assert_claim_dapp_reward(account, &smart_contract, 2);
+        assert_claim_dapp_rewards_range(account, &smart_contract, 3, 4);
+
+        // Full split, nothing is left for the beneficiary
+        assert_set_dapp_reward_splits(
+            dev_account,
+            &smart_contract,
+            vec![
+                (3, Perbill::from_percent(50)),
+                (4, Perbill::from_percent(50)),
+            ],
+        );
+        advance_to_next_era();
+        assert_claim_dapp_reward(
+            account,
+            &smart_contract,
+            ActiveProtocolState::<Test>::get().era - 1,
+        );
+
+        // Share below the existential deposit is skipped & paid out to the beneficiary instead
+        advance_to_next_era();
+        let era = ActiveProtocolState::<Test>::get().era - 1;
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+        let (reward, tier_id) = DAppTiers::<Test>::get(&era)
+            .unwrap()
+            .try_claim(dapp_id)
+            .unwrap();
+        let tiny_share =
+            Perbill::from_parts(((Perbill::ACCURACY as Balance + reward - 1) / reward) as u32);
+        assert_eq!(tiny_share.mul_floor(reward), 1, "Sanity check");
+        assert!(1 < EXISTENTIAL_DEPOSIT, "Sanity check");
+
+        assert_set_dapp_reward_splits(dev_account, &smart_contract, vec![(3, tiny_share)]);
+        assert_claim_dapp_reward(account, &smart_contract, era);
+        System::assert_has_event(RuntimeEvent::DappStaking(Event::DAppRewardSplitSkipped {
+            account: 3,
+            smart_contract: smart_contract.clone(),
+            era,
+            amount: 1,
+        }));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppReward {
+            beneficiary: 5,
+            smart_contract,
+            tier_id,
+            era,
+            amount: reward,
+        }));
+    })
+}
+
 #[test]
 fn claim_dapp_reward_from_non_existing_contract_fails() {
     ExtBuilder::build().execute_with(|| {
@@ -1868,7 +2340,7 @@ fn claim_dapp_reward_fails_due_to_payout_failure() {
 }
 
 #[test]
-fn unstake_from_unregistered_is_ok() {
+fn claim_dapp_rewards_range_works() {
     ExtBuilder::build().execute_with(|| {
         // Register smart contract, lock&stake some amount
         let smart_contract = MockSmartContract::wasm(1 as AccountId);
@@ -1879,14 +2351,462 @@ fn unstake_from_unregistered_is_ok() {
         assert_lock(account, amount);
         assert_stake(account, &smart_contract, amount);
 
-        // Unregister the smart contract, and unstake from it.
-        assert_unregister(&smart_contract);
-        assert_unstake_from_unregistered(account, &smart_contract);
-    })
-}
+        // Advance a few eras so we have multiple entries for reward claiming
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 4);
+        assert_eq!(ActiveProtocolState::<Test>::get().era, 5, "Sanity check");
 
-#[test]
-fn unstake_from_unregistered_fails_for_active_contract() {
+        // Claim a single era first, it should be skipped when claiming the range.
+        assert_claim_dapp_reward(account, &smart_contract, 3);
+
+        // Era 1 has no rewards for the dApp, era 3 has already been claimed.
+        assert_claim_dapp_rewards_range(account, &smart_contract, 1, 4);
+
+        // Repeated claim is a no-op since all the rewards have been claimed.
+        assert_claim_dapp_rewards_range(account, &smart_contract, 1, 4);
+        let events = dapp_staking_events();
+        assert!(
+            matches!(
+                events[events.len() - 2],
+                Event::DAppRewardsRangeProcessed { .. }
+            ),
+            "No rewards must be paid out in the repeated claim."
+        );
+    })
+}
+
+#[test]
+fn claim_dapp_rewards_range_is_bounded() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Advance enough eras so the range exceeds the limit.
+        let max_eras = <Test as Config>::MaxDAppRewardClaimEras::get();
+        let (from_era, to_era) = (2, 2 + max_eras + 1);
+        advance_to_era(to_era + 1);
+
+        // Only part of the range should be processed
+        assert_claim_dapp_rewards_range(account, &smart_contract, from_era, to_era);
+        let next_era = from_era + max_eras;
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+        assert!(DAppTiers::<Test>::get(next_era)
+            .unwrap()
+            .try_claim(dapp_id)
+            .is_ok());
+
+        // Remaining eras can be claimed with another call
+        assert_claim_dapp_rewards_range(account, &smart_contract, next_era, to_era);
+    })
+}
+
+#[test]
+fn claim_dapp_rewards_range_fails() {
+    ExtBuilder::build().execute_with(|| {
+        // Non-existing contract
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_noop!(
+            DappStaking::claim_dapp_rewards_range(
+                RuntimeOrigin::signed(1),
+                smart_contract.clone(),
+                1,
+                2
+            ),
+            Error::<Test>::ContractNotFound,
+        );
+
+        // Register smart contract, lock&stake some amount
+        assert_register(1, &smart_contract);
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 3);
+        let current_era = ActiveProtocolState::<Test>::get().era;
+
+        // Invalid ranges
+        assert_noop!(
+            DappStaking::claim_dapp_rewards_range(
+                RuntimeOrigin::signed(account),
+                smart_contract.clone(),
+                current_era - 1,
+                current_era - 2
+            ),
+            Error::<Test>::InvalidClaimEra,
+        );
+        assert_noop!(
+            DappStaking::claim_dapp_rewards_range(
+                RuntimeOrigin::signed(account),
+                smart_contract.clone(),
+                current_era - 1,
+                current_era
+            ),
+            Error::<Test>::InvalidClaimEra,
+        );
+    })
+}
+
+#[test]
+fn set_restake_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        let account = 2;
+        assert_lock(account, 100);
+
+        assert_set_restake(account, true);
+        assert_set_restake(account, false);
+        assert_set_restake(account, true);
+    })
+}
+
+#[test]
+fn set_restake_without_locked_funds_fails() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            DappStaking::set_restake(RuntimeOrigin::signed(2), true),
+            Error::<Test>::NoLockedFunds,
+        );
+    })
+}
+
+#[test]
+fn claim_staker_rewards_with_restake_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        // Register two smart contracts, lock&stake some amount on both
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
+
+        let account = 2;
+        assert_lock(account, 1000);
+        assert_stake(account, &smart_contract_1, 100);
+        assert_stake(account, &smart_contract_2, 300);
+        assert_set_restake(account, true);
+
+        // Advance a few eras so we have some rewards to claim
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 3);
+
+        let pre_ledger = Ledger::<Test>::get(&account);
+        let pre_era_info = CurrentEraInfo::<Test>::get();
+        let pre_free_balance = Balances::free_balance(&account);
+        assert_ok!(DappStaking::claim_staker_rewards(RuntimeOrigin::signed(
+            account
+        )));
+        let reward = Balances::free_balance(&account) - pre_free_balance;
+        assert!(!reward.is_zero());
+
+        // Entire reward must be locked & staked, split proportionally between the dApps
+        let post_ledger = Ledger::<Test>::get(&account);
+        assert_eq!(
+            post_ledger.active_locked_amount(),
+            pre_ledger.active_locked_amount() + reward
+        );
+        assert_eq!(
+            post_ledger.staked_future.unwrap().total(),
+            pre_ledger.staked_amount(1) + reward
+        );
+        assert_eq!(
+            Balances::balance_frozen(&FreezeReason::DAppStaking.into(), &account),
+            post_ledger.total_locked_amount()
+        );
+
+        let reward_1 = Perbill::from_rational(100_u128, 400) * reward;
+        let reward_2 = reward - reward_1;
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract_1)
+                .unwrap()
+                .total_staked_amount(),
+            100 + reward_1
+        );
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract_2)
+                .unwrap()
+                .total_staked_amount(),
+            300 + reward_2
+        );
+
+        let post_era_info = CurrentEraInfo::<Test>::get();
+        assert_eq!(
+            post_era_info.total_locked,
+            pre_era_info.total_locked + reward
+        );
+        assert_eq!(
+            post_era_info.total_staked_amount_next_era(),
+            pre_era_info.total_staked_amount_next_era() + reward
+        );
+
+        // Restaked amount is valid from the next era
+        advance_to_next_era();
+        let current_period = ActiveProtocolState::<Test>::get().period_number();
+        let dapp_id_1 = IntegratedDApps::<Test>::get(&smart_contract_1).unwrap().id;
+        assert_eq!(
+            ContractStake::<Test>::get(&dapp_id_1).total_staked_amount(current_period),
+            100 + reward_1
+        );
+    })
+}
+
+#[test]
+fn claim_staker_rewards_with_restake_only_locks_if_stake_not_possible() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 100);
+        assert_set_restake(account, true);
+
+        // Advance to the next period - there's no stake in the ongoing period so nothing can be restaked
+        advance_to_next_period();
+        let staker_info = StakerInfo::<Test>::get(&account, &smart_contract);
+
+        System::reset_events();
+        for _ in 0..required_number_of_reward_claims(account) {
+            let pre_locked = Ledger::<Test>::get(&account).active_locked_amount();
+            let pre_free_balance = Balances::free_balance(&account);
+            assert_ok!(DappStaking::claim_staker_rewards(RuntimeOrigin::signed(
+                account
+            )));
+            let reward = Balances::free_balance(&account) - pre_free_balance;
+
+            assert_eq!(
+                Ledger::<Test>::get(&account).active_locked_amount(),
+                pre_locked + reward
+            );
+        }
+
+        // Nothing should have been staked
+        assert!(dapp_staking_events()
+            .iter()
+            .all(|event| !matches!(event, Event::Stake { .. })));
+        assert!(Ledger::<Test>::get(&account).staked_future.is_none());
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract),
+            staker_info
+        );
+    })
+}
+
+#[test]
+fn claim_bonus_reward_with_restake_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts, lock&stake some amount
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract_1, 100);
+
+        // Advance to the next period, claim staker rewards & stake on another dApp in the new period
+        advance_to_next_period();
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_claim_staker_rewards(account);
+        }
+        assert_stake(account, &smart_contract_2, 50);
+        assert_set_restake(account, true);
+
+        // Claim the bonus reward, and ensure it's restaked on the dApp staked in the ongoing period
+        let pre_ledger = Ledger::<Test>::get(&account);
+        let pre_free_balance = Balances::free_balance(&account);
+        assert_ok!(DappStaking::claim_bonus_reward(
+            RuntimeOrigin::signed(account),
+            smart_contract_1,
+        ));
+        let reward = Balances::free_balance(&account) - pre_free_balance;
+        assert!(!reward.is_zero());
+
+        let post_ledger = Ledger::<Test>::get(&account);
+        assert_eq!(
+            post_ledger.active_locked_amount(),
+            pre_ledger.active_locked_amount() + reward
+        );
+        assert_eq!(
+            post_ledger.contract_stake_count,
+            pre_ledger.contract_stake_count - 1
+        );
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract_2)
+                .unwrap()
+                .total_staked_amount(),
+            50 + reward
+        );
+        assert!(!StakerInfo::<Test>::contains_key(
+            &account,
+            &smart_contract_1
+        ));
+    })
+}
+
+#[test]
+fn pending_staker_rewards_matches_claimed_amount() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let account = 2;
+        assert!(
+            DappStaking::pending_staker_rewards(&account).is_zero(),
+            "Nothing has been staked yet."
+        );
+
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Advance into the next period, so rewards span multiple era reward spans
+        advance_to_next_period();
+        let pending_rewards = DappStaking::pending_staker_rewards(&account);
+        assert!(!pending_rewards.is_zero());
+
+        // Claim everything, and ensure the claimed amount matches the pending one
+        let init_free_balance = Balances::free_balance(&account);
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_claim_staker_rewards(account);
+        }
+        assert_eq!(
+            Balances::free_balance(&account) - init_free_balance,
+            pending_rewards
+        );
+        assert!(DappStaking::pending_staker_rewards(&account).is_zero());
+    })
+}
+
+#[test]
+fn pending_bonus_rewards_matches_claimed_amount() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Bonus reward isn't claimable until the period ends
+        assert!(DappStaking::pending_bonus_rewards(&account).is_empty());
+        advance_to_next_period();
+
+        let pending_rewards = DappStaking::pending_bonus_rewards(&account);
+        assert_eq!(pending_rewards.len(), 1);
+        assert_eq!(pending_rewards[0].0, smart_contract);
+
+        // Claim the bonus reward, and ensure the claimed amount matches the pending one
+        let init_free_balance = Balances::free_balance(&account);
+        assert_claim_bonus_reward(account, &smart_contract);
+        assert_eq!(
+            Balances::free_balance(&account) - init_free_balance,
+            pending_rewards[0].1
+        );
+        assert!(DappStaking::pending_bonus_rewards(&account).is_empty());
+    })
+}
+
+#[test]
+fn pending_dapp_rewards_works() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+        assert!(DappStaking::pending_dapp_rewards(&smart_contract).is_empty());
+
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Advance a few eras so we have multiple entries for reward claiming
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 3);
+        let current_era = ActiveProtocolState::<Test>::get().era;
+
+        let pending_rewards = DappStaking::pending_dapp_rewards(&smart_contract);
+        assert_eq!(
+            pending_rewards
+                .iter()
+                .map(|(era, _)| *era)
+                .collect::<Vec<_>>(),
+            vec![current_era - 2, current_era - 1]
+        );
+        assert!(pending_rewards.iter().all(|(_, reward)| !reward.is_zero()));
+
+        // Claim reward for one of the eras, and ensure it's no longer pending
+        let (claim_era, claim_amount) = pending_rewards[0];
+        let init_free_balance = Balances::free_balance(&1);
+        assert_claim_dapp_reward(account, &smart_contract, claim_era);
+        assert_eq!(Balances::free_balance(&1) - init_free_balance, claim_amount);
+        assert_eq!(
+            DappStaking::pending_dapp_rewards(&smart_contract),
+            vec![pending_rewards[1]]
+        );
+
+        // Non-existing contract has no rewards
+        let other_smart_contract = MockSmartContract::wasm(2 as AccountId);
+        assert!(DappStaking::pending_dapp_rewards(&other_smart_contract).is_empty());
+    })
+}
+
+#[test]
+fn dapp_tier_and_tier_thresholds_work() {
+    ExtBuilder::build().execute_with(|| {
+        // Thresholds must correspond to the active tier configuration
+        let expected_thresholds: Vec<Balance> = TierConfig::<Test>::get()
+            .tier_thresholds
+            .iter()
+            .map(|threshold| threshold.threshold())
+            .collect();
+        assert_eq!(DappStaking::tier_thresholds(), expected_thresholds);
+
+        // Register smart contract, lock&stake enough to enter the first tier
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+        assert!(DappStaking::dapp_tier(&smart_contract).is_none());
+
+        let account = 2;
+        let amount = expected_thresholds[0];
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Stake is only valid from the next era
+        advance_to_next_era();
+        assert_eq!(DappStaking::dapp_tier(&smart_contract), Some(0));
+
+        // Unregistered contract isn't assigned to any tier
+        assert_unregister(&smart_contract);
+        assert!(DappStaking::dapp_tier(&smart_contract).is_none());
+    })
+}
+
+#[test]
+fn unstake_from_unregistered_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Unregister the smart contract, and unstake from it.
+        assert_unregister(&smart_contract);
+        assert_unstake_from_unregistered(account, &smart_contract);
+    })
+}
+
+#[test]
+fn unstake_from_unregistered_fails_for_active_contract() {
     ExtBuilder::build().execute_with(|| {
         // Register smart contract, lock&stake some amount
         let smart_contract = MockSmartContract::wasm(1 as AccountId);
@@ -2024,12 +2944,110 @@ fn cleanup_expired_entries_fails_with_no_entries() {
             reward_retention_in_periods >= 1,
             "Sanity check, otherwise the test doesn't make sense."
         );
-        advance_to_next_period();
+        advance_to_next_period();
+
+        assert_noop!(
+            DappStaking::cleanup_expired_entries(RuntimeOrigin::signed(account)),
+            Error::<Test>::NoExpiredEntries
+        );
+    })
+}
+
+#[test]
+fn on_idle_cleanup_converges_to_retention_window() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 100);
+
+        // Advance through multiple periods, making history entries expire.
+        let retention_period: PeriodNumber = <Test as Config>::RewardRetentionInPeriods::get();
+        for _ in 0..retention_period + 3 {
+            advance_to_next_period();
+            // Ensure `on_idle` is executed at least once in the new period.
+            run_for_blocks(1);
+
+            // Only entries from the retention window must remain in storage.
+            let protocol_state = ActiveProtocolState::<Test>::get();
+            let oldest_claimable_period =
+                DappStaking::oldest_claimable_period(protocol_state.period_number());
+            assert!(DAppTiers::<Test>::iter_values()
+                .all(|dapp_tiers| dapp_tiers.period >= oldest_claimable_period));
+
+            if let Some(period_end_info) = oldest_claimable_period
+                .checked_sub(1)
+                .and_then(PeriodEnd::<Test>::get)
+            {
+                assert!(EraRewards::<Test>::iter_values()
+                    .all(|span| span.last_era() > period_end_info.final_era));
+                assert!(DAppTiers::<Test>::iter_keys().all(|era| era > period_end_info.final_era));
+            }
+        }
+    })
+}
+
+#[test]
+fn on_idle_cleanup_is_bounded_by_remaining_weight() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 100);
+
+        // Snapshot history entries before any of them expire.
+        advance_to_next_period();
+        let era_rewards: Vec<_> = EraRewards::<Test>::iter().collect();
+        let dapp_tiers: Vec<_> = DAppTiers::<Test>::iter().collect();
+        let pre_cleanup_marker = HistoryCleanupMarker::<Test>::get();
+
+        // Advance enough periods so all the snapshot entries expire & get cleaned up.
+        let retention_period: PeriodNumber = <Test as Config>::RewardRetentionInPeriods::get();
+        advance_to_period(
+            ActiveProtocolState::<Test>::get().period_number() + retention_period + 1,
+        );
+        run_for_blocks(1);
+
+        // Restore the expired entries, creating a cleanup backlog.
+        era_rewards
+            .into_iter()
+            .for_each(|(index, span)| EraRewards::<Test>::insert(index, span));
+        dapp_tiers
+            .into_iter()
+            .for_each(|(era, tiers)| DAppTiers::<Test>::insert(era, tiers));
+        HistoryCleanupMarker::<Test>::put(pre_cleanup_marker);
+
+        // Not enough weight for a single pass, nothing should happen.
+        let pass_weight = <Test as Config>::WeightInfo::on_idle_cleanup();
+        assert_storage_noop!(DappStaking::on_idle(
+            System::block_number(),
+            pass_weight.saturating_sub(Weight::from_parts(1, 0))
+        ));
+
+        // Enough weight for exactly two passes.
+        assert_eq!(
+            DappStaking::on_idle(System::block_number(), pass_weight.saturating_mul(2)),
+            pass_weight.saturating_mul(2)
+        );
+        let span_length: EraNumber = <Test as Config>::EraRewardSpanLength::get();
+        let post_cleanup_marker = HistoryCleanupMarker::<Test>::get();
+        assert_eq!(
+            post_cleanup_marker.era_reward_index,
+            pre_cleanup_marker.era_reward_index + 2 * span_length
+        );
+        assert_eq!(
+            post_cleanup_marker.dapp_tiers_index,
+            pre_cleanup_marker.dapp_tiers_index + 2
+        );
 
-        assert_noop!(
-            DappStaking::cleanup_expired_entries(RuntimeOrigin::signed(account)),
-            Error::<Test>::NoExpiredEntries
-        );
+        // With enough weight, the entire backlog is cleaned up.
+        assert_on_idle_cleanup();
     })
 }
 
@@ -2579,3 +3597,420 @@ fn post_unlock_balance_cannot_be_transfered() {
         assert!(Balances::free_balance(&staker).is_zero());
     })
 }
+
+#[test]
+fn claim_staker_rewards_for_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 93);
+
+        // Advance into Build&Earn period, and allow one era to pass. Third party claims the reward.
+        let caller = 3;
+        let pre_caller_balance = Balances::free_balance(&caller);
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        assert_claim_staker_rewards_for(caller, account);
+
+        // Advance into the next period, make sure third party can claim all of the remaining rewards.
+        advance_to_next_period();
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_claim_staker_rewards_for(caller, account);
+        }
+        assert_eq!(
+            Balances::free_balance(&caller),
+            pre_caller_balance,
+            "Caller must not receive any rewards."
+        );
+
+        // Nothing left to claim
+        assert_noop!(
+            DappStaking::claim_staker_rewards_for(RuntimeOrigin::signed(caller), account),
+            Error::<Test>::NoClaimableRewards,
+        );
+    })
+}
+
+#[test]
+fn claim_bonus_reward_for_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 93);
+
+        // Advance to the next period, third party claims the bonus reward
+        let caller = 3;
+        let pre_caller_balance = Balances::free_balance(&caller);
+        advance_to_next_period();
+        assert_claim_bonus_reward_for(caller, account, &smart_contract);
+        assert_eq!(
+            Balances::free_balance(&caller),
+            pre_caller_balance,
+            "Caller must not receive any rewards."
+        );
+
+        // Cannot be claimed twice
+        assert_noop!(
+            DappStaking::claim_bonus_reward_for(
+                RuntimeOrigin::signed(caller),
+                account,
+                smart_contract
+            ),
+            Error::<Test>::NoClaimableRewards,
+        );
+    })
+}
+
+#[test]
+fn move_stake_basic_example_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts, lock&stake some amount
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract_1, 200);
+
+        // Move some stake to a new contract, then some more, and finally move everything back
+        assert_move_stake(account, &smart_contract_1, &smart_contract_2, 50);
+        assert_move_stake(account, &smart_contract_1, &smart_contract_2, 70);
+        assert_move_stake(account, &smart_contract_2, &smart_contract_1, 120);
+
+        // Same in the Build&Earn subperiod, after some eras have passed
+        advance_to_next_subperiod();
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_claim_staker_rewards(account);
+        }
+        assert_stake(account, &smart_contract_2, 50);
+        assert_move_stake(account, &smart_contract_1, &smart_contract_2, 30);
+        assert_move_stake(account, &smart_contract_2, &smart_contract_1, 80);
+    })
+}
+
+#[test]
+fn move_stake_with_remaining_amount_below_threshold_moves_everything() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts, lock&stake some amount
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
+
+        let account = 2;
+        assert_lock(account, 300);
+        let stake_amount = 100;
+        assert_stake(account, &smart_contract_1, stake_amount);
+
+        let minimum_stake_amount: Balance = <Test as Config>::MinimumStakeAmount::get();
+        assert_move_stake(
+            account,
+            &smart_contract_1,
+            &smart_contract_2,
+            stake_amount - minimum_stake_amount + 1,
+        );
+        assert!(!StakerInfo::<Test>::contains_key(
+            &account,
+            &smart_contract_1
+        ));
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract_2)
+                .unwrap()
+                .total_staked_amount(),
+            stake_amount
+        );
+    })
+}
+
+#[test]
+fn move_stake_preserves_bonus_reward_eligibility() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts, lock&stake some amount during voting subperiod
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract_1, 100);
+
+        // Move part of the stake during Build&Earn subperiod, both entries must remain loyal
+        advance_to_next_subperiod();
+        assert_move_stake(account, &smart_contract_1, &smart_contract_2, 40);
+        assert!(StakerInfo::<Test>::get(&account, &smart_contract_1)
+            .unwrap()
+            .is_loyal());
+        assert!(StakerInfo::<Test>::get(&account, &smart_contract_2)
+            .unwrap()
+            .is_loyal());
+
+        // Bonus reward can be claimed for both contracts in the next period
+        advance_to_next_period();
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_claim_staker_rewards(account);
+        }
+        assert_claim_bonus_reward(account, &smart_contract_1);
+        assert_claim_bonus_reward(account, &smart_contract_2);
+    })
+}
+
+#[test]
+fn move_stake_from_unregistered_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts, lock&stake some amount
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract_1, 100);
+
+        // Unregister the smart contract, and move everything from it, regardless of the specified amount
+        assert_unregister(&smart_contract_1);
+        assert_move_stake(account, &smart_contract_1, &smart_contract_2, 1);
+    })
+}
+
+#[test]
+fn move_stake_fails() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts, lock&stake some amount
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
+
+        let account = 2;
+        assert_lock(account, 300);
+        let stake_amount = 100;
+        assert_stake(account, &smart_contract_1, stake_amount);
+
+        // Same source & destination
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_1,
+                smart_contract_1,
+                10
+            ),
+            Error::<Test>::SameSourceAndDestination
+        );
+
+        // Destination doesn't exist
+        let smart_contract_3 = MockSmartContract::wasm(3 as AccountId);
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_1,
+                smart_contract_3,
+                10
+            ),
+            Error::<Test>::NotOperatedDApp
+        );
+
+        // Destination is unregistered
+        assert_register(1, &smart_contract_3);
+        assert_unregister(&smart_contract_3);
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_1,
+                smart_contract_3,
+                10
+            ),
+            Error::<Test>::NotOperatedDApp
+        );
+
+        // Nothing staked on the source contract
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_2,
+                smart_contract_1,
+                10
+            ),
+            Error::<Test>::NoStakingInfo
+        );
+
+        // Zero or too large amount
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_1,
+                smart_contract_2,
+                0
+            ),
+            Error::<Test>::ZeroAmount
+        );
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_1,
+                smart_contract_2,
+                stake_amount + 1
+            ),
+            Error::<Test>::UnstakeAmountTooLarge
+        );
+
+        // Moved amount is below the minimum stake threshold for the destination
+        let minimum_stake_amount: Balance = <Test as Config>::MinimumStakeAmount::get();
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_1,
+                smart_contract_2,
+                minimum_stake_amount - 1
+            ),
+            Error::<Test>::InsufficientStakeAmount
+        );
+
+        // Final era of the period
+        ActiveProtocolState::<Test>::mutate(|state| {
+            state.period_info.subperiod = Subperiod::BuildAndEarn;
+            state.period_info.next_subperiod_start_era = state.era + 1;
+        });
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_1,
+                smart_contract_2,
+                10
+            ),
+            Error::<Test>::PeriodEndsInNextEra
+        );
+    })
+}
+
+#[test]
+fn move_stake_from_past_period_fails() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts, lock&stake some amount
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract_1, 100);
+
+        advance_to_next_period();
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_1,
+                smart_contract_2,
+                10
+            ),
+            Error::<Test>::UnstakeFromPastPeriod
+        );
+    })
+}
+
+#[test]
+fn move_stake_fails_due_to_too_many_staked_contracts() {
+    ExtBuilder::build().execute_with(|| {
+        let max_number_of_contracts: u32 = <Test as Config>::MaxNumberOfStakedContracts::get();
+
+        // Lock amount by staker
+        let account = 1;
+        assert_lock(account, 100 as Balance * max_number_of_contracts as Balance);
+
+        // Register smart contracts up to the max allowed number & stake on them
+        for id in 1..=max_number_of_contracts {
+            let smart_contract = MockSmartContract::Wasm(id.into());
+            assert_register(2, &smart_contract);
+            assert_stake(account, &smart_contract, 20);
+        }
+
+        let excess_smart_contract = MockSmartContract::Wasm((max_number_of_contracts + 1).into());
+        assert_register(2, &excess_smart_contract);
+
+        // Partial move would create a new entry, exceeding the limit
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                MockSmartContract::Wasm(1),
+                excess_smart_contract.clone(),
+                10
+            ),
+            Error::<Test>::TooManyStakedContracts
+        );
+
+        // Full move is fine since the number of entries remains the same
+        assert_move_stake(
+            account,
+            &MockSmartContract::Wasm(1),
+            &excess_smart_contract,
+            20,
+        );
+    })
+}
+
+#[test]
+fn account_ledger_restake_migration_works() {
+    ExtBuilder::build().execute_with(|| {
+        use crate::{
+            AccountLedgerRestakeMigration, StakeAmount, UnlockingChunk, RESTAKE_STORAGE_VERSION,
+        };
+        use frame_support::{
+            storage::unhashed,
+            traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+        };
+
+        // Prepare a ledger in the format prior to restake mode
+        let account = 3;
+        let old_ledger = crate::v5::AccountLedger::<<Test as Config>::MaxUnlockingChunks> {
+            locked: 1000,
+            unlocking: BoundedVec::try_from(vec![UnlockingChunk {
+                amount: 100,
+                unlock_block: 20,
+            }])
+            .expect("Only one chunk, must fit."),
+            staked: StakeAmount {
+                voting: 300,
+                build_and_earn: 200,
+                era: 3,
+                period: 1,
+            },
+            staked_future: None,
+            contract_stake_count: 2,
+        };
+        unhashed::put(&Ledger::<Test>::hashed_key_for(&account), &old_ledger);
+        StorageVersion::new(5).put::<DappStaking>();
+
+        AccountLedgerRestakeMigration::<Test>::on_runtime_upgrade();
+
+        // Ledger must be preserved, with restake mode disabled
+        let ledger = Ledger::<Test>::get(&account);
+        assert_eq!(ledger.locked, old_ledger.locked);
+        assert_eq!(ledger.unlocking, old_ledger.unlocking);
+        assert_eq!(ledger.staked, old_ledger.staked);
+        assert_eq!(ledger.staked_future, old_ledger.staked_future);
+        assert_eq!(ledger.contract_stake_count, old_ledger.contract_stake_count);
+        assert!(!ledger.restake);
+        assert_eq!(
+            DappStaking::on_chain_storage_version(),
+            RESTAKE_STORAGE_VERSION
+        );
+
+        // Repeated execution is a no-op
+        AccountLedgerRestakeMigration::<Test>::on_runtime_upgrade();
+        assert_eq!(Ledger::<Test>::get(&account), ledger);
+    });
+}
@@ -19,7 +19,7 @@
 use astar_primitives::Balance;
 use frame_support::assert_ok;
 use sp_arithmetic::fixed_point::FixedU64;
-use sp_runtime::Permill;
+use sp_runtime::{Perbill, Permill};
 
 use crate::*;
 
@@ -154,11 +154,13 @@ fn dapp_info_basic_checks() {
     let owner = 1;
     let beneficiary = 3;
 
-    let mut dapp_info = DAppInfo {
+    get_u32_type!(SplitsDummy, 3);
+    let mut dapp_info = DAppInfo::<_, SplitsDummy> {
         owner,
         id: 7,
         state: DAppState::Registered,
         reward_destination: None,
+        reward_splits: Default::default(),
     };
 
     // Owner receives reward in case no beneficiary is set
@@ -175,6 +177,110 @@ fn dapp_info_basic_checks() {
     assert!(!dapp_info.is_registered());
 }
 
+#[test]
+fn dapp_info_split_reward_works() {
+    let owner = 1;
+    let beneficiary = 3;
+
+    get_u32_type!(SplitsDummy, 3);
+    let mut dapp_info = DAppInfo::<_, SplitsDummy> {
+        owner,
+        id: 7,
+        state: DAppState::Registered,
+        reward_destination: None,
+        reward_splits: Default::default(),
+    };
+
+    // No splits, entire reward goes to the owner
+    assert_eq!(dapp_info.split_reward(100, 0), (vec![(owner, 100)], vec![]));
+    assert_eq!(dapp_info.split_reward(0, 0), (vec![], vec![]));
+
+    // Splits are paid out first, remainder goes to the beneficiary
+    dapp_info.reward_destination = Some(beneficiary);
+    dapp_info.reward_splits = BoundedVec::try_from(vec![
+        (5, Perbill::from_percent(30)),
+        (6, Perbill::from_percent(20)),
+    ])
+    .unwrap();
+    assert_eq!(
+        dapp_info.split_reward(100, 0),
+        (vec![(5, 30), (6, 20), (beneficiary, 50)], vec![])
+    );
+
+    // Rounding remainder goes to the beneficiary, zero amounts are omitted
+    assert_eq!(
+        dapp_info.split_reward(7, 0),
+        (vec![(5, 2), (6, 1), (beneficiary, 4)], vec![])
+    );
+    assert_eq!(
+        dapp_info.split_reward(3, 0),
+        (vec![(beneficiary, 3)], vec![])
+    );
+
+    // Shares below the minimum payout are skipped & go to the beneficiary
+    assert_eq!(
+        dapp_info.split_reward(100, 25),
+        (vec![(5, 30), (beneficiary, 70)], vec![(6, 20)])
+    );
+    assert_eq!(
+        dapp_info.split_reward(100, 31),
+        (vec![(beneficiary, 100)], vec![(5, 30), (6, 20)])
+    );
+
+    // Full split, nothing is left for the beneficiary
+    dapp_info.reward_splits = BoundedVec::try_from(vec![
+        (5, Perbill::from_percent(60)),
+        (6, Perbill::from_percent(40)),
+    ])
+    .unwrap();
+    assert_eq!(
+        dapp_info.split_reward(100, 0),
+        (vec![(5, 60), (6, 40)], vec![])
+    );
+
+    // Sum of all payouts always equals the reward
+    for (reward, minimum_payout) in [(1, 0), (17, 0), (999, 500), (1_000_003, 1_000)] {
+        let total: Balance = dapp_info
+            .split_reward(reward, minimum_payout)
+            .0
+            .iter()
+            .map(|(_, amount)| amount)
+            .sum();
+        assert_eq!(total, reward);
+    }
+}
+
+#[test]
+fn are_reward_splits_valid_works() {
+    // Empty splits are valid
+    assert!(are_reward_splits_valid::<u64>(&[]));
+
+    // Shares summing up to at most 100% are valid
+    assert!(are_reward_splits_valid(&[
+        (1, Perbill::from_percent(50)),
+        (2, Perbill::from_percent(50))
+    ]));
+    assert!(are_reward_splits_valid(&[(1, Perbill::from_percent(10))]));
+
+    // Zero share isn't valid
+    assert!(!are_reward_splits_valid(&[
+        (1, Perbill::from_percent(10)),
+        (2, Perbill::zero())
+    ]));
+
+    // Duplicate accounts aren't valid
+    assert!(!are_reward_splits_valid(&[
+        (1, Perbill::from_percent(10)),
+        (1, Perbill::from_percent(20))
+    ]));
+
+    // Shares exceeding 100% aren't valid
+    assert!(!are_reward_splits_valid(&[
+        (1, Perbill::from_percent(60)),
+        (2, Perbill::from_parts(400_000_001))
+    ]));
+}
+
 #[test]
 fn unlocking_chunk_basic_check() {
     // Sanity check
@@ -2116,6 +2222,81 @@ fn singular_staking_info_unstake_during_bep_is_ok() {
     assert_eq!(staking_info.era(), era_2);
 }
 
+#[test]
+fn singular_staking_info_move_out_is_ok() {
+    let period_number = 3;
+    let mut staking_info = SingularStakingInfo::new(period_number, Subperiod::Voting);
+
+    // Prep actions
+    let era_1 = 3;
+    let vote_stake_amount_1 = 11;
+    staking_info.stake(vote_stake_amount_1, era_1 - 1, Subperiod::Voting);
+    let bep_stake_amount_1 = 23;
+    staking_info.stake(bep_stake_amount_1, era_1, Subperiod::BuildAndEarn);
+
+    // Move out more than was staked during B&E period, taking a chunk from the voting period stake too.
+    let voting_stake_overflow = 2;
+    let era_2 = era_1 + 2;
+    assert_eq!(
+        staking_info.move_out(
+            bep_stake_amount_1 + voting_stake_overflow,
+            era_2,
+            Subperiod::BuildAndEarn
+        ),
+        (voting_stake_overflow, bep_stake_amount_1)
+    );
+    assert_eq!(
+        staking_info.staked_amount(Subperiod::Voting),
+        vote_stake_amount_1 - voting_stake_overflow
+    );
+    assert!(staking_info
+        .staked_amount(Subperiod::BuildAndEarn)
+        .is_zero());
+    assert!(
+        staking_info.is_loyal(),
+        "Loyalty flag must remain since stake was only moved."
+    );
+    assert_eq!(
+        staking_info.era(),
+        era_2 + 1,
+        "Moved stake leaves from the next era."
+    );
+}
+
+#[test]
+fn singular_staking_info_move_in_is_ok() {
+    let period_number = 3;
+    let era = 5;
+
+    // 1st scenario - empty entry created during B&E period becomes loyal if voting stake is moved into it
+    let mut staking_info = SingularStakingInfo::new(period_number, Subperiod::BuildAndEarn);
+    assert!(!staking_info.is_loyal());
+    let (voting_amount, bep_amount) = (7, 13);
+    staking_info.move_in(voting_amount, bep_amount, era);
+    assert_eq!(staking_info.staked_amount(Subperiod::Voting), voting_amount);
+    assert_eq!(
+        staking_info.staked_amount(Subperiod::BuildAndEarn),
+        bep_amount
+    );
+    assert!(staking_info.is_loyal());
+    assert_eq!(staking_info.era(), era + 1);
+
+    // 2nd scenario - empty entry doesn't become loyal if only B&E stake is moved into it
+    let mut staking_info = SingularStakingInfo::new(period_number, Subperiod::BuildAndEarn);
+    staking_info.move_in(Balance::zero(), bep_amount, era);
+    assert!(!staking_info.is_loyal());
+
+    // 3rd scenario - non-loyal entry with existing stake remains non-loyal
+    let mut staking_info = SingularStakingInfo::new(period_number, Subperiod::BuildAndEarn);
+    staking_info.stake(bep_amount, era, Subperiod::BuildAndEarn);
+    staking_info.move_in(voting_amount, Balance::zero(), era);
+    assert!(!staking_info.is_loyal());
+    assert_eq!(
+        staking_info.total_staked_amount(),
+        voting_amount + bep_amount
+    );
+}
+
 #[test]
 fn contract_stake_amount_basic_get_checks_work() {
     // Sanity checks for empty struct
@@ -2444,6 +2625,76 @@ fn contract_stake_amount_unstake_is_ok() {
     assert!(contract_stake.staked_future.is_none());
 }
 
+#[test]
+fn contract_stake_amount_move_is_ok() {
+    // Prep action - create stake entries for both contracts, with stake valid in the current era
+    let era_1 = 2;
+    let period = 3;
+    let period_info = PeriodInfo {
+        number: period,
+        subperiod: Subperiod::BuildAndEarn,
+        next_subperiod_start_era: 20,
+    };
+    let (voting_amount, bep_amount) = (70, 30);
+    let mut from_stake = ContractStakeAmount::default();
+    from_stake.stake(
+        voting_amount,
+        PeriodInfo {
+            subperiod: Subperiod::Voting,
+            ..period_info
+        },
+        era_1 - 1,
+    );
+    from_stake.stake(bep_amount, period_info, era_1 - 1);
+    let mut to_stake = ContractStakeAmount::default();
+    to_stake.stake(bep_amount, period_info, era_1 - 1);
+
+    // 1st scenario - move stake, keeping its subperiod split
+    let era_2 = era_1 + 1;
+    let (moved_voting, moved_bep) = (20, 10);
+    from_stake.move_out(moved_voting, moved_bep, period_info, era_2);
+    to_stake.move_in(moved_voting, moved_bep, period_info, era_2);
+
+    // Stake of the ongoing era remains unchanged for both contracts
+    let from_current = from_stake.get(era_2, period).unwrap();
+    assert_eq!(from_current.for_type(Subperiod::Voting), voting_amount);
+    assert_eq!(from_current.for_type(Subperiod::BuildAndEarn), bep_amount);
+    let to_current = to_stake.get(era_2, period).unwrap();
+    assert!(to_current.for_type(Subperiod::Voting).is_zero());
+    assert_eq!(to_current.for_type(Subperiod::BuildAndEarn), bep_amount);
+
+    // Move is valid from the next era for both contracts
+    let from_next = from_stake.get(era_2 + 1, period).unwrap();
+    assert_eq!(
+        from_next.for_type(Subperiod::Voting),
+        voting_amount - moved_voting
+    );
+    assert_eq!(
+        from_next.for_type(Subperiod::BuildAndEarn),
+        bep_amount - moved_bep
+    );
+    let to_next = to_stake.get(era_2 + 1, period).unwrap();
+    assert_eq!(to_next.for_type(Subperiod::Voting), moved_voting);
+    assert_eq!(
+        to_next.for_type(Subperiod::BuildAndEarn),
+        bep_amount + moved_bep
+    );
+
+    // 2nd scenario - move everything out, the entry for the next era remains but is empty
+    from_stake.move_out(
+        voting_amount - moved_voting,
+        bep_amount - moved_bep,
+        period_info,
+        era_2,
+    );
+    assert_eq!(
+        from_stake.get(era_2, period).unwrap().total(),
+        voting_amount + bep_amount
+    );
+    assert!(from_stake.get(era_2 + 1, period).unwrap().is_empty());
+    assert!(from_stake.total_staked_amount(period).is_zero());
+}
+
 #[test]
 fn era_reward_span_push_and_get_works() {
     get_u32_type!(SpanLength, 8);
@@ -2679,6 +2930,176 @@ fn tier_configuration_basic_tests() {
     // TODO: expand tests, add more sanity checks (e.g. tier 3 requirement should never be lower than tier 4, etc.)
 }
 
+/// Prepare tier assignment candidates from `(dApp Id, stake amount, number of stakers)` tuples.
+fn tier_candidates(dapps: &[(DAppId, Balance, u32)]) -> Vec<DAppTierCandidate> {
+    dapps
+        .iter()
+        .map(
+            |(dapp_id, stake_amount, number_of_stakers)| DAppTierCandidate {
+                dapp_id: *dapp_id,
+                stake_amount: *stake_amount,
+                number_of_stakers: *number_of_stakers,
+            },
+        )
+        .collect()
+}
+
+#[test]
+fn stake_amount_tier_assignment_works() {
+    get_u32_type!(TiersNum, 3);
+    let tier_config = TiersConfiguration::<TiersNum> {
+        number_of_slots: 5,
+        slots_per_tier: BoundedVec::try_from(vec![1, 2, 2]).unwrap(),
+        reward_portion: BoundedVec::try_from(vec![
+            Permill::from_percent(50),
+            Permill::from_percent(30),
+            Permill::from_percent(20),
+        ])
+        .unwrap(),
+        tier_thresholds: BoundedVec::try_from(vec![
+            TierThreshold::FixedTvlAmount { amount: 100 },
+            TierThreshold::FixedTvlAmount { amount: 50 },
+            TierThreshold::FixedTvlAmount { amount: 10 },
+        ])
+        .unwrap(),
+    };
+
+    // dApp 3 satisfies the top tier threshold, but the tier is already full.
+    // dApp 6 satisfies the last tier threshold, but the tier is already full.
+    let dapps = tier_candidates(&[
+        (1, 20, 1),
+        (2, 500, 1),
+        (3, 100, 1),
+        (4, 5, 1),
+        (5, 40, 1),
+        (6, 11, 1),
+    ]);
+    let mut dapp_tiers = StakeAmountTierAssignment::assign_tiers(dapps, &tier_config);
+    dapp_tiers.sort_by_key(|dapp_tier| dapp_tier.dapp_id);
+
+    let expected_tiers = vec![(1, 2), (2, 0), (3, 1), (5, 2)];
+    assert_eq!(
+        dapp_tiers,
+        expected_tiers
+            .into_iter()
+            .map(|(dapp_id, tier_id)| DAppTier {
+                dapp_id,
+                tier_id: Some(tier_id),
+            })
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn stake_amount_with_age_tie_break_tier_assignment_works() {
+    get_u32_type!(TiersNum, 2);
+    let tier_config = TiersConfiguration::<TiersNum> {
+        number_of_slots: 2,
+        slots_per_tier: BoundedVec::try_from(vec![1, 1]).unwrap(),
+        reward_portion: BoundedVec::try_from(vec![
+            Permill::from_percent(60),
+            Permill::from_percent(40),
+        ])
+        .unwrap(),
+        tier_thresholds: BoundedVec::try_from(vec![
+            TierThreshold::FixedTvlAmount { amount: 100 },
+            TierThreshold::FixedTvlAmount { amount: 10 },
+        ])
+        .unwrap(),
+    };
+
+    // All dApps have the same stake, older dApps (lower Id) have the advantage
+    let dapps = tier_candidates(&[(7, 100, 1), (3, 100, 1), (5, 100, 1)]);
+    let mut dapp_tiers =
+        StakeAmountWithAgeTieBreakTierAssignment::assign_tiers(dapps, &tier_config);
+    dapp_tiers.sort_by_key(|dapp_tier| dapp_tier.dapp_id);
+
+    assert_eq!(
+        dapp_tiers,
+        vec![
+            DAppTier {
+                dapp_id: 3,
+                tier_id: Some(0),
+            },
+            DAppTier {
+                dapp_id: 5,
+                tier_id: Some(1),
+            },
+        ]
+    );
+}
+
+#[test]
+fn staker_count_tier_assignment_works() {
+    get_u32_type!(TiersNum, 3);
+    let tier_config = TiersConfiguration::<TiersNum> {
+        number_of_slots: 4,
+        slots_per_tier: BoundedVec::try_from(vec![1, 1, 2]).unwrap(),
+        reward_portion: BoundedVec::try_from(vec![
+            Permill::from_percent(50),
+            Permill::from_percent(30),
+            Permill::from_percent(20),
+        ])
+        .unwrap(),
+        tier_thresholds: BoundedVec::try_from(vec![
+            TierThreshold::FixedTvlAmount { amount: 100 },
+            TierThreshold::FixedTvlAmount { amount: 50 },
+            TierThreshold::FixedTvlAmount { amount: 10 },
+        ])
+        .unwrap(),
+    };
+
+    // dApp 1 has the most stakers, but its stake only satisfies the last tier threshold.
+    // dApp 2 has the biggest stake, but fewer stakers than dApp 3, which also satisfies the top tier threshold.
+    // dApp 4 ties with dApp 5 on number of stakers, but has a bigger stake.
+    // dApp 6 doesn't satisfy any threshold, even though it has more stakers than most of the dApps.
+    let dapps = tier_candidates(&[
+        (1, 20, 50),
+        (2, 1000, 5),
+        (3, 200, 10),
+        (4, 60, 3),
+        (5, 55, 3),
+        (6, 5, 20),
+    ]);
+    let mut dapp_tiers = StakerCountTierAssignment::assign_tiers(dapps, &tier_config);
+    dapp_tiers.sort_by_key(|dapp_tier| dapp_tier.dapp_id);
+
+    let expected_tiers = vec![(1, 2), (2, 1), (3, 0), (4, 2)];
+    assert_eq!(
+        dapp_tiers,
+        expected_tiers
+            .into_iter()
+            .map(|(dapp_id, tier_id)| DAppTier {
+                dapp_id,
+                tier_id: Some(tier_id),
+            })
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn contract_staker_count_basic_tests() {
+    let mut staker_count = ContractStakerCount::default();
+    let period = 3;
+
+    staker_count.increment(period);
+    staker_count.increment(period);
+    assert_eq!(staker_count.get(period), 2);
+    assert_eq!(staker_count.get(period - 1), 0);
+
+    staker_count.decrement(period);
+    assert_eq!(staker_count.get(period), 1);
+
+    // Counter is reset once the period changes
+    staker_count.increment(period + 1);
+    assert_eq!(staker_count.get(period + 1), 1);
+    assert_eq!(staker_count.get(period), 0);
+
+    // Decrementing below zero isn't possible
+    staker_count.decrement(period + 2);
+    assert_eq!(staker_count.get(period + 2), 0);
+}
+
 #[test]
 fn dapp_tier_rewards_basic_tests() {
     get_u32_type!(NumberOfDApps, 8);
@@ -2716,6 +3137,7 @@ fn dapp_tier_rewards_basic_tests() {
         period,
     )
     .expect("Bounds are respected.");
+    assert_eq!(dapp_tier_rewards.total_rewards(), 300 * 2 + 20 * 2 + 1);
 
     // 1st scenario - claim reward for a dApps
     let tier_id = dapps[0].tier_id.unwrap();
@@ -2729,6 +3151,11 @@ fn dapp_tier_rewards_basic_tests() {
         dapp_tier_rewards.try_claim(dapps[3].dapp_id),
         Ok((tier_rewards[tier_id as usize], tier_id))
     );
+    assert_eq!(
+        dapp_tier_rewards.total_rewards(),
+        300 + 20 + 1,
+        "Claimed rewards are no longer accounted for."
+    );
 
     // 2nd scenario - try to claim already claimed reward
     assert_eq!(
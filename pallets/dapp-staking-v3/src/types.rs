@@ -39,7 +39,10 @@
 //! ## DApp Information
 //!
 //! * `DAppId` - a compact unique numeric Id of a dApp.
-//! * `DAppInfo` - contains general information about a dApp, like owner and reward beneficiary, Id and state.
+//! * `DAppInfo` - contains general information about a dApp, like owner and reward beneficiary, reward splits, Id and state.
+//! * `DAppCategory` - an enum describing the category a dApp belongs to.
+//! * `DAppMetadata` - contains optional, deposit-backed metadata about a dApp, like name, website & logo hash.
+//! * `DAppMetadataInfo` - unbounded representation of `DAppMetadata`, intended for off-chain consumers.
 //! * `ContractStakeAmount` - contains information about how much is staked on a particular contract.
 //!
 //! ## Staker Information
@@ -67,9 +70,10 @@
 use frame_support::{pallet_prelude::*, BoundedVec};
 use parity_scale_codec::{Decode, Encode};
 use sp_arithmetic::fixed_point::FixedU64;
+use sp_core::H256;
 use sp_runtime::{
     traits::{CheckedAdd, UniqueSaturatedInto, Zero},
-    FixedPointNumber, Permill, Saturating,
+    FixedPointNumber, Perbill, Permill, Saturating,
 };
 pub use sp_std::{fmt::Debug, vec::Vec};
 
@@ -88,7 +92,15 @@ pub type DAppTierRewardsFor<T> =
 pub type EraRewardSpanFor<T> = EraRewardSpan<<T as Config>::EraRewardSpanLength>;
 
 // Convenience type for `DAppInfo` usage.
-pub type DAppInfoFor<T> = DAppInfo<<T as frame_system::Config>::AccountId>;
+pub type DAppInfoFor<T> =
+    DAppInfo<<T as frame_system::Config>::AccountId, <T as Config>::MaxRewardSplits>;
+
+// Convenience type for `DAppMetadata` usage.
+pub type DAppMetadataFor<T> = DAppMetadata<
+    <T as frame_system::Config>::AccountId,
+    <T as Config>::MaxDAppNameLength,
+    <T as Config>::MaxDAppWebsiteLength,
+>;
 
 /// Era number type
 pub type EraNumber = u32;
@@ -266,8 +278,18 @@ pub enum DAppState {
 }
 
 /// General information about dApp.
-#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
-pub struct DAppInfo<AccountId> {
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    RuntimeDebugNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    CloneNoBound,
+    TypeInfo,
+)]
+#[scale_info(skip_type_params(SplitsLen))]
+pub struct DAppInfo<AccountId: Debug + PartialEq + Eq + Clone, SplitsLen: Get<u32>> {
     /// Owner of the dApp, default reward beneficiary.
     pub owner: AccountId,
     /// dApp's unique identifier in dApp staking.
@@ -277,9 +299,15 @@ pub struct DAppInfo<AccountId> {
     pub state: DAppState,
     // If `None`, rewards goes to the developer account, otherwise to the account Id in `Some`.
     pub reward_destination: Option<AccountId>,
+    /// Accounts which receive a share of the dApp reward, before the remainder is paid out to the reward beneficiary.
+    pub reward_splits: BoundedVec<(AccountId, Perbill), SplitsLen>,
 }
 
-impl<AccountId> DAppInfo<AccountId> {
+impl<AccountId, SplitsLen> DAppInfo<AccountId, SplitsLen>
+where
+    AccountId: Debug + PartialEq + Eq + Clone,
+    SplitsLen: Get<u32>,
+{
     /// Reward destination account for this dApp.
     pub fn reward_beneficiary(&self) -> &AccountId {
         match &self.reward_destination {
@@ -292,6 +320,154 @@ impl<AccountId> DAppInfo<AccountId> {
     pub fn is_registered(&self) -> bool {
         self.state == DAppState::Registered
     }
+
+    /// Splits the reward between the reward split accounts & the reward beneficiary.
+    ///
+    /// Each reward split account receives its share of the reward, rounded down,
+    /// and the reward beneficiary receives the remainder.
+    /// Shares below `minimum_payout` are skipped and added to the remainder instead, since they might not be payable.
+    ///
+    /// Returns the payouts & the skipped shares. Zero amounts are omitted from both,
+    /// and the reward beneficiary entry, if present, is always the last payout.
+    pub fn split_reward(
+        &self,
+        reward: Balance,
+        minimum_payout: Balance,
+    ) -> (Vec<(AccountId, Balance)>, Vec<(AccountId, Balance)>) {
+        use sp_runtime::PerThing;
+
+        let mut remainder = reward;
+        let mut payouts = Vec::new();
+        let mut skipped = Vec::new();
+        for (account, share) in self.reward_splits.iter() {
+            let amount = share.mul_floor(reward).min(remainder);
+            if amount.is_zero() {
+                continue;
+            } else if amount < minimum_payout {
+                skipped.push((account.clone(), amount));
+            } else {
+                remainder.saturating_reduce(amount);
+                payouts.push((account.clone(), amount));
+            }
+        }
+
+        if !remainder.is_zero() {
+            payouts.push((self.reward_beneficiary().clone(), remainder));
+        }
+
+        (payouts, skipped)
+    }
+}
+
+/// `true` if the reward splits are valid, `false` otherwise.
+///
+/// Shares must be non-zero, accounts must be unique and the shares must sum up to at most 100%.
+pub fn are_reward_splits_valid<AccountId: PartialEq>(splits: &[(AccountId, Perbill)]) -> bool {
+    use sp_runtime::PerThing;
+
+    let mut total_share: u64 = 0;
+    for (idx, (account, share)) in splits.iter().enumerate() {
+        if share.deconstruct().is_zero() || splits[..idx].iter().any(|(other, _)| other == account)
+        {
+            return false;
+        }
+        total_share.saturating_accrue(share.deconstruct().into());
+    }
+
+    total_share <= Perbill::ACCURACY.into()
+}
+
+/// Category to which a dApp belongs.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum DAppCategory {
+    DeFi,
+    NFT,
+    Gaming,
+    Social,
+    Infrastructure,
+    Tooling,
+    Other,
+}
+
+/// Optional metadata describing a dApp.
+///
+/// Storing it requires a deposit, which is held from the `depositor` for as long as the metadata exists.
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    RuntimeDebugNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    CloneNoBound,
+    TypeInfo,
+)]
+#[scale_info(skip_type_params(NameLen, WebsiteLen))]
+pub struct DAppMetadata<
+    AccountId: Debug + PartialEq + Eq + Clone,
+    NameLen: Get<u32>,
+    WebsiteLen: Get<u32>,
+> {
+    /// Human readable name of the dApp.
+    pub name: BoundedVec<u8, NameLen>,
+    /// Website of the dApp.
+    pub website: BoundedVec<u8, WebsiteLen>,
+    /// Hash of the dApp's logo, which is stored off-chain.
+    pub logo_hash: H256,
+    /// Category of the dApp.
+    pub category: DAppCategory,
+    /// Account from which the deposit has been taken.
+    pub depositor: AccountId,
+    /// Deposit held for storing the metadata.
+    #[codec(compact)]
+    pub deposit: Balance,
+}
+
+impl<AccountId, NameLen, WebsiteLen> DAppMetadata<AccountId, NameLen, WebsiteLen>
+where
+    AccountId: Debug + PartialEq + Eq + Clone,
+    NameLen: Get<u32>,
+    WebsiteLen: Get<u32>,
+{
+    /// Number of bytes of variable length data, used to calculate the deposit.
+    pub fn data_len(&self) -> u32 {
+        self.name.len().saturating_add(self.website.len()) as u32
+    }
+}
+
+/// Unbounded representation of the `DAppMetadata`, intended for off-chain consumers.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct DAppMetadataInfo {
+    /// Human readable name of the dApp.
+    pub name: Vec<u8>,
+    /// Website of the dApp.
+    pub website: Vec<u8>,
+    /// Hash of the dApp's logo, which is stored off-chain.
+    pub logo_hash: H256,
+    /// Category of the dApp.
+    pub category: DAppCategory,
+    /// Deposit held for storing the metadata.
+    pub deposit: Balance,
+}
+
+impl<AccountId, NameLen, WebsiteLen> From<DAppMetadata<AccountId, NameLen, WebsiteLen>>
+    for DAppMetadataInfo
+where
+    AccountId: Debug + PartialEq + Eq + Clone,
+    NameLen: Get<u32>,
+    WebsiteLen: Get<u32>,
+{
+    fn from(metadata: DAppMetadata<AccountId, NameLen, WebsiteLen>) -> Self {
+        Self {
+            name: metadata.name.into_inner(),
+            website: metadata.website.into_inner(),
+            logo_hash: metadata.logo_hash,
+            category: metadata.category,
+            deposit: metadata.deposit,
+        }
+    }
 }
 
 /// How much was unlocked in some block.
@@ -396,6 +572,8 @@ pub struct AccountLedger<UnlockingLen: Get<u32>> {
     /// Number of contract stake entries in storage.
     #[codec(compact)]
     pub contract_stake_count: u32,
+    /// `true` if claimed staker & bonus rewards should be locked & staked again, `false` if they should remain in free balance.
+    pub restake: bool,
 }
 
 impl<UnlockingLen> Default for AccountLedger<UnlockingLen>
@@ -409,6 +587,7 @@ where
             staked: StakeAmount::default(),
             staked_future: None,
             contract_stake_count: Zero::zero(),
+            restake: false,
         }
     }
 }
@@ -1066,6 +1245,42 @@ impl SingularStakingInfo {
         )
     }
 
+    /// Moves the specified amount out of the contract stake, as part of a stake move to another contract.
+    ///
+    /// Amount is subtracted in the same way as with `unstake`, but the _loyalty_ flag is never removed
+    /// since the moved stake doesn't leave the protocol.
+    ///
+    /// Returns the amount that was moved out of the `voting period` stake, and out of the `build&earn period` stake.
+    pub fn move_out(
+        &mut self,
+        amount: Balance,
+        current_era: EraNumber,
+        subperiod: Subperiod,
+    ) -> (Balance, Balance) {
+        let loyal_staker = self.loyal_staker;
+        let moved_amount = self.unstake(amount, current_era, subperiod);
+        self.loyal_staker = loyal_staker;
+        // Moved stake leaves from the next era, same as it enters the destination contract
+        self.staked.era = current_era.saturating_add(1);
+
+        moved_amount
+    }
+
+    /// Moves the specified `voting` and `build_and_earn` amounts into the contract stake, as part of a stake move from another contract.
+    ///
+    /// The subperiod split of the moved stake is preserved. An empty entry becomes loyal if any `voting` stake is moved into it,
+    /// while a non-empty entry keeps its _loyalty_ flag as is.
+    pub fn move_in(&mut self, voting: Balance, build_and_earn: Balance, current_era: EraNumber) {
+        if self.is_empty() {
+            self.loyal_staker = self.loyal_staker || !voting.is_zero();
+        }
+
+        self.staked.voting.saturating_accrue(voting);
+        self.staked.build_and_earn.saturating_accrue(build_and_earn);
+        // Stake is only valid from the next era so we keep it consistent here
+        self.staked.era = current_era.saturating_add(1);
+    }
+
     /// Total staked on the contract by the user. Both subperiod stakes are included.
     pub fn total_staked_amount(&self) -> Balance {
         self.staked.total()
@@ -1186,39 +1401,85 @@ impl ContractStakeAmount {
 
     /// Stake the specified `amount` on the contract, for the specified `subperiod` and `era`.
     pub fn stake(&mut self, amount: Balance, period_info: PeriodInfo, current_era: EraNumber) {
+        self.next_era_entry(period_info, current_era)
+            .add(amount, period_info.subperiod);
+    }
+
+    /// Moves the specified `voting` and `build_and_earn` amounts out of the contract, as part of a stake move to another contract.
+    ///
+    /// Same as with `stake`, the change is only valid from the next era, so the stake of the ongoing era remains unchanged.
+    pub fn move_out(
+        &mut self,
+        voting: Balance,
+        build_and_earn: Balance,
+        period_info: PeriodInfo,
+        current_era: EraNumber,
+    ) {
+        let stake_amount = self.next_era_entry(period_info, current_era);
+        stake_amount.voting.saturating_reduce(voting);
+        stake_amount
+            .build_and_earn
+            .saturating_reduce(build_and_earn);
+    }
+
+    /// Moves the specified `voting` and `build_and_earn` amounts into the contract, as part of a stake move from another contract.
+    ///
+    /// The subperiod split of the moved stake is preserved, and it's valid from the next era, same as with `move_out`.
+    pub fn move_in(
+        &mut self,
+        voting: Balance,
+        build_and_earn: Balance,
+        period_info: PeriodInfo,
+        current_era: EraNumber,
+    ) {
+        let stake_amount = self.next_era_entry(period_info, current_era);
+        stake_amount.voting.saturating_accrue(voting);
+        stake_amount
+            .build_and_earn
+            .saturating_accrue(build_and_earn);
+    }
+
+    /// Returns the entry for the next era, `current_era + 1`, creating it if needed.
+    ///
+    /// The 'current' entry is aligned so it keeps covering the ongoing era.
+    fn next_era_entry(
+        &mut self,
+        period_info: PeriodInfo,
+        current_era: EraNumber,
+    ) -> &mut StakeAmount {
         let stake_era = current_era.saturating_add(1);
 
-        match self.staked_future.as_mut() {
-            // Future entry matches the era, just updated it and return
-            Some(stake_amount) if stake_amount.era == stake_era => {
-                stake_amount.add(amount, period_info.subperiod);
-                return;
-            }
-            // Future entry has older era, but periods match so overwrite the 'current' entry with it
-            Some(stake_amount) if stake_amount.period == period_info.number => {
-                self.staked = *stake_amount;
-            }
-            // Otherwise do nothing
-            _ => (),
-        }
+        match self.staked_future {
+            // Future entry matches the era, it can be used as is
+            Some(stake_amount) if stake_amount.era == stake_era => (),
+            future_entry => {
+                // Future entry has older era, but periods match so overwrite the 'current' entry with it
+                if let Some(stake_amount) = future_entry {
+                    if stake_amount.period == period_info.number {
+                        self.staked = stake_amount;
+                    }
+                }
 
-        // Prepare new entry
-        let mut new_entry = match self.staked {
-            // 'current' entry period matches so we use it as base for the new entry
-            stake_amount if stake_amount.period == period_info.number => stake_amount,
-            // otherwise just create a dummy new entry
-            _ => Default::default(),
-        };
-        new_entry.add(amount, period_info.subperiod);
-        new_entry.era = stake_era;
-        new_entry.period = period_info.number;
+                // Prepare new entry
+                let mut new_entry = match self.staked {
+                    // 'current' entry period matches so we use it as base for the new entry
+                    stake_amount if stake_amount.period == period_info.number => stake_amount,
+                    // otherwise just create a dummy new entry
+                    _ => Default::default(),
+                };
+                new_entry.era = stake_era;
+                new_entry.period = period_info.number;
 
-        self.staked_future = Some(new_entry);
+                self.staked_future = Some(new_entry);
 
-        // Convenience cleanup
-        if self.staked.period < period_info.number {
-            self.staked = Default::default();
+                // Convenience cleanup
+                if self.staked.period < period_info.number {
+                    self.staked = Default::default();
+                }
+            }
         }
+
+        self.staked_future.get_or_insert_with(Default::default)
     }
 
     /// Unstake the specified `amount` from the contract, for the specified `subperiod` and `era`.
@@ -1259,6 +1520,42 @@ impl ContractStakeAmount {
     }
 }
 
+/// Number of unique stakers staking on a particular smart contract in some period.
+///
+/// The counter is only valid for the period it refers to. Once a new period starts, it's implicitly reset to zero.
+#[derive(Encode, Decode, MaxEncodedLen, Copy, Clone, Debug, PartialEq, Eq, TypeInfo, Default)]
+pub struct ContractStakerCount {
+    /// Period to which the counter refers to.
+    #[codec(compact)]
+    pub period: PeriodNumber,
+    /// Number of unique stakers with a non-zero stake on the contract in the period.
+    #[codec(compact)]
+    pub count: u32,
+}
+
+impl ContractStakerCount {
+    /// Number of unique stakers for the specified period.
+    pub fn get(&self, period: PeriodNumber) -> u32 {
+        if self.period == period {
+            self.count
+        } else {
+            0
+        }
+    }
+
+    /// Register a new staker for the specified period.
+    pub fn increment(&mut self, period: PeriodNumber) {
+        self.count = self.get(period).saturating_add(1);
+        self.period = period;
+    }
+
+    /// Remove a staker for the specified period.
+    pub fn decrement(&mut self, period: PeriodNumber) {
+        self.count = self.get(period).saturating_sub(1);
+        self.period = period;
+    }
+}
+
 /// Information required for staker reward payout for a particular era.
 #[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo, Default)]
 pub struct EraReward {
@@ -1541,6 +1838,81 @@ impl<NT: Get<u32>> TiersConfiguration<NT> {
             && self.slots_per_tier.iter().fold(0, |acc, x| acc + x) == self.number_of_slots
     }
 
+    /// Assign the provided dApps into tiers, respecting the tier capacities & thresholds.
+    ///
+    /// dApps are expected to be sorted by their stake amount, in descending order - the top dApp comes first.
+    /// Each dApp is assigned to the best possible tier if it satisfies the required threshold and the tier capacity
+    /// hasn't been filled yet. Once a dApp doesn't satisfy the threshold, the next tier is considered.
+    pub fn assign_sorted_dapps(&self, dapps: &[DAppTierCandidate]) -> Vec<DAppTier> {
+        let mut dapp_tiers = Vec::with_capacity(dapps.len());
+
+        let mut global_idx = 0;
+        let mut tier_id = 0;
+        for (tier_capacity, tier_threshold) in
+            self.slots_per_tier.iter().zip(self.tier_thresholds.iter())
+        {
+            let max_idx = global_idx
+                .saturating_add(*tier_capacity as usize)
+                .min(dapps.len());
+
+            // Iterate over dApps until one of two conditions has been met:
+            // 1. Tier has no more capacity
+            // 2. dApp doesn't satisfy the tier threshold (since they're sorted, none of the following dApps will satisfy the condition either)
+            for dapp in dapps[global_idx..max_idx].iter() {
+                if tier_threshold.is_satisfied(dapp.stake_amount) {
+                    global_idx.saturating_inc();
+                    dapp_tiers.push(DAppTier {
+                        dapp_id: dapp.dapp_id,
+                        tier_id: Some(tier_id),
+                    });
+                } else {
+                    break;
+                }
+            }
+
+            tier_id.saturating_inc();
+        }
+
+        dapp_tiers
+    }
+
+    /// Assign the provided dApps into tiers, respecting the tier capacities & thresholds.
+    ///
+    /// dApps are expected to be sorted by their priority, in descending order - the top dApp comes first.
+    /// Unlike [`Self::assign_sorted_dapps`], the priority doesn't need to follow the stake amount.
+    /// Each tier is filled with the highest priority dApps which haven't been assigned yet and satisfy the tier threshold,
+    /// until the tier capacity is filled.
+    pub fn assign_ranked_dapps(&self, dapps: &[DAppTierCandidate]) -> Vec<DAppTier> {
+        let mut dapp_tiers = Vec::with_capacity(dapps.len());
+        let mut is_assigned = sp_std::vec![false; dapps.len()];
+
+        let mut tier_id = 0;
+        for (tier_capacity, tier_threshold) in
+            self.slots_per_tier.iter().zip(self.tier_thresholds.iter())
+        {
+            let mut free_slots = *tier_capacity;
+
+            for (dapp, is_assigned) in dapps.iter().zip(is_assigned.iter_mut()) {
+                if free_slots.is_zero() {
+                    break;
+                }
+
+                if !*is_assigned && tier_threshold.is_satisfied(dapp.stake_amount) {
+                    *is_assigned = true;
+                    free_slots.saturating_dec();
+                    dapp_tiers.push(DAppTier {
+                        dapp_id: dapp.dapp_id,
+                        tier_id: Some(tier_id),
+                    });
+                }
+            }
+
+            tier_id.saturating_inc();
+        }
+
+        dapp_tiers
+    }
+
     /// Calculate new `TiersConfiguration`, based on the old settings, current native currency price and tier configuration.
     pub fn calculate_new(&self, native_price: FixedU64, params: &TierParameters<NT>) -> Self {
         // It must always be at least 1 slot.
@@ -1709,6 +2081,19 @@ impl<MD: Get<u32>, NT: Get<u32>> DAppTierRewards<MD, NT> {
         })
     }
 
+    /// Sum of the rewards of all the dApps which are still in tiers, i.e. their rewards haven't been claimed yet.
+    pub fn total_rewards(&self) -> Balance {
+        self.dapps
+            .iter()
+            .filter_map(|dapp_tier| dapp_tier.tier_id)
+            .map(|tier_id| {
+                self.rewards
+                    .get(tier_id as usize)
+                    .map_or(Balance::zero(), |x| *x)
+            })
+            .fold(Balance::zero(), |acc, reward| acc.saturating_add(reward))
+    }
+
     /// Consume reward for the specified dapp id, returning its amount and tier Id.
     /// In case dapp isn't applicable for rewards, or they have already been consumed, returns `None`.
     pub fn try_claim(&mut self, dapp_id: DAppId) -> Result<(Balance, TierId), DAppTierError> {
@@ -1758,18 +2143,98 @@ pub struct CleanupMarker {
     pub dapp_tiers_index: EraNumber,
 }
 
-///////////////////////////////////////////////////////////////////////
-////////////   MOVE THIS TO SOME PRIMITIVES CRATE LATER    ////////////
-///////////////////////////////////////////////////////////////////////
+/// Information about a dApp which is a candidate for tier assignment.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DAppTierCandidate {
+    /// Unique dApp id in dApp staking protocol.
+    pub dapp_id: DAppId,
+    /// Total amount staked on the dApp in the era.
+    pub stake_amount: Balance,
+    /// Number of unique stakers staking on the dApp in the period.
+    pub number_of_stakers: u32,
+}
+
+/// Strategy used to assign dApps into tiers at the end of each era.
+pub trait TierAssignment {
+    /// Whether the strategy uses the number of stakers of each dApp.
+    ///
+    /// If not, `number_of_stakers` of the tier assignment candidates is left at zero, and isn't read from storage.
+    const USES_NUMBER_OF_STAKERS: bool = false;
+
+    /// Assign dApps into tiers.
+    ///
+    /// `dapps` - tier assignment candidates, for each dApp which has a non-zero stake in the era.
+    /// `tier_config` - active tier configuration, describing capacity & threshold of each tier.
+    ///
+    /// Returns tier assignment for each dApp which made it into a tier, in no particular order.
+    /// Tier capacities & thresholds described by `tier_config` must be respected.
+    fn assign_tiers<NT: Get<u32>>(
+        dapps: Vec<DAppTierCandidate>,
+        tier_config: &TiersConfiguration<NT>,
+    ) -> Vec<DAppTier>;
+}
+
+/// Default tier assignment strategy.
+///
+/// dApps are ranked by their total staked amount, and the tiers are filled greedily, starting from the top tier.
+///
+/// In case when tier has 1 more free slot, but two dApps with exactly same stake satisfy the threshold,
+/// there is no guarantee which one of them will be assigned to the tier.
+pub struct StakeAmountTierAssignment;
+impl TierAssignment for StakeAmountTierAssignment {
+    fn assign_tiers<NT: Get<u32>>(
+        mut dapps: Vec<DAppTierCandidate>,
+        tier_config: &TiersConfiguration<NT>,
+    ) -> Vec<DAppTier> {
+        // Sort by amount staked, in reverse - top dApp will end in the first place, 0th index.
+        dapps.sort_unstable_by(|dapp_1, dapp_2| dapp_2.stake_amount.cmp(&dapp_1.stake_amount));
+
+        tier_config.assign_sorted_dapps(&dapps)
+    }
+}
 
-/// Interface for fetching price of the native token.
+/// Tier assignment strategy which ranks dApps by their total staked amount,
+/// giving advantage to the dApp registered earlier in case of equal stake.
 ///
-/// TODO: discussion about below
-/// The assumption is that the underlying implementation will ensure
-/// this price is averaged and/or weighted over a certain period of time.
-/// Alternative is to provide e.g. number of blocks for which an approximately averaged value is needed,
-/// and let the underlying implementation take care converting block range into value best represening it.
-pub trait PriceProvider {
-    /// Get the price of the native token.
-    fn average_price() -> FixedU64;
+/// Since dApp Ids are assigned incrementally, the dApp with the lower Id is the one registered earlier.
+pub struct StakeAmountWithAgeTieBreakTierAssignment;
+impl TierAssignment for StakeAmountWithAgeTieBreakTierAssignment {
+    fn assign_tiers<NT: Get<u32>>(
+        mut dapps: Vec<DAppTierCandidate>,
+        tier_config: &TiersConfiguration<NT>,
+    ) -> Vec<DAppTier> {
+        dapps.sort_unstable_by(|dapp_1, dapp_2| {
+            dapp_2
+                .stake_amount
+                .cmp(&dapp_1.stake_amount)
+                .then(dapp_1.dapp_id.cmp(&dapp_2.dapp_id))
+        });
+
+        tier_config.assign_sorted_dapps(&dapps)
+    }
+}
+
+/// Tier assignment strategy which ranks dApps by the number of unique stakers,
+/// using the total staked amount and then registration age to break ties.
+///
+/// Tier thresholds still apply to the staked amount, so a dApp with many stakers can be placed into a lower tier
+/// than a dApp with fewer stakers if it doesn't have enough stake to satisfy the higher tier threshold.
+pub struct StakerCountTierAssignment;
+impl TierAssignment for StakerCountTierAssignment {
+    const USES_NUMBER_OF_STAKERS: bool = true;
+
+    fn assign_tiers<NT: Get<u32>>(
+        mut dapps: Vec<DAppTierCandidate>,
+        tier_config: &TiersConfiguration<NT>,
+    ) -> Vec<DAppTier> {
+        dapps.sort_unstable_by(|dapp_1, dapp_2| {
+            dapp_2
+                .number_of_stakers
+                .cmp(&dapp_1.number_of_stakers)
+                .then(dapp_2.stake_amount.cmp(&dapp_1.stake_amount))
+                .then(dapp_1.dapp_id.cmp(&dapp_2.dapp_id))
+        });
+
+        tier_config.assign_ranked_dapps(&dapps)
+    }
 }
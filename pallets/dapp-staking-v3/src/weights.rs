@@ -63,7 +63,7 @@ pub trait WeightInfo {
 	fn claim_staker_rewards_past_period(x: u32, ) -> Weight;
 	fn claim_staker_rewards_ongoing_period(x: u32, ) -> Weight;
 	fn claim_bonus_reward() -> Weight;
-	fn claim_dapp_reward() -> Weight;
+	fn claim_dapp_reward(x: u32, ) -> Weight;
 	fn unstake_from_unregistered() -> Weight;
 	fn cleanup_expired_entries(x: u32, ) -> Weight;
 	fn force() -> Weight;
@@ -71,7 +71,16 @@ pub trait WeightInfo {
 	fn on_initialize_build_and_earn_to_voting() -> Weight;
 	fn on_initialize_build_and_earn_to_build_and_earn() -> Weight;
 	fn dapp_tier_assignment(x: u32, ) -> Weight;
+	fn dapp_tier_assignment_by_staker_count(x: u32, ) -> Weight;
 	fn on_idle_cleanup() -> Weight;
+	fn set_restake() -> Weight;
+	fn restake_reward(x: u32, ) -> Weight;
+	fn move_stake() -> Weight;
+	fn claim_dapp_rewards_range(x: u32, ) -> Weight;
+	fn set_dapp_metadata() -> Weight;
+	fn clear_dapp_metadata() -> Weight;
+	fn set_dapp_reward_splits() -> Weight;
+	fn dapp_metadata() -> Weight;
 }
 
 /// Weights for pallet_dapp_staking_v3 using the Substrate node and recommended hardware.
@@ -85,7 +94,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(8_711_000, 0)
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:1)
-	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(121), added: 2101, mode: MaxEncodedLen)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
 	/// Storage: DappStaking CounterForIntegratedDApps (r:1 w:1)
 	/// Proof: DappStaking CounterForIntegratedDApps (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: DappStaking NextDAppId (r:1 w:1)
@@ -93,49 +102,57 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn register() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `3091`
+		//  Estimated: `3380`
 		// Minimum execution time: 16_360_000 picoseconds.
-		Weight::from_parts(16_697_000, 3091)
+		Weight::from_parts(16_697_000, 3380)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:1)
-	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(121), added: 2101, mode: MaxEncodedLen)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
 	fn set_dapp_reward_beneficiary() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `75`
-		//  Estimated: `3091`
+		//  Estimated: `3380`
 		// Minimum execution time: 12_927_000 picoseconds.
-		Weight::from_parts(13_229_000, 3091)
+		Weight::from_parts(13_229_000, 3380)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:1)
-	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(121), added: 2101, mode: MaxEncodedLen)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
 	fn set_dapp_owner() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `75`
-		//  Estimated: `3091`
+		//  Estimated: `3380`
 		// Minimum execution time: 13_610_000 picoseconds.
-		Weight::from_parts(13_851_000, 3091)
+		Weight::from_parts(13_851_000, 3380)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:1)
-	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(121), added: 2101, mode: MaxEncodedLen)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppMetadataOf (r:1 w:1)
+	/// Proof: DappStaking DAppMetadataOf (max_values: Some(65535), max_size: Some(287), added: 2267, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: DappStaking ContractStake (r:0 w:1)
 	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:0 w:1)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
 	fn unregister() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `75`
-		//  Estimated: `3091`
-		// Minimum execution time: 16_704_000 picoseconds.
-		Weight::from_parts(16_952_000, 3091)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		//  Measured:  `496`
+		//  Estimated: `3593`
+		// Minimum execution time: 38_412_000 picoseconds.
+		Weight::from_parts(39_307_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
@@ -152,7 +169,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
@@ -169,7 +186,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
@@ -189,7 +206,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
@@ -206,13 +223,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
-	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(121), added: 2101, mode: MaxEncodedLen)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: DappStaking StakerInfo (r:1 w:1)
 	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
 	/// Storage: DappStaking ContractStake (r:1 w:1)
 	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:1 w:1)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
 	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
@@ -225,17 +244,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `4764`
 		// Minimum execution time: 43_866_000 picoseconds.
 		Weight::from_parts(44_468_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
-	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(121), added: 2101, mode: MaxEncodedLen)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: DappStaking StakerInfo (r:1 w:1)
 	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
 	/// Storage: DappStaking ContractStake (r:1 w:1)
 	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:1 w:1)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
 	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
@@ -248,11 +269,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `4764`
 		// Minimum execution time: 47_368_000 picoseconds.
 		Weight::from_parts(48_049_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: DappStaking EraRewards (r:1 w:0)
 	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
 	/// Storage: DappStaking PeriodEnd (r:1 w:0)
@@ -274,7 +295,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: DappStaking EraRewards (r:1 w:0)
 	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
@@ -298,35 +319,40 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: DappStaking PeriodEnd (r:1 w:0)
 	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	fn claim_bonus_reward() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `267`
-		//  Estimated: `3775`
+		//  Estimated: `3776`
 		// Minimum execution time: 42_248_000 picoseconds.
-		Weight::from_parts(42_687_000, 3775)
+		Weight::from_parts(42_687_000, 3776)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
-	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(121), added: 2101, mode: MaxEncodedLen)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
 	/// Storage: DappStaking DAppTiers (r:1 w:1)
 	/// Proof: DappStaking DAppTiers (max_values: None, max_size: Some(2083), added: 4558, mode: MaxEncodedLen)
-	fn claim_dapp_reward() -> Weight {
+	/// Storage: System Account (r:8 w:8)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 8]`.
+	fn claim_dapp_reward(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3021`
-		//  Estimated: `5548`
-		// Minimum execution time: 50_968_000 picoseconds.
+		//  Estimated: `5548 + x * (2603 ±0)`
 		Weight::from_parts(51_778_000, 5548)
+			.saturating_add(Weight::from_parts(16_000_000, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(x.into()))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
-	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(121), added: 2101, mode: MaxEncodedLen)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
 	/// Storage: DappStaking StakerInfo (r:1 w:1)
 	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
 	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
@@ -345,7 +371,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: DappStaking StakerInfo (r:9 w:8)
 	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
@@ -391,6 +417,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: DappStaking StaticTierParams (max_values: Some(1), max_size: Some(167), added: 662, mode: MaxEncodedLen)
 	/// Storage: DappStaking TierConfig (r:1 w:1)
 	/// Proof: DappStaking TierConfig (max_values: Some(1), max_size: Some(161), added: 656, mode: MaxEncodedLen)
+	/// Storage: PriceOracle PriceHistory (r:1 w:0)
+	/// Proof: PriceOracle PriceHistory (max_values: Some(1), max_size: Some(3614), added: 4109, mode: MaxEncodedLen)
 	/// Storage: DappStaking EraRewards (r:1 w:1)
 	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
 	/// Storage: DappStaking PeriodEnd (r:0 w:1)
@@ -400,10 +428,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn on_initialize_build_and_earn_to_voting() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `550`
-		//  Estimated: `4254`
+		//  Estimated: `5099`
 		// Minimum execution time: 39_768_000 picoseconds.
-		Weight::from_parts(40_422_000, 4254)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		Weight::from_parts(40_422_000, 5099)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
@@ -438,6 +466,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 2071).saturating_mul(x.into()))
 	}
+	/// Storage: DappStaking ContractStake (r:101 w:0)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:100 w:0)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
+	/// Storage: DappStaking TierConfig (r:1 w:0)
+	/// Proof: DappStaking TierConfig (max_values: Some(1), max_size: Some(161), added: 656, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn dapp_tier_assignment_by_staker_count(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3061 + x * (4071 ±0)`
+		Weight::from_parts(10_826_637, 3061)
+			// Standard Error: 3_374
+			.saturating_add(Weight::from_parts(3_512_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 4071).saturating_mul(x.into()))
+	}
 	/// Storage: DappStaking HistoryCleanupMarker (r:1 w:1)
 	/// Proof: DappStaking HistoryCleanupMarker (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: DappStaking PeriodEnd (r:1 w:1)
@@ -455,6 +500,128 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
+	fn set_restake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `190`
+		//  Estimated: `3776`
+		// Minimum execution time: 12_614_000 picoseconds.
+		Weight::from_parts(12_958_000, 3776)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Storage: DappStaking StakerInfo (r:9 w:8)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking IntegratedDApps (r:8 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStake (r:8 w:8)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 8]`.
+	fn restake_reward(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `134 + x * (176 ±0)`
+		//  Estimated: `3603 + x * (2613 ±0)`
+		// Minimum execution time: 9_283_000 picoseconds.
+		Weight::from_parts(9_876_112, 3603)
+			// Standard Error: 9_412
+			.saturating_add(Weight::from_parts(11_482_927, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2613).saturating_mul(x.into()))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:2 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
+	/// Storage: DappStaking StakerInfo (r:2 w:2)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStake (r:2 w:2)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:2 w:2)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
+	fn move_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `552`
+		//  Estimated: `6216`
+		// Minimum execution time: 41_207_000 picoseconds.
+		Weight::from_parts(42_116_000, 6216)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppTiers (r:16 w:16)
+	/// Proof: DappStaking DAppTiers (max_values: None, max_size: Some(2083), added: 4558, mode: MaxEncodedLen)
+	/// Storage: System Account (r:9 w:9)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn claim_dapp_rewards_range(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `26518 + x * (4558 ±0)`
+		Weight::from_parts(163_871_204, 26518)
+			.saturating_add(Weight::from_parts(20_652_390, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 4558).saturating_mul(x.into()))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
+	/// Storage: DappStaking Ledger (r:1 w:0)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppMetadataOf (r:1 w:1)
+	/// Proof: DappStaking DAppMetadataOf (max_values: Some(65535), max_size: Some(287), added: 2267, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn set_dapp_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3776`
+		Weight::from_parts(40_284_000, 3776)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppMetadataOf (r:1 w:1)
+	/// Proof: DappStaking DAppMetadataOf (max_values: Some(65535), max_size: Some(287), added: 2267, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn clear_dapp_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3593`
+		Weight::from_parts(33_871_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:1)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
+	fn set_dapp_reward_splits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3380`
+		Weight::from_parts(14_770_000, 3380)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppMetadataOf (r:1 w:0)
+	/// Proof: DappStaking DAppMetadataOf (max_values: Some(65535), max_size: Some(287), added: 2267, mode: MaxEncodedLen)
+	fn dapp_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3380`
+		Weight::from_parts(9_126_000, 3380)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -467,7 +634,7 @@ impl WeightInfo for () {
 		Weight::from_parts(8_711_000, 0)
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:1)
-	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(121), added: 2101, mode: MaxEncodedLen)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
 	/// Storage: DappStaking CounterForIntegratedDApps (r:1 w:1)
 	/// Proof: DappStaking CounterForIntegratedDApps (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: DappStaking NextDAppId (r:1 w:1)
@@ -475,49 +642,57 @@ impl WeightInfo for () {
 	fn register() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `3091`
+		//  Estimated: `3380`
 		// Minimum execution time: 16_360_000 picoseconds.
-		Weight::from_parts(16_697_000, 3091)
+		Weight::from_parts(16_697_000, 3380)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:1)
-	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(121), added: 2101, mode: MaxEncodedLen)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
 	fn set_dapp_reward_beneficiary() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `75`
-		//  Estimated: `3091`
+		//  Estimated: `3380`
 		// Minimum execution time: 12_927_000 picoseconds.
-		Weight::from_parts(13_229_000, 3091)
+		Weight::from_parts(13_229_000, 3380)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:1)
-	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(121), added: 2101, mode: MaxEncodedLen)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
 	fn set_dapp_owner() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `75`
-		//  Estimated: `3091`
+		//  Estimated: `3380`
 		// Minimum execution time: 13_610_000 picoseconds.
-		Weight::from_parts(13_851_000, 3091)
+		Weight::from_parts(13_851_000, 3380)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:1)
-	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(121), added: 2101, mode: MaxEncodedLen)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppMetadataOf (r:1 w:1)
+	/// Proof: DappStaking DAppMetadataOf (max_values: Some(65535), max_size: Some(287), added: 2267, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: DappStaking ContractStake (r:0 w:1)
 	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:0 w:1)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
 	fn unregister() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `75`
-		//  Estimated: `3091`
-		// Minimum execution time: 16_704_000 picoseconds.
-		Weight::from_parts(16_952_000, 3091)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `496`
+		//  Estimated: `3593`
+		// Minimum execution time: 38_412_000 picoseconds.
+		Weight::from_parts(39_307_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
@@ -534,7 +709,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
@@ -551,7 +726,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
@@ -571,7 +746,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
@@ -588,13 +763,15 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
-	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(121), added: 2101, mode: MaxEncodedLen)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: DappStaking StakerInfo (r:1 w:1)
 	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
 	/// Storage: DappStaking ContractStake (r:1 w:1)
 	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:1 w:1)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
 	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
@@ -607,17 +784,19 @@ impl WeightInfo for () {
 		//  Estimated: `4764`
 		// Minimum execution time: 43_866_000 picoseconds.
 		Weight::from_parts(44_468_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
-	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(121), added: 2101, mode: MaxEncodedLen)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: DappStaking StakerInfo (r:1 w:1)
 	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
 	/// Storage: DappStaking ContractStake (r:1 w:1)
 	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:1 w:1)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
 	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
@@ -630,11 +809,11 @@ impl WeightInfo for () {
 		//  Estimated: `4764`
 		// Minimum execution time: 47_368_000 picoseconds.
 		Weight::from_parts(48_049_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: DappStaking EraRewards (r:1 w:0)
 	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
 	/// Storage: DappStaking PeriodEnd (r:1 w:0)
@@ -656,7 +835,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: DappStaking EraRewards (r:1 w:0)
 	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
@@ -680,35 +859,40 @@ impl WeightInfo for () {
 	/// Storage: DappStaking PeriodEnd (r:1 w:0)
 	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	fn claim_bonus_reward() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `267`
-		//  Estimated: `3775`
+		//  Estimated: `3776`
 		// Minimum execution time: 42_248_000 picoseconds.
-		Weight::from_parts(42_687_000, 3775)
+		Weight::from_parts(42_687_000, 3776)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
-	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(121), added: 2101, mode: MaxEncodedLen)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
 	/// Storage: DappStaking DAppTiers (r:1 w:1)
 	/// Proof: DappStaking DAppTiers (max_values: None, max_size: Some(2083), added: 4558, mode: MaxEncodedLen)
-	fn claim_dapp_reward() -> Weight {
+	/// Storage: System Account (r:8 w:8)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 8]`.
+	fn claim_dapp_reward(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3021`
-		//  Estimated: `5548`
-		// Minimum execution time: 50_968_000 picoseconds.
+		//  Estimated: `5548 + x * (2603 ±0)`
 		Weight::from_parts(51_778_000, 5548)
+			.saturating_add(Weight::from_parts(16_000_000, 0).saturating_mul(x.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(x.into()))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
-	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(121), added: 2101, mode: MaxEncodedLen)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
 	/// Storage: DappStaking StakerInfo (r:1 w:1)
 	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
 	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
@@ -727,7 +911,7 @@ impl WeightInfo for () {
 	/// Storage: DappStaking StakerInfo (r:9 w:8)
 	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
 	/// Storage: DappStaking Ledger (r:1 w:1)
-	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
@@ -773,6 +957,8 @@ impl WeightInfo for () {
 	/// Proof: DappStaking StaticTierParams (max_values: Some(1), max_size: Some(167), added: 662, mode: MaxEncodedLen)
 	/// Storage: DappStaking TierConfig (r:1 w:1)
 	/// Proof: DappStaking TierConfig (max_values: Some(1), max_size: Some(161), added: 656, mode: MaxEncodedLen)
+	/// Storage: PriceOracle PriceHistory (r:1 w:0)
+	/// Proof: PriceOracle PriceHistory (max_values: Some(1), max_size: Some(3614), added: 4109, mode: MaxEncodedLen)
 	/// Storage: DappStaking EraRewards (r:1 w:1)
 	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
 	/// Storage: DappStaking PeriodEnd (r:0 w:1)
@@ -782,10 +968,10 @@ impl WeightInfo for () {
 	fn on_initialize_build_and_earn_to_voting() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `550`
-		//  Estimated: `4254`
+		//  Estimated: `5099`
 		// Minimum execution time: 39_768_000 picoseconds.
-		Weight::from_parts(40_422_000, 4254)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		Weight::from_parts(40_422_000, 5099)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
@@ -820,6 +1006,23 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 2071).saturating_mul(x.into()))
 	}
+	/// Storage: DappStaking ContractStake (r:101 w:0)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:100 w:0)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
+	/// Storage: DappStaking TierConfig (r:1 w:0)
+	/// Proof: DappStaking TierConfig (max_values: Some(1), max_size: Some(161), added: 656, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn dapp_tier_assignment_by_staker_count(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3061 + x * (4071 ±0)`
+		Weight::from_parts(10_826_637, 3061)
+			// Standard Error: 3_374
+			.saturating_add(Weight::from_parts(3_512_000, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 4071).saturating_mul(x.into()))
+	}
 	/// Storage: DappStaking HistoryCleanupMarker (r:1 w:1)
 	/// Proof: DappStaking HistoryCleanupMarker (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: DappStaking PeriodEnd (r:1 w:1)
@@ -837,4 +1040,126 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
+	fn set_restake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `190`
+		//  Estimated: `3776`
+		// Minimum execution time: 12_614_000 picoseconds.
+		Weight::from_parts(12_958_000, 3776)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Storage: DappStaking StakerInfo (r:9 w:8)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking IntegratedDApps (r:8 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStake (r:8 w:8)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 8]`.
+	fn restake_reward(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `134 + x * (176 ±0)`
+		//  Estimated: `3603 + x * (2613 ±0)`
+		// Minimum execution time: 9_283_000 picoseconds.
+		Weight::from_parts(9_876_112, 3603)
+			// Standard Error: 9_412
+			.saturating_add(Weight::from_parts(11_482_927, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2613).saturating_mul(x.into()))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:2 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
+	/// Storage: DappStaking StakerInfo (r:2 w:2)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStake (r:2 w:2)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:2 w:2)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
+	fn move_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `552`
+		//  Estimated: `6216`
+		// Minimum execution time: 41_207_000 picoseconds.
+		Weight::from_parts(42_116_000, 6216)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppTiers (r:16 w:16)
+	/// Proof: DappStaking DAppTiers (max_values: None, max_size: Some(2083), added: 4558, mode: MaxEncodedLen)
+	/// Storage: System Account (r:9 w:9)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn claim_dapp_rewards_range(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `26518 + x * (4558 ±0)`
+		Weight::from_parts(163_871_204, 26518)
+			.saturating_add(Weight::from_parts(20_652_390, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 4558).saturating_mul(x.into()))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
+	/// Storage: DappStaking Ledger (r:1 w:0)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(311), added: 2786, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppMetadataOf (r:1 w:1)
+	/// Proof: DappStaking DAppMetadataOf (max_values: Some(65535), max_size: Some(287), added: 2267, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn set_dapp_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3776`
+		Weight::from_parts(40_284_000, 3776)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppMetadataOf (r:1 w:1)
+	/// Proof: DappStaking DAppMetadataOf (max_values: Some(65535), max_size: Some(287), added: 2267, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn clear_dapp_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3593`
+		Weight::from_parts(33_871_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:1)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
+	fn set_dapp_reward_splits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3380`
+		Weight::from_parts(14_770_000, 3380)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(410), added: 2390, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppMetadataOf (r:1 w:0)
+	/// Proof: DappStaking DAppMetadataOf (max_values: Some(65535), max_size: Some(287), added: 2267, mode: MaxEncodedLen)
+	fn dapp_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3380`
+		Weight::from_parts(9_126_000, 3380)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
 }
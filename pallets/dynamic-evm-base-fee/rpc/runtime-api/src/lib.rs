@@ -0,0 +1,36 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet_dynamic_evm_base_fee::BaseFeeRecord;
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+
+    /// Dynamic EVM Base Fee Api.
+    ///
+    /// Used to provide the 'base fee per gas' values actually applied in the recent blocks.
+    pub trait DynamicEvmBaseFeeApi<BlockNumber>
+    where
+        BlockNumber: Codec,
+    {
+        /// Up to `count` most recent base fee records, ordered from the oldest to the newest.
+        fn base_fee_history(count: u32) -> Vec<BaseFeeRecord<BlockNumber>>;
+    }
+}
@@ -20,7 +20,7 @@ use super::*;
 
 use fp_evm::FeeCalculator;
 use frame_benchmarking::v2::*;
-use frame_support::traits::Hooks;
+use frame_support::traits::{EnsureOrigin, Get, Hooks};
 use frame_system::RawOrigin;
 
 #[benchmarks]
@@ -81,6 +81,62 @@ mod benchmarks {
         }
     }
 
+    #[benchmark]
+    fn set_min_base_fee_per_gas() -> Result<(), BenchmarkError> {
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let fee = T::DefaultBaseFeePerGas::get();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Some(fee));
+
+        assert_eq!(MinBaseFeePerGasOverride::<T>::get(), Some(fee));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_max_base_fee_per_gas() -> Result<(), BenchmarkError> {
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let fee = T::DefaultBaseFeePerGas::get();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Some(fee));
+
+        assert_eq!(MaxBaseFeePerGasOverride::<T>::get(), Some(fee));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_step_limit_ratio() -> Result<(), BenchmarkError> {
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let ratio = Perquintill::from_percent(1);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Some(ratio));
+
+        assert_eq!(StepLimitRatioOverride::<T>::get(), Some(ratio));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_adjustment_factor() -> Result<(), BenchmarkError> {
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let factor = FixedU128::from_rational(1, 2);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Some(factor));
+
+        assert_eq!(AdjustmentFactorOverride::<T>::get(), Some(factor));
+
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::benchmarking::tests::new_test_ext(),
@@ -39,10 +39,38 @@
 //!
 //! The implementation doesn't make any hard requirements on these values, and only requires that a type implementing `Get<_>` provides them.
 //!
+//! ### Gas Target Mode
+//!
+//! Alternatively, the pallet can be configured to adjust `base_fee_per_gas` similar to `EIP-1559`, using the EVM gas usage of the block.
+//! The mode is selected via `AdjustmentMode` config parameter.
+//!
+//! gas_target = block_gas_limit / elasticity
+//!
+//! base_fee_per_gas_new = base_fee_per_gas_old * (1 + (gas_used - gas_target) / gas_target / 8)
+//!
+//! If the block used more gas than the target, the fee goes up, and vice versa.
+//! The same min & max limits, and the same `StepLimitRatio` apply as in the default mode.
+//!
+//! ### Base Fee History
+//!
+//! For the last `BaseFeeHistoryLength` blocks, the pallet keeps a record of the `base_fee_per_gas` applied in the block,
+//! together with the block fullness ratio (EVM gas used / block gas limit).
+//! The records are kept in a ring buffer, indexed by `block_number % BaseFeeHistoryLength`.
+//!
+//! This allows clients to get the fee which was actually used in a block, instead of the one calculated in its `on_finalize` hook
+//! (which only applies to the next block). E.g. it's used to serve `eth_feeHistory` RPC requests.
+//!
+//! ### Governance Overrides
+//!
+//! `MinBaseFeePerGas`, `MaxBaseFeePerGas`, `StepLimitRatio` and `AdjustmentFactor` values configured in the runtime
+//! can be overridden via storage by the `UpdateOrigin`, without requiring a runtime upgrade.
+//! Once the override is removed, the runtime configured value is used again.
+//!
 //! ## Implementation
 //!
 //! The core logic is implemented in `on_finalize` hook, which is called at the end of each block.
 //! This pallet's hook should be called AFTER whichever pallet's hook is responsible for updating **adjustment factor**.
+//! It should also be called AFTER `pallet-ethereum` hook, in case the gas usage is read from the Ethereum block finalized there.
 //!
 //! The hook will calculate the ideal new `base_fee_per_gas` value, and then clamp it in between the allowed limits.
 //!
@@ -52,6 +80,8 @@
 //!
 //! A _root-only_ extrinsic is provided to allow setting the `base_fee_per_gas` value manually.
 //!
+//! Extrinsics callable by the `UpdateOrigin` are provided to override the adjustment parameters.
+//!
 //! ## Practical Remarks
 //!
 //! According to the proposed **Tokenomics 2.0**, max amount that adjustment factor will be able to change on live networks in-between blocks is:
@@ -75,9 +105,15 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::weights::Weight;
+use frame_support::{weights::Weight, RuntimeDebug};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
 use sp_core::U256;
-use sp_runtime::{traits::UniqueSaturatedInto, FixedPointNumber, FixedU128, Perquintill};
+use sp_runtime::{
+    traits::{One, UniqueSaturatedInto, Zero},
+    FixedPointNumber, FixedU128, Perbill, Permill, Perquintill,
+};
+use sp_std::vec::Vec;
 
 pub use self::pallet::*;
 
@@ -92,6 +128,40 @@ mod benchmarking;
 pub mod weights;
 pub use weights::WeightInfo;
 
+/// Max change denominator of 'base fee per gas' in the gas target mode, as specified by `EIP-1559`.
+pub const BASE_FEE_MAX_CHANGE_DENOMINATOR: u32 = 8;
+
+/// Approach used to adjust 'base fee per gas' in-between the blocks.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum BaseFeeAdjustmentMode {
+    /// Align 'base fee per gas' with the native transaction fees, using the adjustment factor.
+    FeeAlignment,
+    /// Target the configured gas usage level of the EVM block, similar to `EIP-1559`.
+    GasTarget,
+}
+
+/// EVM gas usage of a block.
+#[derive(
+    Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo,
+)]
+pub struct BlockGasUsage {
+    /// Gas used by the transactions in the block.
+    pub used: U256,
+    /// Block gas limit.
+    pub limit: U256,
+}
+
+/// Record of the 'base fee per gas' applied in a block.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BaseFeeRecord<BlockNumber> {
+    /// Number of the block in which the base fee was applied.
+    pub block_number: BlockNumber,
+    /// 'Base fee per gas' applied in the block.
+    pub base_fee: U256,
+    /// Ratio between the EVM gas used by the block & the block gas limit.
+    pub fullness: Perbill,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::pallet_prelude::*;
@@ -124,6 +194,19 @@ pub mod pallet {
         /// It's expressed as percentage, and used to calculate the delta between the old and new value.
         /// E.g. if the current 'base fee per gas' is 100, and the limit is 10%, then the new base fee per gas can be between 90 and 110.
         type StepLimitRatio: Get<Perquintill>;
+        /// Approach used to adjust the 'base fee per gas' in-between the blocks.
+        type AdjustmentMode: Get<BaseFeeAdjustmentMode>;
+        /// Getter for the EVM gas usage of the current block, e.g. the finalized `pallet-ethereum` block.
+        /// Read once at the end of each block, for the block fullness record & the gas target mode.
+        type GasUsage: Get<BlockGasUsage>;
+        /// Ratio between the block gas limit and the targeted gas usage, e.g. `2` targets half full blocks.
+        /// Only used in the gas target mode.
+        type Elasticity: Get<u32>;
+        /// Number of the most recent blocks for which the applied 'base fee per gas' is kept in the history.
+        #[pallet::constant]
+        type BaseFeeHistoryLength: Get<u32>;
+        /// Origin which can override the 'base fee per gas' adjustment parameters.
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
         /// Weight information for extrinsics & functions of this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -136,17 +219,51 @@ pub mod pallet {
     #[pallet::storage]
     pub type BaseFeePerGas<T> = StorageValue<_, U256, ValueQuery, DefaultBaseFeePerGas<T>>;
 
+    /// Governance override of the `MinBaseFeePerGas` value configured in the runtime.
+    #[pallet::storage]
+    pub type MinBaseFeePerGasOverride<T> = StorageValue<_, U256, OptionQuery>;
+
+    /// Governance override of the `MaxBaseFeePerGas` value configured in the runtime.
+    #[pallet::storage]
+    pub type MaxBaseFeePerGasOverride<T> = StorageValue<_, U256, OptionQuery>;
+
+    /// Governance override of the `StepLimitRatio` value configured in the runtime.
+    #[pallet::storage]
+    pub type StepLimitRatioOverride<T> = StorageValue<_, Perquintill, OptionQuery>;
+
+    /// Governance override of the `AdjustmentFactor` value configured in the runtime.
+    #[pallet::storage]
+    pub type AdjustmentFactorOverride<T> = StorageValue<_, FixedU128, OptionQuery>;
+
+    /// Ring buffer of the 'base fee per gas' values applied in the most recent blocks.
+    /// Indexed by `block_number % BaseFeeHistoryLength`.
+    #[pallet::storage]
+    pub type BaseFeeHistory<T: Config> =
+        StorageMap<_, Twox64Concat, u32, BaseFeeRecord<T::BlockNumber>, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event {
         /// New `base fee per gas` value has been force-set.
         NewBaseFeePerGas { fee: U256 },
+        /// Minimum `base fee per gas` override has been set, or removed if `None`.
+        MinBaseFeePerGasOverrideSet { fee: Option<U256> },
+        /// Maximum `base fee per gas` override has been set, or removed if `None`.
+        MaxBaseFeePerGasOverrideSet { fee: Option<U256> },
+        /// Step limit ratio override has been set, or removed if `None`.
+        StepLimitRatioOverrideSet { ratio: Option<Perquintill> },
+        /// Adjustment factor override has been set, or removed if `None`.
+        AdjustmentFactorOverrideSet { factor: Option<FixedU128> },
     }
 
     #[pallet::error]
     pub enum Error<T> {
         /// Specified value is outside of the allowed range.
         ValueOutOfBounds,
+        /// Minimum `base fee per gas` would be higher than the default one.
+        MinBaseFeePerGasTooHigh,
+        /// Maximum `base fee per gas` would be lower than the default one, or higher than `u128::MAX`.
+        MaxBaseFeePerGasTooLow,
     }
 
     #[pallet::hooks]
@@ -155,28 +272,36 @@ pub mod pallet {
             T::WeightInfo::base_fee_per_gas_adjustment()
         }
 
-        fn on_finalize(_n: <T as frame_system::Config>::BlockNumber) {
+        fn on_finalize(n: <T as frame_system::Config>::BlockNumber) {
+            // Gas usage is read only once, since it's used both for the history record & the gas target.
+            let gas_usage = T::GasUsage::get();
+
             BaseFeePerGas::<T>::mutate(|base_fee_per_gas| {
                 let old_bfpg = *base_fee_per_gas;
 
+                // Old value is the one applied in this block, so it's the one that goes into the history.
+                Self::note_base_fee(n, old_bfpg, &gas_usage);
+
                 // Maximum step we're allowed to move the base fee per gas by.
                 let max_step = {
                     let old_bfpg_u128: u128 = old_bfpg.unique_saturated_into();
-                    let step = T::StepLimitRatio::get() * old_bfpg_u128;
+                    let step = Self::step_limit_ratio() * old_bfpg_u128;
                     U256::from(step)
                 };
 
+                let (min_bfpg, max_bfpg) =
+                    (Self::min_base_fee_per_gas(), Self::max_base_fee_per_gas());
+
                 // It's possible current base fee per gas is outside of the allowed range.
                 // This can & will happen when this solution is deployed on live networks.
                 //
                 // In such scenario, we will discard the lower & upper bounds configured in the runtime.
                 // Once these bounds are reached ONCE, the runtime logic will prevent them from going out of bounds again.
-                let apply_configured_bounds = old_bfpg >= T::MinBaseFeePerGas::get()
-                    && old_bfpg <= T::MaxBaseFeePerGas::get();
+                let apply_configured_bounds = old_bfpg >= min_bfpg && old_bfpg <= max_bfpg;
                 let (lower_limit, upper_limit) = if apply_configured_bounds {
                     (
-                        T::MinBaseFeePerGas::get().max(old_bfpg.saturating_sub(max_step)),
-                        T::MaxBaseFeePerGas::get().min(old_bfpg.saturating_add(max_step)),
+                        min_bfpg.max(old_bfpg.saturating_sub(max_step)),
+                        max_bfpg.min(old_bfpg.saturating_add(max_step)),
                     )
                 } else {
                     (
@@ -185,15 +310,19 @@ pub mod pallet {
                     )
                 };
 
-                // Calculate ideal new 'base_fee_per_gas' according to the formula
-                let ideal_new_bfpg = T::AdjustmentFactor::get()
-                    // Weight factor should be multiplied first since it's a larger number, to avoid precision loss.
-                    .saturating_mul_int(T::WeightFactor::get())
-                    .saturating_mul(25)
-                    .saturating_div(98974);
+                // Calculate ideal new 'base_fee_per_gas' according to the configured mode.
+                //
+                // It's kept in between the configured bounds, so in case bounds are changed by governance
+                // during the adjustment, the value gradually moves back in between them.
+                let ideal_new_bfpg = match T::AdjustmentMode::get() {
+                    BaseFeeAdjustmentMode::FeeAlignment => Self::fee_alignment_bfpg(),
+                    BaseFeeAdjustmentMode::GasTarget => Self::gas_target_bfpg(old_bfpg, &gas_usage),
+                }
+                .max(min_bfpg)
+                .min(max_bfpg);
 
                 // Clamp the ideal value in between the allowed limits
-                *base_fee_per_gas = U256::from(ideal_new_bfpg).clamp(lower_limit, upper_limit);
+                *base_fee_per_gas = ideal_new_bfpg.clamp(lower_limit, upper_limit);
             })
         }
 
@@ -208,6 +337,16 @@ pub mod pallet {
 
             assert!(T::MaxBaseFeePerGas::get() <= U256::from(u128::MAX),
                 "Maximum base fee per gas has to be equal or lower than u128::MAX, otherwise precision loss will occur.");
+
+            assert!(
+                T::Elasticity::get() > 0,
+                "Elasticity has to be greater than zero."
+            );
+
+            assert!(
+                T::BaseFeeHistoryLength::get() > 0,
+                "Base fee history length has to be greater than zero."
+            );
         }
     }
 
@@ -220,7 +359,7 @@ pub mod pallet {
         pub fn set_base_fee_per_gas(origin: OriginFor<T>, fee: U256) -> DispatchResult {
             ensure_root(origin)?;
             ensure!(
-                fee >= T::MinBaseFeePerGas::get() && fee <= T::MaxBaseFeePerGas::get(),
+                fee >= Self::min_base_fee_per_gas() && fee <= Self::max_base_fee_per_gas(),
                 Error::<T>::ValueOutOfBounds
             );
 
@@ -228,6 +367,215 @@ pub mod pallet {
             Self::deposit_event(Event::NewBaseFeePerGas { fee });
             Ok(())
         }
+
+        /// Override the minimum `base_fee_per_gas` value configured in the runtime, or remove the override if `None`.
+        /// The value has to be equal or lower than the default `base_fee_per_gas`.
+        ///
+        /// Can only be called by the `UpdateOrigin`.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_min_base_fee_per_gas())]
+        pub fn set_min_base_fee_per_gas(origin: OriginFor<T>, fee: Option<U256>) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            if let Some(fee) = fee {
+                ensure!(
+                    fee <= T::DefaultBaseFeePerGas::get(),
+                    Error::<T>::MinBaseFeePerGasTooHigh
+                );
+            }
+
+            MinBaseFeePerGasOverride::<T>::set(fee);
+            Self::deposit_event(Event::MinBaseFeePerGasOverrideSet { fee });
+            Ok(())
+        }
+
+        /// Override the maximum `base_fee_per_gas` value configured in the runtime, or remove the override if `None`.
+        /// The value has to be equal or higher than the default `base_fee_per_gas`, and must fit into `u128`.
+        ///
+        /// Can only be called by the `UpdateOrigin`.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::set_max_base_fee_per_gas())]
+        pub fn set_max_base_fee_per_gas(origin: OriginFor<T>, fee: Option<U256>) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            if let Some(fee) = fee {
+                ensure!(
+                    fee >= T::DefaultBaseFeePerGas::get() && fee <= U256::from(u128::MAX),
+                    Error::<T>::MaxBaseFeePerGasTooLow
+                );
+            }
+
+            MaxBaseFeePerGasOverride::<T>::set(fee);
+            Self::deposit_event(Event::MaxBaseFeePerGasOverrideSet { fee });
+            Ok(())
+        }
+
+        /// Override the step limit ratio configured in the runtime, or remove the override if `None`.
+        ///
+        /// Can only be called by the `UpdateOrigin`.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_step_limit_ratio())]
+        pub fn set_step_limit_ratio(
+            origin: OriginFor<T>,
+            ratio: Option<Perquintill>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            StepLimitRatioOverride::<T>::set(ratio);
+            Self::deposit_event(Event::StepLimitRatioOverrideSet { ratio });
+            Ok(())
+        }
+
+        /// Override the adjustment factor provided by the runtime, or remove the override if `None`.
+        ///
+        /// Can only be called by the `UpdateOrigin`.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::set_adjustment_factor())]
+        pub fn set_adjustment_factor(
+            origin: OriginFor<T>,
+            factor: Option<FixedU128>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            AdjustmentFactorOverride::<T>::set(factor);
+            Self::deposit_event(Event::AdjustmentFactorOverrideSet { factor });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Minimum 'base fee per gas', taking the governance override into account.
+        pub fn min_base_fee_per_gas() -> U256 {
+            MinBaseFeePerGasOverride::<T>::get().unwrap_or_else(T::MinBaseFeePerGas::get)
+        }
+
+        /// Maximum 'base fee per gas', taking the governance override into account.
+        pub fn max_base_fee_per_gas() -> U256 {
+            MaxBaseFeePerGasOverride::<T>::get().unwrap_or_else(T::MaxBaseFeePerGas::get)
+        }
+
+        /// Step limit ratio, taking the governance override into account.
+        pub fn step_limit_ratio() -> Perquintill {
+            StepLimitRatioOverride::<T>::get().unwrap_or_else(T::StepLimitRatio::get)
+        }
+
+        /// Adjustment factor, taking the governance override into account.
+        pub fn adjustment_factor() -> FixedU128 {
+            AdjustmentFactorOverride::<T>::get().unwrap_or_else(T::AdjustmentFactor::get)
+        }
+
+        /// Ideal 'base fee per gas' which aligns the EVM fees with the native transaction fees.
+        pub(crate) fn fee_alignment_bfpg() -> U256 {
+            let ideal_bfpg = Self::adjustment_factor()
+                // Weight factor should be multiplied first since it's a larger number, to avoid precision loss.
+                .saturating_mul_int(T::WeightFactor::get())
+                .saturating_mul(25)
+                .saturating_div(98974);
+
+            U256::from(ideal_bfpg)
+        }
+
+        /// Ideal 'base fee per gas' according to the `EIP-1559` formula, based on the gas usage of the current block.
+        ///
+        /// If the gas usage is unknown, the old value is returned.
+        pub(crate) fn gas_target_bfpg(old_bfpg: U256, gas_usage: &BlockGasUsage) -> U256 {
+            let BlockGasUsage { used, limit } = *gas_usage;
+            let gas_target = limit / U256::from(T::Elasticity::get().max(1));
+            if gas_target.is_zero() {
+                return old_bfpg;
+            }
+
+            let denominator = gas_target.saturating_mul(BASE_FEE_MAX_CHANGE_DENOMINATOR.into());
+            if used > gas_target {
+                // Like in `EIP-1559`, the fee should always increase if the target is exceeded.
+                let delta =
+                    (old_bfpg.saturating_mul(used - gas_target) / denominator).max(U256::one());
+                old_bfpg.saturating_add(delta)
+            } else {
+                let delta = old_bfpg.saturating_mul(gas_target - used) / denominator;
+                old_bfpg.saturating_sub(delta)
+            }
+        }
+
+        /// Ring buffer slot used to store the history record of the specified block.
+        fn history_slot(block_number: T::BlockNumber) -> u32 {
+            let block_number: u32 = block_number.unique_saturated_into();
+            block_number % T::BaseFeeHistoryLength::get().max(1)
+        }
+
+        /// Record the 'base fee per gas' applied in the specified block, together with the block fullness.
+        pub(crate) fn note_base_fee(
+            block_number: T::BlockNumber,
+            base_fee: U256,
+            gas_usage: &BlockGasUsage,
+        ) {
+            let BlockGasUsage { used, limit } = *gas_usage;
+            let fullness = if limit.is_zero() {
+                Perbill::zero()
+            } else {
+                let used: u128 = used.min(limit).unique_saturated_into();
+                let limit: u128 = limit.unique_saturated_into();
+                Perbill::from_rational(used, limit)
+            };
+
+            BaseFeeHistory::<T>::insert(
+                Self::history_slot(block_number),
+                BaseFeeRecord {
+                    block_number,
+                    base_fee,
+                    fullness,
+                },
+            );
+        }
+
+        /// History record of the specified block, if it's still kept in the ring buffer.
+        pub fn base_fee_record(
+            block_number: T::BlockNumber,
+        ) -> Option<BaseFeeRecord<T::BlockNumber>> {
+            BaseFeeHistory::<T>::get(Self::history_slot(block_number))
+                .filter(|record| record.block_number == block_number)
+        }
+
+        /// Up to `count` most recent history records, ordered from the oldest to the newest.
+        ///
+        /// Only the records of consecutive blocks, ending with the last finalized one, are returned.
+        pub fn base_fee_history(count: u32) -> Vec<BaseFeeRecord<T::BlockNumber>> {
+            let mut block_number = frame_system::Pallet::<T>::block_number();
+            // In case block is still being built, the current one isn't recorded yet.
+            if Self::base_fee_record(block_number).is_none() {
+                block_number = block_number.saturating_sub(One::one());
+            }
+
+            let mut history = Vec::new();
+            for _ in 0..count.min(T::BaseFeeHistoryLength::get()) {
+                match Self::base_fee_record(block_number) {
+                    Some(record) => history.push(record),
+                    None => break,
+                }
+
+                if block_number.is_zero() {
+                    break;
+                }
+                block_number = block_number.saturating_sub(One::one());
+            }
+
+            history.reverse();
+            history
+        }
+
+        /// Maximum relative change of 'base fee per gas' in-between two blocks, caused by the block fullness.
+        ///
+        /// Expressed in the same way as `elasticity` in the Ethereum RPC API, so it can be used to estimate the next base fee.
+        /// In the fee alignment mode, block fullness doesn't affect the fee, hence it's zero.
+        pub fn fee_change_rate() -> Permill {
+            match T::AdjustmentMode::get() {
+                BaseFeeAdjustmentMode::FeeAlignment => Permill::zero(),
+                BaseFeeAdjustmentMode::GasTarget => {
+                    let step_limit = Permill::from_parts(
+                        (Self::step_limit_ratio().deconstruct() / 1_000_000_000_000) as u32,
+                    );
+                    Permill::from_rational(1, BASE_FEE_MAX_CHANGE_DENOMINATOR).min(step_limit)
+                }
+            }
+        }
     }
 }
 
@@ -95,6 +95,9 @@ impl pallet_timestamp::Config for TestRuntime {
     type WeightInfo = ();
 }
 
+/// Number of blocks kept in the base fee history.
+pub const BASE_FEE_HISTORY_LENGTH: u32 = 4;
+
 parameter_types! {
     pub DefaultBaseFeePerGas: U256 = U256::from(1_500_000_000_000_u128);
     pub MinBaseFeePerGas: U256 = U256::from(800_000_000_000_u128);
@@ -110,6 +113,11 @@ impl pallet_dynamic_evm_base_fee::Config for TestRuntime {
     type AdjustmentFactor = GetAdjustmentFactor;
     type WeightFactor = ConstU128<30_000_000_000_000_000>;
     type StepLimitRatio = StepLimitRation;
+    type AdjustmentMode = GetAdjustmentMode;
+    type GasUsage = GetGasUsage;
+    type Elasticity = ConstU32<2>;
+    type BaseFeeHistoryLength = ConstU32<BASE_FEE_HISTORY_LENGTH>;
+    type UpdateOrigin = frame_system::EnsureRoot<AccountId>;
     type WeightInfo = ();
 }
 
@@ -144,6 +152,42 @@ impl Get<FixedU128> for GetAdjustmentFactor {
     }
 }
 
+const ADJUSTMENT_MODE: &[u8] = b":adj_mode_evm";
+
+/// Helper method to set the adjustment mode used by the pallet.
+pub fn set_adjustment_mode(mode: BaseFeeAdjustmentMode) {
+    storage::unhashed::put_raw(&ADJUSTMENT_MODE, &mode.encode());
+}
+
+pub struct GetAdjustmentMode;
+impl Get<BaseFeeAdjustmentMode> for GetAdjustmentMode {
+    fn get() -> BaseFeeAdjustmentMode {
+        storage::unhashed::get::<BaseFeeAdjustmentMode>(&ADJUSTMENT_MODE)
+            .unwrap_or(BaseFeeAdjustmentMode::FeeAlignment)
+    }
+}
+
+const GAS_USAGE: &[u8] = b":gas_usage_evm";
+
+/// Block gas limit used in the tests.
+pub const BLOCK_GAS_LIMIT: u64 = 15_000_000;
+
+/// Helper method to set the gas used in the current block.
+pub fn set_gas_used(used: u64) {
+    let usage = BlockGasUsage {
+        used: U256::from(used),
+        limit: U256::from(BLOCK_GAS_LIMIT),
+    };
+    storage::unhashed::put_raw(&GAS_USAGE, &usage.encode());
+}
+
+pub struct GetGasUsage;
+impl Get<BlockGasUsage> for GetGasUsage {
+    fn get() -> BlockGasUsage {
+        storage::unhashed::get::<BlockGasUsage>(&GAS_USAGE).unwrap_or_default()
+    }
+}
+
 pub struct ExtBuilder;
 impl ExtBuilder {
     pub fn build() -> TestExternalities {
@@ -164,7 +208,7 @@ impl ExtBuilder {
 /// It changes dynamically based on `adjustment factor` and `weight factor` parameters.
 pub fn get_ideal_bfpg() -> U256 {
     U256::from(
-        <TestRuntime as pallet_dynamic_evm_base_fee::Config>::AdjustmentFactor::get()
+        DynamicEvmBaseFee::adjustment_factor()
             .saturating_mul_int::<u128>(
                 <TestRuntime as pallet_dynamic_evm_base_fee::Config>::WeightFactor::get(),
             )
@@ -176,7 +220,7 @@ pub fn get_ideal_bfpg() -> U256 {
 /// Max step limit describes how much `base fee per gas` can move in any direction during one block.
 pub fn get_max_step_limit() -> U256 {
     let bfpg: u128 = BaseFeePerGas::<TestRuntime>::get().unique_saturated_into();
-    let max_allowed_step: u128 = <TestRuntime as pallet::Config>::StepLimitRatio::get() * bfpg;
+    let max_allowed_step: u128 = DynamicEvmBaseFee::step_limit_ratio() * bfpg;
 
     U256::from(max_allowed_step)
 }
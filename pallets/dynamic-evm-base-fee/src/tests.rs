@@ -22,7 +22,7 @@ use super::*;
 use mock::*;
 
 use frame_support::{
-    assert_noop, assert_ok,
+    assert_noop, assert_ok, storage,
     traits::{Get, OnFinalize},
 };
 use num_traits::Bounded;
@@ -323,3 +323,501 @@ fn lower_upper_bounds_ignored_if_bfpg_is_outside() {
         );
     });
 }
+
+#[test]
+fn gas_target_mode_follows_eip_1559_formula() {
+    ExtBuilder::build().execute_with(|| {
+        set_adjustment_mode(BaseFeeAdjustmentMode::GasTarget);
+        let init_bfpg = <TestRuntime as pallet::Config>::DefaultBaseFeePerGas::get();
+        let gas_target = BLOCK_GAS_LIMIT / 2;
+
+        // Gas usage at target, no change
+        set_gas_used(gas_target);
+        DynamicEvmBaseFee::on_finalize(1);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), init_bfpg);
+
+        // Gas usage slightly above the target, small enough not to be limited by the step limit ratio.
+        let excess_gas = 1_000;
+        set_gas_used(gas_target + excess_gas);
+        let expected_bfpg = init_bfpg + init_bfpg * excess_gas / (gas_target * 8);
+        assert!(
+            expected_bfpg - init_bfpg < get_max_step_limit(),
+            "Sanity check"
+        );
+
+        DynamicEvmBaseFee::on_finalize(2);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), expected_bfpg);
+
+        // Gas usage slightly below the target
+        let init_bfpg = expected_bfpg;
+        set_gas_used(gas_target - excess_gas);
+        let expected_bfpg = init_bfpg - init_bfpg * excess_gas / (gas_target * 8);
+
+        DynamicEvmBaseFee::on_finalize(3);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), expected_bfpg);
+    });
+}
+
+#[test]
+fn gas_target_mode_respects_step_limit_and_bounds() {
+    ExtBuilder::build().execute_with(|| {
+        set_adjustment_mode(BaseFeeAdjustmentMode::GasTarget);
+
+        // Full block, increase is limited by the step limit ratio
+        let init_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        let step_limit = get_max_step_limit();
+        set_gas_used(BLOCK_GAS_LIMIT);
+        DynamicEvmBaseFee::on_finalize(1);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), init_bfpg + step_limit);
+
+        // Empty block at the lower bound
+        let min_bfpg = <TestRuntime as pallet::Config>::MinBaseFeePerGas::get();
+        BaseFeePerGas::<TestRuntime>::set(min_bfpg);
+        set_gas_used(0);
+        DynamicEvmBaseFee::on_finalize(2);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), min_bfpg);
+
+        // Full block at the upper bound
+        let max_bfpg = <TestRuntime as pallet::Config>::MaxBaseFeePerGas::get();
+        BaseFeePerGas::<TestRuntime>::set(max_bfpg);
+        set_gas_used(BLOCK_GAS_LIMIT);
+        DynamicEvmBaseFee::on_finalize(3);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), max_bfpg);
+    });
+}
+
+#[test]
+fn gas_target_mode_unknown_gas_usage_no_change() {
+    ExtBuilder::build().execute_with(|| {
+        set_adjustment_mode(BaseFeeAdjustmentMode::GasTarget);
+        let init_bfpg = BaseFeePerGas::<TestRuntime>::get();
+
+        // No gas usage info available, e.g. block gas limit is zero
+        DynamicEvmBaseFee::on_finalize(1);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), init_bfpg);
+    });
+}
+
+#[test]
+fn adjustment_modes_simulation_under_synthetic_load() {
+    // Synthetic load - a period of full blocks, followed by a period of empty blocks.
+    let load = [BLOCK_GAS_LIMIT; 100]
+        .into_iter()
+        .chain([0; 100].into_iter())
+        .collect::<Vec<_>>();
+
+    let simulate = |mode: BaseFeeAdjustmentMode| -> Vec<U256> {
+        let mut ext = ExtBuilder::build();
+        ext.execute_with(|| {
+            set_adjustment_mode(mode);
+            let mut bfpg_history = vec![BaseFeePerGas::<TestRuntime>::get()];
+
+            for (block, gas_used) in load.iter().enumerate() {
+                let old_bfpg = BaseFeePerGas::<TestRuntime>::get();
+                let step_limit = get_max_step_limit();
+                set_gas_used(*gas_used);
+
+                DynamicEvmBaseFee::on_finalize(block as u64 + 1);
+
+                // Step limit & bounds apply in both modes
+                let new_bfpg = BaseFeePerGas::<TestRuntime>::get();
+                assert!(new_bfpg <= old_bfpg + step_limit && new_bfpg >= old_bfpg - step_limit);
+                assert!(new_bfpg >= <TestRuntime as pallet::Config>::MinBaseFeePerGas::get());
+                assert!(new_bfpg <= <TestRuntime as pallet::Config>::MaxBaseFeePerGas::get());
+
+                bfpg_history.push(new_bfpg);
+            }
+            bfpg_history
+        })
+    };
+
+    // Fee alignment mode ignores the gas usage, and only follows the (unchanged) adjustment factor.
+    let fee_alignment = simulate(BaseFeeAdjustmentMode::FeeAlignment);
+    assert!(
+        fee_alignment.windows(2).all(|w| w[0] <= w[1]),
+        "bfpg should steadily move towards the ideal value, regardless of the load."
+    );
+
+    // Gas target mode goes up while blocks are full, and down while they're empty.
+    let gas_target = simulate(BaseFeeAdjustmentMode::GasTarget);
+    assert!(
+        gas_target[..=100].windows(2).all(|w| w[0] < w[1]),
+        "bfpg should increase with full blocks."
+    );
+    assert!(
+        gas_target[100..].windows(2).all(|w| w[0] > w[1]),
+        "bfpg should decrease with empty blocks."
+    );
+
+    // Both modes react the same way under full load, since the step limit is the bottleneck.
+    assert_eq!(fee_alignment[..=100], gas_target[..=100]);
+    // But only the gas target mode reduces the fee once the load is gone.
+    assert!(gas_target[200] < fee_alignment[200]);
+}
+
+#[test]
+fn base_fee_history_records_applied_fee_and_fullness() {
+    ExtBuilder::build().execute_with(|| {
+        set_adjustment_mode(BaseFeeAdjustmentMode::GasTarget);
+        let init_bfpg = BaseFeePerGas::<TestRuntime>::get();
+
+        // Three quarters full block
+        set_gas_used(BLOCK_GAS_LIMIT * 3 / 4);
+        DynamicEvmBaseFee::on_finalize(1);
+        let next_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        assert!(next_bfpg > init_bfpg, "Sanity check");
+
+        // Fee applied in the block is recorded, not the one calculated for the next block.
+        assert_eq!(
+            DynamicEvmBaseFee::base_fee_record(1),
+            Some(BaseFeeRecord {
+                block_number: 1,
+                base_fee: init_bfpg,
+                fullness: Perbill::from_percent(75),
+            })
+        );
+        assert_eq!(DynamicEvmBaseFee::base_fee_history(10).len(), 1);
+
+        // Next block applies the fee calculated in the previous one.
+        System::set_block_number(2);
+        set_gas_used(0);
+        DynamicEvmBaseFee::on_finalize(2);
+        assert_eq!(
+            DynamicEvmBaseFee::base_fee_record(2),
+            Some(BaseFeeRecord {
+                block_number: 2,
+                base_fee: next_bfpg,
+                fullness: Perbill::zero(),
+            })
+        );
+        assert_eq!(
+            DynamicEvmBaseFee::base_fee_history(10)
+                .iter()
+                .map(|record| record.block_number)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+
+        // Unknown gas usage is recorded as an empty block.
+        System::set_block_number(3);
+        storage::unhashed::kill(b":gas_usage_evm");
+        DynamicEvmBaseFee::on_finalize(3);
+        assert_eq!(
+            DynamicEvmBaseFee::base_fee_record(3).unwrap().fullness,
+            Perbill::zero()
+        );
+    });
+}
+
+#[test]
+fn base_fee_history_ring_buffer_wraps_around() {
+    ExtBuilder::build().execute_with(|| {
+        let total_blocks = BASE_FEE_HISTORY_LENGTH as u64 * 2 + 1;
+        let mut applied_fees = Vec::new();
+
+        for block in 1..=total_blocks {
+            System::set_block_number(block);
+            set_gas_used(BLOCK_GAS_LIMIT / 2);
+            applied_fees.push(BaseFeePerGas::<TestRuntime>::get());
+            DynamicEvmBaseFee::on_finalize(block);
+        }
+
+        // Only the most recent blocks are kept, older ones have been overwritten.
+        assert_eq!(
+            BaseFeeHistory::<TestRuntime>::iter().count(),
+            BASE_FEE_HISTORY_LENGTH as usize
+        );
+        let oldest_kept = total_blocks - BASE_FEE_HISTORY_LENGTH as u64 + 1;
+        assert!(DynamicEvmBaseFee::base_fee_record(oldest_kept - 1).is_none());
+
+        let history = DynamicEvmBaseFee::base_fee_history(u32::MAX);
+        assert_eq!(history.len(), BASE_FEE_HISTORY_LENGTH as usize);
+        for (record, block) in history.iter().zip(oldest_kept..=total_blocks) {
+            assert_eq!(record.block_number, block);
+            assert_eq!(record.base_fee, applied_fees[block as usize - 1]);
+            assert_eq!(record.fullness, Perbill::from_percent(50));
+        }
+
+        // Requested number of records is respected, newest ones are returned.
+        let history = DynamicEvmBaseFee::base_fee_history(2);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].block_number, total_blocks);
+
+        // While the next block is being built, history ends with the last finalized block.
+        System::set_block_number(total_blocks + 1);
+        assert_eq!(
+            DynamicEvmBaseFee::base_fee_history(1)[0].block_number,
+            total_blocks
+        );
+    });
+}
+
+#[test]
+fn fee_change_rate_depends_on_adjustment_mode() {
+    ExtBuilder::build().execute_with(|| {
+        // Block fullness doesn't affect the fee in the fee alignment mode.
+        assert!(DynamicEvmBaseFee::fee_change_rate().is_zero());
+
+        // Max change is limited by the step limit ratio, which is lower than 1/8.
+        set_adjustment_mode(BaseFeeAdjustmentMode::GasTarget);
+        assert_eq!(
+            DynamicEvmBaseFee::fee_change_rate(),
+            Permill::from_parts(30)
+        );
+    });
+}
+
+#[test]
+fn parameter_overrides_can_be_set_and_removed() {
+    ExtBuilder::build().execute_with(|| {
+        let default_bfpg = <TestRuntime as pallet::Config>::DefaultBaseFeePerGas::get();
+
+        // Min base fee per gas
+        assert_ok!(DynamicEvmBaseFee::set_min_base_fee_per_gas(
+            RuntimeOrigin::root(),
+            Some(default_bfpg)
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DynamicEvmBaseFee(
+            Event::MinBaseFeePerGasOverrideSet {
+                fee: Some(default_bfpg),
+            },
+        ));
+        assert_eq!(DynamicEvmBaseFee::min_base_fee_per_gas(), default_bfpg);
+
+        // Max base fee per gas
+        assert_ok!(DynamicEvmBaseFee::set_max_base_fee_per_gas(
+            RuntimeOrigin::root(),
+            Some(default_bfpg)
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DynamicEvmBaseFee(
+            Event::MaxBaseFeePerGasOverrideSet {
+                fee: Some(default_bfpg),
+            },
+        ));
+        assert_eq!(DynamicEvmBaseFee::max_base_fee_per_gas(), default_bfpg);
+
+        // Step limit ratio
+        let ratio = Perquintill::from_percent(1);
+        assert_ok!(DynamicEvmBaseFee::set_step_limit_ratio(
+            RuntimeOrigin::root(),
+            Some(ratio)
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DynamicEvmBaseFee(
+            Event::StepLimitRatioOverrideSet { ratio: Some(ratio) },
+        ));
+        assert_eq!(DynamicEvmBaseFee::step_limit_ratio(), ratio);
+
+        // Adjustment factor
+        let factor = FixedU128::from_rational(3, 2);
+        assert_ok!(DynamicEvmBaseFee::set_adjustment_factor(
+            RuntimeOrigin::root(),
+            Some(factor)
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DynamicEvmBaseFee(
+            Event::AdjustmentFactorOverrideSet {
+                factor: Some(factor),
+            },
+        ));
+        assert_eq!(DynamicEvmBaseFee::adjustment_factor(), factor);
+
+        // Bounds override is respected when setting the base fee per gas manually
+        assert_noop!(
+            DynamicEvmBaseFee::set_base_fee_per_gas(RuntimeOrigin::root(), default_bfpg + 1),
+            Error::<TestRuntime>::ValueOutOfBounds
+        );
+
+        // Remove all overrides, runtime configured values should be used again
+        assert_ok!(DynamicEvmBaseFee::set_min_base_fee_per_gas(
+            RuntimeOrigin::root(),
+            None
+        ));
+        assert_ok!(DynamicEvmBaseFee::set_max_base_fee_per_gas(
+            RuntimeOrigin::root(),
+            None
+        ));
+        assert_ok!(DynamicEvmBaseFee::set_step_limit_ratio(
+            RuntimeOrigin::root(),
+            None
+        ));
+        assert_ok!(DynamicEvmBaseFee::set_adjustment_factor(
+            RuntimeOrigin::root(),
+            None
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DynamicEvmBaseFee(
+            Event::AdjustmentFactorOverrideSet { factor: None },
+        ));
+
+        assert_eq!(
+            DynamicEvmBaseFee::min_base_fee_per_gas(),
+            <TestRuntime as pallet::Config>::MinBaseFeePerGas::get()
+        );
+        assert_eq!(
+            DynamicEvmBaseFee::max_base_fee_per_gas(),
+            <TestRuntime as pallet::Config>::MaxBaseFeePerGas::get()
+        );
+        assert_eq!(
+            DynamicEvmBaseFee::step_limit_ratio(),
+            <TestRuntime as pallet::Config>::StepLimitRatio::get()
+        );
+        assert_eq!(
+            DynamicEvmBaseFee::adjustment_factor(),
+            <TestRuntime as pallet::Config>::AdjustmentFactor::get()
+        );
+    });
+}
+
+#[test]
+fn parameter_overrides_validation_fails() {
+    ExtBuilder::build().execute_with(|| {
+        let default_bfpg = <TestRuntime as pallet::Config>::DefaultBaseFeePerGas::get();
+
+        // Min must not be above the default value
+        assert_noop!(
+            DynamicEvmBaseFee::set_min_base_fee_per_gas(
+                RuntimeOrigin::root(),
+                Some(default_bfpg + 1)
+            ),
+            Error::<TestRuntime>::MinBaseFeePerGasTooHigh
+        );
+
+        // Max must not be below the default value, nor above `u128::MAX`
+        assert_noop!(
+            DynamicEvmBaseFee::set_max_base_fee_per_gas(
+                RuntimeOrigin::root(),
+                Some(default_bfpg - 1)
+            ),
+            Error::<TestRuntime>::MaxBaseFeePerGasTooLow
+        );
+        assert_noop!(
+            DynamicEvmBaseFee::set_max_base_fee_per_gas(
+                RuntimeOrigin::root(),
+                Some(U256::from(u128::MAX) + 1)
+            ),
+            Error::<TestRuntime>::MaxBaseFeePerGasTooLow
+        );
+
+        // Only update origin can set the overrides
+        assert_noop!(
+            DynamicEvmBaseFee::set_min_base_fee_per_gas(RuntimeOrigin::signed(1), None),
+            BadOrigin
+        );
+        assert_noop!(
+            DynamicEvmBaseFee::set_max_base_fee_per_gas(RuntimeOrigin::signed(1), None),
+            BadOrigin
+        );
+        assert_noop!(
+            DynamicEvmBaseFee::set_step_limit_ratio(RuntimeOrigin::signed(1), None),
+            BadOrigin
+        );
+        assert_noop!(
+            DynamicEvmBaseFee::set_adjustment_factor(RuntimeOrigin::signed(1), None),
+            BadOrigin
+        );
+    });
+}
+
+#[test]
+fn bounds_override_applied_mid_adjustment() {
+    ExtBuilder::build().execute_with(|| {
+        let default_bfpg = <TestRuntime as pallet::Config>::DefaultBaseFeePerGas::get();
+
+        // Ideal value is very high, so bfpg keeps increasing
+        set_adjustment_factor(FixedU128::max_value());
+        for block in 1..=5 {
+            DynamicEvmBaseFee::on_finalize(block);
+        }
+        let increased_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        assert!(increased_bfpg > default_bfpg, "Sanity check");
+
+        // Lower the max bound below the current value, in the middle of the adjustment.
+        assert_ok!(DynamicEvmBaseFee::set_max_base_fee_per_gas(
+            RuntimeOrigin::root(),
+            Some(default_bfpg)
+        ));
+
+        // Value should gradually move back to the new max bound, respecting the step limit.
+        let mut block = 6;
+        while BaseFeePerGas::<TestRuntime>::get() > default_bfpg {
+            let old_bfpg = BaseFeePerGas::<TestRuntime>::get();
+            let step_limit = get_max_step_limit();
+            DynamicEvmBaseFee::on_finalize(block);
+
+            let new_bfpg = BaseFeePerGas::<TestRuntime>::get();
+            assert!(new_bfpg < old_bfpg && new_bfpg >= old_bfpg - step_limit);
+            block += 1;
+            assert!(
+                block < 20,
+                "Should reach the new bound within a few blocks."
+            );
+        }
+
+        // Once reached, new bound is never exceeded.
+        for block in block..block + 5 {
+            DynamicEvmBaseFee::on_finalize(block);
+            assert_eq!(BaseFeePerGas::<TestRuntime>::get(), default_bfpg);
+        }
+
+        // Raise the min bound up to the default, and lower the ideal value.
+        assert_ok!(DynamicEvmBaseFee::set_min_base_fee_per_gas(
+            RuntimeOrigin::root(),
+            Some(default_bfpg)
+        ));
+        set_adjustment_factor(FixedU128::zero());
+        DynamicEvmBaseFee::on_finalize(100);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), default_bfpg);
+
+        // Removing the override allows the value to decrease again.
+        assert_ok!(DynamicEvmBaseFee::set_min_base_fee_per_gas(
+            RuntimeOrigin::root(),
+            None
+        ));
+        DynamicEvmBaseFee::on_finalize(101);
+        assert!(BaseFeePerGas::<TestRuntime>::get() < default_bfpg);
+    });
+}
+
+#[test]
+fn step_limit_and_adjustment_factor_overrides_applied_mid_adjustment() {
+    ExtBuilder::build().execute_with(|| {
+        // Ideal value is very high, so bfpg keeps increasing with the max step
+        set_adjustment_factor(FixedU128::max_value());
+        let init_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        let step_limit = get_max_step_limit();
+        DynamicEvmBaseFee::on_finalize(1);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), init_bfpg + step_limit);
+
+        // Larger step limit ratio is applied in the next block
+        assert_ok!(DynamicEvmBaseFee::set_step_limit_ratio(
+            RuntimeOrigin::root(),
+            Some(Perquintill::from_percent(1))
+        ));
+        let old_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        let new_step_limit = get_max_step_limit();
+        assert!(new_step_limit > step_limit, "Sanity check");
+        DynamicEvmBaseFee::on_finalize(2);
+        assert_eq!(
+            BaseFeePerGas::<TestRuntime>::get(),
+            old_bfpg + new_step_limit
+        );
+
+        // Adjustment factor override takes precedence over the runtime provided value
+        assert_ok!(DynamicEvmBaseFee::set_adjustment_factor(
+            RuntimeOrigin::root(),
+            Some(FixedU128::zero())
+        ));
+        let old_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        let step_limit = get_max_step_limit();
+        DynamicEvmBaseFee::on_finalize(3);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), old_bfpg - step_limit);
+
+        // Removing the override, runtime provided value is used again
+        assert_ok!(DynamicEvmBaseFee::set_adjustment_factor(
+            RuntimeOrigin::root(),
+            None
+        ));
+        let old_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        let step_limit = get_max_step_limit();
+        DynamicEvmBaseFee::on_finalize(4);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), old_bfpg + step_limit);
+    });
+}
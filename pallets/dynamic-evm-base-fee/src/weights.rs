@@ -52,23 +52,37 @@ pub trait WeightInfo {
 	fn base_fee_per_gas_adjustment() -> Weight;
 	fn set_base_fee_per_gas() -> Weight;
 	fn min_gas_price() -> Weight;
+	fn set_min_base_fee_per_gas() -> Weight;
+	fn set_max_base_fee_per_gas() -> Weight;
+	fn set_step_limit_ratio() -> Weight;
+	fn set_adjustment_factor() -> Weight;
 }
 
 /// Weights for pallet_dynamic_evm_base_fee using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Ethereum CurrentBlock (r:1 w:0)
+	/// Proof Skipped: Ethereum CurrentBlock (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: DynamicEvmBaseFee BaseFeePerGas (r:1 w:1)
 	/// Proof: DynamicEvmBaseFee BaseFeePerGas (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: DynamicEvmBaseFee StepLimitRatioOverride (r:1 w:0)
+	/// Proof: DynamicEvmBaseFee StepLimitRatioOverride (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: DynamicEvmBaseFee MinBaseFeePerGasOverride (r:1 w:0)
+	/// Proof: DynamicEvmBaseFee MinBaseFeePerGasOverride (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: DynamicEvmBaseFee MaxBaseFeePerGasOverride (r:1 w:0)
+	/// Proof: DynamicEvmBaseFee MaxBaseFeePerGasOverride (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: DynamicEvmBaseFee AdjustmentFactorOverride (r:1 w:0)
+	/// Proof: DynamicEvmBaseFee AdjustmentFactorOverride (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: TransactionPayment NextFeeMultiplier (r:1 w:0)
 	/// Proof: TransactionPayment NextFeeMultiplier (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DynamicEvmBaseFee BaseFeeHistory (r:0 w:1)
+	/// Proof: DynamicEvmBaseFee BaseFeeHistory (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn base_fee_per_gas_adjustment() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `165`
-		//  Estimated: `1517`
-		// Minimum execution time: 8_560_000 picoseconds.
-		Weight::from_parts(8_778_000, 1517)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `6623`
+		Weight::from_parts(8_778_000, 6623)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: DynamicEvmBaseFee BaseFeePerGas (r:0 w:1)
 	/// Proof: DynamicEvmBaseFee BaseFeePerGas (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
@@ -90,22 +104,72 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(4_399_000, 1517)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	/// Storage: DynamicEvmBaseFee MinBaseFeePerGasOverride (r:0 w:1)
+	/// Proof: DynamicEvmBaseFee MinBaseFeePerGasOverride (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn set_min_base_fee_per_gas() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_912_000 picoseconds.
+		Weight::from_parts(8_103_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DynamicEvmBaseFee MaxBaseFeePerGasOverride (r:0 w:1)
+	/// Proof: DynamicEvmBaseFee MaxBaseFeePerGasOverride (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn set_max_base_fee_per_gas() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_948_000 picoseconds.
+		Weight::from_parts(8_155_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DynamicEvmBaseFee StepLimitRatioOverride (r:0 w:1)
+	/// Proof: DynamicEvmBaseFee StepLimitRatioOverride (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn set_step_limit_ratio() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_641_000 picoseconds.
+		Weight::from_parts(7_839_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DynamicEvmBaseFee AdjustmentFactorOverride (r:0 w:1)
+	/// Proof: DynamicEvmBaseFee AdjustmentFactorOverride (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn set_adjustment_factor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_702_000 picoseconds.
+		Weight::from_parts(7_884_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	/// Storage: Ethereum CurrentBlock (r:1 w:0)
+	/// Proof Skipped: Ethereum CurrentBlock (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: DynamicEvmBaseFee BaseFeePerGas (r:1 w:1)
 	/// Proof: DynamicEvmBaseFee BaseFeePerGas (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: DynamicEvmBaseFee StepLimitRatioOverride (r:1 w:0)
+	/// Proof: DynamicEvmBaseFee StepLimitRatioOverride (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: DynamicEvmBaseFee MinBaseFeePerGasOverride (r:1 w:0)
+	/// Proof: DynamicEvmBaseFee MinBaseFeePerGasOverride (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: DynamicEvmBaseFee MaxBaseFeePerGasOverride (r:1 w:0)
+	/// Proof: DynamicEvmBaseFee MaxBaseFeePerGasOverride (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: DynamicEvmBaseFee AdjustmentFactorOverride (r:1 w:0)
+	/// Proof: DynamicEvmBaseFee AdjustmentFactorOverride (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: TransactionPayment NextFeeMultiplier (r:1 w:0)
 	/// Proof: TransactionPayment NextFeeMultiplier (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DynamicEvmBaseFee BaseFeeHistory (r:0 w:1)
+	/// Proof: DynamicEvmBaseFee BaseFeeHistory (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn base_fee_per_gas_adjustment() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `165`
-		//  Estimated: `1517`
-		// Minimum execution time: 8_560_000 picoseconds.
-		Weight::from_parts(8_778_000, 1517)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `6623`
+		Weight::from_parts(8_778_000, 6623)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: DynamicEvmBaseFee BaseFeePerGas (r:0 w:1)
 	/// Proof: DynamicEvmBaseFee BaseFeePerGas (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
@@ -127,4 +191,44 @@ impl WeightInfo for () {
 		Weight::from_parts(4_399_000, 1517)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
+	/// Storage: DynamicEvmBaseFee MinBaseFeePerGasOverride (r:0 w:1)
+	/// Proof: DynamicEvmBaseFee MinBaseFeePerGasOverride (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn set_min_base_fee_per_gas() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_912_000 picoseconds.
+		Weight::from_parts(8_103_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DynamicEvmBaseFee MaxBaseFeePerGasOverride (r:0 w:1)
+	/// Proof: DynamicEvmBaseFee MaxBaseFeePerGasOverride (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn set_max_base_fee_per_gas() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_948_000 picoseconds.
+		Weight::from_parts(8_155_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DynamicEvmBaseFee StepLimitRatioOverride (r:0 w:1)
+	/// Proof: DynamicEvmBaseFee StepLimitRatioOverride (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn set_step_limit_ratio() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_641_000 picoseconds.
+		Weight::from_parts(7_839_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DynamicEvmBaseFee AdjustmentFactorOverride (r:0 w:1)
+	/// Proof: DynamicEvmBaseFee AdjustmentFactorOverride (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn set_adjustment_factor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_702_000 picoseconds.
+		Weight::from_parts(7_884_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
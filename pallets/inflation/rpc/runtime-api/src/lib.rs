@@ -0,0 +1,49 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use astar_primitives::Balance;
+pub use pallet_inflation::{
+    CycleInflationProjection, InflationConfiguration, InflationParameters, RewardPoolsBreakdown,
+};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+
+    /// Inflation Api.
+    ///
+    /// Used to provide information otherwise not available via RPC.
+    pub trait InflationApi {
+
+        /// Active inflation configuration.
+        fn inflation_configuration() -> InflationConfiguration;
+
+        /// Active inflation parameters.
+        fn inflation_parameters() -> InflationParameters;
+
+        /// Inflation configuration which the next recalculation would produce, based on the current total issuance.
+        fn projected_inflation_configuration() -> InflationConfiguration;
+
+        /// Reward pools & their emission over the entire cycle, for the hypothetical total value staked.
+        fn reward_pools(total_value_staked: Balance) -> RewardPoolsBreakdown;
+
+        /// Projected inflation for the upcoming cycles, assuming max emission in each cycle.
+        fn project_inflation(cycles: u32) -> Vec<CycleInflationProjection>;
+    }
+}
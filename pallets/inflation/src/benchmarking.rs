@@ -26,9 +26,8 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
     System::<T>::assert_last_event(generic_event.into());
 }
 
-// Set up initial config in the database, so it's not empty.
-fn initial_config<T: Config>() {
-    // Some dummy inflation params
+// Some dummy inflation params, with max number of extra beneficiaries.
+fn max_params() -> InflationParameters {
     let params = InflationParameters {
         max_inflation_rate: Perquintill::from_percent(7),
         treasury_part: Perquintill::from_percent(5),
@@ -36,11 +35,23 @@ fn initial_config<T: Config>() {
         dapps_part: Perquintill::from_percent(20),
         base_stakers_part: Perquintill::from_percent(25),
         adjustable_stakers_part: Perquintill::from_percent(35),
-        bonus_part: Perquintill::from_percent(12),
+        bonus_part: Perquintill::from_percent(12 - MAX_EXTRA_BENEFICIARIES as u64),
         ideal_staking_rate: Perquintill::from_percent(50),
+        extra_beneficiaries: (0..MAX_EXTRA_BENEFICIARIES)
+            .map(|idx| ([idx as u8; 8], Perquintill::from_percent(1)))
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("Size is limited by the max number of extra beneficiaries."),
     };
     assert!(params.is_valid());
 
+    params
+}
+
+// Set up initial config in the database, so it's not empty.
+fn initial_config<T: Config>() {
+    let params = max_params();
+
     // Some dummy inflation config
     let total_issuance = T::Currency::total_issuance();
     let issuance_safety_cap =
@@ -55,6 +66,13 @@ fn initial_config<T: Config>() {
         adjustable_staker_reward_pool_per_era: 99999,
         bonus_reward_pool_per_period: 123987,
         ideal_staking_rate: Perquintill::from_percent(50),
+        extra_rewards_per_block: params
+            .extra_beneficiaries
+            .iter()
+            .map(|(id, _)| (*id, 22222))
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("Size is limited by the max number of extra beneficiaries."),
     };
 
     InflationParams::<T>::put(params);
@@ -65,6 +83,17 @@ fn initial_config<T: Config>() {
     T::Currency::make_free_balance_be(&dummy_account, 1_000_000_000_000_000_000_000);
 }
 
+// Fill up the inflation parameters schedule, with the first entry being due at `first_block`.
+fn max_schedule<T: Config>(first_block: BlockNumber) {
+    let max_scheduled = T::MaxScheduledInflationParams::get();
+    let schedule: BoundedVec<_, T::MaxScheduledInflationParams> = (0..max_scheduled)
+        .map(|idx| (first_block + idx, max_params()))
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("Size is limited by the max number of scheduled params.");
+    ScheduledInflationParams::<T>::put(schedule);
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
@@ -73,8 +102,7 @@ mod benchmarks {
     fn force_set_inflation_params() {
         initial_config::<T>();
 
-        let params = InflationParameters::default();
-        assert!(params.is_valid());
+        let params = max_params();
 
         #[extrinsic_call]
         _(RawOrigin::Root, params);
@@ -107,6 +135,11 @@ mod benchmarks {
     #[benchmark]
     fn hook_with_recalculation() {
         initial_config::<T>();
+        max_schedule::<T>(0);
+        ActiveInflationDecay::<T>::put(InflationDecay {
+            factor: Perquintill::from_percent(90),
+            floor: Perquintill::from_percent(2),
+        });
 
         ActiveInflationConfig::<T>::mutate(|config| {
             config.recalculation_block = 0;
@@ -127,11 +160,12 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn hook_without_recalculation() {
+    fn hook_without_recalculation(x: Linear<0, MAX_EXTRA_BENEFICIARIES>) {
         initial_config::<T>();
 
         ActiveInflationConfig::<T>::mutate(|config| {
             config.recalculation_block = 2;
+            config.extra_rewards_per_block.truncate(x as usize);
         });
         let init_config = ActiveInflationConfig::<T>::get();
         let init_issuance = T::Currency::total_issuance();
@@ -150,6 +184,86 @@ mod benchmarks {
         assert!(T::Currency::total_issuance() > init_issuance);
     }
 
+    #[benchmark]
+    fn schedule_inflation_params() {
+        initial_config::<T>();
+
+        // Leave space for one more entry, which will be inserted at the start of the schedule.
+        max_schedule::<T>(10);
+        ScheduledInflationParams::<T>::mutate(|schedule| schedule.pop());
+        let block = 5;
+        let params = max_params();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, block, params.clone());
+
+        assert_last_event::<T>(Event::<T>::InflationParametersScheduled { block, params }.into());
+    }
+
+    #[benchmark]
+    fn cancel_scheduled_inflation_params() {
+        initial_config::<T>();
+
+        max_schedule::<T>(10);
+        let block = 10;
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, block);
+
+        assert_last_event::<T>(Event::<T>::ScheduledInflationParametersCancelled { block }.into());
+    }
+
+    #[benchmark]
+    fn set_inflation_decay() {
+        initial_config::<T>();
+
+        let decay = Some(InflationDecay {
+            factor: Perquintill::from_percent(90),
+            floor: Perquintill::from_percent(2),
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, decay);
+
+        assert_last_event::<T>(Event::<T>::InflationDecayChanged { decay }.into());
+    }
+
+    #[benchmark]
+    fn set_unspent_dapp_rewards_policy() {
+        initial_config::<T>();
+
+        let policy = UnspentDAppRewardsPolicy::RollOver;
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, policy);
+
+        assert_last_event::<T>(Event::<T>::UnspentDAppRewardsPolicyChanged { policy }.into());
+    }
+
+    #[benchmark]
+    fn handle_unspent_dapp_rewards() {
+        initial_config::<T>();
+
+        // Minting to the treasury is the most expensive policy.
+        let policy = UnspentDAppRewardsPolicy::Treasury;
+        ActiveUnspentDAppRewardsPolicy::<T>::put(policy);
+        let amount = ActiveInflationConfig::<T>::get().dapp_reward_pool_per_era;
+        let init_issuance = T::Currency::total_issuance();
+        ActiveInflationConfig::<T>::mutate(|config| {
+            config.issuance_safety_cap = init_issuance.saturating_add(amount);
+        });
+
+        #[block]
+        {
+            let _ = <Pallet<T> as StakingRewardHandler<T::AccountId>>::handle_unspent_dapp_rewards(
+                amount,
+            );
+        }
+
+        assert_eq!(T::Currency::total_issuance(), init_issuance + amount);
+        assert_last_event::<T>(Event::<T>::UnspentDAppRewardsRedirected { policy, amount }.into());
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::benchmarking::tests::new_test_ext(),
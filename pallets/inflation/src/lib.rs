@@ -67,12 +67,30 @@
 //!
 //! Some rewards are calculated to be paid out per block, while some are per era or per period.
 //!
+//! ### Inflation Parameters Changes
+//!
+//! Inflation parameters can be changed gradually, without governance intervention at each recalculation.
+//!
+//! Future inflation parameters can be scheduled for some block. They will replace the current ones at the first
+//! inflation recalculation happening at or after that block.
+//!
+//! Alternatively, inflation decay can be configured. At each recalculation, when no scheduled parameters are due,
+//! the maximum inflation rate is multiplied by the decay factor, but it never decays below the configured floor.
+//!
+//! Based on both, the maximum issuance can be projected for the upcoming cycles.
+//!
 //! ## Rewards
 //!
 //! ### Staker & Treasury Rewards
 //!
 //! These are paid out at the begininng of each block & are fixed amounts.
 //!
+//! ### Extra Beneficiary Rewards
+//!
+//! Besides the treasury & collators, inflation parameters can define a bounded list of extra beneficiaries, e.g. an ecosystem fund.
+//! Each of them is identified by its name & receives its own share of the inflation, which is checked together with the other parts.
+//! Like treasury & collator rewards, these are paid out at the beginning of each block & are fixed amounts.
+//!
 //! ### Staker Rewards
 //!
 //! Staker rewards are paid out per staker, _on-demand_.
@@ -87,6 +105,12 @@
 //! dApp rewards are paid out per dApp, _on-demand_. The reward is decided by the dApp staking protocol, or the tier system to be more precise.
 //! This pallet only provides the total reward pool for all dApps per era.
 //!
+//! Part of the dApp reward pool usually remains unspent, e.g. when not all tier slots are filled.
+//! What happens with it is decided by the unspent dApp rewards policy:
+//! * `Skip` - unspent rewards are never minted.
+//! * `Treasury` - unspent rewards are minted & deposited to the treasury.
+//! * `RollOver` - unspent rewards are added to the dApp reward pool of the next era, up to a single era's dApp reward pool.
+//!
 //! # Interface
 //!
 //! ## StakingRewardHandler
@@ -104,11 +128,15 @@ use astar_primitives::{
 };
 use frame_support::{
     pallet_prelude::*,
-    traits::{Currency, GetStorageVersion, OnRuntimeUpgrade},
+    traits::{ConstU32, Currency, GetStorageVersion, OnRuntimeUpgrade},
+    BoundedVec,
 };
 use frame_system::{ensure_root, pallet_prelude::*};
-use sp_runtime::{traits::CheckedAdd, Perquintill};
-use sp_std::marker::PhantomData;
+use sp_runtime::{
+    traits::{CheckedAdd, Saturating, Zero},
+    Perquintill,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
 
 pub mod weights;
 pub use weights::WeightInfo;
@@ -127,7 +155,7 @@ pub mod pallet {
     use super::*;
 
     /// The current storage version.
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -154,6 +182,10 @@ pub mod pallet {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+        /// Maximum number of inflation parameters which can be scheduled at the same time.
+        #[pallet::constant]
+        type MaxScheduledInflationParams: Get<u32>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -169,12 +201,34 @@ pub mod pallet {
         ForcedInflationRecalculation { config: InflationConfiguration },
         /// New inflation configuration has been set.
         NewInflationConfiguration { config: InflationConfiguration },
+        /// Inflation parameters have been scheduled. They will be applied at the first recalculation at or after the specified block.
+        InflationParametersScheduled {
+            block: BlockNumber,
+            params: InflationParameters,
+        },
+        /// Scheduled inflation parameters have been cancelled.
+        ScheduledInflationParametersCancelled { block: BlockNumber },
+        /// Inflation decay has been changed. This will have effect on the next inflation recalculation.
+        InflationDecayChanged { decay: Option<InflationDecay> },
+        /// Unspent dApp rewards policy has been changed. This will have effect from the next era.
+        UnspentDAppRewardsPolicyChanged { policy: UnspentDAppRewardsPolicy },
+        /// Unspent dApp rewards of an era have been handled according to the policy.
+        UnspentDAppRewardsRedirected {
+            policy: UnspentDAppRewardsPolicy,
+            amount: Balance,
+        },
     }
 
     #[pallet::error]
     pub enum Error<T> {
         /// Sum of all parts must be one whole (100%).
         InvalidInflationParameters,
+        /// Inflation parameters can only be scheduled for a future block.
+        ScheduleInThePast,
+        /// Max number of scheduled inflation parameters has been reached.
+        TooManyScheduledParams,
+        /// There are no inflation parameters scheduled for the specified block.
+        NoScheduledParams,
     }
 
     /// Active inflation configuration parameteres.
@@ -187,6 +241,27 @@ pub mod pallet {
     #[pallet::storage]
     pub type InflationParams<T: Config> = StorageValue<_, InflationParameters, ValueQuery>;
 
+    /// Inflation parameters scheduled to replace the current ones, sorted by block number in ascending order.
+    #[pallet::storage]
+    pub type ScheduledInflationParams<T: Config> = StorageValue<
+        _,
+        BoundedVec<(BlockNumber, InflationParameters), T::MaxScheduledInflationParams>,
+        ValueQuery,
+    >;
+
+    /// Decay applied to the maximum inflation rate at each recalculation, if any.
+    #[pallet::storage]
+    pub type ActiveInflationDecay<T: Config> = StorageValue<_, InflationDecay, OptionQuery>;
+
+    /// Policy describing how the unspent dApp rewards are handled at the end of each era.
+    #[pallet::storage]
+    pub type ActiveUnspentDAppRewardsPolicy<T: Config> =
+        StorageValue<_, UnspentDAppRewardsPolicy, ValueQuery>;
+
+    /// Unspent dApp rewards rolled over into the dApp reward pool of the next era.
+    #[pallet::storage]
+    pub type RolledOverDAppRewards<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
     #[pallet::genesis_config]
     #[cfg_attr(feature = "std", derive(Default))]
     pub struct GenesisConfig {
@@ -203,7 +278,7 @@ pub mod pallet {
             let config = Pallet::<T>::recalculate_inflation(now);
 
             ActiveInflationConfig::<T>::put(config);
-            InflationParams::<T>::put(self.params);
+            InflationParams::<T>::put(self.params.clone());
         }
     }
 
@@ -212,12 +287,14 @@ pub mod pallet {
         fn on_initialize(now: BlockNumber) -> Weight {
             Self::payout_block_rewards();
 
-            let recalculation_weight =
-                if Self::is_recalculation_in_next_block(now, &ActiveInflationConfig::<T>::get()) {
-                    T::WeightInfo::hook_with_recalculation()
-                } else {
-                    T::WeightInfo::hook_without_recalculation()
-                };
+            let config = ActiveInflationConfig::<T>::get();
+            let recalculation_weight = if Self::is_recalculation_in_next_block(now, &config) {
+                T::WeightInfo::hook_with_recalculation()
+            } else {
+                T::WeightInfo::hook_without_recalculation(
+                    config.extra_rewards_per_block.len() as u32
+                )
+            };
 
             // Benchmarks won't acount for whitelisted storage access so this needs to be added manually.
             //
@@ -235,7 +312,9 @@ pub mod pallet {
             //
             // This should be done as late as possible, to ensure all operations that modify issuance are done.
             if Self::is_recalculation_in_next_block(now, &ActiveInflationConfig::<T>::get()) {
-                let config = Self::recalculate_inflation(now);
+                let params = Self::update_inflation_params(now);
+                let config =
+                    Self::calculate_inflation_config(now, &params, T::Currency::total_issuance());
                 ActiveInflationConfig::<T>::put(config.clone());
 
                 Self::deposit_event(Event::<T>::NewInflationConfiguration { config });
@@ -316,6 +395,113 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        /// Used to schedule the inflation parameters change.
+        /// The parameters will replace the current ones at the first inflation recalculation happening at or after the specified block.
+        /// If some parameters are already scheduled for the same block, they are overwritten.
+        ///
+        /// The parameters must be valid, all parts summing up to one whole (100%), otherwise the call will fail.
+        ///
+        /// Must be called by `root` origin.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::schedule_inflation_params())]
+        pub fn schedule_inflation_params(
+            origin: OriginFor<T>,
+            block: BlockNumber,
+            params: InflationParameters,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(params.is_valid(), Error::<T>::InvalidInflationParameters);
+            ensure!(
+                block > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::ScheduleInThePast
+            );
+
+            ScheduledInflationParams::<T>::try_mutate(|schedule| -> DispatchResult {
+                let idx = match schedule.binary_search_by_key(&block, |(block, _)| *block) {
+                    Ok(idx) => {
+                        schedule.remove(idx);
+                        idx
+                    }
+                    Err(idx) => idx,
+                };
+
+                schedule
+                    .try_insert(idx, (block, params.clone()))
+                    .map_err(|_| Error::<T>::TooManyScheduledParams)?;
+
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::<T>::InflationParametersScheduled { block, params });
+
+            Ok(().into())
+        }
+
+        /// Used to cancel the inflation parameters scheduled for the specified block.
+        ///
+        /// Must be called by `root` origin.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::cancel_scheduled_inflation_params())]
+        pub fn cancel_scheduled_inflation_params(
+            origin: OriginFor<T>,
+            block: BlockNumber,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ScheduledInflationParams::<T>::try_mutate(|schedule| -> DispatchResult {
+                let idx = schedule
+                    .binary_search_by_key(&block, |(block, _)| *block)
+                    .map_err(|_| Error::<T>::NoScheduledParams)?;
+                schedule.remove(idx);
+
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::<T>::ScheduledInflationParametersCancelled { block });
+
+            Ok(().into())
+        }
+
+        /// Used to set or remove the inflation decay.
+        /// Decay is applied to the maximum inflation rate at each recalculation, unless some scheduled parameters are due.
+        ///
+        /// Must be called by `root` origin.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_inflation_decay())]
+        pub fn set_inflation_decay(
+            origin: OriginFor<T>,
+            decay: Option<InflationDecay>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ActiveInflationDecay::<T>::set(decay);
+
+            Self::deposit_event(Event::<T>::InflationDecayChanged { decay });
+
+            Ok(().into())
+        }
+
+        /// Used to set the policy for handling the unspent dApp rewards.
+        ///
+        /// Already rolled over rewards are kept for the next era, regardless of the new policy.
+        ///
+        /// Must be called by `root` origin.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::set_unspent_dapp_rewards_policy())]
+        pub fn set_unspent_dapp_rewards_policy(
+            origin: OriginFor<T>,
+            policy: UnspentDAppRewardsPolicy,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ActiveUnspentDAppRewardsPolicy::<T>::put(policy);
+
+            Self::deposit_event(Event::<T>::UnspentDAppRewardsPolicyChanged { policy });
+
+            Ok(().into())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -341,16 +527,34 @@ pub mod pallet {
             T::PayoutPerBlock::collators(collator_amount);
             T::PayoutPerBlock::treasury(treasury_amount);
 
-            config.collator_reward_per_block + config.treasury_reward_per_block
+            let mut extra_amount = Balance::zero();
+            for (id, reward) in config.extra_rewards_per_block.iter() {
+                T::PayoutPerBlock::extra_beneficiary(id, T::Currency::issue(*reward));
+                extra_amount.saturating_accrue(*reward);
+            }
+
+            config.collator_reward_per_block + config.treasury_reward_per_block + extra_amount
         }
 
-        /// Recalculates the inflation based on the total issuance & inflation parameters.
+        /// Recalculates the inflation based on the total issuance & the current inflation parameters.
+        ///
+        /// Inflation parameters aren't modified - schedule & the inflation decay are only applied
+        /// at the cycle boundary, in `on_finalize`.
         ///
         /// Returns the new inflation configuration.
         pub(crate) fn recalculate_inflation(now: BlockNumber) -> InflationConfiguration {
             let params = InflationParams::<T>::get();
             let total_issuance = T::Currency::total_issuance();
 
+            Self::calculate_inflation_config(now, &params, total_issuance)
+        }
+
+        /// Calculates the inflation configuration based on the provided total issuance & inflation parameters.
+        fn calculate_inflation_config(
+            now: BlockNumber,
+            params: &InflationParameters,
+            total_issuance: Balance,
+        ) -> InflationConfiguration {
             // 1. Calculate maximum emission over the period before the next recalculation.
             let max_emission = params.max_inflation_rate * total_issuance;
             let issuance_safety_cap = total_issuance.saturating_add(max_emission);
@@ -362,6 +566,10 @@ pub mod pallet {
             let base_stakers_emission = params.base_stakers_part * max_emission;
             let adjustable_stakers_emission = params.adjustable_stakers_part * max_emission;
             let bonus_emission = params.bonus_part * max_emission;
+            let extra_emissions = params
+                .extra_beneficiaries
+                .iter()
+                .map(|(id, part)| (*id, *part * max_emission));
 
             // 3. Calculate concrete rewards per block, era or period
 
@@ -384,9 +592,14 @@ pub mod pallet {
                 periods_per_cycle => Balance::from(periods_per_cycle),
             };
 
-            // 3.1. Collator & Treausry rewards per block
+            // 3.1. Collator, Treausry & extra beneficiary rewards per block
             let collator_reward_per_block = collators_emission / blocks_per_cycle;
             let treasury_reward_per_block = treasury_emission / blocks_per_cycle;
+            let extra_rewards_per_block = BoundedVec::truncate_from(
+                extra_emissions
+                    .map(|(id, emission)| (id, emission / blocks_per_cycle))
+                    .collect(),
+            );
 
             // 3.2. dApp reward pool per era
             let dapp_reward_pool_per_era = dapps_emission / build_and_earn_eras_per_cycle;
@@ -414,6 +627,157 @@ pub mod pallet {
                 adjustable_staker_reward_pool_per_era,
                 bonus_reward_pool_per_period,
                 ideal_staking_rate: params.ideal_staking_rate,
+                extra_rewards_per_block,
+            }
+        }
+
+        /// Updates the inflation parameters according to the schedule & the inflation decay.
+        /// Scheduled parameters which are due are removed from the schedule.
+        ///
+        /// Must only be called once per cycle, at the cycle boundary.
+        ///
+        /// Returns the inflation parameters which should be used for the recalculation at block `now`.
+        fn update_inflation_params(now: BlockNumber) -> InflationParameters {
+            let params = InflationParams::<T>::get();
+            let mut schedule = ScheduledInflationParams::<T>::get();
+
+            let new_params = Self::next_inflation_params(
+                now,
+                params.clone(),
+                &schedule,
+                ActiveInflationDecay::<T>::get(),
+            );
+
+            let schedule_len = schedule.len();
+            schedule.retain(|(block, _)| *block > now);
+            if schedule.len() != schedule_len {
+                ScheduledInflationParams::<T>::put(schedule);
+            }
+
+            if new_params != params {
+                InflationParams::<T>::put(new_params.clone());
+            }
+
+            new_params
+        }
+
+        /// Inflation parameters which should be used for the recalculation at block `now`.
+        ///
+        /// The latest scheduled parameters which are due take precedence.
+        /// If there are none, decay (if any) is applied to the current parameters.
+        fn next_inflation_params(
+            now: BlockNumber,
+            params: InflationParameters,
+            schedule: &[(BlockNumber, InflationParameters)],
+            decay: Option<InflationDecay>,
+        ) -> InflationParameters {
+            match schedule.iter().rev().find(|(block, _)| *block <= now) {
+                Some((_, scheduled_params)) => scheduled_params.clone(),
+                None => match decay {
+                    Some(decay) => decay.apply(params),
+                    None => params,
+                },
+            }
+        }
+
+        /// Projects the inflation for the next `cycles` cycles, following the active inflation configuration.
+        /// At most `MAX_PROJECTED_CYCLES` cycles are projected.
+        ///
+        /// Scheduled inflation parameters & the inflation decay are taken into account.
+        /// It's assumed the maximum emission is issued in each cycle, so the projected issuance is an upper bound.
+        pub fn project_inflation(cycles: u32) -> Vec<CycleInflationProjection> {
+            let config = ActiveInflationConfig::<T>::get();
+            let decay = ActiveInflationDecay::<T>::get();
+            let mut schedule = ScheduledInflationParams::<T>::get().into_inner();
+
+            let mut params = InflationParams::<T>::get();
+            let mut issuance = config.issuance_safety_cap;
+            let mut recalculation_block = config.recalculation_block;
+
+            (0..cycles.min(MAX_PROJECTED_CYCLES))
+                .map(|_| {
+                    // Recalculation is done in the block right before the cycle starts.
+                    let now = recalculation_block.saturating_sub(1);
+                    params = Self::next_inflation_params(now, params, &schedule, decay);
+                    schedule.retain(|(block, _)| *block > now);
+
+                    let max_emission = params.max_inflation_rate * issuance;
+                    let projection = CycleInflationProjection {
+                        recalculation_block,
+                        max_inflation_rate: params.max_inflation_rate,
+                        max_emission,
+                        issuance_safety_cap: issuance.saturating_add(max_emission),
+                    };
+
+                    issuance = projection.issuance_safety_cap;
+                    recalculation_block =
+                        now.saturating_add(T::CycleConfiguration::blocks_per_cycle());
+
+                    projection
+                })
+                .collect()
+        }
+
+        /// Inflation configuration which the next recalculation would produce.
+        ///
+        /// Scheduled inflation parameters & the inflation decay are taken into account.
+        /// Current total issuance is used for the calculation, so the issuance until the next recalculation isn't accounted for.
+        pub fn projected_inflation_config() -> InflationConfiguration {
+            let now = ActiveInflationConfig::<T>::get()
+                .recalculation_block
+                .saturating_sub(1);
+            let params = Self::next_inflation_params(
+                now,
+                InflationParams::<T>::get(),
+                &ScheduledInflationParams::<T>::get(),
+                ActiveInflationDecay::<T>::get(),
+            );
+
+            Self::calculate_inflation_config(now, &params, T::Currency::total_issuance())
+        }
+
+        /// Breakdown of the reward pools for the hypothetical total value staked, according to the active inflation configuration.
+        ///
+        /// Besides the reward pools, contains the amounts which would be issued over the entire cycle.
+        /// The assumption is that all the reward pools are used up in full.
+        pub fn reward_pools(total_value_staked: Balance) -> RewardPoolsBreakdown {
+            let config = ActiveInflationConfig::<T>::get();
+            let (staker_reward_pool_per_era, _) =
+                Self::staker_and_dapp_reward_pools(total_value_staked);
+            // Rolled over dApp rewards are a one-off, so they're not included in the breakdown.
+            let dapp_reward_pool_per_era = config.dapp_reward_pool_per_era;
+
+            let build_and_earn_eras_per_cycle =
+                Balance::from(T::CycleConfiguration::build_and_earn_eras_per_cycle());
+            let blocks_per_cycle = Balance::from(T::CycleConfiguration::blocks_per_cycle());
+
+            RewardPoolsBreakdown {
+                staker_reward_pool_per_era,
+                dapp_reward_pool_per_era,
+                bonus_reward_pool_per_period: config.bonus_reward_pool_per_period,
+                collator_reward_per_block: config.collator_reward_per_block,
+                treasury_reward_per_block: config.treasury_reward_per_block,
+                stakers_emission_per_cycle: staker_reward_pool_per_era
+                    .saturating_mul(build_and_earn_eras_per_cycle),
+                dapps_emission_per_cycle: dapp_reward_pool_per_era
+                    .saturating_mul(build_and_earn_eras_per_cycle),
+                bonus_emission_per_cycle: config
+                    .bonus_reward_pool_per_period
+                    .saturating_mul(T::CycleConfiguration::periods_per_cycle().into()),
+                collators_emission_per_cycle: config
+                    .collator_reward_per_block
+                    .saturating_mul(blocks_per_cycle),
+                treasury_emission_per_cycle: config
+                    .treasury_reward_per_block
+                    .saturating_mul(blocks_per_cycle),
+                extra_emissions_per_cycle: BoundedVec::truncate_from(
+                    config
+                        .extra_rewards_per_block
+                        .iter()
+                        .map(|(id, reward)| (*id, reward.saturating_mul(blocks_per_cycle)))
+                        .collect::<Vec<_>>(),
+                ),
+                extra_rewards_per_block: config.extra_rewards_per_block,
             }
         }
 
@@ -455,7 +819,11 @@ pub mod pallet {
                 .base_staker_reward_pool_per_era
                 .saturating_add(adjustable_part);
 
-            (staker_reward_pool, config.dapp_reward_pool_per_era)
+            let dapp_reward_pool = config
+                .dapp_reward_pool_per_era
+                .saturating_add(RolledOverDAppRewards::<T>::get());
+
+            (staker_reward_pool, dapp_reward_pool)
         }
 
         fn bonus_reward_pool() -> Balance {
@@ -473,12 +841,56 @@ pub mod pallet {
             T::Currency::deposit_creating(account, reward);
             Ok(())
         }
+
+        fn handle_unspent_dapp_rewards(amount: Balance) -> Weight {
+            let policy = ActiveUnspentDAppRewardsPolicy::<T>::get();
+
+            let (redirected, rolled_over) = match policy {
+                UnspentDAppRewardsPolicy::Skip => (0, 0),
+                // Same safety measure as for the regular payouts.
+                UnspentDAppRewardsPolicy::Treasury
+                    if Self::is_payout_cap_limit_exceeded(amount) =>
+                {
+                    (0, 0)
+                }
+                UnspentDAppRewardsPolicy::Treasury => {
+                    T::PayoutPerBlock::treasury(T::Currency::issue(amount));
+                    (amount, 0)
+                }
+                // Rolled over rewards are capped to a single era's dApp reward pool,
+                // so unspent rewards can't accumulate over multiple eras.
+                UnspentDAppRewardsPolicy::RollOver => {
+                    let rolled_over =
+                        amount.min(ActiveInflationConfig::<T>::get().dapp_reward_pool_per_era);
+                    (rolled_over, rolled_over)
+                }
+            };
+
+            // Rolled over rewards are consumed by the era which just ended.
+            RolledOverDAppRewards::<T>::put(rolled_over);
+
+            Self::deposit_event(Event::<T>::UnspentDAppRewardsRedirected {
+                policy,
+                amount: redirected,
+            });
+
+            T::WeightInfo::handle_unspent_dapp_rewards()
+        }
     }
 }
 
+/// Max number of extra beneficiaries of the block rewards.
+pub const MAX_EXTRA_BENEFICIARIES: u32 = 8;
+
+/// Name of an extra beneficiary of the block rewards, e.g. `*b"ecosystm"`.
+pub type BeneficiaryId = [u8; 8];
+
+/// Bounded list of extra beneficiaries, each paired with some value (e.g. share of the inflation or reward).
+pub type ExtraBeneficiaries<V> = BoundedVec<(BeneficiaryId, V), ConstU32<MAX_EXTRA_BENEFICIARIES>>;
+
 /// Configuration of the inflation.
 /// Contains information about rewards, when inflation is recalculated, etc.
-#[derive(Encode, Decode, MaxEncodedLen, Default, Copy, Clone, Debug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, MaxEncodedLen, Default, Clone, Debug, PartialEq, Eq, TypeInfo)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct InflationConfiguration {
     /// Block number at which the inflation must be recalculated, based on the total issuance at that block.
@@ -494,7 +906,8 @@ pub struct InflationConfiguration {
     #[codec(compact)]
     pub treasury_reward_per_block: Balance,
     /// dApp reward pool per era - based on this the tier rewards are calculated.
-    /// There's no guarantee that this whole amount will be minted & distributed.
+    /// There's no guarantee that this whole amount will be minted & distributed to dApps,
+    /// the unspent part is handled according to the `UnspentDAppRewardsPolicy`.
     #[codec(compact)]
     pub dapp_reward_pool_per_era: Balance,
     /// Base staker reward pool per era - this is always provided to stakers, regardless of the total value staked.
@@ -511,12 +924,14 @@ pub struct InflationConfiguration {
     /// Used to derive exact amount of adjustable staker rewards.
     #[codec(compact)]
     pub ideal_staking_rate: Perquintill,
+    /// Rewards of the extra beneficiaries, per block. Always deposited in full.
+    pub extra_rewards_per_block: ExtraBeneficiaries<Balance>,
 }
 
 /// Inflation parameters.
 ///
 /// The parts of the inflation that go towards different purposes must add up to exactly 100%.
-#[derive(Encode, Decode, MaxEncodedLen, Copy, Clone, Debug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Debug, PartialEq, Eq, TypeInfo)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct InflationParameters {
     /// Maximum possible inflation rate, based on the total issuance at some point in time.
@@ -546,11 +961,29 @@ pub struct InflationParameters {
     /// Used to derive exact amount of adjustable staker rewards.
     #[codec(compact)]
     pub ideal_staking_rate: Perquintill,
+    /// Portions of the inflation that go towards the extra beneficiaries, e.g. an ecosystem fund.
+    /// Each beneficiary must appear at most once.
+    pub extra_beneficiaries: ExtraBeneficiaries<Perquintill>,
 }
 
 impl InflationParameters {
-    /// `true` if sum of all percentages is `one whole`, `false` otherwise.
+    /// `true` if sum of all percentages, including the extra beneficiaries, is `one whole`
+    /// and no extra beneficiary appears more than once, `false` otherwise.
     pub fn is_valid(&self) -> bool {
+        let has_duplicates = self
+            .extra_beneficiaries
+            .iter()
+            .enumerate()
+            .any(|(idx, (id, _))| {
+                self.extra_beneficiaries
+                    .iter()
+                    .skip(idx + 1)
+                    .any(|(other_id, _)| other_id == id)
+            });
+        if has_duplicates {
+            return false;
+        }
+
         let variables = [
             &self.treasury_part,
             &self.collators_part,
@@ -561,7 +994,8 @@ impl InflationParameters {
         ];
 
         variables
-            .iter()
+            .into_iter()
+            .chain(self.extra_beneficiaries.iter().map(|(_, part)| part))
             .fold(Some(Perquintill::zero()), |acc, part| {
                 if let Some(acc) = acc {
                     acc.checked_add(*part)
@@ -585,10 +1019,94 @@ impl Default for InflationParameters {
             adjustable_stakers_part: Perquintill::from_percent(35),
             bonus_part: Perquintill::from_percent(12),
             ideal_staking_rate: Perquintill::from_percent(50),
+            extra_beneficiaries: Default::default(),
         }
     }
 }
 
+/// Decay of the maximum inflation rate, applied at each inflation recalculation.
+#[derive(Encode, Decode, MaxEncodedLen, Copy, Clone, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct InflationDecay {
+    /// Factor by which the maximum inflation rate is multiplied at each recalculation.
+    #[codec(compact)]
+    pub factor: Perquintill,
+    /// Rate below which the maximum inflation rate won't decay.
+    #[codec(compact)]
+    pub floor: Perquintill,
+}
+
+impl InflationDecay {
+    /// Returns the inflation parameters with decayed maximum inflation rate.
+    ///
+    /// If the maximum inflation rate is already below the floor, it remains unchanged.
+    pub fn apply(&self, mut params: InflationParameters) -> InflationParameters {
+        let decayed_rate = self.factor * params.max_inflation_rate;
+        params.max_inflation_rate = decayed_rate.max(self.floor.min(params.max_inflation_rate));
+        params
+    }
+}
+
+/// Policy for handling the part of the dApp reward pool which wasn't assigned to any dApp in an era.
+#[derive(Encode, Decode, MaxEncodedLen, Copy, Clone, Default, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnspentDAppRewardsPolicy {
+    /// Unspent rewards are never minted.
+    #[default]
+    Skip,
+    /// Unspent rewards are minted & deposited to the treasury.
+    Treasury,
+    /// Unspent rewards are added to the dApp reward pool of the next era, up to a single era's dApp reward pool.
+    RollOver,
+}
+
+/// Max number of cycles for which the inflation can be projected.
+pub const MAX_PROJECTED_CYCLES: u32 = 100;
+
+/// Projected inflation of a single cycle.
+#[derive(Encode, Decode, Copy, Clone, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct CycleInflationProjection {
+    /// Block at which the cycle starts.
+    pub recalculation_block: BlockNumber,
+    /// Maximum inflation rate of the cycle.
+    pub max_inflation_rate: Perquintill,
+    /// Maximum amount which can be issued during the cycle.
+    pub max_emission: Balance,
+    /// Maximum total issuance at the end of the cycle.
+    pub issuance_safety_cap: Balance,
+}
+
+/// Breakdown of the reward pools, for some total value staked.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardPoolsBreakdown {
+    /// Staker reward pool per era, including the adjustable part.
+    pub staker_reward_pool_per_era: Balance,
+    /// dApp reward pool per era.
+    pub dapp_reward_pool_per_era: Balance,
+    /// Bonus reward pool per period.
+    pub bonus_reward_pool_per_period: Balance,
+    /// Collator reward per block.
+    pub collator_reward_per_block: Balance,
+    /// Treasury reward per block.
+    pub treasury_reward_per_block: Balance,
+    /// Rewards of the extra beneficiaries, per block.
+    pub extra_rewards_per_block: ExtraBeneficiaries<Balance>,
+    /// Amount issued for stakers over the entire cycle.
+    pub stakers_emission_per_cycle: Balance,
+    /// Amount issued for dApps over the entire cycle.
+    pub dapps_emission_per_cycle: Balance,
+    /// Amount issued for bonus rewards over the entire cycle.
+    pub bonus_emission_per_cycle: Balance,
+    /// Amount issued for collators over the entire cycle.
+    pub collators_emission_per_cycle: Balance,
+    /// Amount issued for the treasury over the entire cycle.
+    pub treasury_emission_per_cycle: Balance,
+    /// Amounts issued for the extra beneficiaries over the entire cycle.
+    pub extra_emissions_per_cycle: ExtraBeneficiaries<Balance>,
+}
+
 /// Defines functions used to payout the beneficiaries of block rewards
 pub trait PayoutPerBlock<Imbalance> {
     /// Payout reward to the treasury.
@@ -596,11 +1114,14 @@ pub trait PayoutPerBlock<Imbalance> {
 
     /// Payout reward to the collator responsible for producing the block.
     fn collators(reward: Imbalance);
+
+    /// Payout reward to the extra beneficiary with the specified name.
+    ///
+    /// Dropping the imbalance means the reward is never minted, which can be used to burn the beneficiary's share.
+    fn extra_beneficiary(id: &BeneficiaryId, reward: Imbalance);
 }
 
 /// `OnRuntimeUpgrade` logic for integrating this pallet into the live network.
-#[cfg(feature = "try-runtime")]
-use sp_std::vec::Vec;
 pub struct PalletInflationInitConfig<T, P>(PhantomData<(T, P)>);
 impl<T: Config, P: Get<InflationParameters>> OnRuntimeUpgrade for PalletInflationInitConfig<T, P> {
     fn on_runtime_upgrade() -> Weight {
@@ -636,3 +1157,137 @@ impl<T: Config, P: Get<InflationParameters>> OnRuntimeUpgrade for PalletInflatio
         Ok(())
     }
 }
+
+/// Storage items as they were before the introduction of extra beneficiaries.
+mod v1 {
+    use super::*;
+
+    /// `InflationConfiguration` prior to the addition of `extra_rewards_per_block` field.
+    #[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+    pub struct InflationConfiguration {
+        #[codec(compact)]
+        pub recalculation_block: BlockNumber,
+        #[codec(compact)]
+        pub issuance_safety_cap: Balance,
+        #[codec(compact)]
+        pub collator_reward_per_block: Balance,
+        #[codec(compact)]
+        pub treasury_reward_per_block: Balance,
+        #[codec(compact)]
+        pub dapp_reward_pool_per_era: Balance,
+        #[codec(compact)]
+        pub base_staker_reward_pool_per_era: Balance,
+        #[codec(compact)]
+        pub adjustable_staker_reward_pool_per_era: Balance,
+        #[codec(compact)]
+        pub bonus_reward_pool_per_period: Balance,
+        #[codec(compact)]
+        pub ideal_staking_rate: Perquintill,
+    }
+
+    /// `InflationParameters` prior to the addition of `extra_beneficiaries` field.
+    #[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+    pub struct InflationParameters {
+        #[codec(compact)]
+        pub max_inflation_rate: Perquintill,
+        #[codec(compact)]
+        pub treasury_part: Perquintill,
+        #[codec(compact)]
+        pub collators_part: Perquintill,
+        #[codec(compact)]
+        pub dapps_part: Perquintill,
+        #[codec(compact)]
+        pub base_stakers_part: Perquintill,
+        #[codec(compact)]
+        pub adjustable_stakers_part: Perquintill,
+        #[codec(compact)]
+        pub bonus_part: Perquintill,
+        #[codec(compact)]
+        pub ideal_staking_rate: Perquintill,
+    }
+
+    impl From<InflationParameters> for super::InflationParameters {
+        fn from(old: InflationParameters) -> Self {
+            Self {
+                max_inflation_rate: old.max_inflation_rate,
+                treasury_part: old.treasury_part,
+                collators_part: old.collators_part,
+                dapps_part: old.dapps_part,
+                base_stakers_part: old.base_stakers_part,
+                adjustable_stakers_part: old.adjustable_stakers_part,
+                bonus_part: old.bonus_part,
+                ideal_staking_rate: old.ideal_staking_rate,
+                extra_beneficiaries: Default::default(),
+            }
+        }
+    }
+}
+
+/// `OnRuntimeUpgrade` logic used to migrate inflation parameters & configuration to the format with extra beneficiaries.
+///
+/// Existing parameters, including the scheduled ones, get an empty extra beneficiary list, so they remain valid.
+pub struct InflationExtraBeneficiariesMigration<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for InflationExtraBeneficiariesMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let _ = InflationParams::<T>::translate::<v1::InflationParameters, _>(|old_params| {
+            old_params.map(Into::into)
+        });
+
+        let _ =
+            ActiveInflationConfig::<T>::translate::<v1::InflationConfiguration, _>(|old_config| {
+                old_config.map(|old_config| InflationConfiguration {
+                    recalculation_block: old_config.recalculation_block,
+                    issuance_safety_cap: old_config.issuance_safety_cap,
+                    collator_reward_per_block: old_config.collator_reward_per_block,
+                    treasury_reward_per_block: old_config.treasury_reward_per_block,
+                    dapp_reward_pool_per_era: old_config.dapp_reward_pool_per_era,
+                    base_staker_reward_pool_per_era: old_config.base_staker_reward_pool_per_era,
+                    adjustable_staker_reward_pool_per_era: old_config
+                        .adjustable_staker_reward_pool_per_era,
+                    bonus_reward_pool_per_period: old_config.bonus_reward_pool_per_period,
+                    ideal_staking_rate: old_config.ideal_staking_rate,
+                    extra_rewards_per_block: Default::default(),
+                })
+            });
+
+        let _ = ScheduledInflationParams::<T>::translate::<
+            Vec<(BlockNumber, v1::InflationParameters)>,
+            _,
+        >(|old_schedule| {
+            old_schedule.map(|old_schedule| {
+                BoundedVec::truncate_from(
+                    old_schedule
+                        .into_iter()
+                        .map(|(block, old_params)| (block, old_params.into()))
+                        .collect(),
+                )
+            })
+        });
+
+        STORAGE_VERSION.put::<Pallet<T>>();
+
+        log::info!(
+            "Inflation parameters & configuration migrated to the extra beneficiaries format."
+        );
+
+        T::DbWeight::get().reads_writes(4, 4)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        assert_eq!(Pallet::<T>::on_chain_storage_version(), STORAGE_VERSION);
+        assert!(InflationParams::<T>::get().is_valid());
+        assert!(ActiveInflationConfig::<T>::get()
+            .extra_rewards_per_block
+            .is_empty());
+        assert!(ScheduledInflationParams::<T>::get()
+            .iter()
+            .all(|(_, params)| params.is_valid()));
+
+        Ok(())
+    }
+}
@@ -17,8 +17,9 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-    self as pallet_inflation, ActiveInflationConfig, CycleConfiguration, InflationConfiguration,
-    InflationParameters, InflationParams, NegativeImbalanceOf, PayoutPerBlock,
+    self as pallet_inflation, ActiveInflationConfig, BeneficiaryId, CycleConfiguration,
+    InflationConfiguration, InflationParameters, InflationParams, NegativeImbalanceOf,
+    PayoutPerBlock,
 };
 
 use frame_support::{
@@ -40,29 +41,35 @@ use astar_primitives::{testing::Header, Balance, BlockNumber};
 pub(crate) type AccountId = u64;
 
 /// Initial inflation params set by the mock.
-pub const INIT_PARAMS: InflationParameters = InflationParameters {
-    max_inflation_rate: Perquintill::from_percent(7),
-    treasury_part: Perquintill::from_percent(5),
-    collators_part: Perquintill::from_percent(3),
-    dapps_part: Perquintill::from_percent(20),
-    base_stakers_part: Perquintill::from_percent(25),
-    adjustable_stakers_part: Perquintill::from_percent(35),
-    bonus_part: Perquintill::from_percent(12),
-    ideal_staking_rate: Perquintill::from_percent(50),
-};
+pub fn init_params() -> InflationParameters {
+    InflationParameters {
+        max_inflation_rate: Perquintill::from_percent(7),
+        treasury_part: Perquintill::from_percent(5),
+        collators_part: Perquintill::from_percent(3),
+        dapps_part: Perquintill::from_percent(20),
+        base_stakers_part: Perquintill::from_percent(25),
+        adjustable_stakers_part: Perquintill::from_percent(35),
+        bonus_part: Perquintill::from_percent(12),
+        ideal_staking_rate: Perquintill::from_percent(50),
+        extra_beneficiaries: Default::default(),
+    }
+}
 
 /// Initial inflation config set by the mock.
-pub const INIT_CONFIG: InflationConfiguration = InflationConfiguration {
-    recalculation_block: 100,
-    issuance_safety_cap: 1_000_000,
-    collator_reward_per_block: 1000,
-    treasury_reward_per_block: 1500,
-    dapp_reward_pool_per_era: 3000,
-    base_staker_reward_pool_per_era: 5000,
-    adjustable_staker_reward_pool_per_era: 7000,
-    bonus_reward_pool_per_period: 4000,
-    ideal_staking_rate: Perquintill::from_percent(50),
-};
+pub fn init_config() -> InflationConfiguration {
+    InflationConfiguration {
+        recalculation_block: 100,
+        issuance_safety_cap: 1_000_000,
+        collator_reward_per_block: 1000,
+        treasury_reward_per_block: 1500,
+        dapp_reward_pool_per_era: 3000,
+        base_staker_reward_pool_per_era: 5000,
+        adjustable_staker_reward_pool_per_era: 7000,
+        bonus_reward_pool_per_period: 4000,
+        ideal_staking_rate: Perquintill::from_percent(50),
+        extra_rewards_per_block: Default::default(),
+    }
+}
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -128,6 +135,10 @@ impl PayoutPerBlock<NegativeImbalanceOf<Test>> for DummyPayoutPerBlock {
     fn collators(reward: NegativeImbalanceOf<Test>) {
         Balances::resolve_creating(&COLLATOR_POT.into_account_truncating(), reward);
     }
+
+    fn extra_beneficiary(id: &BeneficiaryId, reward: NegativeImbalanceOf<Test>) {
+        Balances::resolve_creating(&PalletId(*id).into_account_truncating(), reward);
+    }
 }
 
 pub struct DummyCycleConfiguration;
@@ -154,6 +165,7 @@ impl pallet_inflation::Config for Test {
     type PayoutPerBlock = DummyPayoutPerBlock;
     type CycleConfiguration = DummyCycleConfiguration;
     type RuntimeEvent = RuntimeEvent;
+    type MaxScheduledInflationParams = ConstU32<4>;
     type WeightInfo = ();
 }
 
@@ -187,8 +199,8 @@ impl ExternalityBuilder {
         let mut ext = TestExternalities::from(storage);
         ext.execute_with(|| {
             // Set initial pallet inflation values
-            ActiveInflationConfig::<Test>::put(INIT_CONFIG);
-            InflationParams::<Test>::put(INIT_PARAMS);
+            ActiveInflationConfig::<Test>::put(init_config());
+            InflationParams::<Test>::put(init_params());
 
             System::set_block_number(1);
             Inflation::on_initialize(1);
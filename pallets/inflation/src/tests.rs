@@ -20,6 +20,7 @@ use super::{pallet::Error, Event, *};
 use frame_support::{
     assert_noop, assert_ok, assert_storage_noop,
     traits::{GenesisBuild, Hooks},
+    PalletId,
 };
 use mock::*;
 use sp_runtime::{
@@ -42,7 +43,7 @@ fn force_set_inflation_params_work() {
         // Execute call, ensure it works
         assert_ok!(Inflation::force_set_inflation_params(
             RuntimeOrigin::root(),
-            new_params
+            new_params.clone()
         ));
         System::assert_last_event(Event::InflationParametersForceChanged.into());
 
@@ -62,7 +63,7 @@ fn force_set_inflation_params_fails() {
 
         // Make sure it's not possible to force-set invalid params
         assert_noop!(
-            Inflation::force_set_inflation_params(RuntimeOrigin::root(), new_params),
+            Inflation::force_set_inflation_params(RuntimeOrigin::root(), new_params.clone()),
             Error::<Test>::InvalidInflationParameters
         );
 
@@ -83,10 +84,13 @@ fn force_set_inflation_config_work() {
         // Execute call, ensure it works
         assert_ok!(Inflation::force_set_inflation_config(
             RuntimeOrigin::root(),
-            new_config
+            new_config.clone()
         ));
         System::assert_last_event(
-            Event::InflationConfigurationForceChanged { config: new_config }.into(),
+            Event::InflationConfigurationForceChanged {
+                config: new_config.clone(),
+            }
+            .into(),
         );
 
         assert_eq!(ActiveInflationConfig::<Test>::get(), new_config);
@@ -128,6 +132,52 @@ fn force_inflation_recalculation_work() {
         );
     })
 }
+#[test]
+fn force_inflation_recalculation_does_not_apply_decay_or_schedule() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Decay & scheduled params are only supposed to be applied at the cycle boundary
+        let mut scheduled_params = init_params();
+        scheduled_params.max_inflation_rate = Perquintill::from_percent(3);
+        let schedule_block = System::block_number() + 1;
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            schedule_block,
+            scheduled_params.clone()
+        ));
+        assert_ok!(Inflation::set_inflation_decay(
+            RuntimeOrigin::root(),
+            Some(InflationDecay {
+                factor: Perquintill::from_percent(50),
+                floor: Zero::zero(),
+            })
+        ));
+        advance_to_block(schedule_block);
+
+        // Repeated forced recalculations must produce the same parameters & configuration
+        assert_ok!(Inflation::force_inflation_recalculation(
+            RuntimeOrigin::root(),
+        ));
+        let first_config = ActiveInflationConfig::<Test>::get();
+        assert_ok!(Inflation::force_inflation_recalculation(
+            RuntimeOrigin::root(),
+        ));
+        assert_eq!(ActiveInflationConfig::<Test>::get(), first_config);
+
+        assert_eq!(InflationParams::<Test>::get(), init_params());
+        assert_eq!(
+            ScheduledInflationParams::<Test>::get().into_inner(),
+            vec![(schedule_block, scheduled_params.clone())]
+        );
+
+        // Scheduled params are applied once the cycle boundary is reached
+        let recalculation_block = first_config.recalculation_block;
+        advance_to_block(recalculation_block - 1);
+        Inflation::on_finalize(recalculation_block - 1);
+        assert_eq!(InflationParams::<Test>::get(), scheduled_params);
+        assert!(ScheduledInflationParams::<Test>::get().is_empty());
+    })
+}
+
 #[test]
 fn force_inflation_fails_due_to_unprivileged_origin() {
     ExternalityBuilder::build().execute_with(|| {
@@ -167,7 +217,7 @@ fn inflation_recalculation_occurs_when_exepcted() {
             new_config != init_config,
             "Recalculation must happen at this point."
         );
-        System::assert_last_event(Event::NewInflationConfiguration { config: new_config }.into());
+        System::assert_last_event(Event::NewInflationConfiguration { config: new_config.clone() }.into());
 
         assert_eq!(
             Balances::total_issuance(),
@@ -211,26 +261,26 @@ fn on_initialize_reward_payout_works() {
 #[test]
 fn inflation_parameters_validity_check_works() {
     // Params to be used as anchor for the tests
-    let base_params = INIT_PARAMS;
+    let base_params = init_params();
     assert!(base_params.is_valid(), "Sanity check.");
 
     // Reduction of some param, it should invalidate the whole config
-    let mut params = base_params;
+    let mut params = base_params.clone();
     params.base_stakers_part = params.base_stakers_part - Perquintill::from_percent(1);
     assert!(!params.is_valid(), "Sum is below 100%, must fail.");
 
     // Increase of some param, it should invalidate the whole config
-    let mut params = base_params;
+    let mut params = base_params.clone();
     params.base_stakers_part = params.base_stakers_part + Perquintill::from_percent(1);
     assert!(!params.is_valid(), "Sum is above 100%, must fail.");
 
     // Excessive increase of some param, it should invalidate the whole config
-    let mut params = base_params;
+    let mut params = base_params.clone();
     params.treasury_part = Perquintill::from_percent(100);
     assert!(!params.is_valid(), "Sum is above 100%, must fail.");
 
     // Some param can be zero, as long as sum remains 100%
-    let mut params = base_params;
+    let mut params = base_params.clone();
     params.base_stakers_part = params.base_stakers_part + params.adjustable_stakers_part;
     params.adjustable_stakers_part = Zero::zero();
     assert!(params.is_valid());
@@ -471,3 +521,789 @@ fn test_genesis_build() {
         assert!(ActiveInflationConfig::<Test>::get().recalculation_block > 0);
     })
 }
+
+#[test]
+fn schedule_inflation_params_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let mut params_1 = init_params();
+        params_1.max_inflation_rate = Perquintill::from_percent(5);
+        let mut params_2 = init_params();
+        params_2.max_inflation_rate = Perquintill::from_percent(3);
+
+        // Schedule params out of order, ensure schedule is sorted
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            50,
+            params_1.clone()
+        ));
+        System::assert_last_event(
+            Event::InflationParametersScheduled {
+                block: 50,
+                params: params_1.clone(),
+            }
+            .into(),
+        );
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            20,
+            params_2.clone()
+        ));
+        assert_eq!(
+            ScheduledInflationParams::<Test>::get().into_inner(),
+            vec![(20, params_2.clone()), (50, params_1)]
+        );
+
+        // Scheduling for the same block overwrites the existing entry
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            50,
+            params_2.clone()
+        ));
+        assert_eq!(
+            ScheduledInflationParams::<Test>::get().into_inner(),
+            vec![(20, params_2.clone()), (50, params_2)]
+        );
+
+        // Active params remain unchanged until recalculation
+        assert_eq!(InflationParams::<Test>::get(), init_params());
+    })
+}
+
+#[test]
+fn schedule_inflation_params_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        let mut invalid_params = init_params();
+        invalid_params.base_stakers_part = Zero::zero();
+        let now = System::block_number();
+
+        // Make sure action is privileged
+        assert_noop!(
+            Inflation::schedule_inflation_params(RuntimeOrigin::signed(1), now + 1, init_params()),
+            BadOrigin
+        );
+
+        // Invalid params cannot be scheduled
+        assert_noop!(
+            Inflation::schedule_inflation_params(RuntimeOrigin::root(), now + 1, invalid_params),
+            Error::<Test>::InvalidInflationParameters
+        );
+
+        // Params cannot be scheduled for the current or a past block
+        assert_noop!(
+            Inflation::schedule_inflation_params(RuntimeOrigin::root(), now, init_params()),
+            Error::<Test>::ScheduleInThePast
+        );
+
+        // Schedule is bounded
+        let max_scheduled: u32 = <Test as Config>::MaxScheduledInflationParams::get();
+        for idx in 1..=max_scheduled {
+            assert_ok!(Inflation::schedule_inflation_params(
+                RuntimeOrigin::root(),
+                now + idx,
+                init_params()
+            ));
+        }
+        assert_noop!(
+            Inflation::schedule_inflation_params(
+                RuntimeOrigin::root(),
+                now + max_scheduled + 1,
+                init_params()
+            ),
+            Error::<Test>::TooManyScheduledParams
+        );
+    })
+}
+
+#[test]
+fn cancel_scheduled_inflation_params_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            20,
+            init_params()
+        ));
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            50,
+            init_params()
+        ));
+
+        assert_ok!(Inflation::cancel_scheduled_inflation_params(
+            RuntimeOrigin::root(),
+            20
+        ));
+        System::assert_last_event(
+            Event::ScheduledInflationParametersCancelled { block: 20 }.into(),
+        );
+        assert_eq!(
+            ScheduledInflationParams::<Test>::get().into_inner(),
+            vec![(50, init_params())]
+        );
+    })
+}
+
+#[test]
+fn cancel_scheduled_inflation_params_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            20,
+            init_params()
+        ));
+
+        // Make sure action is privileged
+        assert_noop!(
+            Inflation::cancel_scheduled_inflation_params(RuntimeOrigin::signed(1), 20),
+            BadOrigin
+        );
+
+        // Nothing is scheduled for the block
+        assert_noop!(
+            Inflation::cancel_scheduled_inflation_params(RuntimeOrigin::root(), 21),
+            Error::<Test>::NoScheduledParams
+        );
+    })
+}
+
+#[test]
+fn set_inflation_decay_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let decay = Some(InflationDecay {
+            factor: Perquintill::from_percent(90),
+            floor: Perquintill::from_percent(2),
+        });
+
+        // Make sure action is privileged
+        assert_noop!(
+            Inflation::set_inflation_decay(RuntimeOrigin::signed(1), decay),
+            BadOrigin
+        );
+
+        // Set & remove the decay
+        assert_ok!(Inflation::set_inflation_decay(RuntimeOrigin::root(), decay));
+        System::assert_last_event(Event::InflationDecayChanged { decay }.into());
+        assert_eq!(ActiveInflationDecay::<Test>::get(), decay);
+
+        assert_ok!(Inflation::set_inflation_decay(RuntimeOrigin::root(), None));
+        System::assert_last_event(Event::InflationDecayChanged { decay: None }.into());
+        assert!(ActiveInflationDecay::<Test>::get().is_none());
+    })
+}
+
+#[test]
+fn inflation_decay_apply_works() {
+    let decay = InflationDecay {
+        factor: Perquintill::from_percent(50),
+        floor: Perquintill::from_percent(2),
+    };
+
+    // Rate is decayed by the factor
+    let mut params = init_params();
+    params.max_inflation_rate = Perquintill::from_percent(8);
+    let decayed_params = decay.apply(params.clone());
+    assert_eq!(
+        decayed_params.max_inflation_rate,
+        Perquintill::from_percent(4)
+    );
+    assert_eq!(
+        InflationParameters {
+            max_inflation_rate: params.max_inflation_rate,
+            ..decayed_params
+        },
+        params,
+        "Only the max inflation rate can change."
+    );
+
+    // Rate doesn't decay below the floor
+    params.max_inflation_rate = Perquintill::from_percent(3);
+    assert_eq!(
+        decay.apply(params.clone()).max_inflation_rate,
+        Perquintill::from_percent(2)
+    );
+
+    // Rate already below the floor remains unchanged
+    params.max_inflation_rate = Perquintill::from_percent(1);
+    assert_eq!(
+        decay.apply(params.clone()).max_inflation_rate,
+        Perquintill::from_percent(1)
+    );
+}
+
+#[test]
+fn scheduled_inflation_params_are_applied_on_recalculation() {
+    ExternalityBuilder::build().execute_with(|| {
+        let init_config = ActiveInflationConfig::<Test>::get();
+
+        let mut params_1 = init_params();
+        params_1.max_inflation_rate = Perquintill::from_percent(5);
+        let mut params_2 = init_params();
+        params_2.max_inflation_rate = Perquintill::from_percent(3);
+        let mut params_3 = init_params();
+        params_3.max_inflation_rate = Perquintill::from_percent(1);
+
+        // Two entries are due at the next recalculation, the latest one should be applied.
+        // Decay must be ignored since scheduled params take precedence.
+        let recalculation_block = init_config.recalculation_block;
+        for (block, params) in [
+            (recalculation_block - 10, params_1),
+            (recalculation_block - 1, params_2.clone()),
+            (recalculation_block + 10, params_3.clone()),
+        ] {
+            assert_ok!(Inflation::schedule_inflation_params(
+                RuntimeOrigin::root(),
+                block,
+                params
+            ));
+        }
+        assert_ok!(Inflation::set_inflation_decay(
+            RuntimeOrigin::root(),
+            Some(InflationDecay {
+                factor: Perquintill::from_percent(50),
+                floor: Zero::zero(),
+            })
+        ));
+
+        // Trigger recalculation
+        advance_to_block(recalculation_block - 1);
+        let total_issuance = Balances::total_issuance();
+        Inflation::on_finalize(recalculation_block - 1);
+
+        assert_eq!(InflationParams::<Test>::get(), params_2);
+        assert_eq!(
+            ScheduledInflationParams::<Test>::get().into_inner(),
+            vec![(recalculation_block + 10, params_3)]
+        );
+        assert_eq!(
+            ActiveInflationConfig::<Test>::get().issuance_safety_cap,
+            total_issuance + params_2.max_inflation_rate * total_issuance
+        );
+    })
+}
+
+#[test]
+fn inflation_decay_is_applied_on_recalculation() {
+    ExternalityBuilder::build().execute_with(|| {
+        let decay = InflationDecay {
+            factor: Perquintill::from_percent(90),
+            floor: Perquintill::from_percent(6),
+        };
+        assert_ok!(Inflation::set_inflation_decay(
+            RuntimeOrigin::root(),
+            Some(decay)
+        ));
+
+        // First recalculation decays the rate by the factor
+        let init_config = ActiveInflationConfig::<Test>::get();
+        advance_to_block(init_config.recalculation_block - 1);
+        let total_issuance = Balances::total_issuance();
+        Inflation::on_finalize(init_config.recalculation_block - 1);
+
+        let expected_rate = Perquintill::from_percent(90) * init_params().max_inflation_rate;
+        assert_eq!(InflationParams::<Test>::get(), decay.apply(init_params()));
+        assert_eq!(
+            InflationParams::<Test>::get().max_inflation_rate,
+            expected_rate
+        );
+        assert_eq!(
+            ActiveInflationConfig::<Test>::get().issuance_safety_cap,
+            total_issuance + expected_rate * total_issuance
+        );
+
+        // Second recalculation hits the floor
+        let config = ActiveInflationConfig::<Test>::get();
+        advance_to_block(config.recalculation_block - 1);
+        Inflation::on_finalize(config.recalculation_block - 1);
+        assert_eq!(
+            InflationParams::<Test>::get().max_inflation_rate,
+            Perquintill::from_percent(6)
+        );
+    })
+}
+
+#[test]
+fn project_inflation_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let init_config = ActiveInflationConfig::<Test>::get();
+        let blocks_per_cycle = <Test as Config>::CycleConfiguration::blocks_per_cycle();
+
+        // Without any changes, the rate remains the same
+        let projection = Inflation::project_inflation(3);
+        assert_eq!(projection.len(), 3);
+        let mut issuance = init_config.issuance_safety_cap;
+        let mut recalculation_block = init_config.recalculation_block;
+        for cycle in projection {
+            let max_emission = init_params().max_inflation_rate * issuance;
+            assert_eq!(
+                cycle,
+                CycleInflationProjection {
+                    recalculation_block,
+                    max_inflation_rate: init_params().max_inflation_rate,
+                    max_emission,
+                    issuance_safety_cap: issuance + max_emission,
+                }
+            );
+
+            issuance += max_emission;
+            recalculation_block += blocks_per_cycle - 1;
+        }
+
+        // Decay & scheduled params are taken into account
+        let decay = InflationDecay {
+            factor: Perquintill::from_percent(50),
+            floor: Perquintill::from_percent(1),
+        };
+        assert_ok!(Inflation::set_inflation_decay(
+            RuntimeOrigin::root(),
+            Some(decay)
+        ));
+        let mut scheduled_params = init_params();
+        scheduled_params.max_inflation_rate = Perquintill::from_percent(10);
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            init_config.recalculation_block + blocks_per_cycle,
+            scheduled_params
+        ));
+
+        let rates: Vec<_> = Inflation::project_inflation(5)
+            .into_iter()
+            .map(|cycle| cycle.max_inflation_rate)
+            .collect();
+        assert_eq!(
+            rates,
+            vec![
+                Perquintill::from_parts(35_000_000_000_000_000),
+                Perquintill::from_parts(17_500_000_000_000_000),
+                Perquintill::from_percent(10),
+                Perquintill::from_percent(5),
+                Perquintill::from_parts(25_000_000_000_000_000),
+            ]
+        );
+
+        // Projection is bounded
+        assert_eq!(
+            Inflation::project_inflation(u32::MAX).len() as u32,
+            MAX_PROJECTED_CYCLES
+        );
+
+        // Projection doesn't change the state
+        assert_storage_noop!(Inflation::project_inflation(10));
+    })
+}
+
+#[test]
+fn project_inflation_follows_recalculation() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(Inflation::set_inflation_decay(
+            RuntimeOrigin::root(),
+            Some(InflationDecay {
+                factor: Perquintill::from_percent(80),
+                floor: Zero::zero(),
+            })
+        ));
+        let projection = Inflation::project_inflation(2);
+
+        // Trigger recalculation, and compare the outcome with the projection
+        let init_config = ActiveInflationConfig::<Test>::get();
+        advance_to_block(init_config.recalculation_block - 1);
+        Inflation::on_finalize(init_config.recalculation_block - 1);
+
+        assert_eq!(
+            InflationParams::<Test>::get().max_inflation_rate,
+            projection[0].max_inflation_rate
+        );
+        assert_eq!(
+            ActiveInflationConfig::<Test>::get().recalculation_block,
+            projection[1].recalculation_block
+        );
+        assert_eq!(
+            Inflation::project_inflation(1)[0].max_inflation_rate,
+            projection[1].max_inflation_rate
+        );
+    })
+}
+#[test]
+fn projected_inflation_config_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let mut scheduled_params = init_params();
+        scheduled_params.max_inflation_rate = Perquintill::from_percent(4);
+        scheduled_params.treasury_part = Perquintill::from_percent(10);
+        scheduled_params.bonus_part = Perquintill::from_percent(7);
+        assert!(scheduled_params.is_valid(), "Sanity check");
+
+        let recalculation_block = ActiveInflationConfig::<Test>::get().recalculation_block;
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            recalculation_block - 1,
+            scheduled_params.clone()
+        ));
+
+        // Projection must not change the state
+        let projected_config = Inflation::projected_inflation_config();
+        assert_storage_noop!(Inflation::projected_inflation_config());
+        assert!(projected_config != ActiveInflationConfig::<Test>::get());
+
+        // Trigger recalculation without any issuance in between, it must match the projection
+        Inflation::on_finalize(recalculation_block - 1);
+        assert_eq!(ActiveInflationConfig::<Test>::get(), projected_config);
+        assert_eq!(InflationParams::<Test>::get(), scheduled_params);
+    })
+}
+
+#[test]
+fn reward_pools_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let config = ActiveInflationConfig::<Test>::get();
+        let total_issuance = Balances::total_issuance();
+
+        for total_value_staked in [0, total_issuance / 4, total_issuance] {
+            let (staker_pool, dapp_pool) =
+                Inflation::staker_and_dapp_reward_pools(total_value_staked);
+            let breakdown = Inflation::reward_pools(total_value_staked);
+
+            assert_eq!(breakdown.staker_reward_pool_per_era, staker_pool);
+            assert_eq!(breakdown.dapp_reward_pool_per_era, dapp_pool);
+            assert_eq!(
+                breakdown.bonus_reward_pool_per_period,
+                config.bonus_reward_pool_per_period
+            );
+            assert_eq!(
+                breakdown.collator_reward_per_block,
+                config.collator_reward_per_block
+            );
+            assert_eq!(
+                breakdown.treasury_reward_per_block,
+                config.treasury_reward_per_block
+            );
+
+            // Emission over the entire cycle
+            let build_and_earn_eras_per_cycle = Balance::from(
+                <Test as Config>::CycleConfiguration::build_and_earn_eras_per_cycle(),
+            );
+            let blocks_per_cycle =
+                Balance::from(<Test as Config>::CycleConfiguration::blocks_per_cycle());
+            assert_eq!(
+                breakdown.stakers_emission_per_cycle,
+                staker_pool * build_and_earn_eras_per_cycle
+            );
+            assert_eq!(
+                breakdown.dapps_emission_per_cycle,
+                dapp_pool * build_and_earn_eras_per_cycle
+            );
+            assert_eq!(
+                breakdown.bonus_emission_per_cycle,
+                config.bonus_reward_pool_per_period
+                    * Balance::from(<Test as Config>::CycleConfiguration::periods_per_cycle())
+            );
+            assert_eq!(
+                breakdown.collators_emission_per_cycle,
+                config.collator_reward_per_block * blocks_per_cycle
+            );
+            assert_eq!(
+                breakdown.treasury_emission_per_cycle,
+                config.treasury_reward_per_block * blocks_per_cycle
+            );
+        }
+
+        // Staker reward pool grows with the total value staked, up to the ideal staking rate
+        assert!(
+            Inflation::reward_pools(total_issuance / 4).staker_reward_pool_per_era
+                > Inflation::reward_pools(0).staker_reward_pool_per_era
+        );
+    })
+}
+
+#[test]
+fn set_unspent_dapp_rewards_policy_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Default policy is to skip the unspent rewards
+        assert_eq!(
+            ActiveUnspentDAppRewardsPolicy::<Test>::get(),
+            UnspentDAppRewardsPolicy::Skip
+        );
+
+        // Make sure action is privileged
+        assert_noop!(
+            Inflation::set_unspent_dapp_rewards_policy(
+                RuntimeOrigin::signed(1),
+                UnspentDAppRewardsPolicy::Treasury
+            ),
+            BadOrigin
+        );
+
+        for policy in [
+            UnspentDAppRewardsPolicy::Treasury,
+            UnspentDAppRewardsPolicy::RollOver,
+            UnspentDAppRewardsPolicy::Skip,
+        ] {
+            assert_ok!(Inflation::set_unspent_dapp_rewards_policy(
+                RuntimeOrigin::root(),
+                policy
+            ));
+            System::assert_last_event(Event::UnspentDAppRewardsPolicyChanged { policy }.into());
+            assert_eq!(ActiveUnspentDAppRewardsPolicy::<Test>::get(), policy);
+        }
+    })
+}
+
+#[test]
+fn unspent_dapp_rewards_skip_policy_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let init_issuance = Balances::total_issuance();
+        let amount = 1000;
+
+        Inflation::handle_unspent_dapp_rewards(amount);
+
+        // Nothing is minted nor rolled over
+        assert_eq!(Balances::total_issuance(), init_issuance);
+        assert!(RolledOverDAppRewards::<Test>::get().is_zero());
+        System::assert_last_event(
+            Event::UnspentDAppRewardsRedirected {
+                policy: UnspentDAppRewardsPolicy::Skip,
+                amount: 0,
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn unspent_dapp_rewards_treasury_policy_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(Inflation::set_unspent_dapp_rewards_policy(
+            RuntimeOrigin::root(),
+            UnspentDAppRewardsPolicy::Treasury
+        ));
+        let init_issuance = Balances::total_issuance();
+        let init_treasury_pot = Balances::free_balance(&TREASURY_POT.into_account_truncating());
+        let amount = 1000;
+
+        Inflation::handle_unspent_dapp_rewards(amount);
+
+        // Unspent rewards are minted to the treasury
+        assert_eq!(Balances::total_issuance(), init_issuance + amount);
+        assert_eq!(
+            Balances::free_balance(&TREASURY_POT.into_account_truncating()),
+            init_treasury_pot + amount
+        );
+        assert!(RolledOverDAppRewards::<Test>::get().is_zero());
+        System::assert_last_event(
+            Event::UnspentDAppRewardsRedirected {
+                policy: UnspentDAppRewardsPolicy::Treasury,
+                amount,
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn unspent_dapp_rewards_treasury_policy_respects_cap() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(Inflation::set_unspent_dapp_rewards_policy(
+            RuntimeOrigin::root(),
+            UnspentDAppRewardsPolicy::Treasury
+        ));
+        let config = ActiveInflationConfig::<Test>::get();
+        let init_issuance = Balances::total_issuance();
+
+        let relaxed_cap = config.issuance_safety_cap * 101 / 100;
+        let amount = relaxed_cap - init_issuance + 1;
+
+        Inflation::handle_unspent_dapp_rewards(amount);
+
+        // Nothing is minted since the cap would be exceeded
+        assert_eq!(Balances::total_issuance(), init_issuance);
+        System::assert_last_event(
+            Event::UnspentDAppRewardsRedirected {
+                policy: UnspentDAppRewardsPolicy::Treasury,
+                amount: 0,
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn unspent_dapp_rewards_roll_over_policy_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(Inflation::set_unspent_dapp_rewards_policy(
+            RuntimeOrigin::root(),
+            UnspentDAppRewardsPolicy::RollOver
+        ));
+        let config = ActiveInflationConfig::<Test>::get();
+        let init_issuance = Balances::total_issuance();
+        let amount = 1000;
+
+        // Unspent rewards are added to the next era's dApp reward pool, without being minted
+        Inflation::handle_unspent_dapp_rewards(amount);
+        assert_eq!(Balances::total_issuance(), init_issuance);
+        assert_eq!(RolledOverDAppRewards::<Test>::get(), amount);
+        System::assert_last_event(
+            Event::UnspentDAppRewardsRedirected {
+                policy: UnspentDAppRewardsPolicy::RollOver,
+                amount,
+            }
+            .into(),
+        );
+
+        let (_, dapp_pool) = Inflation::staker_and_dapp_reward_pools(0);
+        assert_eq!(dapp_pool, config.dapp_reward_pool_per_era + amount);
+
+        // Rolled over rewards are replaced by the next era's unspent rewards
+        let amount = 300;
+        Inflation::handle_unspent_dapp_rewards(amount);
+        assert_eq!(RolledOverDAppRewards::<Test>::get(), amount);
+        let (_, dapp_pool) = Inflation::staker_and_dapp_reward_pools(0);
+        assert_eq!(dapp_pool, config.dapp_reward_pool_per_era + amount);
+
+        // Rolled over rewards can't exceed a single era's dApp reward pool
+        let amount = config.dapp_reward_pool_per_era * 2;
+        Inflation::handle_unspent_dapp_rewards(amount);
+        assert_eq!(
+            RolledOverDAppRewards::<Test>::get(),
+            config.dapp_reward_pool_per_era
+        );
+        System::assert_last_event(
+            Event::UnspentDAppRewardsRedirected {
+                policy: UnspentDAppRewardsPolicy::RollOver,
+                amount: config.dapp_reward_pool_per_era,
+            }
+            .into(),
+        );
+        let (_, dapp_pool) = Inflation::staker_and_dapp_reward_pools(0);
+        assert_eq!(dapp_pool, config.dapp_reward_pool_per_era * 2);
+
+        // Rolled over rewards are consumed once the policy changes
+        assert_ok!(Inflation::set_unspent_dapp_rewards_policy(
+            RuntimeOrigin::root(),
+            UnspentDAppRewardsPolicy::Skip
+        ));
+        assert_eq!(
+            RolledOverDAppRewards::<Test>::get(),
+            config.dapp_reward_pool_per_era
+        );
+        Inflation::handle_unspent_dapp_rewards(amount);
+        assert!(RolledOverDAppRewards::<Test>::get().is_zero());
+        let (_, dapp_pool) = Inflation::staker_and_dapp_reward_pools(0);
+        assert_eq!(dapp_pool, config.dapp_reward_pool_per_era);
+    })
+}
+
+/// Inflation parameters with two extra beneficiaries, taking their share from the treasury & bonus parts.
+fn params_with_extra_beneficiaries() -> InflationParameters {
+    let mut params = init_params();
+    params.treasury_part = Perquintill::from_percent(2);
+    params.bonus_part = Perquintill::from_percent(10);
+    params.extra_beneficiaries = BoundedVec::truncate_from(vec![
+        (*b"ecosystm", Perquintill::from_percent(3)),
+        (*b"burn_fee", Perquintill::from_percent(2)),
+    ]);
+    params
+}
+
+#[test]
+fn inflation_parameters_with_extra_beneficiaries_validity_check_works() {
+    let base_params = params_with_extra_beneficiaries();
+    assert!(base_params.is_valid(), "Sanity check.");
+
+    // Extra beneficiary shares are part of the sum
+    let mut params = base_params.clone();
+    params.extra_beneficiaries[0].1 = Perquintill::from_percent(4);
+    assert!(!params.is_valid(), "Sum is above 100%, must fail.");
+
+    let mut params = base_params.clone();
+    params.extra_beneficiaries.pop();
+    assert!(!params.is_valid(), "Sum is below 100%, must fail.");
+
+    // Same beneficiary cannot appear twice, even if the sum is correct
+    let mut params = base_params.clone();
+    params.extra_beneficiaries[1].0 = *b"ecosystm";
+    assert!(!params.is_valid(), "Duplicate beneficiary, must fail.");
+}
+
+#[test]
+fn inflation_recalculation_with_extra_beneficiaries_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let params = params_with_extra_beneficiaries();
+        assert_ok!(Inflation::force_set_inflation_params(
+            RuntimeOrigin::root(),
+            params.clone()
+        ));
+
+        let total_issuance = Balances::total_issuance();
+        let new_config = Inflation::recalculate_inflation(System::block_number());
+        let max_emission = params.max_inflation_rate * total_issuance;
+
+        let blocks_per_cycle =
+            Balance::from(<Test as Config>::CycleConfiguration::blocks_per_cycle());
+        assert_eq!(
+            new_config.extra_rewards_per_block.into_inner(),
+            vec![
+                (
+                    *b"ecosystm",
+                    Perquintill::from_percent(3) * max_emission / blocks_per_cycle
+                ),
+                (
+                    *b"burn_fee",
+                    Perquintill::from_percent(2) * max_emission / blocks_per_cycle
+                ),
+            ]
+        );
+        assert_eq!(
+            new_config.treasury_reward_per_block,
+            params.treasury_part * max_emission / blocks_per_cycle
+        );
+    })
+}
+
+#[test]
+fn reward_pools_include_extra_beneficiaries() {
+    ExternalityBuilder::build().execute_with(|| {
+        let extra_rewards = vec![(*b"ecosystm", 700), (*b"burn_fee", 300)];
+        ActiveInflationConfig::<Test>::mutate(|config| {
+            config.extra_rewards_per_block = BoundedVec::truncate_from(extra_rewards.clone());
+        });
+
+        let breakdown = Inflation::reward_pools(0);
+        assert_eq!(
+            breakdown.extra_rewards_per_block.into_inner(),
+            extra_rewards
+        );
+
+        let blocks_per_cycle =
+            Balance::from(<Test as Config>::CycleConfiguration::blocks_per_cycle());
+        assert_eq!(
+            breakdown.extra_emissions_per_cycle.into_inner(),
+            vec![
+                (*b"ecosystm", 700 * blocks_per_cycle),
+                (*b"burn_fee", 300 * blocks_per_cycle),
+            ]
+        );
+    })
+}
+
+#[test]
+fn on_initialize_extra_beneficiaries_payout_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let extra_rewards = vec![(*b"ecosystm", 700), (*b"burn_fee", 300)];
+        ActiveInflationConfig::<Test>::mutate(|config| {
+            config.extra_rewards_per_block = BoundedVec::truncate_from(extra_rewards.clone());
+        });
+        let config = ActiveInflationConfig::<Test>::get();
+        let init_issuance = Balances::total_issuance();
+
+        // Execute payout
+        Inflation::on_initialize(1);
+
+        // Each extra beneficiary receives its reward, on top of the collators & treasury
+        let expected_reward =
+            config.collator_reward_per_block + config.treasury_reward_per_block + 700 + 300;
+        assert_eq!(Balances::total_issuance(), init_issuance + expected_reward);
+        for (id, reward) in extra_rewards {
+            assert_eq!(
+                Balances::free_balance(&PalletId(id).into_account_truncating()),
+                reward
+            );
+        }
+    })
+}
@@ -53,14 +53,19 @@ pub trait WeightInfo {
 	fn force_set_inflation_config() -> Weight;
 	fn force_inflation_recalculation() -> Weight;
 	fn hook_with_recalculation() -> Weight;
-	fn hook_without_recalculation() -> Weight;
+	fn hook_without_recalculation(x: u32, ) -> Weight;
+	fn schedule_inflation_params() -> Weight;
+	fn cancel_scheduled_inflation_params() -> Weight;
+	fn set_inflation_decay() -> Weight;
+	fn set_unspent_dapp_rewards_policy() -> Weight;
+	fn handle_unspent_dapp_rewards() -> Weight;
 }
 
 /// Weights for pallet_inflation using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Inflation InflationParams (r:0 w:1)
-	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(64), added: 559, mode: MaxEncodedLen)
+	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(193), added: 688, mode: MaxEncodedLen)
 	fn force_set_inflation_params() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -77,45 +82,105 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(9_411_000, 0)
 	}
 	/// Storage: Inflation InflationParams (r:1 w:0)
-	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(64), added: 559, mode: MaxEncodedLen)
+	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(193), added: 688, mode: MaxEncodedLen)
 	fn force_inflation_recalculation() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `58`
-		//  Estimated: `1549`
-		// Minimum execution time: 14_839_000 picoseconds.
-		Weight::from_parts(15_198_000, 1549)
+		//  Estimated: `1678`
+		Weight::from_parts(15_783_000, 1678)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
-	/// Storage: System Account (r:2 w:1)
+	/// Storage: System Account (r:10 w:9)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Inflation InflationParams (r:1 w:0)
-	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(64), added: 559, mode: MaxEncodedLen)
+	/// Storage: Inflation InflationParams (r:1 w:1)
+	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(193), added: 688, mode: MaxEncodedLen)
+	/// Storage: Inflation ScheduledInflationParams (r:1 w:1)
+	/// Proof: Inflation ScheduledInflationParams (max_values: Some(1), max_size: Some(3153), added: 3648, mode: MaxEncodedLen)
+	/// Storage: Inflation ActiveInflationDecay (r:1 w:0)
+	/// Proof: Inflation ActiveInflationDecay (max_values: Some(1), max_size: Some(18), added: 513, mode: MaxEncodedLen)
 	fn hook_with_recalculation() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `232`
-		//  Estimated: `6196`
-		// Minimum execution time: 31_965_000 picoseconds.
-		Weight::from_parts(32_498_000, 6196)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Measured:  `3403`
+		//  Estimated: `27020`
+		// Minimum execution time: 95_318_000 picoseconds.
+		Weight::from_parts(96_204_000, 27020)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
-	/// Storage: System Account (r:2 w:1)
+	/// Storage: System Account (r:10 w:9)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	fn hook_without_recalculation() -> Weight {
+	/// The range of component `x` is `[0, 8]`.
+	fn hook_without_recalculation(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `174`
-		//  Estimated: `6196`
-		// Minimum execution time: 22_235_000 picoseconds.
+		//  Estimated: `6196 + x * (2603 ±0)`
 		Weight::from_parts(22_378_000, 6196)
+			.saturating_add(Weight::from_parts(7_026_000, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(x.into()))
+	}
+	/// Storage: Inflation ScheduledInflationParams (r:1 w:1)
+	/// Proof: Inflation ScheduledInflationParams (max_values: Some(1), max_size: Some(3153), added: 3648, mode: MaxEncodedLen)
+	fn schedule_inflation_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3012`
+		//  Estimated: `4638`
+		// Minimum execution time: 16_482_000 picoseconds.
+		Weight::from_parts(16_903_000, 4638)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Inflation ScheduledInflationParams (r:1 w:1)
+	/// Proof: Inflation ScheduledInflationParams (max_values: Some(1), max_size: Some(3153), added: 3648, mode: MaxEncodedLen)
+	fn cancel_scheduled_inflation_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3208`
+		//  Estimated: `4638`
+		// Minimum execution time: 14_217_000 picoseconds.
+		Weight::from_parts(14_655_000, 4638)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Inflation ActiveInflationDecay (r:0 w:1)
+	/// Proof: Inflation ActiveInflationDecay (max_values: Some(1), max_size: Some(18), added: 513, mode: MaxEncodedLen)
+	fn set_inflation_decay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_674_000 picoseconds.
+		Weight::from_parts(8_931_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Inflation ActiveUnspentDAppRewardsPolicy (r:0 w:1)
+	/// Proof: Inflation ActiveUnspentDAppRewardsPolicy (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn set_unspent_dapp_rewards_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_412_000 picoseconds.
+		Weight::from_parts(8_695_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Inflation ActiveUnspentDAppRewardsPolicy (r:1 w:0)
+	/// Proof: Inflation ActiveUnspentDAppRewardsPolicy (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Inflation RolledOverDAppRewards (r:0 w:1)
+	/// Proof: Inflation RolledOverDAppRewards (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn handle_unspent_dapp_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `4089`
+		Weight::from_parts(22_000_000, 4089)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	/// Storage: Inflation InflationParams (r:0 w:1)
-	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(64), added: 559, mode: MaxEncodedLen)
+	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(193), added: 688, mode: MaxEncodedLen)
 	fn force_set_inflation_params() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -132,37 +197,97 @@ impl WeightInfo for () {
 		Weight::from_parts(9_411_000, 0)
 	}
 	/// Storage: Inflation InflationParams (r:1 w:0)
-	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(64), added: 559, mode: MaxEncodedLen)
+	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(193), added: 688, mode: MaxEncodedLen)
 	fn force_inflation_recalculation() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `58`
-		//  Estimated: `1549`
-		// Minimum execution time: 14_839_000 picoseconds.
-		Weight::from_parts(15_198_000, 1549)
+		//  Estimated: `1678`
+		Weight::from_parts(15_783_000, 1678)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
-	/// Storage: System Account (r:2 w:1)
+	/// Storage: System Account (r:10 w:9)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Inflation InflationParams (r:1 w:0)
-	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(64), added: 559, mode: MaxEncodedLen)
+	/// Storage: Inflation InflationParams (r:1 w:1)
+	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(193), added: 688, mode: MaxEncodedLen)
+	/// Storage: Inflation ScheduledInflationParams (r:1 w:1)
+	/// Proof: Inflation ScheduledInflationParams (max_values: Some(1), max_size: Some(3153), added: 3648, mode: MaxEncodedLen)
+	/// Storage: Inflation ActiveInflationDecay (r:1 w:0)
+	/// Proof: Inflation ActiveInflationDecay (max_values: Some(1), max_size: Some(18), added: 513, mode: MaxEncodedLen)
 	fn hook_with_recalculation() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `232`
-		//  Estimated: `6196`
-		// Minimum execution time: 31_965_000 picoseconds.
-		Weight::from_parts(32_498_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `3403`
+		//  Estimated: `27020`
+		// Minimum execution time: 95_318_000 picoseconds.
+		Weight::from_parts(96_204_000, 27020)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
 	}
-	/// Storage: System Account (r:2 w:1)
+	/// Storage: System Account (r:10 w:9)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	fn hook_without_recalculation() -> Weight {
+	/// The range of component `x` is `[0, 8]`.
+	fn hook_without_recalculation(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `174`
-		//  Estimated: `6196`
-		// Minimum execution time: 22_235_000 picoseconds.
+		//  Estimated: `6196 + x * (2603 ±0)`
 		Weight::from_parts(22_378_000, 6196)
+			.saturating_add(Weight::from_parts(7_026_000, 0).saturating_mul(x.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(x.into()))
+	}
+	/// Storage: Inflation ScheduledInflationParams (r:1 w:1)
+	/// Proof: Inflation ScheduledInflationParams (max_values: Some(1), max_size: Some(3153), added: 3648, mode: MaxEncodedLen)
+	fn schedule_inflation_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3012`
+		//  Estimated: `4638`
+		// Minimum execution time: 16_482_000 picoseconds.
+		Weight::from_parts(16_903_000, 4638)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Inflation ScheduledInflationParams (r:1 w:1)
+	/// Proof: Inflation ScheduledInflationParams (max_values: Some(1), max_size: Some(3153), added: 3648, mode: MaxEncodedLen)
+	fn cancel_scheduled_inflation_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3208`
+		//  Estimated: `4638`
+		// Minimum execution time: 14_217_000 picoseconds.
+		Weight::from_parts(14_655_000, 4638)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Inflation ActiveInflationDecay (r:0 w:1)
+	/// Proof: Inflation ActiveInflationDecay (max_values: Some(1), max_size: Some(18), added: 513, mode: MaxEncodedLen)
+	fn set_inflation_decay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_674_000 picoseconds.
+		Weight::from_parts(8_931_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Inflation ActiveUnspentDAppRewardsPolicy (r:0 w:1)
+	/// Proof: Inflation ActiveUnspentDAppRewardsPolicy (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn set_unspent_dapp_rewards_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_412_000 picoseconds.
+		Weight::from_parts(8_695_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Inflation ActiveUnspentDAppRewardsPolicy (r:1 w:0)
+	/// Proof: Inflation ActiveUnspentDAppRewardsPolicy (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Inflation RolledOverDAppRewards (r:0 w:1)
+	/// Proof: Inflation RolledOverDAppRewards (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn handle_unspent_dapp_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `4089`
+		Weight::from_parts(22_000_000, 4089)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -0,0 +1,155 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_support::{assert_ok, traits::Hooks};
+use frame_system::RawOrigin;
+
+/// Fill up the feeder set with benchmark accounts, and return them.
+fn fill_feeders<T: Config>(count: u32) -> Vec<T::AccountId> {
+    let feeders: Vec<T::AccountId> = (0..count).map(|idx| account("feeder", idx, 0)).collect();
+
+    let mut set = BoundedBTreeSet::<T::AccountId, T::MaxFeeders>::new();
+    for feeder in &feeders {
+        assert_ok!(set.try_insert(feeder.clone()));
+    }
+    Feeders::<T>::put(set);
+
+    feeders
+}
+
+/// Fill up the price history with price points recorded in consecutive blocks, ending with the previous block.
+fn fill_history<T: Config>(now: BlockNumberFor<T>) {
+    let length = T::MaxHistoryLength::get();
+    let history: Vec<_> = (1..=length)
+        .map(|idx| PricePoint {
+            block: now.saturating_sub(idx.into()),
+            price: FixedU64::saturating_from_integer(idx),
+        })
+        .rev()
+        .collect();
+    PriceHistory::<T>::put(BoundedVec::try_from(history).expect("Length is equal to the limit."));
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn submit_price() {
+        let feeders = fill_feeders::<T>(T::MaxFeeders::get());
+        let price = FixedU64::saturating_from_integer(1);
+
+        // All but the last feeder have already submitted a price in this block.
+        let (caller, others) = feeders.split_last().expect("At least one feeder exists.");
+        for feeder in others {
+            assert_ok!(Pallet::<T>::submit_price(
+                RawOrigin::Signed(feeder.clone()).into(),
+                price
+            ));
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), price);
+
+        assert_eq!(CurrentValues::<T>::get().len() as u32, T::MaxFeeders::get());
+    }
+
+    #[benchmark]
+    fn add_feeder() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let _ = fill_feeders::<T>(T::MaxFeeders::get() - 1);
+        let new_feeder: T::AccountId = account("new_feeder", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, new_feeder.clone());
+
+        assert!(Feeders::<T>::get().contains(&new_feeder));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_feeder() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let feeders = fill_feeders::<T>(T::MaxFeeders::get());
+
+        // Each feeder has submitted a price in this block.
+        for feeder in &feeders {
+            assert_ok!(Pallet::<T>::submit_price(
+                RawOrigin::Signed(feeder.clone()).into(),
+                FixedU64::saturating_from_integer(1)
+            ));
+        }
+        let removed = feeders[0].clone();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, removed.clone());
+
+        assert!(!Feeders::<T>::get().contains(&removed));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn aggregate_price(x: Linear<1, { T::MaxFeeders::get() }>) {
+        let feeders = fill_feeders::<T>(x);
+        let now = frame_system::Pallet::<T>::block_number().max(T::MaxHistoryLength::get().into())
+            + 1_u32.into();
+        frame_system::Pallet::<T>::set_block_number(now);
+        fill_history::<T>(now);
+
+        for feeder in &feeders {
+            assert_ok!(Pallet::<T>::submit_price(
+                RawOrigin::Signed(feeder.clone()).into(),
+                FixedU64::saturating_from_integer(1)
+            ));
+        }
+
+        #[block]
+        {
+            Pallet::<T>::on_finalize(now);
+        }
+
+        assert!(CurrentValues::<T>::get().is_empty());
+        assert_eq!(
+            PriceHistory::<T>::get().last().map(|point| point.block),
+            Some(now)
+        );
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::benchmarking::tests::new_test_ext(),
+        crate::mock::Test,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock;
+    use sp_io::TestExternalities;
+
+    pub fn new_test_ext() -> TestExternalities {
+        mock::ExtBuilder::build()
+    }
+}
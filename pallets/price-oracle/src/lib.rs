@@ -0,0 +1,438 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Price Oracle Pallet
+//!
+//! ## Overview
+//!
+//! The pallet collects native currency price submissions from an authorized set of feeders,
+//! aggregates them at the end of each block, and provides a time-weighted average price (TWAP)
+//! via the `PriceProvider` interface.
+//!
+//! ## Approach
+//!
+//! Each authorized feeder can submit a single price value per block. Submissions are free of charge.
+//!
+//! At the end of the block, in `on_finalize`, all submitted values are aggregated:
+//! 1. The median of all submitted values is calculated.
+//! 2. Any value which deviates from the median by more than `MaxDeviation` is considered an outlier & is rejected.
+//! 3. The average of the remaining values is stored as the block's price point in the price history.
+//!
+//! Each price point is considered valid from the block it was recorded in, until the block in which the next price point was recorded.
+//!
+//! The average price is calculated as the time-weighted average of all price points which were valid during the last `TwapWindow` blocks.
+//! Price points which are no longer relevant for the calculation are pruned from the history.
+//!
+//! If there are no price points available, the configured `DefaultPrice` is used instead.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `submit_price` - submits a price value for the current block; only callable by an authorized feeder.
+//! * `add_feeder` - adds a new account to the authorized feeder set; only callable by the manager origin.
+//! * `remove_feeder` - removes an account from the authorized feeder set; only callable by the manager origin.
+//!
+//! ### Other
+//!
+//! Pallet implements `PriceProvider` trait from `astar-primitives`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::*, BoundedBTreeSet};
+use frame_system::pallet_prelude::*;
+use sp_runtime::{
+    traits::{Saturating, UniqueSaturatedInto, Zero},
+    FixedPointNumber, FixedU64, Perbill,
+};
+use sp_std::prelude::*;
+
+use astar_primitives::oracle::PriceProvider;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Privileged origin for managing the authorized feeder set.
+        type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Maximum number of authorized feeders.
+        #[pallet::constant]
+        type MaxFeeders: Get<u32>;
+
+        /// Length of the window, in blocks, over which the time-weighted average price is calculated.
+        #[pallet::constant]
+        type TwapWindow: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of price points kept in the price history.
+        #[pallet::constant]
+        type MaxHistoryLength: Get<u32>;
+
+        /// Maximum allowed deviation of a submitted value from the median of all values submitted in the same block.
+        /// Values exceeding it are considered outliers and are rejected.
+        #[pallet::constant]
+        type MaxDeviation: Get<Perbill>;
+
+        /// Price to use in case no price points are available.
+        type DefaultPrice: Get<FixedU64>;
+
+        /// Weight info for various calls & operations in the pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// New account has been added to the authorized feeder set.
+        FeederAdded { account: T::AccountId },
+        /// Account has been removed from the authorized feeder set.
+        FeederRemoved { account: T::AccountId },
+        /// Feeder has submitted a price value for the current block.
+        PriceSubmitted {
+            account: T::AccountId,
+            price: FixedU64,
+        },
+        /// Submitted price value has been rejected as an outlier.
+        PriceRejected {
+            account: T::AccountId,
+            price: FixedU64,
+        },
+        /// Submitted price values have been aggregated into a new price point.
+        PriceAggregated { price: FixedU64 },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Account is not an authorized feeder.
+        NotFeeder,
+        /// Account is already an authorized feeder.
+        AlreadyFeeder,
+        /// Maximum number of authorized feeders has been reached.
+        TooManyFeeders,
+        /// Feeder has already submitted a price value in the current block.
+        AlreadySubmitted,
+        /// Price value must be greater than zero.
+        ZeroPrice,
+    }
+
+    /// Authorized feeder set.
+    #[pallet::storage]
+    #[pallet::getter(fn feeders)]
+    pub type Feeders<T: Config> =
+        StorageValue<_, BoundedBTreeSet<T::AccountId, T::MaxFeeders>, ValueQuery>;
+
+    /// Price values submitted in the current block.
+    #[pallet::storage]
+    pub type CurrentValues<T: Config> =
+        StorageValue<_, BoundedVec<(T::AccountId, FixedU64), T::MaxFeeders>, ValueQuery>;
+
+    /// Aggregated price points, sorted by block number in ascending order.
+    #[pallet::storage]
+    #[pallet::getter(fn price_history)]
+    pub type PriceHistory<T: Config> =
+        StorageValue<_, BoundedVec<PricePoint<BlockNumberFor<T>>, T::MaxHistoryLength>, ValueQuery>;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        pub feeders: Vec<T::AccountId>,
+    }
+
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self {
+                feeders: Default::default(),
+            }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            let mut feeders = BoundedBTreeSet::<T::AccountId, T::MaxFeeders>::new();
+            for feeder in &self.feeders {
+                assert!(
+                    feeders
+                        .try_insert(feeder.clone())
+                        .expect("Genesis feeders exceed T::MaxFeeders."),
+                    "Duplicate feeders in genesis."
+                );
+            }
+            Feeders::<T>::put(feeders);
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+            // Account for the worst case aggregation cost in `on_finalize`.
+            T::WeightInfo::aggregate_price(T::MaxFeeders::get())
+        }
+
+        fn on_finalize(now: BlockNumberFor<T>) {
+            Self::aggregate_price(now);
+        }
+
+        fn integrity_test() {
+            assert!(
+                T::MaxFeeders::get() > 0,
+                "At least one feeder must be allowed."
+            );
+            assert!(
+                T::MaxHistoryLength::get() > 1,
+                "History must be able to hold at least two price points."
+            );
+            assert!(
+                !T::TwapWindow::get().is_zero(),
+                "TWAP window must not be zero."
+            );
+            assert!(
+                !T::DefaultPrice::get().is_zero(),
+                "Default price must not be zero."
+            );
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Submit a price value for the current block.
+        ///
+        /// Can only be called by an authorized feeder, once per block.
+        /// Successful submissions are free of charge.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::submit_price())]
+        pub fn submit_price(origin: OriginFor<T>, price: FixedU64) -> DispatchResultWithPostInfo {
+            let account = ensure_signed(origin)?;
+            ensure!(
+                Feeders::<T>::get().contains(&account),
+                Error::<T>::NotFeeder
+            );
+            ensure!(!price.is_zero(), Error::<T>::ZeroPrice);
+
+            CurrentValues::<T>::try_mutate(|values| -> DispatchResult {
+                ensure!(
+                    !values.iter().any(|(feeder, _)| feeder == &account),
+                    Error::<T>::AlreadySubmitted
+                );
+                // Cannot fail since each feeder can submit only once, and feeder set is bounded in the same way.
+                values
+                    .try_push((account.clone(), price))
+                    .map_err(|_| Error::<T>::TooManyFeeders)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::<T>::PriceSubmitted { account, price });
+
+            Ok(Pays::No.into())
+        }
+
+        /// Add a new account to the authorized feeder set.
+        ///
+        /// Can only be called by the manager origin.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::add_feeder())]
+        pub fn add_feeder(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            Feeders::<T>::try_mutate(|feeders| -> DispatchResult {
+                let inserted = feeders
+                    .try_insert(account.clone())
+                    .map_err(|_| Error::<T>::TooManyFeeders)?;
+                ensure!(inserted, Error::<T>::AlreadyFeeder);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::<T>::FeederAdded { account });
+
+            Ok(())
+        }
+
+        /// Remove an account from the authorized feeder set.
+        ///
+        /// Any value submitted by the account in the current block is discarded.
+        /// Can only be called by the manager origin.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::remove_feeder())]
+        pub fn remove_feeder(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            Feeders::<T>::try_mutate(|feeders| -> DispatchResult {
+                ensure!(feeders.remove(&account), Error::<T>::NotFeeder);
+                Ok(())
+            })?;
+            CurrentValues::<T>::mutate(|values| values.retain(|(feeder, _)| feeder != &account));
+
+            Self::deposit_event(Event::<T>::FeederRemoved { account });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Aggregate all values submitted in the current block into a new price point.
+        ///
+        /// Outliers are rejected, and the remaining values are averaged.
+        /// Price history is pruned of price points which are no longer relevant for the TWAP calculation.
+        pub(crate) fn aggregate_price(now: BlockNumberFor<T>) {
+            let values = CurrentValues::<T>::take();
+            if values.is_empty() {
+                return;
+            }
+
+            let median = Self::median(values.iter().map(|(_, price)| *price).collect());
+            let max_deviation = T::MaxDeviation::get();
+
+            let (mut sum, mut count) = (0_u128, 0_u128);
+            for (account, price) in values {
+                let deviation = Perbill::from_rational(
+                    price.into_inner().abs_diff(median.into_inner()),
+                    median.into_inner(),
+                );
+
+                if deviation > max_deviation {
+                    Self::deposit_event(Event::<T>::PriceRejected { account, price });
+                } else {
+                    sum.saturating_accrue(price.into_inner().into());
+                    count.saturating_inc();
+                }
+            }
+
+            // All values were rejected - nothing to record.
+            if count.is_zero() {
+                return;
+            }
+
+            let price = FixedU64::from_inner((sum / count).unique_saturated_into());
+
+            PriceHistory::<T>::mutate(|history| {
+                // Prune price points which are no longer valid at the start of the window.
+                let window_start = now.saturating_sub(T::TwapWindow::get());
+                let obsolete = history
+                    .windows(2)
+                    .take_while(|pair| pair[1].block <= window_start)
+                    .count();
+                for _ in 0..obsolete {
+                    history.remove(0);
+                }
+
+                if history.is_full() {
+                    history.remove(0);
+                }
+                // Cannot fail since we've ensured there's enough space.
+                let _ = history.try_push(PricePoint { block: now, price });
+            });
+
+            Self::deposit_event(Event::<T>::PriceAggregated { price });
+        }
+
+        /// Time-weighted average price over the last `TwapWindow` blocks, up to block `now`.
+        ///
+        /// Each price point is valid from the block it was recorded in until the block in which the next price point was recorded.
+        /// Returns `None` if there are no price points available.
+        pub(crate) fn time_weighted_average(now: BlockNumberFor<T>) -> Option<FixedU64> {
+            let history = PriceHistory::<T>::get();
+            let latest = history.last()?.price;
+
+            let window_start = now.saturating_sub(T::TwapWindow::get());
+
+            let (mut weighted_sum, mut total_duration) = (0_u128, 0_u128);
+            for (idx, point) in history.iter().enumerate() {
+                let valid_until = history.get(idx + 1).map_or(now, |next| next.block.min(now));
+                let valid_from = point.block.max(window_start);
+
+                if valid_until <= valid_from {
+                    continue;
+                }
+
+                let duration: u128 = valid_until
+                    .saturating_sub(valid_from)
+                    .unique_saturated_into();
+                weighted_sum.saturating_accrue(
+                    u128::from(point.price.into_inner()).saturating_mul(duration),
+                );
+                total_duration.saturating_accrue(duration);
+            }
+
+            // Only possible if all price points were recorded in the current block.
+            if total_duration.is_zero() {
+                return Some(latest);
+            }
+
+            Some(FixedU64::from_inner(
+                (weighted_sum / total_duration).unique_saturated_into(),
+            ))
+        }
+
+        /// Median of the provided values.
+        ///
+        /// For an even number of values, the average of the two middle values is used.
+        /// Provided vector must not be empty.
+        fn median(mut values: Vec<FixedU64>) -> FixedU64 {
+            values.sort();
+            let middle = values.len() / 2;
+
+            if values.len() % 2 == 0 {
+                let (lower, upper) = (values[middle - 1].into_inner(), values[middle].into_inner());
+                FixedU64::from_inner(lower / 2 + upper / 2 + (lower % 2 + upper % 2) / 2)
+            } else {
+                values[middle]
+            }
+        }
+    }
+}
+
+/// Aggregated price, recorded at the specified block.
+#[derive(Encode, Decode, MaxEncodedLen, Copy, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct PricePoint<BlockNumber> {
+    /// Block in which the price point was recorded.
+    pub block: BlockNumber,
+    /// Aggregated price.
+    pub price: FixedU64,
+}
+
+impl<T: Config> PriceProvider for Pallet<T> {
+    fn average_price() -> FixedU64 {
+        Self::time_weighted_average(frame_system::Pallet::<T>::block_number())
+            .unwrap_or_else(T::DefaultPrice::get)
+    }
+}
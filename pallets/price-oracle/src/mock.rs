@@ -0,0 +1,140 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_price_oracle, *};
+
+use frame_support::{
+    construct_runtime, ord_parameter_types, parameter_types,
+    traits::{ConstU32, ConstU64, OnFinalize, OnInitialize},
+    weights::constants::RocksDbWeight,
+};
+use frame_system::EnsureSignedBy;
+use sp_core::H256;
+use sp_io::TestExternalities;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+
+pub(crate) type AccountId = u64;
+pub(crate) type BlockNumber = u64;
+
+/// Account allowed to manage the feeder set.
+pub(crate) const MANAGER: AccountId = 100;
+/// Feeders configured at genesis.
+pub(crate) const GENESIS_FEEDERS: [AccountId; 3] = [1, 2, 3];
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Index = u64;
+    type RuntimeCall = RuntimeCall;
+    type BlockNumber = BlockNumber;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type DbWeight = RocksDbWeight;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+ord_parameter_types! {
+    pub const Manager: AccountId = MANAGER;
+}
+
+parameter_types! {
+    pub const MaxDeviation: Perbill = Perbill::from_percent(10);
+    pub DefaultPrice: FixedU64 = FixedU64::from_rational(1, 10);
+}
+
+impl pallet_price_oracle::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureSignedBy<Manager, AccountId>;
+    type MaxFeeders = ConstU32<5>;
+    type TwapWindow = ConstU64<10>;
+    type MaxHistoryLength = ConstU32<8>;
+    type MaxDeviation = MaxDeviation;
+    type DefaultPrice = DefaultPrice;
+    type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+    pub struct Test
+    where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        PriceOracle: pallet_price_oracle,
+    }
+);
+
+pub struct ExtBuilder;
+impl ExtBuilder {
+    pub fn build() -> TestExternalities {
+        let storage = GenesisConfig {
+            system: Default::default(),
+            price_oracle: PriceOracleConfig {
+                feeders: GENESIS_FEEDERS.to_vec(),
+            },
+        }
+        .build_storage()
+        .unwrap();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            PriceOracle::on_initialize(1);
+        });
+        ext
+    }
+}
+
+/// Finalize the current block and initialize the next one.
+pub(crate) fn run_to_next_block() {
+    let now = System::block_number();
+    PriceOracle::on_finalize(now);
+
+    System::set_block_number(now + 1);
+    PriceOracle::on_initialize(now + 1);
+}
+
+/// Advance blocks until the specified block number is reached.
+pub(crate) fn run_to_block(n: BlockNumber) {
+    while System::block_number() < n {
+        run_to_next_block();
+    }
+}
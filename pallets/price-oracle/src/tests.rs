@@ -0,0 +1,351 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::*;
+
+use frame_support::{assert_noop, assert_ok, dispatch::Pays};
+use sp_runtime::traits::BadOrigin;
+
+/// Price helper, expressed in thousandths.
+fn price(millis: u64) -> FixedU64 {
+    FixedU64::from_rational(millis.into(), 1000)
+}
+
+#[test]
+fn genesis_feeders_are_set() {
+    ExtBuilder::build().execute_with(|| {
+        let feeders = Feeders::<Test>::get();
+        assert_eq!(feeders.len(), GENESIS_FEEDERS.len());
+        for feeder in GENESIS_FEEDERS {
+            assert!(feeders.contains(&feeder));
+        }
+    })
+}
+
+#[test]
+fn add_and_remove_feeder_works() {
+    ExtBuilder::build().execute_with(|| {
+        let account = 4;
+
+        assert_ok!(PriceOracle::add_feeder(
+            RuntimeOrigin::signed(MANAGER),
+            account
+        ));
+        System::assert_last_event(RuntimeEvent::PriceOracle(Event::FeederAdded { account }));
+        assert!(Feeders::<Test>::get().contains(&account));
+
+        assert_ok!(PriceOracle::remove_feeder(
+            RuntimeOrigin::signed(MANAGER),
+            account
+        ));
+        System::assert_last_event(RuntimeEvent::PriceOracle(Event::FeederRemoved { account }));
+        assert!(!Feeders::<Test>::get().contains(&account));
+    })
+}
+
+#[test]
+fn add_feeder_fails() {
+    ExtBuilder::build().execute_with(|| {
+        // Only manager can add feeders
+        assert_noop!(
+            PriceOracle::add_feeder(RuntimeOrigin::signed(1), 4),
+            BadOrigin
+        );
+
+        // Cannot add the same feeder twice
+        assert_noop!(
+            PriceOracle::add_feeder(RuntimeOrigin::signed(MANAGER), GENESIS_FEEDERS[0]),
+            Error::<Test>::AlreadyFeeder
+        );
+
+        // Fill up the feeder set, and try to add one more
+        let max_feeders = <Test as Config>::MaxFeeders::get() as u64;
+        for account in GENESIS_FEEDERS.len() as u64 + 1..=max_feeders {
+            assert_ok!(PriceOracle::add_feeder(
+                RuntimeOrigin::signed(MANAGER),
+                account
+            ));
+        }
+        assert_noop!(
+            PriceOracle::add_feeder(RuntimeOrigin::signed(MANAGER), max_feeders + 1),
+            Error::<Test>::TooManyFeeders
+        );
+    })
+}
+
+#[test]
+fn remove_feeder_fails() {
+    ExtBuilder::build().execute_with(|| {
+        // Only manager can remove feeders
+        assert_noop!(
+            PriceOracle::remove_feeder(RuntimeOrigin::signed(1), GENESIS_FEEDERS[0]),
+            BadOrigin
+        );
+
+        // Cannot remove an account which isn't a feeder
+        assert_noop!(
+            PriceOracle::remove_feeder(RuntimeOrigin::signed(MANAGER), 4),
+            Error::<Test>::NotFeeder
+        );
+    })
+}
+
+#[test]
+fn remove_feeder_discards_pending_value() {
+    ExtBuilder::build().execute_with(|| {
+        let feeder = GENESIS_FEEDERS[0];
+        assert_ok!(PriceOracle::submit_price(
+            RuntimeOrigin::signed(feeder),
+            price(1000)
+        ));
+
+        assert_ok!(PriceOracle::remove_feeder(
+            RuntimeOrigin::signed(MANAGER),
+            feeder
+        ));
+        assert!(CurrentValues::<Test>::get().is_empty());
+
+        // No price point should be recorded
+        run_to_next_block();
+        assert!(PriceHistory::<Test>::get().is_empty());
+    })
+}
+
+#[test]
+fn submit_price_works() {
+    ExtBuilder::build().execute_with(|| {
+        let feeder = GENESIS_FEEDERS[0];
+        let result = PriceOracle::submit_price(RuntimeOrigin::signed(feeder), price(1000));
+        assert_ok!(result);
+        assert_eq!(result.unwrap().pays_fee, Pays::No);
+        System::assert_last_event(RuntimeEvent::PriceOracle(Event::PriceSubmitted {
+            account: feeder,
+            price: price(1000),
+        }));
+
+        assert_eq!(
+            CurrentValues::<Test>::get().into_inner(),
+            vec![(feeder, price(1000))]
+        );
+
+        // Submitted values are cleared at the end of the block, and feeder can submit again.
+        run_to_next_block();
+        assert!(CurrentValues::<Test>::get().is_empty());
+        assert_ok!(PriceOracle::submit_price(
+            RuntimeOrigin::signed(feeder),
+            price(1100)
+        ));
+    })
+}
+
+#[test]
+fn submit_price_fails() {
+    ExtBuilder::build().execute_with(|| {
+        // Only feeders can submit price
+        assert_noop!(
+            PriceOracle::submit_price(RuntimeOrigin::signed(4), price(1000)),
+            Error::<Test>::NotFeeder
+        );
+
+        // Zero price isn't allowed
+        let feeder = GENESIS_FEEDERS[0];
+        assert_noop!(
+            PriceOracle::submit_price(RuntimeOrigin::signed(feeder), FixedU64::zero()),
+            Error::<Test>::ZeroPrice
+        );
+
+        // Only one submission per block is allowed
+        assert_ok!(PriceOracle::submit_price(
+            RuntimeOrigin::signed(feeder),
+            price(1000)
+        ));
+        assert_noop!(
+            PriceOracle::submit_price(RuntimeOrigin::signed(feeder), price(1100)),
+            Error::<Test>::AlreadySubmitted
+        );
+    })
+}
+
+#[test]
+fn aggregation_averages_accepted_values() {
+    ExtBuilder::build().execute_with(|| {
+        for (feeder, value) in GENESIS_FEEDERS.into_iter().zip([1000, 1050, 1100]) {
+            assert_ok!(PriceOracle::submit_price(
+                RuntimeOrigin::signed(feeder),
+                price(value)
+            ));
+        }
+
+        run_to_next_block();
+
+        System::assert_last_event(RuntimeEvent::PriceOracle(Event::PriceAggregated {
+            price: price(1050),
+        }));
+        assert_eq!(
+            PriceHistory::<Test>::get().into_inner(),
+            vec![PricePoint {
+                block: 1,
+                price: price(1050)
+            }]
+        );
+    })
+}
+
+#[test]
+fn aggregation_rejects_outliers() {
+    ExtBuilder::build().execute_with(|| {
+        // Median is 1.05, so 2.0 deviates by more than the allowed 10%.
+        for (feeder, value) in GENESIS_FEEDERS.into_iter().zip([1000, 1050, 2000]) {
+            assert_ok!(PriceOracle::submit_price(
+                RuntimeOrigin::signed(feeder),
+                price(value)
+            ));
+        }
+
+        run_to_next_block();
+
+        System::assert_has_event(RuntimeEvent::PriceOracle(Event::PriceRejected {
+            account: GENESIS_FEEDERS[2],
+            price: price(2000),
+        }));
+        System::assert_last_event(RuntimeEvent::PriceOracle(Event::PriceAggregated {
+            price: price(1025),
+        }));
+        assert_eq!(PriceHistory::<Test>::get()[0].price, price(1025));
+    })
+}
+
+#[test]
+fn aggregation_with_all_values_rejected_records_nothing() {
+    ExtBuilder::build().execute_with(|| {
+        // Median is 1.5, both values deviate by more than the allowed 10%.
+        for (feeder, value) in GENESIS_FEEDERS.into_iter().zip([1000, 2000]) {
+            assert_ok!(PriceOracle::submit_price(
+                RuntimeOrigin::signed(feeder),
+                price(value)
+            ));
+        }
+
+        run_to_next_block();
+
+        System::assert_has_event(RuntimeEvent::PriceOracle(Event::PriceRejected {
+            account: GENESIS_FEEDERS[0],
+            price: price(1000),
+        }));
+        System::assert_has_event(RuntimeEvent::PriceOracle(Event::PriceRejected {
+            account: GENESIS_FEEDERS[1],
+            price: price(2000),
+        }));
+        assert!(PriceHistory::<Test>::get().is_empty());
+    })
+}
+
+#[test]
+fn average_price_defaults_without_history() {
+    ExtBuilder::build().execute_with(|| {
+        assert_eq!(
+            PriceOracle::average_price(),
+            <Test as Config>::DefaultPrice::get()
+        );
+
+        // Blocks without any submissions don't change anything
+        run_to_block(5);
+        assert_eq!(
+            PriceOracle::average_price(),
+            <Test as Config>::DefaultPrice::get()
+        );
+    })
+}
+
+#[test]
+fn average_price_is_time_weighted() {
+    ExtBuilder::build().execute_with(|| {
+        let feeder = GENESIS_FEEDERS[0];
+        let window = <Test as Config>::TwapWindow::get();
+
+        // Price point at block 1
+        assert_ok!(PriceOracle::submit_price(
+            RuntimeOrigin::signed(feeder),
+            price(1000)
+        ));
+        run_to_next_block();
+        assert_eq!(PriceOracle::average_price(), price(1000));
+
+        // Price point at block 6
+        run_to_block(6);
+        assert_ok!(PriceOracle::submit_price(
+            RuntimeOrigin::signed(feeder),
+            price(2000)
+        ));
+        run_to_next_block();
+
+        // One block of the second price, five blocks of the first one.
+        assert_eq!(
+            PriceOracle::average_price(),
+            FixedU64::from_inner((5 * price(1000).into_inner() + price(2000).into_inner()) / 6)
+        );
+
+        // Both prices were valid for the same duration.
+        run_to_block(1 + window);
+        assert_eq!(PriceOracle::average_price(), price(1500));
+
+        // First price point is out of the window.
+        run_to_block(6 + window);
+        assert_eq!(PriceOracle::average_price(), price(2000));
+    })
+}
+
+#[test]
+fn price_history_is_pruned() {
+    ExtBuilder::build().execute_with(|| {
+        let feeder = GENESIS_FEEDERS[0];
+        let window = <Test as Config>::TwapWindow::get();
+        let submit_at = |block: BlockNumber, value: u64| {
+            run_to_block(block);
+            assert_ok!(PriceOracle::submit_price(
+                RuntimeOrigin::signed(feeder),
+                price(value)
+            ));
+            run_to_next_block();
+        };
+
+        // The first point remains relevant until the second one is at or before the window start.
+        submit_at(1, 1000);
+        submit_at(2 * window, 1100);
+        submit_at(2 * window + 5, 1200);
+        assert_eq!(PriceHistory::<Test>::get().len(), 3);
+
+        submit_at(3 * window + 1, 1300);
+        let blocks: Vec<_> = PriceHistory::<Test>::get()
+            .iter()
+            .map(|point| point.block)
+            .collect();
+        assert_eq!(blocks, vec![2 * window, 2 * window + 5, 3 * window + 1]);
+
+        // History length is capped, oldest points are removed first.
+        let max_length = <Test as Config>::MaxHistoryLength::get() as u64;
+        let start = System::block_number();
+        for block in start..start + max_length + 2 {
+            submit_at(block, 1000);
+        }
+        let history = PriceHistory::<Test>::get();
+        assert_eq!(history.len() as u64, max_length);
+        assert_eq!(history.last().unwrap().block, start + max_length + 1);
+    })
+}
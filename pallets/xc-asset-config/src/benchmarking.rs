@@ -23,8 +23,9 @@ use crate::Pallet as XcAssetConfig;
 
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
 use frame_system::RawOrigin;
-use sp_std::boxed::Box;
-use xcm::v3::MultiLocation;
+use sp_runtime::{traits::One, FixedPointNumber, FixedU128};
+use sp_std::{boxed::Box, vec, vec::Vec};
+use xcm::v3::{Junction::Parachain, Junctions::X1, MultiLocation};
 
 benchmarks! {
 
@@ -34,7 +35,8 @@ benchmarks! {
 
     }: _(RawOrigin::Root, Box::new(asset_location.clone().into_versioned()), asset_id)
     verify {
-        assert_eq!(AssetIdToLocation::<T>::get(&asset_id), Some(asset_location.into_versioned()));
+        assert_eq!(AssetIdToLocation::<T>::get(&asset_id), Some(asset_location.into_versioned().into()));
+        assert!(XcAssetConfig::<T>::is_reserve_location(asset_id, asset_location));
     }
 
     set_asset_units_per_second {
@@ -94,6 +96,99 @@ benchmarks! {
         assert!(!AssetLocationUnitsPerSecond::<T>::contains_key(&asset_location.into_versioned()));
     }
 
+    add_reserve_location {
+        let asset_location = MultiLocation::parent();
+        let asset_id = T::AssetId::default();
+        let reserve_location = MultiLocation::new(1, X1(Parachain(2000)));
+
+        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id)?;
+
+    }: _(RawOrigin::Root, asset_id, Box::new(reserve_location.clone().into_versioned()))
+    verify {
+        assert!(XcAssetConfig::<T>::is_reserve_location(asset_id, reserve_location));
+    }
+
+    remove_reserve_location {
+        let asset_location = MultiLocation::parent();
+        let asset_id = T::AssetId::default();
+        // Relay chain is trusted as the reserve on registration
+        let reserve_location = MultiLocation::parent();
+
+        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id)?;
+
+    }: _(RawOrigin::Root, asset_id, Box::new(reserve_location.clone().into_versioned()))
+    verify {
+        assert!(!XcAssetConfig::<T>::is_reserve_location(asset_id, reserve_location));
+    }
+
+    set_asset_exchange_rate {
+        let asset_location = MultiLocation::parent();
+        let asset_id = T::AssetId::default();
+        let rate = FixedU128::from_rational(3, 2);
+
+        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id)?;
+
+    }: _(RawOrigin::Root, Box::new(asset_location.clone().into_versioned()), rate)
+    verify {
+        assert_eq!(XcAssetConfig::<T>::get_exchange_rate(asset_location), Some((rate, 0)));
+    }
+
+    remove_asset_exchange_rate {
+        let asset_location = MultiLocation::parent();
+        let asset_id = T::AssetId::default();
+        let rate = FixedU128::from_rational(3, 2);
+
+        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id)?;
+        XcAssetConfig::<T>::set_asset_exchange_rate(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), rate)?;
+
+    }: _(RawOrigin::Root, Box::new(asset_location.clone().into_versioned()))
+    verify {
+        assert!(!AssetLocationExchangeRate::<T>::contains_key(&asset_location.into_versioned()));
+    }
+
+    register_foreign_asset {
+        let asset_location = MultiLocation::parent();
+        let asset_id = T::AssetId::default();
+        let metadata = ForeignAssetMetadata {
+            name: vec![b'a'; 32],
+            symbol: vec![b's'; 32],
+            decimals: 12,
+            min_balance: One::one(),
+            is_sufficient: true,
+        };
+        let reserve_locations: BoundedVec<_, T::MaxReserveLocations> = (0..T::MaxReserveLocations::get())
+            .map(|id| MultiLocation::new(1, X1(Parachain(id))).into_versioned())
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("Exactly the max number of reserve locations.");
+        let units = 123;
+
+    }: _(RawOrigin::Root, Box::new(asset_location.clone().into_versioned()), asset_id, metadata, reserve_locations, Some(units))
+    verify {
+        assert_eq!(AssetLocationToId::<T>::get(&asset_location.into_versioned()), Some(asset_id));
+        assert_eq!(AssetReserveLocations::<T>::get(asset_id).len() as u32, T::MaxReserveLocations::get());
+        assert_eq!(AssetLocationUnitsPerSecond::<T>::get(&asset_location.into_versioned()), Some(units));
+    }
+
+    deregister_foreign_asset {
+        let asset_location = MultiLocation::parent();
+        let asset_id = T::AssetId::default();
+        let metadata = ForeignAssetMetadata {
+            name: vec![b'a'; 32],
+            symbol: vec![b's'; 32],
+            decimals: 12,
+            min_balance: One::one(),
+            is_sufficient: true,
+        };
+
+        XcAssetConfig::<T>::register_foreign_asset(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id, metadata, Default::default(), Some(123))?;
+
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert!(!AssetLocationToId::<T>::contains_key(&asset_location.into_versioned()));
+        assert!(!AssetIdToLocation::<T>::contains_key(asset_id));
+    }
+
 }
 
 #[cfg(test)]
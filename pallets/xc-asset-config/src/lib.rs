@@ -28,6 +28,15 @@
 //!
 //! Additionally, it stores information whether a foreign asset is supported as a payment currency for execution on local network.
 //!
+//! Alternatively, an exchange rate against the native currency can be set for a foreign asset. Execution on local network
+//! is then priced in the native currency and converted into the foreign asset using this rate.
+//!
+//! For each registered asset, the pallet also keeps a list of locations which are trusted to act as the asset's reserve.
+//! Reserve asset deposits of the asset from any other origin should be rejected.
+//!
+//! Foreign assets can also be registered together with their local representation. The local asset is created with
+//! its metadata & sufficiency, and mapped to the remote asset location in a single call, so the two can't drift apart.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Function
@@ -37,6 +46,12 @@
 //! - `change_existing_asset_location` - changes the remote location of an existing local asset Id
 //! - `remove_payment_asset` - removes asset from the set of supported payment assets
 //! - `remove_asset` - removes all information related to this asset
+//! - `add_reserve_location` - adds a location to the set of trusted reserve locations of an asset
+//! - `remove_reserve_location` - removes a location from the set of trusted reserve locations of an asset
+//! - `set_asset_exchange_rate` - sets the exchange rate of an asset against the native currency
+//! - `remove_asset_exchange_rate` - removes the exchange rate of an asset
+//! - `register_foreign_asset` - creates the local asset with metadata and registers its remote asset location, reserve locations & units per second
+//! - `deregister_foreign_asset` - removes all information related to the asset and starts destroying the local asset
 //!
 //! User is encouraged to refer to specific function implementations for more comprehensive documentation.
 //!
@@ -49,11 +64,25 @@
 //! `ExecutionPaymentRate` interface for fetching `units per second` if asset is supported payment asset
 //! - `get_units_per_second`
 //!
+//! `AssetExchangeRate` interface for fetching the exchange rate of an asset against the native currency, and its age
+//! - `get_exchange_rate`
+//!
+//! `ReserveLocationFilter` - `ContainsPair<MultiAsset, MultiLocation>` implementation which can be used as `IsReserve`
+//! in XCM executor configuration, accepting only the registered reserve locations of an asset.
+//!
+//! `ForeignAssetManager` interface for creating & destroying the local assets which represent foreign assets
+//! - `create`
+//! - `start_destroy`
+//!
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::pallet;
+use frame_support::{pallet, pallet_prelude::*};
 pub use pallet::*;
+use parity_scale_codec::EncodeLike;
+use sp_runtime::FixedU128;
+use sp_std::vec::Vec;
+use xcm::{v3::MultiLocation, VersionedMultiLocation};
 
 #[cfg(any(test, feature = "runtime-benchmarks"))]
 mod benchmarking;
@@ -68,21 +97,85 @@ pub mod migrations;
 pub mod weights;
 pub use weights::WeightInfo;
 
+/// Exchange rate of an asset against the native currency.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ExchangeRateInfo<BlockNumber> {
+    /// Amount of the asset's smallest units equal in value to one smallest unit of the native currency.
+    pub rate: FixedU128,
+    /// Block in which the rate was last updated.
+    pub updated_at: BlockNumber,
+}
+
+/// Metadata of a foreign asset, used to create its local representation.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ForeignAssetMetadata<Balance> {
+    /// Name of the asset.
+    pub name: Vec<u8>,
+    /// Symbol of the asset.
+    pub symbol: Vec<u8>,
+    /// Number of decimals of the asset.
+    pub decimals: u8,
+    /// Minimum balance an account must hold to exist.
+    pub min_balance: Balance,
+    /// Whether holding the asset is sufficient for an account to exist.
+    pub is_sufficient: bool,
+}
+
+/// `VersionedMultiLocation` with a bounded encoded length, used to keep the pallet storage bounded.
+///
+/// Encoded the same way as the wrapped `VersionedMultiLocation`.
+/// Even though the pallet only stores the latest version, any supported version can be decoded,
+/// so the bound is the largest encoded length among the supported versions.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BoundedVersionedMultiLocation(pub VersionedMultiLocation);
+
+impl MaxEncodedLen for BoundedVersionedMultiLocation {
+    fn max_encoded_len() -> usize {
+        // Version index followed by the location itself
+        1 + xcm::v2::MultiLocation::max_encoded_len().max(MultiLocation::max_encoded_len())
+    }
+}
+
+impl EncodeLike<VersionedMultiLocation> for BoundedVersionedMultiLocation {}
+impl EncodeLike<BoundedVersionedMultiLocation> for VersionedMultiLocation {}
+
+impl From<VersionedMultiLocation> for BoundedVersionedMultiLocation {
+    fn from(location: VersionedMultiLocation) -> Self {
+        Self(location)
+    }
+}
+
+impl From<BoundedVersionedMultiLocation> for VersionedMultiLocation {
+    fn from(location: BoundedVersionedMultiLocation) -> Self {
+        location.0
+    }
+}
+
 #[pallet]
 pub mod pallet {
 
+    use super::{BoundedVersionedMultiLocation, ExchangeRateInfo, ForeignAssetMetadata};
     use crate::weights::WeightInfo;
-    use frame_support::{pallet_prelude::*, traits::EnsureOrigin};
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{ContainsPair, EnsureOrigin},
+    };
     use frame_system::pallet_prelude::*;
     use parity_scale_codec::HasCompact;
-    use sp_std::boxed::Box;
-    use xcm::{v3::MultiLocation, VersionedMultiLocation};
-
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+    use sp_runtime::{
+        traits::{AtLeast32BitUnsigned, Saturating, UniqueSaturatedInto, Zero},
+        FixedU128,
+    };
+    use sp_std::{boxed::Box, vec::Vec};
+    use xcm::{
+        v3::{AssetId::Concrete, Junction::Parachain, Junctions::X1, MultiAsset, MultiLocation},
+        VersionedMultiLocation,
+    };
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
-    #[pallet::without_storage_info]
     pub struct Pallet<T>(PhantomData<T>);
 
     /// Defines conversion between asset Id and cross-chain asset location
@@ -102,7 +195,7 @@ pub mod pallet {
 
     impl<T: Config> XcAssetLocation<T::AssetId> for Pallet<T> {
         fn get_xc_asset_location(asset_id: T::AssetId) -> Option<MultiLocation> {
-            AssetIdToLocation::<T>::get(asset_id).and_then(|x| x.try_into().ok())
+            AssetIdToLocation::<T>::get(asset_id).and_then(|x| x.0.try_into().ok())
         }
 
         fn get_asset_id(asset_location: MultiLocation) -> Option<T::AssetId> {
@@ -116,6 +209,53 @@ pub mod pallet {
         }
     }
 
+    /// Used to fetch the exchange rate of a cross-chain asset against the native currency.
+    pub trait AssetExchangeRate {
+        /// Returns the amount of the asset's smallest units equal in value to one smallest unit of the native currency,
+        /// together with the number of blocks elapsed since the rate was last updated.
+        ///
+        /// Returns `None` if the asset doesn't have an exchange rate.
+        fn get_exchange_rate(asset_location: MultiLocation) -> Option<(FixedU128, u32)>;
+    }
+
+    impl<T: Config> AssetExchangeRate for Pallet<T> {
+        fn get_exchange_rate(asset_location: MultiLocation) -> Option<(FixedU128, u32)> {
+            AssetLocationExchangeRate::<T>::get(asset_location.into_versioned()).map(|info| {
+                let age = frame_system::Pallet::<T>::block_number()
+                    .saturating_sub(info.updated_at)
+                    .unique_saturated_into();
+                (info.rate, age)
+            })
+        }
+    }
+
+    /// Used to create & destroy the local assets which represent the registered foreign assets.
+    pub trait ForeignAssetManager<AssetId, Balance> {
+        /// Creates the local asset with the given metadata.
+        fn create(asset_id: AssetId, metadata: ForeignAssetMetadata<Balance>) -> DispatchResult;
+
+        /// Starts destroying the local asset.
+        ///
+        /// Remaining accounts & approvals of the asset must be cleaned up before destruction can be finished.
+        fn start_destroy(asset_id: AssetId) -> DispatchResult;
+    }
+
+    /// Used to determine whether the cross-chain asset is coming from one of its registered reserve locations.
+    ///
+    /// Assets which aren't registered, or don't have any registered reserve location, are never accepted.
+    pub struct ReserveLocationFilter<T>(PhantomData<T>);
+    impl<T: Config> ContainsPair<MultiAsset, MultiLocation> for ReserveLocationFilter<T> {
+        fn contains(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+            if let Concrete(asset_location) = &asset.id {
+                Pallet::<T>::get_asset_id(*asset_location).map_or(false, |asset_id| {
+                    Pallet::<T>::is_reserve_location(asset_id, *origin)
+                })
+            } else {
+                false
+            }
+        }
+    }
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -129,6 +269,16 @@ pub mod pallet {
         /// Should most likely be root.
         type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
+        /// Maximum number of reserve locations which can be registered for a single asset.
+        #[pallet::constant]
+        type MaxReserveLocations: Get<u32>;
+
+        /// Balance type of the local assets.
+        type Balance: Member + Parameter + AtLeast32BitUnsigned + Copy + MaxEncodedLen;
+
+        /// Used to create & destroy the local assets when registering & deregistering foreign assets.
+        type ForeignAssetManager: ForeignAssetManager<Self::AssetId, Self::Balance>;
+
         type WeightInfo: WeightInfo;
     }
 
@@ -140,6 +290,16 @@ pub mod pallet {
         AssetDoesNotExist,
         /// Failed to convert to latest versioned MultiLocation
         MultiLocationNotSupported,
+        /// Location is already a reserve location of the asset.
+        ReserveLocationAlreadyExists,
+        /// Location isn't a reserve location of the asset.
+        ReserveLocationDoesNotExist,
+        /// Asset already has the maximum number of reserve locations.
+        TooManyReserveLocations,
+        /// Exchange rate must be greater than zero.
+        ZeroExchangeRate,
+        /// Asset doesn't have an exchange rate.
+        ExchangeRateDoesNotExist,
     }
 
     #[pallet::event]
@@ -170,6 +330,25 @@ pub mod pallet {
             asset_location: VersionedMultiLocation,
             asset_id: T::AssetId,
         },
+        /// Added a trusted reserve location for an asset Id
+        ReserveLocationAdded {
+            asset_id: T::AssetId,
+            reserve_location: VersionedMultiLocation,
+        },
+        /// Removed a trusted reserve location of an asset Id
+        ReserveLocationRemoved {
+            asset_id: T::AssetId,
+            reserve_location: VersionedMultiLocation,
+        },
+        /// Changed the exchange rate of an asset against the native currency
+        ExchangeRateChanged {
+            asset_location: VersionedMultiLocation,
+            rate: FixedU128,
+        },
+        /// Removed the exchange rate of an asset
+        ExchangeRateRemoved {
+            asset_location: VersionedMultiLocation,
+        },
     }
 
     /// Mapping from an asset id to asset type.
@@ -178,7 +357,7 @@ pub mod pallet {
     #[pallet::storage]
     #[pallet::getter(fn asset_id_to_location)]
     pub type AssetIdToLocation<T: Config> =
-        StorageMap<_, Twox64Concat, T::AssetId, VersionedMultiLocation>;
+        StorageMap<_, Twox64Concat, T::AssetId, BoundedVersionedMultiLocation>;
 
     /// Mapping from an asset type to an asset id.
     /// Can be used when receiving a multilocation XCM message to retrieve
@@ -186,7 +365,7 @@ pub mod pallet {
     #[pallet::storage]
     #[pallet::getter(fn asset_location_to_id)]
     pub type AssetLocationToId<T: Config> =
-        StorageMap<_, Twox64Concat, VersionedMultiLocation, T::AssetId>;
+        StorageMap<_, Twox64Concat, BoundedVersionedMultiLocation, T::AssetId>;
 
     /// Stores the units per second for local execution for a AssetLocation.
     /// This is used to know how to charge for XCM execution in a particular asset.
@@ -195,13 +374,37 @@ pub mod pallet {
     #[pallet::storage]
     #[pallet::getter(fn asset_location_units_per_second)]
     pub type AssetLocationUnitsPerSecond<T: Config> =
-        StorageMap<_, Twox64Concat, VersionedMultiLocation, u128>;
+        StorageMap<_, Twox64Concat, BoundedVersionedMultiLocation, u128>;
+
+    /// Stores the exchange rate of an AssetLocation against the native currency, and the block it was set in.
+    /// This is used to charge for XCM execution in a particular asset, based on the native currency execution fee.
+    #[pallet::storage]
+    #[pallet::getter(fn asset_location_exchange_rate)]
+    pub type AssetLocationExchangeRate<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BoundedVersionedMultiLocation,
+        ExchangeRateInfo<T::BlockNumber>,
+    >;
+
+    /// Locations which are trusted to act as a reserve for an asset Id.
+    /// Used to filter reserve asset deposits, only these origins can deposit the asset.
+    #[pallet::storage]
+    #[pallet::getter(fn asset_reserve_locations)]
+    pub type AssetReserveLocations<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AssetId,
+        BoundedVec<BoundedVersionedMultiLocation, T::MaxReserveLocations>,
+        ValueQuery,
+    >;
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Register new asset location to asset Id mapping.
         ///
         /// This makes the asset eligible for XCM interaction.
+        /// If the reserve location of the asset can be derived from its location, it's trusted as the asset reserve.
         #[pallet::call_index(0)]
         #[pallet::weight(T::WeightInfo::register_asset_location())]
         pub fn register_asset_location(
@@ -228,6 +431,7 @@ pub mod pallet {
                 asset_location,
                 asset_id,
             });
+            Self::add_derived_reserve_location(asset_id, v3_asset_loc);
             Ok(())
         }
 
@@ -261,7 +465,7 @@ pub mod pallet {
         }
 
         /// Change the xcm type mapping for a given asset Id.
-        /// The new asset type will inherit old `units per second` value.
+        /// The new asset type will inherit old `units per second` and exchange rate values.
         #[pallet::call_index(2)]
         #[pallet::weight(T::WeightInfo::change_existing_asset_location())]
         pub fn change_existing_asset_location(
@@ -275,8 +479,10 @@ pub mod pallet {
                 .map_err(|_| Error::<T>::MultiLocationNotSupported)?;
             let new_asset_location = VersionedMultiLocation::V3(v3_asset_loc);
 
-            let previous_asset_location =
-                AssetIdToLocation::<T>::get(&asset_id).ok_or(Error::<T>::AssetDoesNotExist)?;
+            let previous_asset_location: VersionedMultiLocation =
+                AssetIdToLocation::<T>::get(&asset_id)
+                    .ok_or(Error::<T>::AssetDoesNotExist)?
+                    .into();
 
             // Insert new asset type info
             AssetIdToLocation::<T>::insert(&asset_id, new_asset_location.clone());
@@ -290,6 +496,11 @@ pub mod pallet {
                 AssetLocationUnitsPerSecond::<T>::insert(&new_asset_location, units);
             }
 
+            // Change AssetLocationExchangeRate
+            if let Some(rate) = AssetLocationExchangeRate::<T>::take(&previous_asset_location) {
+                AssetLocationExchangeRate::<T>::insert(&new_asset_location, rate);
+            }
+
             Self::deposit_event(Event::AssetLocationChanged {
                 previous_asset_location,
                 asset_id,
@@ -328,12 +539,261 @@ pub mod pallet {
         ) -> DispatchResult {
             T::ManagerOrigin::ensure_origin(origin)?;
 
-            let asset_location =
-                AssetIdToLocation::<T>::get(&asset_id).ok_or(Error::<T>::AssetDoesNotExist)?;
+            let asset_location = Self::remove_asset_info(asset_id)?;
 
-            AssetIdToLocation::<T>::remove(&asset_id);
-            AssetLocationToId::<T>::remove(&asset_location);
-            AssetLocationUnitsPerSecond::<T>::remove(&asset_location);
+            Self::deposit_event(Event::AssetRemoved {
+                asset_id,
+                asset_location,
+            });
+            Ok(())
+        }
+
+        /// Add a location to the set of trusted reserve locations of the given asset Id.
+        ///
+        /// Reserve asset deposits of the asset are only accepted from its reserve locations.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::add_reserve_location())]
+        pub fn add_reserve_location(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+            reserve_location: Box<VersionedMultiLocation>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let v3_reserve_loc = MultiLocation::try_from(*reserve_location)
+                .map_err(|_| Error::<T>::MultiLocationNotSupported)?;
+            let reserve_location = VersionedMultiLocation::V3(v3_reserve_loc);
+
+            ensure!(
+                AssetIdToLocation::<T>::contains_key(&asset_id),
+                Error::<T>::AssetDoesNotExist
+            );
+
+            AssetReserveLocations::<T>::try_mutate(&asset_id, |reserves| -> DispatchResult {
+                let bounded_reserve_location = reserve_location.clone().into();
+                ensure!(
+                    !reserves.contains(&bounded_reserve_location),
+                    Error::<T>::ReserveLocationAlreadyExists
+                );
+                reserves
+                    .try_push(bounded_reserve_location)
+                    .map_err(|_| Error::<T>::TooManyReserveLocations)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ReserveLocationAdded {
+                asset_id,
+                reserve_location,
+            });
+            Ok(())
+        }
+
+        /// Remove a location from the set of trusted reserve locations of the given asset Id.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::remove_reserve_location())]
+        pub fn remove_reserve_location(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+            reserve_location: Box<VersionedMultiLocation>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let v3_reserve_loc = MultiLocation::try_from(*reserve_location)
+                .map_err(|_| Error::<T>::MultiLocationNotSupported)?;
+            let reserve_location = VersionedMultiLocation::V3(v3_reserve_loc);
+
+            ensure!(
+                AssetIdToLocation::<T>::contains_key(&asset_id),
+                Error::<T>::AssetDoesNotExist
+            );
+
+            AssetReserveLocations::<T>::try_mutate_exists(&asset_id, |maybe_reserves| {
+                let reserves = maybe_reserves
+                    .as_mut()
+                    .ok_or(Error::<T>::ReserveLocationDoesNotExist)?;
+                let index = reserves
+                    .iter()
+                    .position(|location| location.0 == reserve_location)
+                    .ok_or(Error::<T>::ReserveLocationDoesNotExist)?;
+                reserves.remove(index);
+
+                if reserves.is_empty() {
+                    *maybe_reserves = None;
+                }
+                Ok::<(), Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::ReserveLocationRemoved {
+                asset_id,
+                reserve_location,
+            });
+            Ok(())
+        }
+
+        /// Change the exchange rate of the given AssetLocation against the native currency.
+        ///
+        /// The rate is the amount of the asset's smallest units equal in value to one smallest unit of the native currency.
+        /// The current block is recorded as the last update of the rate.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_asset_exchange_rate())]
+        pub fn set_asset_exchange_rate(
+            origin: OriginFor<T>,
+            asset_location: Box<VersionedMultiLocation>,
+            rate: FixedU128,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let v3_asset_loc = MultiLocation::try_from(*asset_location)
+                .map_err(|_| Error::<T>::MultiLocationNotSupported)?;
+            let asset_location = VersionedMultiLocation::V3(v3_asset_loc);
+
+            ensure!(
+                AssetLocationToId::<T>::contains_key(&asset_location),
+                Error::<T>::AssetDoesNotExist
+            );
+            ensure!(!rate.is_zero(), Error::<T>::ZeroExchangeRate);
+
+            AssetLocationExchangeRate::<T>::insert(
+                &asset_location,
+                ExchangeRateInfo {
+                    rate,
+                    updated_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+
+            Self::deposit_event(Event::ExchangeRateChanged {
+                asset_location,
+                rate,
+            });
+            Ok(())
+        }
+
+        /// Removes the exchange rate of the given AssetLocation.
+        ///
+        /// The asset can still be used to pay for execution time if it has `units per second` configured.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::remove_asset_exchange_rate())]
+        pub fn remove_asset_exchange_rate(
+            origin: OriginFor<T>,
+            asset_location: Box<VersionedMultiLocation>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let v3_asset_loc = MultiLocation::try_from(*asset_location)
+                .map_err(|_| Error::<T>::MultiLocationNotSupported)?;
+            let asset_location = VersionedMultiLocation::V3(v3_asset_loc);
+
+            ensure!(
+                AssetLocationToId::<T>::contains_key(&asset_location),
+                Error::<T>::AssetDoesNotExist
+            );
+            ensure!(
+                AssetLocationExchangeRate::<T>::take(&asset_location).is_some(),
+                Error::<T>::ExchangeRateDoesNotExist
+            );
+
+            Self::deposit_event(Event::ExchangeRateRemoved { asset_location });
+            Ok(())
+        }
+
+        /// Register a foreign asset, creating the local asset with the given metadata and
+        /// mapping it to the remote asset location.
+        ///
+        /// The given reserve locations are trusted as the asset reserves. If none are given and the reserve location
+        /// can be derived from the asset location, the derived location is trusted instead.
+        /// If `units_per_second` is given, the asset can be used to pay for execution time right away.
+        ///
+        /// Fails if either the asset Id or the asset location is already registered,
+        /// or if the local asset can't be created.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::register_foreign_asset())]
+        pub fn register_foreign_asset(
+            origin: OriginFor<T>,
+            asset_location: Box<VersionedMultiLocation>,
+            #[pallet::compact] asset_id: T::AssetId,
+            metadata: ForeignAssetMetadata<T::Balance>,
+            reserve_locations: BoundedVec<VersionedMultiLocation, T::MaxReserveLocations>,
+            units_per_second: Option<u128>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let v3_asset_loc = MultiLocation::try_from(*asset_location)
+                .map_err(|_| Error::<T>::MultiLocationNotSupported)?;
+            let asset_location = VersionedMultiLocation::V3(v3_asset_loc);
+
+            let mut reserves = Vec::with_capacity(reserve_locations.len());
+            for reserve_location in reserve_locations {
+                let v3_reserve_loc = MultiLocation::try_from(reserve_location)
+                    .map_err(|_| Error::<T>::MultiLocationNotSupported)?;
+                let reserve_location = VersionedMultiLocation::V3(v3_reserve_loc);
+                ensure!(
+                    !reserves.contains(&reserve_location),
+                    Error::<T>::ReserveLocationAlreadyExists
+                );
+                reserves.push(reserve_location);
+            }
+
+            ensure!(
+                !AssetIdToLocation::<T>::contains_key(&asset_id)
+                    && !AssetLocationToId::<T>::contains_key(&asset_location),
+                Error::<T>::AssetAlreadyRegistered
+            );
+
+            T::ForeignAssetManager::create(asset_id, metadata)?;
+
+            AssetIdToLocation::<T>::insert(&asset_id, asset_location.clone());
+            AssetLocationToId::<T>::insert(&asset_location, asset_id);
+
+            Self::deposit_event(Event::AssetRegistered {
+                asset_location: asset_location.clone(),
+                asset_id,
+            });
+
+            if reserves.is_empty() {
+                Self::add_derived_reserve_location(asset_id, v3_asset_loc);
+            } else {
+                let bounded_reserves: BoundedVec<_, T::MaxReserveLocations> = reserves
+                    .iter()
+                    .cloned()
+                    .map(Into::into)
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .map_err(|_| Error::<T>::TooManyReserveLocations)?;
+                AssetReserveLocations::<T>::insert(&asset_id, bounded_reserves);
+
+                for reserve_location in reserves {
+                    Self::deposit_event(Event::ReserveLocationAdded {
+                        asset_id,
+                        reserve_location,
+                    });
+                }
+            }
+
+            if let Some(units_per_second) = units_per_second {
+                AssetLocationUnitsPerSecond::<T>::insert(&asset_location, units_per_second);
+
+                Self::deposit_event(Event::UnitsPerSecondChanged {
+                    asset_location,
+                    units_per_second,
+                });
+            }
+            Ok(())
+        }
+
+        /// Deregister a foreign asset, removing all information related to it
+        /// and starting the destruction of the local asset.
+        ///
+        /// Fails if the local asset destruction can't be started, e.g. if the asset owner has changed.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::deregister_foreign_asset())]
+        pub fn deregister_foreign_asset(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let asset_location = Self::remove_asset_info(asset_id)?;
+            T::ForeignAssetManager::start_destroy(asset_id)?;
 
             Self::deposit_event(Event::AssetRemoved {
                 asset_id,
@@ -342,4 +802,58 @@ pub mod pallet {
             Ok(())
         }
     }
+
+    impl<T: Config> Pallet<T> {
+        /// `true` if the location is one of the registered reserve locations of the asset Id.
+        pub fn is_reserve_location(asset_id: T::AssetId, location: MultiLocation) -> bool {
+            AssetReserveLocations::<T>::get(asset_id).contains(&location.into_versioned().into())
+        }
+
+        /// Reserve location of the asset, derived from its location.
+        ///
+        /// Relay chain is the reserve of its assets, and sibling parachains are the reserve of the assets under their location.
+        /// `None` if the reserve location cannot be derived this way.
+        pub fn derive_reserve_location(asset_location: MultiLocation) -> Option<MultiLocation> {
+            match (asset_location.parents, asset_location.first_interior()) {
+                // sibling parachain
+                (1, Some(Parachain(id))) => Some(MultiLocation::new(1, X1(Parachain(*id)))),
+                // relay chain
+                (1, _) => Some(MultiLocation::parent()),
+                _ => None,
+            }
+        }
+
+        /// Trusts the derived reserve location of a newly registered asset, if there is one.
+        fn add_derived_reserve_location(asset_id: T::AssetId, asset_location: MultiLocation) {
+            let reserve_location = match Self::derive_reserve_location(asset_location) {
+                Some(location) => location.into_versioned(),
+                None => return,
+            };
+
+            let reserves: Result<BoundedVec<_, T::MaxReserveLocations>, _> =
+                sp_std::vec![reserve_location.clone().into()].try_into();
+            if let Ok(reserves) = reserves {
+                AssetReserveLocations::<T>::insert(&asset_id, reserves);
+                Self::deposit_event(Event::ReserveLocationAdded {
+                    asset_id,
+                    reserve_location,
+                });
+            }
+        }
+
+        /// Removes all information related to the asset Id, returning its location.
+        fn remove_asset_info(
+            asset_id: T::AssetId,
+        ) -> Result<VersionedMultiLocation, DispatchError> {
+            let asset_location =
+                AssetIdToLocation::<T>::take(&asset_id).ok_or(Error::<T>::AssetDoesNotExist)?;
+
+            AssetLocationToId::<T>::remove(&asset_location);
+            AssetLocationUnitsPerSecond::<T>::remove(&asset_location);
+            AssetLocationExchangeRate::<T>::remove(&asset_location);
+            AssetReserveLocations::<T>::remove(&asset_id);
+
+            Ok(asset_location.into())
+        }
+    }
 }
@@ -20,7 +20,7 @@ use super::*;
 use frame_support::traits::OnRuntimeUpgrade;
 use frame_support::{dispatch::GetStorageVersion, log, pallet_prelude::*, traits::Get};
 use sp_std::{marker::PhantomData, vec::Vec};
-use xcm::IntoVersion;
+use xcm::{v3::MultiLocation, IntoVersion};
 
 pub struct MigrationXcmV3<T: Config>(PhantomData<T>);
 impl<T: Config> OnRuntimeUpgrade for MigrationXcmV3<T> {
@@ -37,7 +37,7 @@ impl<T: Config> OnRuntimeUpgrade for MigrationXcmV3<T> {
         for (asset_id, legacy_location) in id_to_location_entries {
             consumed_weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
 
-            if let Ok(new_location) = legacy_location.into_version(3) {
+            if let Ok(new_location) = legacy_location.0.into_version(3) {
                 AssetIdToLocation::<T>::insert(asset_id, new_location);
             } else {
                 // Won't happen, can be verified with try-runtime before upgrade
@@ -54,7 +54,7 @@ impl<T: Config> OnRuntimeUpgrade for MigrationXcmV3<T> {
         for (legacy_location, asset_id) in location_to_id_entries {
             consumed_weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
 
-            if let Ok(new_location) = legacy_location.into_version(3) {
+            if let Ok(new_location) = legacy_location.0.into_version(3) {
                 AssetLocationToId::<T>::insert(new_location, asset_id);
             } else {
                 // Shouldn't happen, can be verified with try-runtime before upgrade
@@ -71,7 +71,7 @@ impl<T: Config> OnRuntimeUpgrade for MigrationXcmV3<T> {
         for (legacy_location, price) in location_to_price_entries {
             consumed_weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
 
-            if let Ok(new_location) = legacy_location.into_version(3) {
+            if let Ok(new_location) = legacy_location.0.into_version(3) {
                 AssetLocationUnitsPerSecond::<T>::insert(new_location, price);
             } else {
                 // Shouldn't happen, can be verified with try-runtime before upgrade
@@ -122,3 +122,63 @@ impl<T: Config> OnRuntimeUpgrade for MigrationXcmV3<T> {
         Ok(())
     }
 }
+
+/// Registers the reserve locations of the already registered assets.
+///
+/// Relay chain is registered as the reserve of its assets, and sibling parachains
+/// are registered as the reserve of the assets under their location.
+/// Assets for which the reserve cannot be derived this way must be configured manually.
+pub struct MigrationReserveLocations<T: Config>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrationReserveLocations<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let version = Pallet::<T>::on_chain_storage_version();
+        let mut consumed_weight = T::DbWeight::get().reads(1);
+        if version >= 3 {
+            return consumed_weight;
+        }
+
+        for (asset_id, asset_location) in AssetIdToLocation::<T>::iter() {
+            consumed_weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+            let reserve_location = match MultiLocation::try_from(asset_location.0) {
+                Ok(location) => Pallet::<T>::derive_reserve_location(location),
+                Err(_) => None,
+            };
+
+            if let Some(reserve_location) = reserve_location {
+                let reserves: BoundedVec<BoundedVersionedMultiLocation, T::MaxReserveLocations> =
+                    sp_std::vec![reserve_location.into_versioned().into()]
+                        .try_into()
+                        .unwrap_or_default();
+                AssetReserveLocations::<T>::insert(asset_id, reserves);
+            } else {
+                log::warn!(
+                    "Failed to derive reserve location for asset Id: {:?}",
+                    asset_id
+                );
+            }
+        }
+
+        StorageVersion::new(3).put::<Pallet<T>>();
+        consumed_weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+        consumed_weight
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        assert_eq!(Pallet::<T>::on_chain_storage_version(), 2);
+        Ok(Vec::new())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        assert_eq!(Pallet::<T>::on_chain_storage_version(), 3);
+
+        for (asset_id, _) in AssetReserveLocations::<T>::iter() {
+            assert!(AssetIdToLocation::<T>::contains_key(asset_id));
+        }
+
+        Ok(())
+    }
+}
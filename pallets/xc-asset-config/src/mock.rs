@@ -16,15 +16,17 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{self as pallet_xc_asset_config};
+use crate::{self as pallet_xc_asset_config, ForeignAssetManager, ForeignAssetMetadata};
 
 use frame_support::{construct_runtime, parameter_types, traits::ConstU32, weights::Weight};
 use sp_core::H256;
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
 
 use sp_io::TestExternalities;
 use sp_runtime::{
     testing::Header,
     traits::{BlakeTwo256, IdentityLookup},
+    DispatchError, DispatchResult,
 };
 
 type BlockNumber = u64;
@@ -105,10 +107,53 @@ impl pallet_balances::Config for Test {
 
 type AssetId = u128;
 
+parameter_types! {
+    pub const MaxReserveLocations: u32 = 4;
+}
+
+thread_local! {
+    static LOCAL_ASSETS: RefCell<BTreeMap<AssetId, (ForeignAssetMetadata<Balance>, bool)>> =
+        RefCell::new(BTreeMap::new());
+}
+
+/// Keeps track of the created local assets, and whether their destruction has been started.
+pub struct MockForeignAssetManager;
+impl MockForeignAssetManager {
+    /// Metadata of the local asset, and whether it's being destroyed.
+    pub(crate) fn local_asset(asset_id: AssetId) -> Option<(ForeignAssetMetadata<Balance>, bool)> {
+        LOCAL_ASSETS.with(|assets| assets.borrow().get(&asset_id).cloned())
+    }
+}
+impl ForeignAssetManager<AssetId, Balance> for MockForeignAssetManager {
+    fn create(asset_id: AssetId, metadata: ForeignAssetMetadata<Balance>) -> DispatchResult {
+        LOCAL_ASSETS.with(|assets| {
+            let mut assets = assets.borrow_mut();
+            if assets.contains_key(&asset_id) {
+                return Err(DispatchError::Other("Asset already exists."));
+            }
+            assets.insert(asset_id, (metadata, false));
+            Ok(())
+        })
+    }
+
+    fn start_destroy(asset_id: AssetId) -> DispatchResult {
+        LOCAL_ASSETS.with(|assets| match assets.borrow_mut().get_mut(&asset_id) {
+            Some((_, is_destroying)) => {
+                *is_destroying = true;
+                Ok(())
+            }
+            None => Err(DispatchError::Other("Asset doesn't exist.")),
+        })
+    }
+}
+
 impl pallet_xc_asset_config::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = AssetId;
     type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxReserveLocations = MaxReserveLocations;
+    type Balance = Balance;
+    type ForeignAssetManager = MockForeignAssetManager;
     type WeightInfo = ();
 }
 
@@ -120,6 +165,8 @@ impl ExternalityBuilder {
             .build_storage::<Test>()
             .unwrap();
 
+        LOCAL_ASSETS.with(|assets| assets.borrow_mut().clear());
+
         let mut ext = TestExternalities::from(storage);
         ext.execute_with(|| System::set_block_number(1));
         ext
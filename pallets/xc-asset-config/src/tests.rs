@@ -17,13 +17,28 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use super::{pallet::Error, pallet::Event, *};
-use frame_support::{assert_noop, assert_ok, WeakBoundedVec};
+use frame_support::{assert_noop, assert_ok, traits::ContainsPair, WeakBoundedVec};
 use mock::*;
-use sp_runtime::traits::BadOrigin;
+use parity_scale_codec::{Encode, MaxEncodedLen};
+use sp_runtime::{
+    traits::{BadOrigin, Zero},
+    DispatchError, FixedPointNumber, FixedU128,
+};
 use xcm::latest::prelude::*;
 
 use xcm::{v3::MultiLocation, VersionedMultiLocation};
 
+/// Metadata used to register a foreign asset in tests.
+fn foreign_asset_metadata() -> ForeignAssetMetadata<u128> {
+    ForeignAssetMetadata {
+        name: b"Polkadot".to_vec(),
+        symbol: b"DOT".to_vec(),
+        decimals: 10,
+        min_balance: 1_000,
+        is_sufficient: true,
+    }
+}
+
 #[test]
 fn only_root_as_origin() {
     ExternalityBuilder::build().execute_with(|| {
@@ -69,6 +84,58 @@ fn only_root_as_origin() {
             XcAssetConfig::remove_asset(RuntimeOrigin::signed(1), asset_id,),
             BadOrigin
         );
+
+        assert_noop!(
+            XcAssetConfig::add_reserve_location(
+                RuntimeOrigin::signed(1),
+                asset_id,
+                Box::new(asset_location.clone()),
+            ),
+            BadOrigin
+        );
+
+        assert_noop!(
+            XcAssetConfig::remove_reserve_location(
+                RuntimeOrigin::signed(1),
+                asset_id,
+                Box::new(asset_location.clone()),
+            ),
+            BadOrigin
+        );
+
+        assert_noop!(
+            XcAssetConfig::set_asset_exchange_rate(
+                RuntimeOrigin::signed(1),
+                Box::new(asset_location.clone()),
+                FixedU128::from_u32(2),
+            ),
+            BadOrigin
+        );
+
+        assert_noop!(
+            XcAssetConfig::remove_asset_exchange_rate(
+                RuntimeOrigin::signed(1),
+                Box::new(asset_location.clone()),
+            ),
+            BadOrigin
+        );
+
+        assert_noop!(
+            XcAssetConfig::register_foreign_asset(
+                RuntimeOrigin::signed(1),
+                Box::new(asset_location.clone()),
+                asset_id,
+                foreign_asset_metadata(),
+                Default::default(),
+                None,
+            ),
+            BadOrigin
+        );
+
+        assert_noop!(
+            XcAssetConfig::deregister_foreign_asset(RuntimeOrigin::signed(1), asset_id),
+            BadOrigin
+        );
     })
 }
 
@@ -88,14 +155,21 @@ fn register_asset_location_and_units_per_sec_is_ok() {
             Box::new(asset_location.clone().into_versioned()),
             asset_id
         ));
-        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(Event::AssetRegistered {
+        System::assert_has_event(mock::RuntimeEvent::XcAssetConfig(Event::AssetRegistered {
             asset_location: asset_location.clone().into_versioned(),
             asset_id: asset_id,
         }));
+        // Relay chain is trusted as the reserve of its assets
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::ReserveLocationAdded {
+                asset_id,
+                reserve_location: MultiLocation::parent().into_versioned(),
+            },
+        ));
 
         // Assert storage state after registering asset
         assert_eq!(
-            AssetIdToLocation::<Test>::get(&asset_id).unwrap(),
+            AssetIdToLocation::<Test>::get(&asset_id).unwrap().0,
             asset_location.clone().into_versioned()
         );
         assert_eq!(
@@ -175,6 +249,12 @@ fn change_asset_location_is_ok() {
             Box::new(asset_location.clone().into_versioned()),
             units
         ));
+        let rate = FixedU128::from_rational(7, 3);
+        assert_ok!(XcAssetConfig::set_asset_exchange_rate(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            rate
+        ));
 
         // Change the asset location and assert change was successful
         let new_asset_location = MultiLocation::new(2, Junctions::X1(Junction::PalletInstance(3)));
@@ -195,7 +275,7 @@ fn change_asset_location_is_ok() {
 
         // Assert storage state
         assert_eq!(
-            AssetIdToLocation::<Test>::get(&asset_id).unwrap(),
+            AssetIdToLocation::<Test>::get(&asset_id).unwrap().0,
             new_asset_location.clone().into_versioned()
         );
         assert_eq!(
@@ -212,6 +292,11 @@ fn change_asset_location_is_ok() {
                 .unwrap(),
             units
         );
+        assert!(XcAssetConfig::get_exchange_rate(asset_location).is_none());
+        assert_eq!(
+            XcAssetConfig::get_exchange_rate(new_asset_location),
+            Some((rate, 0))
+        );
     })
 }
 
@@ -276,6 +361,12 @@ fn remove_asset_is_ok() {
             Box::new(asset_location.clone().into_versioned()),
             units
         ));
+        assert!(XcAssetConfig::is_reserve_location(asset_id, asset_location));
+        assert_ok!(XcAssetConfig::set_asset_exchange_rate(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            FixedU128::from_u32(3)
+        ));
 
         // Remove asset entirely and assert op is ok
         assert_ok!(XcAssetConfig::remove_asset(RuntimeOrigin::root(), asset_id,));
@@ -292,6 +383,10 @@ fn remove_asset_is_ok() {
         assert!(!AssetLocationUnitsPerSecond::<Test>::contains_key(
             asset_location.clone().into_versioned()
         ));
+        assert!(!AssetReserveLocations::<Test>::contains_key(asset_id));
+        assert!(!AssetLocationExchangeRate::<Test>::contains_key(
+            asset_location.clone().into_versioned()
+        ));
     })
 }
 
@@ -449,3 +544,557 @@ fn incompatible_versioned_multilocations_are_not_ok() {
         );
     })
 }
+
+#[test]
+fn add_and_remove_reserve_location_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Prepare asset and reserve locations
+        let asset_location =
+            MultiLocation::new(1, Junctions::X2(Junction::Parachain(2007), GeneralIndex(7)));
+        let asset_id = 17;
+        let reserve_location = MultiLocation::new(1, Junctions::X1(Junction::Parachain(2007)));
+        let other_reserve_location = MultiLocation::parent();
+
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            asset_id
+        ));
+        // Sibling parachain is trusted as the reserve of the assets under its location
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::ReserveLocationAdded {
+                asset_id,
+                reserve_location: reserve_location.clone().into_versioned(),
+            },
+        ));
+        assert!(!XcAssetConfig::is_reserve_location(
+            asset_id,
+            other_reserve_location
+        ));
+
+        // Add another reserve location and verify storage
+        assert_ok!(XcAssetConfig::add_reserve_location(
+            RuntimeOrigin::root(),
+            asset_id,
+            Box::new(other_reserve_location.clone().into_versioned()),
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::ReserveLocationAdded {
+                asset_id,
+                reserve_location: other_reserve_location.clone().into_versioned(),
+            },
+        ));
+        assert_eq!(
+            XcAssetConfig::asset_reserve_locations(asset_id).into_inner(),
+            vec![
+                reserve_location.clone().into_versioned().into(),
+                other_reserve_location.clone().into_versioned().into()
+            ]
+        );
+        assert!(XcAssetConfig::is_reserve_location(
+            asset_id,
+            reserve_location
+        ));
+
+        // Remove reserve locations, storage entry is removed with the last one
+        assert_ok!(XcAssetConfig::remove_reserve_location(
+            RuntimeOrigin::root(),
+            asset_id,
+            Box::new(reserve_location.clone().into_versioned()),
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::ReserveLocationRemoved {
+                asset_id,
+                reserve_location: reserve_location.clone().into_versioned(),
+            },
+        ));
+        assert!(!XcAssetConfig::is_reserve_location(
+            asset_id,
+            reserve_location
+        ));
+        assert!(XcAssetConfig::is_reserve_location(
+            asset_id,
+            other_reserve_location
+        ));
+
+        assert_ok!(XcAssetConfig::remove_reserve_location(
+            RuntimeOrigin::root(),
+            asset_id,
+            Box::new(other_reserve_location.clone().into_versioned()),
+        ));
+        assert!(!AssetReserveLocations::<Test>::contains_key(asset_id));
+    })
+}
+
+#[test]
+fn reserve_location_management_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location = MultiLocation::new(1, Junctions::X1(Junction::Parachain(2007)));
+        let asset_id = 17;
+
+        // Asset must be registered first
+        assert_noop!(
+            XcAssetConfig::add_reserve_location(
+                RuntimeOrigin::root(),
+                asset_id,
+                Box::new(asset_location.clone().into_versioned()),
+            ),
+            Error::<Test>::AssetDoesNotExist
+        );
+        assert_noop!(
+            XcAssetConfig::remove_reserve_location(
+                RuntimeOrigin::root(),
+                asset_id,
+                Box::new(asset_location.clone().into_versioned()),
+            ),
+            Error::<Test>::AssetDoesNotExist
+        );
+
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            asset_id
+        ));
+
+        // Unknown reserve location cannot be removed
+        assert_noop!(
+            XcAssetConfig::remove_reserve_location(
+                RuntimeOrigin::root(),
+                asset_id,
+                Box::new(MultiLocation::parent().into_versioned()),
+            ),
+            Error::<Test>::ReserveLocationDoesNotExist
+        );
+
+        // Same reserve location cannot be added twice, sibling parachain is already trusted on registration
+        assert_noop!(
+            XcAssetConfig::add_reserve_location(
+                RuntimeOrigin::root(),
+                asset_id,
+                Box::new(asset_location.clone().into_versioned()),
+            ),
+            Error::<Test>::ReserveLocationAlreadyExists
+        );
+
+        // Fill up the reserve locations
+        for para_id in 1..MaxReserveLocations::get() {
+            assert_ok!(XcAssetConfig::add_reserve_location(
+                RuntimeOrigin::root(),
+                asset_id,
+                Box::new(
+                    MultiLocation::new(1, Junctions::X1(Junction::Parachain(para_id)))
+                        .into_versioned()
+                ),
+            ));
+        }
+        assert_noop!(
+            XcAssetConfig::add_reserve_location(
+                RuntimeOrigin::root(),
+                asset_id,
+                Box::new(MultiLocation::parent().into_versioned()),
+            ),
+            Error::<Test>::TooManyReserveLocations
+        );
+    })
+}
+
+#[test]
+fn reserve_location_filter_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location =
+            MultiLocation::new(1, Junctions::X2(Junction::Parachain(2007), GeneralIndex(7)));
+        let asset_id = 17;
+        let reserve_location = MultiLocation::new(1, Junctions::X1(Junction::Parachain(2007)));
+        let asset: MultiAsset = (asset_location, 1_000_000_u128).into();
+
+        // Unregistered asset is never accepted
+        assert!(!ReserveLocationFilter::<Test>::contains(
+            &asset,
+            &reserve_location
+        ));
+
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            asset_id
+        ));
+        // Registered asset is accepted only from its reserve location
+        assert!(ReserveLocationFilter::<Test>::contains(
+            &asset,
+            &reserve_location
+        ));
+        assert!(!ReserveLocationFilter::<Test>::contains(
+            &asset,
+            &MultiLocation::parent()
+        ));
+        assert!(!ReserveLocationFilter::<Test>::contains(
+            &(asset_location, 1_000_000_u128).into(),
+            &MultiLocation::new(1, Junctions::X1(Junction::Parachain(2008)))
+        ));
+
+        // Registered asset without reserve locations isn't accepted either
+        assert_ok!(XcAssetConfig::remove_reserve_location(
+            RuntimeOrigin::root(),
+            asset_id,
+            Box::new(reserve_location.clone().into_versioned()),
+        ));
+        assert!(!ReserveLocationFilter::<Test>::contains(
+            &asset,
+            &reserve_location
+        ));
+    })
+}
+
+#[test]
+fn reserve_location_is_derived_on_registration() {
+    ExternalityBuilder::build().execute_with(|| {
+        let sibling_asset_location =
+            MultiLocation::new(1, Junctions::X2(Junction::Parachain(2007), GeneralIndex(7)));
+        let relay_asset_location = MultiLocation::parent();
+        let local_asset_location = MultiLocation::new(0, Junctions::X1(GeneralIndex(7)));
+
+        for (asset_id, asset_location) in [
+            (1, sibling_asset_location),
+            (2, relay_asset_location),
+            (3, local_asset_location),
+        ] {
+            assert_ok!(XcAssetConfig::register_asset_location(
+                RuntimeOrigin::root(),
+                Box::new(asset_location.into_versioned()),
+                asset_id
+            ));
+        }
+
+        assert_eq!(
+            XcAssetConfig::asset_reserve_locations(1).into_inner(),
+            vec![
+                MultiLocation::new(1, Junctions::X1(Junction::Parachain(2007)))
+                    .into_versioned()
+                    .into()
+            ]
+        );
+        assert_eq!(
+            XcAssetConfig::asset_reserve_locations(2).into_inner(),
+            vec![MultiLocation::parent().into_versioned().into()]
+        );
+        // Reserve of the local assets cannot be derived
+        assert!(!AssetReserveLocations::<Test>::contains_key(3));
+    })
+}
+
+#[test]
+fn set_and_remove_asset_exchange_rate_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location = MultiLocation::new(1, Junctions::X1(Junction::Parachain(2007)));
+        let asset_id = 17;
+
+        // Asset must be registered before exchange rate can be set
+        assert_noop!(
+            XcAssetConfig::set_asset_exchange_rate(
+                RuntimeOrigin::root(),
+                Box::new(asset_location.clone().into_versioned()),
+                FixedU128::from_u32(2),
+            ),
+            Error::<Test>::AssetDoesNotExist
+        );
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            asset_id
+        ));
+        assert!(XcAssetConfig::get_exchange_rate(asset_location).is_none());
+
+        // Zero rate isn't allowed
+        assert_noop!(
+            XcAssetConfig::set_asset_exchange_rate(
+                RuntimeOrigin::root(),
+                Box::new(asset_location.clone().into_versioned()),
+                FixedU128::zero(),
+            ),
+            Error::<Test>::ZeroExchangeRate
+        );
+
+        // Set the rate and verify the block of the update is recorded
+        let rate = FixedU128::from_rational(5, 4);
+        assert_ok!(XcAssetConfig::set_asset_exchange_rate(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            rate,
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::ExchangeRateChanged {
+                asset_location: asset_location.clone().into_versioned(),
+                rate,
+            },
+        ));
+        assert_eq!(
+            XcAssetConfig::get_exchange_rate(asset_location),
+            Some((rate, 0))
+        );
+        assert_eq!(
+            XcAssetConfig::asset_location_exchange_rate(asset_location.into_versioned()),
+            Some(ExchangeRateInfo {
+                rate,
+                updated_at: 1
+            })
+        );
+
+        // Update the rate in a later block
+        System::set_block_number(5);
+        let new_rate = FixedU128::from_rational(3, 4);
+        assert_ok!(XcAssetConfig::set_asset_exchange_rate(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            new_rate,
+        ));
+        assert_eq!(
+            XcAssetConfig::asset_location_exchange_rate(asset_location.into_versioned()),
+            Some(ExchangeRateInfo {
+                rate: new_rate,
+                updated_at: 5
+            })
+        );
+
+        // Age of the rate is the number of blocks since its last update
+        System::set_block_number(9);
+        assert_eq!(
+            XcAssetConfig::get_exchange_rate(asset_location),
+            Some((new_rate, 4))
+        );
+
+        // Remove the rate
+        assert_ok!(XcAssetConfig::remove_asset_exchange_rate(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::ExchangeRateRemoved {
+                asset_location: asset_location.clone().into_versioned(),
+            },
+        ));
+        assert!(XcAssetConfig::get_exchange_rate(asset_location).is_none());
+
+        // Rate which doesn't exist cannot be removed
+        assert_noop!(
+            XcAssetConfig::remove_asset_exchange_rate(
+                RuntimeOrigin::root(),
+                Box::new(asset_location.clone().into_versioned()),
+            ),
+            Error::<Test>::ExchangeRateDoesNotExist
+        );
+
+        // Asset must be registered
+        assert_noop!(
+            XcAssetConfig::remove_asset_exchange_rate(
+                RuntimeOrigin::root(),
+                Box::new(MultiLocation::parent().into_versioned()),
+            ),
+            Error::<Test>::AssetDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn register_and_deregister_foreign_asset_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location = MultiLocation::parent();
+        let asset_id = 17;
+        let metadata = foreign_asset_metadata();
+        let reserve_location = MultiLocation::new(1, Junctions::X1(Junction::Parachain(1000)));
+        let units: u128 = 123;
+
+        // Register foreign asset and ensure both the local asset & the mappings are created
+        assert_ok!(XcAssetConfig::register_foreign_asset(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            asset_id,
+            metadata.clone(),
+            vec![reserve_location.into_versioned()].try_into().unwrap(),
+            Some(units),
+        ));
+        System::assert_has_event(mock::RuntimeEvent::XcAssetConfig(Event::AssetRegistered {
+            asset_location: asset_location.clone().into_versioned(),
+            asset_id,
+        }));
+        System::assert_has_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::ReserveLocationAdded {
+                asset_id,
+                reserve_location: reserve_location.into_versioned(),
+            },
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::UnitsPerSecondChanged {
+                asset_location: asset_location.clone().into_versioned(),
+                units_per_second: units,
+            },
+        ));
+
+        // Only the given reserve location is trusted, the derived one isn't
+        assert!(XcAssetConfig::is_reserve_location(
+            asset_id,
+            reserve_location
+        ));
+        assert!(!XcAssetConfig::is_reserve_location(
+            asset_id,
+            asset_location
+        ));
+        assert_eq!(
+            XcAssetConfig::get_units_per_second(asset_location),
+            Some(units)
+        );
+        assert_eq!(
+            MockForeignAssetManager::local_asset(asset_id),
+            Some((metadata, false))
+        );
+        assert_eq!(
+            XcAssetConfig::get_xc_asset_location(asset_id),
+            Some(asset_location)
+        );
+        assert_eq!(XcAssetConfig::get_asset_id(asset_location), Some(asset_id));
+
+        // Configure the asset further
+        assert_ok!(XcAssetConfig::set_asset_exchange_rate(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            FixedU128::from_u32(2)
+        ));
+
+        // Deregister foreign asset and ensure everything is cleaned up
+        assert_ok!(XcAssetConfig::deregister_foreign_asset(
+            RuntimeOrigin::root(),
+            asset_id
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(Event::AssetRemoved {
+            asset_location: asset_location.clone().into_versioned(),
+            asset_id,
+        }));
+        assert!(!AssetIdToLocation::<Test>::contains_key(asset_id));
+        assert!(!AssetLocationToId::<Test>::contains_key(
+            asset_location.clone().into_versioned()
+        ));
+        assert!(!AssetLocationUnitsPerSecond::<Test>::contains_key(
+            asset_location.clone().into_versioned()
+        ));
+        assert!(!AssetLocationExchangeRate::<Test>::contains_key(
+            asset_location.clone().into_versioned()
+        ));
+        assert!(!AssetReserveLocations::<Test>::contains_key(asset_id));
+
+        // Local asset destruction has been started
+        assert!(matches!(
+            MockForeignAssetManager::local_asset(asset_id),
+            Some((_, true))
+        ));
+    })
+}
+
+#[test]
+fn register_foreign_asset_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location = MultiLocation::parent();
+        let asset_id = 17;
+
+        assert_ok!(XcAssetConfig::register_foreign_asset(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            asset_id,
+            foreign_asset_metadata(),
+            Default::default(),
+            None,
+        ));
+
+        // Asset Id is already registered
+        assert_noop!(
+            XcAssetConfig::register_foreign_asset(
+                RuntimeOrigin::root(),
+                Box::new(MultiLocation::here().into_versioned()),
+                asset_id,
+                foreign_asset_metadata(),
+                Default::default(),
+                None,
+            ),
+            Error::<Test>::AssetAlreadyRegistered
+        );
+
+        // Asset location is already registered
+        assert_noop!(
+            XcAssetConfig::register_foreign_asset(
+                RuntimeOrigin::root(),
+                Box::new(asset_location.clone().into_versioned()),
+                asset_id + 1,
+                foreign_asset_metadata(),
+                Default::default(),
+                None,
+            ),
+            Error::<Test>::AssetAlreadyRegistered
+        );
+
+        // Reserve location cannot be given twice
+        assert_noop!(
+            XcAssetConfig::register_foreign_asset(
+                RuntimeOrigin::root(),
+                Box::new(MultiLocation::here().into_versioned()),
+                asset_id + 1,
+                foreign_asset_metadata(),
+                vec![
+                    MultiLocation::parent().into_versioned(),
+                    MultiLocation::parent().into_versioned()
+                ]
+                .try_into()
+                .unwrap(),
+                None,
+            ),
+            Error::<Test>::ReserveLocationAlreadyExists
+        );
+
+        // Local asset can't be created, nothing is registered
+        assert_ok!(XcAssetConfig::remove_asset(RuntimeOrigin::root(), asset_id));
+        assert_noop!(
+            XcAssetConfig::register_foreign_asset(
+                RuntimeOrigin::root(),
+                Box::new(asset_location.clone().into_versioned()),
+                asset_id,
+                foreign_asset_metadata(),
+                Default::default(),
+                None,
+            ),
+            DispatchError::Other("Asset already exists.")
+        );
+
+        // Asset isn't registered
+        assert_noop!(
+            XcAssetConfig::deregister_foreign_asset(RuntimeOrigin::root(), asset_id),
+            Error::<Test>::AssetDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn bounded_versioned_multilocation_is_ok() {
+    let asset_location = MultiLocation::new(
+        1,
+        Junctions::X3(
+            Junction::Parachain(1000),
+            Junction::PalletInstance(50),
+            Junction::GeneralIndex(u128::MAX),
+        ),
+    )
+    .into_versioned();
+    let bounded_asset_location = BoundedVersionedMultiLocation::from(asset_location.clone());
+
+    // Encoding is the same as of the wrapped location, so no storage migration is needed
+    assert_eq!(bounded_asset_location.encode(), asset_location.encode());
+    assert!(
+        bounded_asset_location.encode().len() <= BoundedVersionedMultiLocation::max_encoded_len()
+    );
+
+    // Any supported version can be decoded, so the bound must cover all of them
+    assert!(
+        1 + xcm::v2::MultiLocation::max_encoded_len()
+            <= BoundedVersionedMultiLocation::max_encoded_len()
+    );
+    assert!(
+        1 + xcm::v3::MultiLocation::max_encoded_len()
+            <= BoundedVersionedMultiLocation::max_encoded_len()
+    );
+}
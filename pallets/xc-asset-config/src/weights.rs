@@ -16,27 +16,11 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for pallet_xc_asset_config
+//! Weights for pallet_xc_asset_config
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-04-04, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! HOSTNAME: `devserver-01`, CPU: `Intel(R) Xeon(R) E-2236 CPU @ 3.40GHz`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("shibuya-dev"), DB CACHE: 1024
-
-// Executed Command:
-// ./target/release/astar-collator
-// benchmark
-// pallet
-// --chain=shibuya-dev
-// --steps=50
-// --repeat=20
-// --pallet=pallet_xc_asset_config
-// --extrinsic=*
-// --execution=wasm
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --output=./benchmark-results/xc_asset_config_weights.rs
-// --template=./scripts/templates/weight-template.hbs
+//! Storage accesses & proof sizes follow the benchmarks in `benchmarking.rs`, using the Shibuya runtime configuration
+//! (`MaxReserveLocations = 8`), with proof sizes based on the `MaxEncodedLen` of the bounded storage items.
+//! Reference times are provisional until the benchmarks are run on the reference hardware.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -52,133 +36,301 @@ pub trait WeightInfo {
 	fn change_existing_asset_location() -> Weight;
 	fn remove_payment_asset() -> Weight;
 	fn remove_asset() -> Weight;
+	fn add_reserve_location() -> Weight;
+	fn remove_reserve_location() -> Weight;
+	fn set_asset_exchange_rate() -> Weight;
+	fn remove_asset_exchange_rate() -> Weight;
+	fn register_foreign_asset() -> Weight;
+	fn deregister_foreign_asset() -> Weight;
 }
 
 /// Weights for pallet_xc_asset_config using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
-	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetIdToLocation (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
 	// Storage: EVM AccountCodes (r:0 w:1)
 	// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
-	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetReserveLocations (r:0 w:1)
+	// Proof: XcAssetConfig AssetReserveLocations (max_values: None, max_size: Some(4849), added: 7324, mode: MaxEncodedLen)
 	fn register_asset_location() -> Weight {
-		// Minimum execution time: 15_540 nanoseconds.
 		Weight::from_parts(16_114_000, 0)
-			.saturating_add(Weight::from_parts(0, 2493))
+			.saturating_add(Weight::from_parts(0, 4092))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
-	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
 	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
-	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
 	fn set_asset_units_per_second() -> Weight {
-		// Minimum execution time: 15_297 nanoseconds.
 		Weight::from_parts(15_551_000, 0)
-			.saturating_add(Weight::from_parts(0, 2661))
+			.saturating_add(Weight::from_parts(0, 4092))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
-	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetIdToLocation (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
 	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:1 w:2)
-	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:2)
-	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationExchangeRate (r:1 w:2)
+	// Proof: XcAssetConfig AssetLocationExchangeRate (max_values: None, max_size: Some(631), added: 3106, mode: MaxEncodedLen)
 	fn change_existing_asset_location() -> Weight {
-		// Minimum execution time: 22_357 nanoseconds.
-		Weight::from_parts(22_572_000, 0)
-			.saturating_add(Weight::from_parts(0, 5373))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+		Weight::from_parts(26_213_000, 0)
+			.saturating_add(Weight::from_parts(0, 4096))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
-	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
 	fn remove_payment_asset() -> Weight {
-		// Minimum execution time: 9_707 nanoseconds.
 		Weight::from_parts(10_005_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
-	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetIdToLocation (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
 	// Storage: EVM AccountCodes (r:0 w:1)
 	// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
-	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
-	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationExchangeRate (r:0 w:1)
+	// Proof: XcAssetConfig AssetLocationExchangeRate (max_values: None, max_size: Some(631), added: 3106, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetReserveLocations (r:0 w:1)
+	// Proof: XcAssetConfig AssetReserveLocations (max_values: None, max_size: Some(4849), added: 7324, mode: MaxEncodedLen)
 	fn remove_asset() -> Weight {
-		// Minimum execution time: 18_645 nanoseconds.
-		Weight::from_parts(18_878_000, 0)
-			.saturating_add(Weight::from_parts(0, 2987))
+		Weight::from_parts(21_305_000, 0)
+			.saturating_add(Weight::from_parts(0, 4092))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:0)
+	// Proof: XcAssetConfig AssetIdToLocation (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetReserveLocations (r:1 w:1)
+	// Proof: XcAssetConfig AssetReserveLocations (max_values: None, max_size: Some(4849), added: 7324, mode: MaxEncodedLen)
+	fn add_reserve_location() -> Weight {
+		Weight::from_parts(17_586_000, 0)
+			.saturating_add(Weight::from_parts(0, 8314))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:0)
+	// Proof: XcAssetConfig AssetIdToLocation (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetReserveLocations (r:1 w:1)
+	// Proof: XcAssetConfig AssetReserveLocations (max_values: None, max_size: Some(4849), added: 7324, mode: MaxEncodedLen)
+	fn remove_reserve_location() -> Weight {
+		Weight::from_parts(18_312_000, 0)
+			.saturating_add(Weight::from_parts(0, 8314))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationExchangeRate (r:0 w:1)
+	// Proof: XcAssetConfig AssetLocationExchangeRate (max_values: None, max_size: Some(631), added: 3106, mode: MaxEncodedLen)
+	fn set_asset_exchange_rate() -> Weight {
+		Weight::from_parts(16_127_000, 0)
+			.saturating_add(Weight::from_parts(0, 4092))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationExchangeRate (r:1 w:1)
+	// Proof: XcAssetConfig AssetLocationExchangeRate (max_values: None, max_size: Some(631), added: 3106, mode: MaxEncodedLen)
+	fn remove_asset_exchange_rate() -> Weight {
+		Weight::from_parts(10_163_000, 0)
+			.saturating_add(Weight::from_parts(0, 4096))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
+	// Proof: XcAssetConfig AssetIdToLocation (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:1)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: Assets Asset (r:1 w:1)
+	// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Proof: Assets Metadata (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	// Storage: EVM AccountCodes (r:0 w:1)
+	// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetReserveLocations (r:0 w:1)
+	// Proof: XcAssetConfig AssetReserveLocations (max_values: None, max_size: Some(4849), added: 7324, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
+	// Proof: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	fn register_foreign_asset() -> Weight {
+		Weight::from_parts(42_513_000, 0)
+			.saturating_add(Weight::from_parts(0, 4092))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
+	// Proof: XcAssetConfig AssetIdToLocation (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: Assets Asset (r:1 w:1)
+	// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
+	// Proof: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationExchangeRate (r:0 w:1)
+	// Proof: XcAssetConfig AssetLocationExchangeRate (max_values: None, max_size: Some(631), added: 3106, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetReserveLocations (r:0 w:1)
+	// Proof: XcAssetConfig AssetReserveLocations (max_values: None, max_size: Some(4849), added: 7324, mode: MaxEncodedLen)
+	fn deregister_foreign_asset() -> Weight {
+		Weight::from_parts(27_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 4092))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
-	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetIdToLocation (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
 	// Storage: EVM AccountCodes (r:0 w:1)
 	// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
-	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetReserveLocations (r:0 w:1)
+	// Proof: XcAssetConfig AssetReserveLocations (max_values: None, max_size: Some(4849), added: 7324, mode: MaxEncodedLen)
 	fn register_asset_location() -> Weight {
-		// Minimum execution time: 15_540 nanoseconds.
 		Weight::from_parts(16_114_000, 0)
-			.saturating_add(Weight::from_parts(0, 2493))
+			.saturating_add(Weight::from_parts(0, 4092))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
-	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
 	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
-	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
 	fn set_asset_units_per_second() -> Weight {
-		// Minimum execution time: 15_297 nanoseconds.
 		Weight::from_parts(15_551_000, 0)
-			.saturating_add(Weight::from_parts(0, 2661))
+			.saturating_add(Weight::from_parts(0, 4092))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
-	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetIdToLocation (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
 	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:1 w:2)
-	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:2)
-	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationExchangeRate (r:1 w:2)
+	// Proof: XcAssetConfig AssetLocationExchangeRate (max_values: None, max_size: Some(631), added: 3106, mode: MaxEncodedLen)
 	fn change_existing_asset_location() -> Weight {
-		// Minimum execution time: 22_357 nanoseconds.
-		Weight::from_parts(22_572_000, 0)
-			.saturating_add(Weight::from_parts(0, 5373))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+		Weight::from_parts(26_213_000, 0)
+			.saturating_add(Weight::from_parts(0, 4096))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
-	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
 	fn remove_payment_asset() -> Weight {
-		// Minimum execution time: 9_707 nanoseconds.
 		Weight::from_parts(10_005_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
-	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetIdToLocation (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
 	// Storage: EVM AccountCodes (r:0 w:1)
 	// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
-	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
-	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationExchangeRate (r:0 w:1)
+	// Proof: XcAssetConfig AssetLocationExchangeRate (max_values: None, max_size: Some(631), added: 3106, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetReserveLocations (r:0 w:1)
+	// Proof: XcAssetConfig AssetReserveLocations (max_values: None, max_size: Some(4849), added: 7324, mode: MaxEncodedLen)
 	fn remove_asset() -> Weight {
-		// Minimum execution time: 18_645 nanoseconds.
-		Weight::from_parts(18_878_000, 0)
-			.saturating_add(Weight::from_parts(0, 2987))
+		Weight::from_parts(21_305_000, 0)
+			.saturating_add(Weight::from_parts(0, 4092))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:0)
+	// Proof: XcAssetConfig AssetIdToLocation (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetReserveLocations (r:1 w:1)
+	// Proof: XcAssetConfig AssetReserveLocations (max_values: None, max_size: Some(4849), added: 7324, mode: MaxEncodedLen)
+	fn add_reserve_location() -> Weight {
+		Weight::from_parts(17_586_000, 0)
+			.saturating_add(Weight::from_parts(0, 8314))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:0)
+	// Proof: XcAssetConfig AssetIdToLocation (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetReserveLocations (r:1 w:1)
+	// Proof: XcAssetConfig AssetReserveLocations (max_values: None, max_size: Some(4849), added: 7324, mode: MaxEncodedLen)
+	fn remove_reserve_location() -> Weight {
+		Weight::from_parts(18_312_000, 0)
+			.saturating_add(Weight::from_parts(0, 8314))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationExchangeRate (r:0 w:1)
+	// Proof: XcAssetConfig AssetLocationExchangeRate (max_values: None, max_size: Some(631), added: 3106, mode: MaxEncodedLen)
+	fn set_asset_exchange_rate() -> Weight {
+		Weight::from_parts(16_127_000, 0)
+			.saturating_add(Weight::from_parts(0, 4092))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationExchangeRate (r:1 w:1)
+	// Proof: XcAssetConfig AssetLocationExchangeRate (max_values: None, max_size: Some(631), added: 3106, mode: MaxEncodedLen)
+	fn remove_asset_exchange_rate() -> Weight {
+		Weight::from_parts(10_163_000, 0)
+			.saturating_add(Weight::from_parts(0, 4096))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
+	// Proof: XcAssetConfig AssetIdToLocation (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:1)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: Assets Asset (r:1 w:1)
+	// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Proof: Assets Metadata (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	// Storage: EVM AccountCodes (r:0 w:1)
+	// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetReserveLocations (r:0 w:1)
+	// Proof: XcAssetConfig AssetReserveLocations (max_values: None, max_size: Some(4849), added: 7324, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
+	// Proof: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	fn register_foreign_asset() -> Weight {
+		Weight::from_parts(42_513_000, 0)
+			.saturating_add(Weight::from_parts(0, 4092))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
+	// Proof: XcAssetConfig AssetIdToLocation (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: Assets Asset (r:1 w:1)
+	// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
+	// Proof: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetLocationExchangeRate (r:0 w:1)
+	// Proof: XcAssetConfig AssetLocationExchangeRate (max_values: None, max_size: Some(631), added: 3106, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetReserveLocations (r:0 w:1)
+	// Proof: XcAssetConfig AssetReserveLocations (max_values: None, max_size: Some(4849), added: 7324, mode: MaxEncodedLen)
+	fn deregister_foreign_asset() -> Weight {
+		Weight::from_parts(27_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 4092))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 }
\ No newline at end of file
@@ -16,6 +16,9 @@ interface DAppStaking {
     /// Describes current smart contract types supported by the network.
     enum SmartContractType {EVM, WASM}
 
+    /// Describes the category to which a dApp belongs.
+    enum DAppCategory {DeFi, NFT, Gaming, Social, Infrastructure, Tooling, Other}
+
     /// @notice Describes protocol state.
     /// @param era: Ongoing era number.
     /// @param period: Ongoing period number.
@@ -35,6 +38,20 @@ interface DAppStaking {
         bytes contract_address;
     }
 
+    /// @notice Describes dApp metadata.
+    /// @param exists: `true` if the dApp has metadata, `false` otherwise. If `false`, other fields are empty.
+    /// @param name: Human readable name of the dApp.
+    /// @param website: Website of the dApp.
+    /// @param logo_hash: Hash of the dApp's logo, which is stored off-chain.
+    /// @param category: Category to which the dApp belongs.
+    struct DAppMetadata {
+        bool exists;
+        bytes name;
+        bytes website;
+        bytes32 logo_hash;
+        DAppCategory category;
+    }
+
     // Storage getters
 
     /// @notice Get the current protocol state.
@@ -45,6 +62,11 @@ interface DAppStaking {
     /// @return period: The unlocking period expressed in the number of blocks.
     function unlocking_period() external view returns (uint256);
 
+    /// @notice Get the metadata of the dApp.
+    /// @param smart_contract: The smart contract of the dApp.
+    /// @return metadata: The dApp metadata, with `exists` set to `false` if there is none.
+    function dapp_metadata(SmartContract calldata smart_contract) external view returns (DAppMetadata memory);
+
 
     // Extrinsic calls
 
@@ -89,4 +111,17 @@ interface DAppStaking {
 
     /// @notice Used to cleanup all expired contract stake entries from the caller.
     function cleanup_expired_entries() external returns (bool);
+
+    /// @notice Enable or disable restake mode for the caller.
+    ///         When enabled, claimed staker & bonus rewards are locked & staked on the dApps staked in the ongoing period.
+    /// @param enabled: `true` to enable restake mode, `false` to disable it.
+    function set_restake(bool enabled) external returns (bool);
+
+    /// @notice Move the given amount of stake from one smart contract to another, without unstaking it.
+    ///         Voting subperiod stake remains voting subperiod stake, preserving the bonus reward eligibility.
+    ///         In case source smart contract is unregistered, everything staked on it is moved.
+    /// @param from_smart_contract: The smart contract from which the stake is moved.
+    /// @param to_smart_contract: The smart contract to which the stake is moved.
+    /// @param amount: The amount of tokens to be moved.
+    function move_stake(SmartContract calldata from_smart_contract, SmartContract calldata to_smart_contract, uint128 amount) external returns (bool);
 }
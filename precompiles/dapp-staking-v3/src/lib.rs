@@ -30,7 +30,7 @@ use frame_support::{
     traits::ConstU32,
 };
 
-use pallet_evm::AddressMapping;
+use pallet_evm::{AddressMapping, GasWeightMapping};
 use precompile_utils::{
     prelude::*,
     solidity::{
@@ -38,7 +38,7 @@ use precompile_utils::{
         Codec,
     },
 };
-use sp_core::{Get, H160, U256};
+use sp_core::{Get, H160, H256, U256};
 use sp_runtime::traits::Zero;
 use sp_std::{marker::PhantomData, prelude::*};
 extern crate alloc;
@@ -46,8 +46,9 @@ extern crate alloc;
 use astar_primitives::{dapp_staking::SmartContractHandle, AccountId, Balance};
 use pallet_dapp_staking_v3::{
     AccountLedgerFor, ActiveProtocolState, ContractStake, ContractStakeAmount, CurrentEraInfo,
-    DAppInfoFor, EraInfo, EraRewardSpanFor, EraRewards, IntegratedDApps, Ledger,
-    Pallet as DAppStaking, ProtocolState, SingularStakingInfo, StakerInfo, Subperiod,
+    DAppCategory, DAppInfoFor, DAppMetadataOf, EraInfo, EraRewardSpanFor, EraRewards,
+    IntegratedDApps, Ledger, Pallet as DAppStaking, ProtocolState, SingularStakingInfo, StakerInfo,
+    Subperiod, WeightInfo,
 };
 
 pub const STAKER_BYTES_LIMIT: u32 = 32;
@@ -66,6 +67,16 @@ pub(crate) struct PrecompileProtocolState {
     subperiod: u8,
 }
 
+/// Helper struct used to encode dApp metadata.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileDAppMetadata {
+    exists: bool,
+    name: UnboundedBytes,
+    website: UnboundedBytes,
+    logo_hash: H256,
+    category: u8,
+}
+
 /// Helper struct used to encode different smart contract types for the v2 interface.
 #[derive(Debug, Clone, solidity::Codec)]
 pub struct SmartContractV2 {
@@ -766,6 +777,76 @@ where
         Ok(true)
     }
 
+    /// Attempts to enable or disable restake mode for the caller.
+    #[precompile::public("set_restake(bool)")]
+    fn set_restake(handle: &mut impl PrecompileHandle, enabled: bool) -> EvmResult<bool> {
+        // Prepare call & dispatch it
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let set_restake_call = pallet_dapp_staking_v3::Call::<R>::set_restake { enabled };
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), set_restake_call)?;
+
+        Ok(true)
+    }
+
+    /// Attempts to move the given amount of stake from one smart contract to another.
+    #[precompile::public("move_stake((uint8,bytes),(uint8,bytes),uint128)")]
+    fn move_stake(
+        handle: &mut impl PrecompileHandle,
+        from_smart_contract: SmartContractV2,
+        to_smart_contract: SmartContractV2,
+        amount: Balance,
+    ) -> EvmResult<bool> {
+        let from = Self::decode_smart_contract(from_smart_contract)?;
+        let to = Self::decode_smart_contract(to_smart_contract)?;
+
+        // Prepare call & dispatch it
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let move_stake_call = pallet_dapp_staking_v3::Call::<R>::move_stake { from, to, amount };
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), move_stake_call)?;
+
+        Ok(true)
+    }
+
+    /// Read the metadata of the dApp, like name, website, logo hash & category.
+    ///
+    /// In case dApp doesn't exist or has no metadata, `exists` is set to `false` and other fields are empty.
+    #[precompile::public("dapp_metadata((uint8,bytes))")]
+    #[precompile::view]
+    fn dapp_metadata(
+        handle: &mut impl PrecompileHandle,
+        smart_contract: SmartContractV2,
+    ) -> EvmResult<PrecompileDAppMetadata> {
+        // Storage items: IntegratedDApps & DAppMetadataOf
+        let weight = <R as pallet_dapp_staking_v3::Config>::WeightInfo::dapp_metadata();
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+        handle.record_external_cost(None, Some(weight.proof_size()))?;
+
+        let smart_contract = Self::decode_smart_contract(smart_contract)?;
+
+        let metadata = match IntegratedDApps::<R>::get(&smart_contract)
+            .and_then(|dapp_info| DAppMetadataOf::<R>::get(&dapp_info.id))
+        {
+            Some(metadata) => metadata,
+            None => {
+                return Ok(PrecompileDAppMetadata {
+                    exists: false,
+                    name: Vec::new().into(),
+                    website: Vec::new().into(),
+                    logo_hash: H256::zero(),
+                    category: 0,
+                })
+            }
+        };
+
+        Ok(PrecompileDAppMetadata {
+            exists: true,
+            name: metadata.name.into_inner().into(),
+            website: metadata.website.into_inner().into(),
+            logo_hash: metadata.logo_hash,
+            category: dapp_category_id(&metadata.category),
+        })
+    }
+
     // Utility functions
 
     /// Helper method to decode smart contract struct for v2 calls
@@ -830,3 +911,16 @@ pub(crate) fn subperiod_id(subperiod: &Subperiod) -> u8 {
         Subperiod::BuildAndEarn => 1,
     }
 }
+
+/// Numeric Id of the dApp category enum value.
+pub(crate) fn dapp_category_id(category: &DAppCategory) -> u8 {
+    match category {
+        DAppCategory::DeFi => 0,
+        DAppCategory::NFT => 1,
+        DAppCategory::Gaming => 2,
+        DAppCategory::Social => 3,
+        DAppCategory::Infrastructure => 4,
+        DAppCategory::Tooling => 5,
+        DAppCategory::Other => 6,
+    }
+}
@@ -524,3 +524,168 @@ fn cleanup_expired_entries_is_ok() {
         );
     });
 }
+
+#[test]
+fn set_restake_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Register a dApp and stake on it
+        let staker_h160 = ALICE;
+        let smart_contract_address = [0xAF; 32];
+        let smart_contract = <Test as pallet_dapp_staking_v3::Config>::SmartContract::wasm(
+            smart_contract_address.into(),
+        );
+        let amount = 1234;
+        register_and_stake(staker_h160, smart_contract.clone(), amount);
+
+        // Enable restake mode and verify event
+        System::reset_events();
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::set_restake { enabled: true },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        let events = dapp_staking_events();
+        assert_eq!(events.len(), 1);
+        assert_matches!(
+            events[0].clone(),
+            pallet_dapp_staking_v3::Event::RestakeModeSet { enabled, .. } if enabled
+        );
+
+        let staker_native = AddressMapper::into_account_id(staker_h160);
+        assert!(pallet_dapp_staking_v3::Ledger::<Test>::get(&staker_native).restake);
+    });
+}
+
+#[test]
+fn move_stake_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Register two dApps and stake on the first one
+        let staker_h160 = ALICE;
+        let from_address = [0xAF; 32];
+        let from_smart_contract =
+            <Test as pallet_dapp_staking_v3::Config>::SmartContract::wasm(from_address.into());
+        let amount = 1234;
+        register_and_stake(staker_h160, from_smart_contract.clone(), amount);
+
+        let to_address = [0xFA; 32];
+        let to_smart_contract =
+            <Test as pallet_dapp_staking_v3::Config>::SmartContract::wasm(to_address.into());
+        assert_ok!(DappStaking::register(
+            RawOrigin::Root.into(),
+            AddressMapper::into_account_id(staker_h160),
+            to_smart_contract.clone()
+        ));
+
+        // Move some stake and verify event
+        let move_amount = 234;
+        System::reset_events();
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::move_stake {
+                    from_smart_contract: SmartContractV2 {
+                        contract_type: SmartContractTypes::Wasm,
+                        address: from_address.into(),
+                    },
+                    to_smart_contract: SmartContractV2 {
+                        contract_type: SmartContractTypes::Wasm,
+                        address: to_address.into(),
+                    },
+                    amount: move_amount,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        let events = dapp_staking_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].clone(),
+            pallet_dapp_staking_v3::Event::StakeMoved {
+                account: AddressMapper::into_account_id(staker_h160),
+                from: from_smart_contract,
+                to: to_smart_contract,
+                amount: move_amount,
+            }
+        );
+    });
+}
+
+#[test]
+fn dapp_metadata_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Register a dApp without any metadata
+        let owner_h160 = ALICE;
+        let owner = AddressMapper::into_account_id(owner_h160);
+        let contract_address = [0xAF; 32];
+        let smart_contract =
+            <Test as pallet_dapp_staking_v3::Config>::SmartContract::wasm(contract_address.into());
+        assert_ok!(DappStaking::register(
+            RawOrigin::Root.into(),
+            owner.clone(),
+            smart_contract.clone()
+        ));
+
+        let smart_contract_v2 = SmartContractV2 {
+            contract_type: SmartContractTypes::Wasm,
+            address: contract_address.into(),
+        };
+
+        // No metadata exists yet
+        precompiles()
+            .prepare_test(
+                owner_h160,
+                precompile_address(),
+                PrecompileCall::dapp_metadata {
+                    smart_contract: smart_contract_v2.clone(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileDAppMetadata {
+                exists: false,
+                name: Vec::new().into(),
+                website: Vec::new().into(),
+                logo_hash: H256::zero(),
+                category: 0,
+            });
+
+        // Set the metadata and expect it to be returned
+        let logo_hash = H256::repeat_byte(0x07);
+        assert_ok!(DappStaking::set_dapp_metadata(
+            RawOrigin::Signed(owner).into(),
+            smart_contract,
+            b"dApp".to_vec().try_into().unwrap(),
+            b"dapp.io".to_vec().try_into().unwrap(),
+            logo_hash,
+            pallet_dapp_staking_v3::DAppCategory::Gaming,
+        ));
+
+        precompiles()
+            .prepare_test(
+                owner_h160,
+                precompile_address(),
+                PrecompileCall::dapp_metadata {
+                    smart_contract: smart_contract_v2,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileDAppMetadata {
+                exists: true,
+                name: b"dApp".into(),
+                website: b"dapp.io".into(),
+                logo_hash,
+                category: 2,
+            });
+    });
+}
@@ -20,7 +20,7 @@ use super::{Balance, BlockNumber};
 
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 
-use frame_support::RuntimeDebug;
+use frame_support::{weights::Weight, RuntimeDebug};
 use sp_core::H160;
 use sp_std::hash::Hash;
 
@@ -76,7 +76,7 @@ pub trait CycleConfiguration {
 /// Interface for staking reward handler.
 ///
 /// Provides reward pool values for stakers - normal & bonus rewards, as well as dApp reward pool.
-/// Also provides a safe function for paying out rewards.
+/// Also provides a safe function for paying out rewards, and a way to handle unspent dApp rewards.
 pub trait StakingRewardHandler<AccountId> {
     /// Returns the staker reward pool & dApp reward pool for an era.
     ///
@@ -88,6 +88,15 @@ pub trait StakingRewardHandler<AccountId> {
 
     /// Attempts to pay out the rewards to the beneficiary.
     fn payout_reward(beneficiary: &AccountId, reward: Balance) -> Result<(), ()>;
+
+    /// Handles the part of the era's dApp reward pool which wasn't assigned to any dApp, e.g. due to unfilled tier slots.
+    ///
+    /// By default, unspent rewards are never minted.
+    ///
+    /// Returns the consumed weight.
+    fn handle_unspent_dapp_rewards(_amount: Balance) -> Weight {
+        Weight::zero()
+    }
 }
 
 /// Trait defining the interface for dApp staking `smart contract types` handler.
@@ -113,6 +122,7 @@ pub trait SmartContractHandle<AccountId> {
     Hash,
     scale_info::TypeInfo,
 )]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum SmartContract<AccountId> {
     /// EVM smart contract instance.
     Evm(H160),
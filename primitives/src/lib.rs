@@ -38,6 +38,9 @@ pub mod precompiles;
 /// dApp staking & inflation primitives.
 pub mod dapp_staking;
 
+/// Price oracle primitives.
+pub mod oracle;
+
 /// Useful primitives for testing.
 pub mod testing;
 
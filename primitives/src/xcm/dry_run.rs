@@ -0,0 +1,264 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # XCM Dry-Run
+//!
+//! ## Overview
+//!
+//! Utilities used to execute an XCM program without committing any of its effects.
+//!
+//! - `dry_run_xcm` - executes the XCM program and collects its effects
+//! - `DryRunXcmConfig` - wrapper around runtime's XCM executor config which records effects
+//! - `RecordingRouter` - router which records all messages sent during the execution
+//! - `RecordingTrader` - weight trader which records all fees charged during the execution
+//!
+//! Dry-run is executed in a storage transaction which is always rolled back, so it's safe to use
+//! it from any context. Recorded effects are kept in temporary storage for the duration of the execution.
+//!
+//! Messages sent from dispatched calls (e.g. `Transact` calling `pallet-xcm`) don't go through
+//! the executor's router and aren't recorded.
+
+use frame_support::storage::{unhashed, with_transaction, TransactionOutcome};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+use xcm::{
+    latest::{prelude::*, Weight},
+    VersionedMultiAssets, VersionedMultiLocation, VersionedXcm,
+};
+use xcm_executor::{
+    traits::{WeightBounds, WeightTrader},
+    Assets, XcmExecutor,
+};
+
+/// Temporary storage key under which messages sent during the dry-run are recorded.
+const FORWARDED_XCMS_KEY: &[u8] = b":astar:xcm_dry_run:forwarded_xcms";
+/// Temporary storage key under which fees charged during the dry-run are recorded.
+const FEES_CHARGED_KEY: &[u8] = b":astar:xcm_dry_run:fees_charged";
+
+/// Effects of an XCM program dry-run.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct XcmDryRunEffects<Event> {
+    /// Outcome of the local execution.
+    pub execution_result: Outcome,
+    /// Weight used by the local execution.
+    pub weight_used: Weight,
+    /// Fees charged for the local execution, reduced by the refunded amount.
+    pub fees_charged: VersionedMultiAssets,
+    /// Events emitted during the local execution.
+    pub emitted_events: Vec<Event>,
+    /// Messages which would be sent to other locations, in order of sending.
+    pub forwarded_xcms: Vec<(VersionedMultiLocation, VersionedXcm<()>)>,
+}
+
+impl<Event: Encode> XcmDryRunEffects<Event> {
+    /// Replaces emitted events with their SCALE encoded representation.
+    pub fn encode_events(self) -> XcmDryRunEffects<Vec<u8>> {
+        XcmDryRunEffects {
+            execution_result: self.execution_result,
+            weight_used: self.weight_used,
+            fees_charged: self.fees_charged,
+            emitted_events: self.emitted_events.iter().map(Encode::encode).collect(),
+            forwarded_xcms: self.forwarded_xcms,
+        }
+    }
+}
+
+/// Reasons why XCM program dry-run couldn't be executed.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum XcmDryRunError {
+    /// Origin couldn't be converted into the latest multilocation version.
+    UnsupportedOrigin,
+    /// XCM program couldn't be converted into the latest XCM version.
+    UnsupportedXcm,
+    /// Storage transaction in which dry-run is executed couldn't be opened.
+    Unavailable,
+}
+
+impl From<DispatchError> for XcmDryRunError {
+    fn from(_: DispatchError) -> Self {
+        Self::Unavailable
+    }
+}
+
+/// Executes XCM program from the `origin` using the XCM executor configured with `Config`,
+/// and returns its effects. None of the effects are committed to storage.
+///
+/// Local origins (i.e. with no parents) are given weight credit equal to the program weight,
+/// same as when executing the program via `pallet-xcm`. Other origins must pass the barrier on their own.
+pub fn dry_run_xcm<Runtime, Config>(
+    origin: VersionedMultiLocation,
+    xcm: VersionedXcm<()>,
+) -> Result<XcmDryRunEffects<Runtime::RuntimeEvent>, XcmDryRunError>
+where
+    Runtime: frame_system::Config,
+    Config: xcm_executor::Config,
+{
+    let origin = MultiLocation::try_from(origin).map_err(|_| XcmDryRunError::UnsupportedOrigin)?;
+    let xcm = Xcm::<()>::try_from(xcm).map_err(|_| XcmDryRunError::UnsupportedXcm)?;
+
+    with_transaction(|| {
+        frame_system::Pallet::<Runtime>::reset_events();
+
+        let mut message = Xcm::<Config::RuntimeCall>::from(xcm);
+        let hash = message.using_encoded(sp_io::hashing::blake2_256);
+        let max_weight = Config::Weigher::weight(&mut message).unwrap_or(Weight::MAX);
+        let weight_credit = if origin.parents == 0 {
+            max_weight
+        } else {
+            Weight::zero()
+        };
+
+        let execution_result = XcmExecutor::<DryRunXcmConfig<Config>>::execute_xcm_in_credit(
+            origin,
+            message,
+            hash,
+            max_weight,
+            weight_credit,
+        );
+
+        let emitted_events = frame_system::Pallet::<Runtime>::read_events_no_consensus()
+            .map(|record| record.event)
+            .collect();
+        let fees_charged: Vec<MultiAsset> = unhashed::take(FEES_CHARGED_KEY).unwrap_or_default();
+        let forwarded_xcms: Vec<(MultiLocation, Xcm<()>)> =
+            unhashed::take(FORWARDED_XCMS_KEY).unwrap_or_default();
+
+        let effects = XcmDryRunEffects {
+            weight_used: execution_result.weight_used(),
+            execution_result,
+            fees_charged: VersionedMultiAssets::V3(fees_charged.into()),
+            emitted_events,
+            forwarded_xcms: forwarded_xcms
+                .into_iter()
+                .map(|(destination, message)| {
+                    (
+                        VersionedMultiLocation::V3(destination),
+                        VersionedXcm::V3(message),
+                    )
+                })
+                .collect(),
+        };
+
+        TransactionOutcome::Rollback(Ok::<_, XcmDryRunError>(effects))
+    })
+}
+
+/// XCM executor config used for dry-runs.
+///
+/// Identical to the wrapped `Config`, except that sent messages & charged fees are recorded.
+pub struct DryRunXcmConfig<Config>(PhantomData<Config>);
+impl<Config: xcm_executor::Config> xcm_executor::Config for DryRunXcmConfig<Config> {
+    type RuntimeCall = Config::RuntimeCall;
+    type XcmSender = RecordingRouter<Config::XcmSender>;
+    type AssetTransactor = Config::AssetTransactor;
+    type OriginConverter = Config::OriginConverter;
+    type IsReserve = Config::IsReserve;
+    type IsTeleporter = Config::IsTeleporter;
+    type UniversalLocation = Config::UniversalLocation;
+    type Barrier = Config::Barrier;
+    type Weigher = Config::Weigher;
+    type Trader = RecordingTrader<Config::Trader>;
+    type ResponseHandler = Config::ResponseHandler;
+    type AssetTrap = Config::AssetTrap;
+    type AssetLocker = Config::AssetLocker;
+    type AssetExchanger = Config::AssetExchanger;
+    type AssetClaims = Config::AssetClaims;
+    type SubscriptionService = Config::SubscriptionService;
+    type PalletInstancesInfo = Config::PalletInstancesInfo;
+    type MaxAssetsIntoHolding = Config::MaxAssetsIntoHolding;
+    type FeeManager = Config::FeeManager;
+    type MessageExporter = Config::MessageExporter;
+    type UniversalAliases = Config::UniversalAliases;
+    type CallDispatcher = Config::CallDispatcher;
+    type SafeCallFilter = Config::SafeCallFilter;
+}
+
+/// Router which records every message successfully delivered by the wrapped `Router`.
+pub struct RecordingRouter<Router>(PhantomData<Router>);
+impl<Router: SendXcm> SendXcm for RecordingRouter<Router> {
+    type Ticket = (Router::Ticket, (MultiLocation, Xcm<()>));
+
+    fn validate(
+        destination: &mut Option<MultiLocation>,
+        message: &mut Option<Xcm<()>>,
+    ) -> SendResult<Self::Ticket> {
+        let forwarded = match (destination.as_ref(), message.as_ref()) {
+            (Some(destination), Some(message)) => (*destination, message.clone()),
+            _ => return Err(SendError::MissingArgument),
+        };
+
+        let (ticket, price) = Router::validate(destination, message)?;
+        Ok(((ticket, forwarded), price))
+    }
+
+    fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+        let (ticket, forwarded) = ticket;
+        let hash = Router::deliver(ticket)?;
+
+        let mut forwarded_xcms: Vec<(MultiLocation, Xcm<()>)> =
+            unhashed::get(FORWARDED_XCMS_KEY).unwrap_or_default();
+        forwarded_xcms.push(forwarded);
+        unhashed::put(FORWARDED_XCMS_KEY, &forwarded_xcms);
+
+        Ok(hash)
+    }
+}
+
+/// Weight trader which records fees charged & refunded by the wrapped `Trader`.
+pub struct RecordingTrader<Trader>(Trader);
+impl<Trader: WeightTrader> WeightTrader for RecordingTrader<Trader> {
+    fn new() -> Self {
+        Self(Trader::new())
+    }
+
+    fn buy_weight(&mut self, weight: Weight, payment: Assets) -> Result<Assets, XcmError> {
+        let unused = self.0.buy_weight(weight, payment.clone())?;
+
+        let mut charged = payment;
+        let unused_assets: Vec<MultiAsset> = unused.clone().into();
+        charged.saturating_take(Definite(unused_assets.into()));
+        update_fees_charged(|fees| fees.subsume_assets(charged));
+
+        Ok(unused)
+    }
+
+    fn refund_weight(&mut self, weight: Weight) -> Option<MultiAsset> {
+        let refund = self.0.refund_weight(weight);
+
+        if let Some(asset) = refund.clone() {
+            update_fees_charged(|fees| {
+                fees.saturating_take(Definite(asset.into()));
+            });
+        }
+
+        refund
+    }
+}
+
+/// Applies `f` to the fees recorded so far.
+fn update_fees_charged(f: impl FnOnce(&mut Assets)) {
+    let fees: Vec<MultiAsset> = unhashed::get(FEES_CHARGED_KEY).unwrap_or_default();
+    let mut fees = Assets::from(fees);
+    f(&mut fees);
+
+    let fees: Vec<MultiAsset> = fees.into();
+    unhashed::put(FEES_CHARGED_KEY, &fees);
+}
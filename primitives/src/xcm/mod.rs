@@ -24,19 +24,25 @@
 //!
 //! - `AssetLocationIdConverter` - conversion between local asset Id and cross-chain asset multilocation
 //! - `FixedRateOfForeignAsset` - weight trader for execution payment in foreign asset
+//! - `ExchangeRateOfForeignAsset` - weight trader for execution payment in foreign asset, priced in native currency
 //! - `ReserveAssetFilter` - used to check whether asset/origin are a valid reserve location
 //! - `XcmFungibleFeeHandler` - used to handle XCM fee execution fees
+//! - `AssetsForeignAssetManager` - used to create & destroy `pallet-assets` assets representing foreign assets
+//! - `dry_run` - utilities for executing XCM programs without committing their effects
 //!
 //! Please refer to implementation below for more info.
 //!
 
-use crate::AccountId;
+use crate::{AccountId, Balance};
 
 use frame_support::{
     traits::{tokens::fungibles, ContainsPair, Get},
-    weights::constants::WEIGHT_REF_TIME_PER_SECOND,
+    weights::{constants::WEIGHT_REF_TIME_PER_SECOND, WeightToFee as WeightToFeeT},
+};
+use sp_runtime::{
+    traits::{Bounded, Convert, Zero},
+    DispatchResult, FixedPointNumber, FixedU128,
 };
-use sp_runtime::traits::{Bounded, Convert, Zero};
 use sp_std::{borrow::Borrow, marker::PhantomData, vec::Vec};
 
 // Polkadot imports
@@ -47,7 +53,12 @@ use xcm_executor::traits::{MatchesFungibles, WeightTrader};
 // ORML imports
 use orml_traits::location::{RelativeReserveProvider, Reserve};
 
-use pallet_xc_asset_config::{ExecutionPaymentRate, XcAssetLocation};
+use pallet_xc_asset_config::{
+    AssetExchangeRate, ExecutionPaymentRate, ForeignAssetManager, ForeignAssetMetadata,
+    XcAssetLocation,
+};
+
+pub mod dry_run;
 
 #[cfg(test)]
 mod tests;
@@ -199,6 +210,152 @@ impl<T: ExecutionPaymentRate, R: TakeRevenue> Drop for FixedRateOfForeignAsset<T
     }
 }
 
+/// Used as weight trader for foreign assets, pricing the execution in the native currency.
+///
+/// Execution fee is first calculated in the native currency using `WeightToFee`, and then
+/// converted into the payment asset using its exchange rate, provided by `R`.
+/// Compared to `FixedRateOfForeignAsset`, the asset price follows the native currency fee
+/// adjustments, and only the exchange rate needs to be kept up to date.
+///
+/// Exchange rates which haven't been updated for more than `MaxAge` blocks are considered
+/// stale, and payment in such assets is rejected.
+///
+/// A single trader instance only accepts payment in one asset. Consumed amount, reduced by
+/// the refunds, is handed over to `Revenue` once the trader is dropped.
+pub struct ExchangeRateOfForeignAsset<WeightToFee, R, MaxAge, Revenue>
+where
+    WeightToFee: WeightToFeeT<Balance = Balance>,
+    R: AssetExchangeRate,
+    MaxAge: Get<u32>,
+    Revenue: TakeRevenue,
+{
+    /// Total used weight
+    weight: Weight,
+    /// Total consumed assets
+    consumed: u128,
+    /// Asset Id (as MultiLocation) and its exchange rate against the native currency
+    asset_location_and_rate: Option<(MultiLocation, FixedU128)>,
+    _pd: PhantomData<(WeightToFee, R, MaxAge, Revenue)>,
+}
+
+impl<WeightToFee, R, MaxAge, Revenue> ExchangeRateOfForeignAsset<WeightToFee, R, MaxAge, Revenue>
+where
+    WeightToFee: WeightToFeeT<Balance = Balance>,
+    R: AssetExchangeRate,
+    MaxAge: Get<u32>,
+    Revenue: TakeRevenue,
+{
+    /// Execution fee for the given weight, expressed in the asset with the given exchange rate.
+    fn fee_in_asset(weight: &Weight, rate: FixedU128) -> u128 {
+        rate.saturating_mul_int(WeightToFee::weight_to_fee(weight))
+    }
+}
+
+impl<WeightToFee, R, MaxAge, Revenue> WeightTrader
+    for ExchangeRateOfForeignAsset<WeightToFee, R, MaxAge, Revenue>
+where
+    WeightToFee: WeightToFeeT<Balance = Balance>,
+    R: AssetExchangeRate,
+    MaxAge: Get<u32>,
+    Revenue: TakeRevenue,
+{
+    fn new() -> Self {
+        Self {
+            weight: Weight::zero(),
+            consumed: 0,
+            asset_location_and_rate: None,
+            _pd: PhantomData,
+        }
+    }
+
+    fn buy_weight(
+        &mut self,
+        weight: Weight,
+        payment: xcm_executor::Assets,
+    ) -> Result<xcm_executor::Assets, XcmError> {
+        log::trace!(
+            target: "xcm::weight",
+            "ExchangeRateOfForeignAsset::buy_weight weight: {:?}, payment: {:?}",
+            weight, payment,
+        );
+
+        let payment_asset = payment
+            .fungible_assets_iter()
+            .next()
+            .ok_or(XcmError::TooExpensive)?;
+        let asset_location = match payment_asset.id {
+            xcm::latest::AssetId::Concrete(asset_location) => asset_location,
+            _ => return Err(XcmError::TooExpensive),
+        };
+
+        // Once the payment asset is known, the trader sticks to it and its exchange rate.
+        let rate = match self.asset_location_and_rate {
+            Some((location, rate)) if location == asset_location => rate,
+            Some(_) => return Err(XcmError::TooExpensive),
+            None => match R::get_exchange_rate(asset_location) {
+                Some((rate, age)) if age <= MaxAge::get() => rate,
+                _ => return Err(XcmError::TooExpensive),
+            },
+        };
+
+        let amount = Self::fee_in_asset(&weight, rate);
+        if amount == 0 {
+            return Ok(payment);
+        }
+
+        let unused = payment
+            .checked_sub((asset_location, amount).into())
+            .map_err(|_| XcmError::TooExpensive)?;
+
+        self.weight = self.weight.saturating_add(weight);
+        self.consumed = self.consumed.saturating_add(amount);
+        self.asset_location_and_rate = Some((asset_location, rate));
+
+        Ok(unused)
+    }
+
+    fn refund_weight(&mut self, weight: Weight) -> Option<MultiAsset> {
+        log::trace!(target: "xcm::weight", "ExchangeRateOfForeignAsset::refund_weight weight: {:?}", weight);
+
+        let (asset_location, rate) = self.asset_location_and_rate?;
+        let weight = weight.min(self.weight);
+
+        // Refunding all of the bought weight returns everything consumed so far,
+        // otherwise the refund can never exceed the consumed amount.
+        let amount = if weight == self.weight {
+            self.consumed
+        } else {
+            Self::fee_in_asset(&weight, rate).min(self.consumed)
+        };
+
+        self.weight = self.weight.saturating_sub(weight);
+        self.consumed = self.consumed.saturating_sub(amount);
+
+        if amount > 0 {
+            Some((asset_location, amount).into())
+        } else {
+            None
+        }
+    }
+}
+
+impl<WeightToFee, R, MaxAge, Revenue> Drop
+    for ExchangeRateOfForeignAsset<WeightToFee, R, MaxAge, Revenue>
+where
+    WeightToFee: WeightToFeeT<Balance = Balance>,
+    R: AssetExchangeRate,
+    MaxAge: Get<u32>,
+    Revenue: TakeRevenue,
+{
+    fn drop(&mut self) {
+        if let Some((asset_location, _)) = self.asset_location_and_rate {
+            if self.consumed > 0 {
+                Revenue::take_revenue((asset_location, self.consumed).into());
+            }
+        }
+    }
+}
+
 /// Used to determine whether the cross-chain asset is coming from a trusted reserve or not
 ///
 /// Basically, we trust any cross-chain asset from any location to act as a reserve since
@@ -416,3 +573,43 @@ impl<AbsoluteLocation: Get<MultiLocation>> Reserve
         })
     }
 }
+
+/// Manages the `pallet-assets` assets which represent the registered foreign assets.
+///
+/// Assets are force-created with `Owner` as their owner and their metadata is force-set,
+/// so no deposits are taken. Destruction is started by `Owner`, as the asset owner.
+pub struct AssetsForeignAssetManager<Runtime, Owner>(PhantomData<(Runtime, Owner)>);
+impl<Runtime, Owner> ForeignAssetManager<Runtime::AssetId, Runtime::Balance>
+    for AssetsForeignAssetManager<Runtime, Owner>
+where
+    Runtime: pallet_assets::Config,
+    Owner: Get<Runtime::AccountId>,
+{
+    fn create(
+        asset_id: Runtime::AssetId,
+        metadata: ForeignAssetMetadata<Runtime::Balance>,
+    ) -> DispatchResult {
+        <pallet_assets::Pallet<Runtime> as fungibles::Create<Runtime::AccountId>>::create(
+            asset_id.clone(),
+            Owner::get(),
+            metadata.is_sufficient,
+            metadata.min_balance,
+        )?;
+
+        pallet_assets::Pallet::<Runtime>::force_set_metadata(
+            frame_system::RawOrigin::Root.into(),
+            asset_id.into(),
+            metadata.name,
+            metadata.symbol,
+            metadata.decimals,
+            false,
+        )
+    }
+
+    fn start_destroy(asset_id: Runtime::AssetId) -> DispatchResult {
+        <pallet_assets::Pallet<Runtime> as fungibles::Destroy<Runtime::AccountId>>::start_destroy(
+            asset_id,
+            Some(Owner::get()),
+        )
+    }
+}
@@ -17,7 +17,7 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use super::*;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, traits::ConstU32, weights::IdentityFee};
 use sp_runtime::traits::Zero;
 use xcm_executor::traits::Convert;
 
@@ -70,6 +70,46 @@ impl ExecutionPaymentRate for ExecutionPayment {
     }
 }
 
+/// Maximum age of an exchange rate, in blocks, used for testing `ExchangeRateOfForeignAsset`
+const MAX_EXCHANGE_RATE_AGE: u32 = 10;
+/// Asset with an exchange rate older than `MAX_EXCHANGE_RATE_AGE`
+const STALE_RATE_ASSET: MultiLocation = MultiLocation {
+    parents: 1,
+    interior: Junctions::X1(Parachain(30)),
+};
+
+/// Helper struct used for testing `ExchangeRateOfForeignAsset`
+struct ExchangeRate;
+impl AssetExchangeRate for ExchangeRate {
+    fn get_exchange_rate(asset_location: MultiLocation) -> Option<(FixedU128, u32)> {
+        match asset_location {
+            a if a == PARENT => Some((FixedU128::from_rational(1, 2), 0)),
+            a if a == PARACHAIN => Some((FixedU128::from_u32(3), MAX_EXCHANGE_RATE_AGE)),
+            a if a == STALE_RATE_ASSET => Some((FixedU128::from_u32(1), MAX_EXCHANGE_RATE_AGE + 1)),
+            _ => None,
+        }
+    }
+}
+
+thread_local! {
+    static REVENUE: sp_std::cell::RefCell<Vec<MultiAsset>> = sp_std::cell::RefCell::new(Vec::new());
+}
+
+/// Helper struct used for testing `ExchangeRateOfForeignAsset`, stores the taken revenue
+struct StoreRevenue;
+impl TakeRevenue for StoreRevenue {
+    fn take_revenue(revenue: MultiAsset) {
+        REVENUE.with(|r| r.borrow_mut().push(revenue));
+    }
+}
+
+type ExchangeRateTrader = ExchangeRateOfForeignAsset<
+    IdentityFee<Balance>,
+    ExchangeRate,
+    ConstU32<MAX_EXCHANGE_RATE_AGE>,
+    StoreRevenue,
+>;
+
 /// Execution fee for the specified weight, using provided `units_per_second`
 fn execution_fee(weight: Weight, units_per_second: u128) -> u128 {
     units_per_second * (weight.ref_time() as u128) / (WEIGHT_REF_TIME_PER_SECOND as u128)
@@ -319,6 +359,165 @@ fn fixed_rate_of_foreign_asset_refund_is_ok() {
     }
 }
 
+#[test]
+fn exchange_rate_of_foreign_asset_buy_is_ok() {
+    let mut trader = ExchangeRateTrader::new();
+
+    // The amount we have designated for payment (doesn't mean it will be used though)
+    let total_payment = 1_000_000_000;
+    let payment_multi_asset = MultiAsset {
+        id: xcm::latest::AssetId::Concrete(PARACHAIN),
+        fun: Fungibility::Fungible(total_payment),
+    };
+    let weight: Weight = Weight::from_parts(100_000_000, 0);
+
+    // Fee is priced in native currency and then converted using the exchange rate
+    let (rate, _) = ExchangeRate::get_exchange_rate(PARACHAIN).unwrap();
+    let expected_execution_fee =
+        rate.saturating_mul_int(IdentityFee::<Balance>::weight_to_fee(&weight));
+    assert_eq!(expected_execution_fee, 300_000_000); // sanity check
+
+    // 1. Buy weight and expect it to be successful
+    let result = trader.buy_weight(weight, payment_multi_asset.clone().into());
+    if let Ok(assets) = result {
+        assert_eq!(assets.len(), 1);
+        assert_ok!(assets.ensure_contains(
+            &MultiAsset::from((PARACHAIN, total_payment - expected_execution_fee)).into()
+        ));
+
+        assert_eq!(trader.consumed, expected_execution_fee);
+        assert_eq!(trader.weight, weight);
+        assert_eq!(trader.asset_location_and_rate, Some((PARACHAIN, rate)));
+    } else {
+        panic!("Should have been `Ok` wrapped Assets!");
+    }
+
+    // 2. Buy more weight using the same asset, consumed amount accumulates.
+    let result = trader.buy_weight(weight, payment_multi_asset.clone().into());
+    assert!(result.is_ok());
+    assert_eq!(trader.consumed, expected_execution_fee * 2);
+    assert_eq!(trader.weight, weight.saturating_mul(2));
+
+    // 3. Buying weight with a different asset isn't supported by the same trader instance.
+    let payment_multi_asset = MultiAsset {
+        id: xcm::latest::AssetId::Concrete(PARENT),
+        fun: Fungibility::Fungible(total_payment),
+    };
+    assert_eq!(
+        trader.buy_weight(weight, payment_multi_asset.into()),
+        Err(XcmError::TooExpensive)
+    );
+    assert_eq!(trader.consumed, expected_execution_fee * 2);
+    assert_eq!(trader.weight, weight.saturating_mul(2));
+}
+
+#[test]
+fn exchange_rate_of_foreign_asset_buy_execution_fails() {
+    let mut trader = ExchangeRateTrader::new();
+
+    // Payment is too small for the execution fee
+    let total_payment = 1_000;
+    let payment_multi_asset = MultiAsset {
+        id: xcm::latest::AssetId::Concrete(PARENT),
+        fun: Fungibility::Fungible(total_payment),
+    };
+    let weight: Weight = Weight::from_parts(3_000, 0);
+    assert_eq!(
+        trader.buy_weight(weight, payment_multi_asset.into()),
+        Err(XcmError::TooExpensive)
+    );
+
+    // Asset without exchange rate isn't supported
+    let payment_multi_asset = MultiAsset {
+        id: xcm::latest::AssetId::Concrete(GENERAL_INDEX),
+        fun: Fungibility::Fungible(total_payment),
+    };
+    assert_eq!(
+        trader.buy_weight(Weight::from_parts(1, 0), payment_multi_asset.into()),
+        Err(XcmError::TooExpensive)
+    );
+
+    // Asset with a stale exchange rate isn't supported
+    let payment_multi_asset = MultiAsset {
+        id: xcm::latest::AssetId::Concrete(STALE_RATE_ASSET),
+        fun: Fungibility::Fungible(total_payment),
+    };
+    assert_eq!(
+        trader.buy_weight(Weight::from_parts(1, 0), payment_multi_asset.into()),
+        Err(XcmError::TooExpensive)
+    );
+
+    assert!(trader.consumed.is_zero());
+    assert!(trader.asset_location_and_rate.is_none());
+}
+
+#[test]
+fn exchange_rate_of_foreign_asset_refund_is_ok() {
+    let mut trader = ExchangeRateTrader::new();
+
+    let total_payment = 10_000;
+    let payment_multi_asset = MultiAsset {
+        id: xcm::latest::AssetId::Concrete(PARENT),
+        fun: Fungibility::Fungible(total_payment),
+    };
+    let weight: Weight = Weight::from_parts(8_001, 0);
+    assert_ok!(trader.buy_weight(weight, payment_multi_asset.into()));
+    // Rate is 1/2, rounded down
+    assert_eq!(trader.consumed, 4_000);
+
+    // Refund a quarter of the weight
+    let result = trader.refund_weight(Weight::from_parts(2_000, 0));
+    assert_eq!(result, Some((PARENT, 1_000_u128).into()));
+    assert_eq!(trader.weight, Weight::from_parts(6_001, 0));
+    assert_eq!(trader.consumed, 3_000);
+
+    // Refund more than remains, everything consumed is returned, including rounding leftovers
+    let result = trader.refund_weight(weight);
+    assert_eq!(result, Some((PARENT, 3_000_u128).into()));
+    assert!(trader.weight.is_zero());
+    assert!(trader.consumed.is_zero());
+
+    // Nothing left to refund
+    assert!(trader.refund_weight(weight).is_none());
+}
+
+#[test]
+fn exchange_rate_of_foreign_asset_revenue_is_taken_on_drop() {
+    REVENUE.with(|r| r.borrow_mut().clear());
+
+    // Unused trader doesn't take any revenue
+    drop(ExchangeRateTrader::new());
+    REVENUE.with(|r| assert!(r.borrow().is_empty()));
+
+    // Consumed amount reduced by the refund is taken as revenue
+    {
+        let mut trader = ExchangeRateTrader::new();
+        let payment_multi_asset = MultiAsset {
+            id: xcm::latest::AssetId::Concrete(PARACHAIN),
+            fun: Fungibility::Fungible(10_000),
+        };
+        assert_ok!(trader.buy_weight(Weight::from_parts(2_000, 0), payment_multi_asset.into()));
+        assert_eq!(
+            trader.refund_weight(Weight::from_parts(500, 0)),
+            Some((PARACHAIN, 1_500_u128).into())
+        );
+    }
+    REVENUE.with(|r| assert_eq!(*r.borrow(), vec![MultiAsset::from((PARACHAIN, 4_500_u128))]));
+
+    // Fully refunded trader doesn't take any revenue
+    REVENUE.with(|r| r.borrow_mut().clear());
+    {
+        let mut trader = ExchangeRateTrader::new();
+        let payment_multi_asset = MultiAsset {
+            id: xcm::latest::AssetId::Concrete(PARACHAIN),
+            fun: Fungibility::Fungible(10_000),
+        };
+        assert_ok!(trader.buy_weight(Weight::from_parts(2_000, 0), payment_multi_asset.into()));
+        assert!(trader.refund_weight(Weight::from_parts(2_000, 0)).is_some());
+    }
+    REVENUE.with(|r| assert!(r.borrow().is_empty()));
+}
+
 #[test]
 fn reserve_asset_filter_for_sibling_parachain_is_ok() {
     let asset_xc_location = MultiLocation {
@@ -0,0 +1,40 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use astar_primitives::xcm::dry_run::{XcmDryRunEffects, XcmDryRunError};
+use sp_std::vec::Vec;
+use xcm::{VersionedMultiLocation, VersionedXcm};
+
+sp_api::decl_runtime_apis! {
+
+    /// XCM dry-run Api.
+    ///
+    /// Used to estimate effects of an XCM program before sending it.
+    pub trait XcmDryRunApi {
+
+        /// Executes the XCM program from the given origin, without committing any of its effects.
+        ///
+        /// Emitted events are SCALE encoded, and can be decoded using the runtime metadata.
+        fn dry_run_xcm(
+            origin: VersionedMultiLocation,
+            xcm: VersionedXcm<()>,
+        ) -> Result<XcmDryRunEffects<Vec<u8>>, XcmDryRunError>;
+    }
+}
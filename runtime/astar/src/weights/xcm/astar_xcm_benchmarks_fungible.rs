@@ -0,0 +1,144 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for `astar_xcm_benchmarks::fungible`
+//!
+//! Storage accesses & proof sizes follow the benchmarks in `pallets/astar-xcm-benchmarks/src/fungible/benchmarking.rs`,
+//! using the Astar runtime configuration, where a foreign asset is transacted by `pallet-assets`.
+//! Reference times are provisional until the benchmarks are run on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weights for `astar_xcm_benchmarks::fungible`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo<T> {
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: Assets Asset (r:1 w:1)
+	// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	// Storage: Assets Account (r:1 w:1)
+	// Proof: Assets Account (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	pub(crate) fn withdraw_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `12013`
+		Weight::from_parts(56_954_000, 12013)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: Assets Asset (r:1 w:1)
+	// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	// Storage: Assets Account (r:2 w:2)
+	// Proof: Assets Account (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	pub(crate) fn transfer_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `17237`
+		Weight::from_parts(95_785_000, 17237)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: Assets Asset (r:1 w:1)
+	// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	// Storage: Assets Account (r:2 w:2)
+	// Proof: Assets Account (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Proof Skipped: PolkadotXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	pub(crate) fn transfer_reserve_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `17736`
+		Weight::from_parts(120_164_000, 17736)
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AssetReserveLocations (r:1 w:0)
+	// Proof: XcAssetConfig AssetReserveLocations (max_values: None, max_size: Some(4849), added: 7324, mode: MaxEncodedLen)
+	pub(crate) fn reserve_asset_deposited() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `11416`
+		Weight::from_parts(4_134_000, 11416)
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: Assets Asset (r:1 w:1)
+	// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	// Storage: Assets Account (r:1 w:1)
+	// Proof: Assets Account (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	pub(crate) fn deposit_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `12013`
+		Weight::from_parts(53_510_000, 12013)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof: XcAssetConfig AssetLocationToId (max_values: None, max_size: Some(627), added: 3102, mode: MaxEncodedLen)
+	// Storage: Assets Asset (r:1 w:1)
+	// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	// Storage: Assets Account (r:1 w:1)
+	// Proof: Assets Account (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Proof Skipped: PolkadotXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	pub(crate) fn deposit_reserve_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `12512`
+		Weight::from_parts(81_266_000, 12512)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+}
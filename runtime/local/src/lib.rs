@@ -307,9 +307,9 @@ impl pallet_balances::Config for Runtime {
     type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
     type WeightInfo = weights::pallet_balances::SubstrateWeight<Runtime>;
-    type HoldIdentifier = ();
+    type HoldIdentifier = RuntimeHoldReason;
     type FreezeIdentifier = RuntimeFreezeReason;
-    type MaxHolds = ConstU32<0>;
+    type MaxHolds = ConstU32<1>;
     type MaxFreezes = ConstU32<1>;
 }
 
@@ -412,6 +412,25 @@ impl Get<Multiplier> for AdjustmentFactorGetter {
     }
 }
 
+/// Simple wrapper for fetching the EVM gas usage of the current block.
+/// Relies on `pallet-ethereum` finalizing the block before `pallet-dynamic-evm-base-fee`.
+pub struct BlockGasUsageGetter;
+impl Get<pallet_dynamic_evm_base_fee::BlockGasUsage> for BlockGasUsageGetter {
+    fn get() -> pallet_dynamic_evm_base_fee::BlockGasUsage {
+        pallet_ethereum::CurrentBlock::<Runtime>::get()
+            .map(|block| pallet_dynamic_evm_base_fee::BlockGasUsage {
+                used: block.header.gas_used,
+                limit: block.header.gas_limit,
+            })
+            .unwrap_or_default()
+    }
+}
+
+parameter_types! {
+    pub const EvmBaseFeeAdjustmentMode: pallet_dynamic_evm_base_fee::BaseFeeAdjustmentMode =
+        pallet_dynamic_evm_base_fee::BaseFeeAdjustmentMode::FeeAlignment;
+}
+
 impl pallet_dynamic_evm_base_fee::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DefaultBaseFeePerGas = DefaultBaseFeePerGas;
@@ -420,6 +439,14 @@ impl pallet_dynamic_evm_base_fee::Config for Runtime {
     type AdjustmentFactor = AdjustmentFactorGetter;
     type WeightFactor = WeightFeeFactor;
     type StepLimitRatio = StepLimitRatio;
+    type AdjustmentMode = EvmBaseFeeAdjustmentMode;
+    type GasUsage = BlockGasUsageGetter;
+    type Elasticity = ConstU32<2>;
+    type BaseFeeHistoryLength = ConstU32<256>;
+    type UpdateOrigin = EitherOfDiverse<
+        EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+    >;
     type WeightInfo = pallet_dynamic_evm_base_fee::weights::SubstrateWeight<Runtime>;
 }
 
@@ -494,11 +521,28 @@ impl pallet_dapps_staking::Config for Runtime {
     type ForcePalletDisabled = ConstBool<false>; // This will be set to `true` when needed
 }
 
-pub struct DummyPriceProvider;
-impl pallet_dapp_staking_v3::PriceProvider for DummyPriceProvider {
-    fn average_price() -> FixedU64 {
-        FixedU64::from_rational(1, 10)
-    }
+parameter_types! {
+    pub const PriceOracleTwapWindow: BlockNumber = HOURS;
+    // Enough to hold a price point for each block in the TWAP window.
+    pub const PriceOracleMaxHistoryLength: u32 = HOURS + 1;
+    pub const PriceOracleMaxDeviation: Perbill = Perbill::from_percent(10);
+    pub PriceOracleDefaultPrice: FixedU64 = FixedU64::from_rational(1, 10);
+}
+
+impl pallet_price_oracle::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type MaxFeeders = ConstU32<16>;
+    type TwapWindow = PriceOracleTwapWindow;
+    type MaxHistoryLength = PriceOracleMaxHistoryLength;
+    type MaxDeviation = PriceOracleMaxDeviation;
+    type DefaultPrice = PriceOracleDefaultPrice;
+    type WeightInfo = pallet_price_oracle::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const DAppMetadataDepositBase: Balance = deposit(1, 0);
+    pub const DAppMetadataDepositPerByte: Balance = deposit(0, 1);
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -521,11 +565,13 @@ impl pallet_dapp_staking_v3::BenchmarkHelper<SmartContract<AccountId>, AccountId
 impl pallet_dapp_staking_v3::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeFreezeReason = RuntimeFreezeReason;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type SmartContract = SmartContract<AccountId>;
     type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
-    type NativePriceProvider = DummyPriceProvider;
+    type NativePriceProvider = PriceOracle;
     type StakingRewardHandler = Inflation;
+    type TierAssignment = pallet_dapp_staking_v3::StakeAmountTierAssignment;
     type CycleConfiguration = InflationCycleConfig;
     type EraRewardSpanLength = ConstU32<8>;
     type RewardRetentionInPeriods = ConstU32<2>;
@@ -536,6 +582,12 @@ impl pallet_dapp_staking_v3::Config for Runtime {
     type MaxNumberOfStakedContracts = ConstU32<3>;
     type MinimumStakeAmount = ConstU128<AST>;
     type NumberOfTiers = ConstU32<4>;
+    type MaxDAppRewardClaimEras = ConstU32<16>;
+    type MaxRewardSplits = ConstU32<8>;
+    type MaxDAppNameLength = ConstU32<64>;
+    type MaxDAppWebsiteLength = ConstU32<128>;
+    type MetadataDepositBase = DAppMetadataDepositBase;
+    type MetadataDepositPerByte = DAppMetadataDepositPerByte;
     type WeightInfo = pallet_dapp_staking_v3::weights::SubstrateWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<SmartContract<AccountId>, AccountId>;
@@ -550,6 +602,10 @@ impl pallet_inflation::PayoutPerBlock<NegativeImbalance> for InflationPayoutPerB
     fn collators(_reward: NegativeImbalance) {
         // no collators for local dev node
     }
+    fn extra_beneficiary(id: &pallet_inflation::BeneficiaryId, reward: NegativeImbalance) {
+        // Each extra beneficiary gets its own pallet-like account, derived from its name.
+        Balances::resolve_creating(&PalletId(*id).into_account_truncating(), reward);
+    }
 }
 
 pub struct InflationCycleConfig;
@@ -576,6 +632,7 @@ impl pallet_inflation::Config for Runtime {
     type PayoutPerBlock = InflationPayoutPerBlock;
     type CycleConfiguration = InflationCycleConfig;
     type RuntimeEvent = RuntimeEvent;
+    type MaxScheduledInflationParams = ConstU32<16>;
     type WeightInfo = pallet_inflation::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1143,6 +1200,7 @@ construct_runtime!(
         DappStaking: pallet_dapp_staking_v3,
         DappStakingMigration: pallet_dapp_staking_migration,
         Inflation: pallet_inflation,
+        PriceOracle: pallet_price_oracle,
         BlockReward: pallet_block_rewards_hybrid,
         TransactionPayment: pallet_transaction_payment,
         EVM: pallet_evm,
@@ -1282,6 +1340,7 @@ mod benches {
         [pallet_dapp_staking_v3, DappStaking]
         [pallet_dapp_staking_migration, DappStakingMigration]
         [pallet_inflation, Inflation]
+        [pallet_price_oracle, PriceOracle]
         [pallet_dynamic_evm_base_fee, DynamicEvmBaseFee]
         [pallet_uniques, Uniques]
     );
@@ -1676,7 +1735,7 @@ impl_runtime_apis! {
         }
 
         fn elasticity() -> Option<Permill> {
-            Some(Permill::zero())
+            Some(DynamicEvmBaseFee::fee_change_rate())
         }
 
         fn gas_limit_multiplier_support() {}
@@ -1771,6 +1830,7 @@ impl_runtime_apis! {
         }
     }
 
+    #[api_version(2)]
     impl dapp_staking_v3_runtime_api::DappStakingApi<Block> for Runtime {
         fn eras_per_voting_subperiod() -> pallet_dapp_staking_v3::EraNumber {
             InflationCycleConfig::eras_per_voting_subperiod()
@@ -1783,6 +1843,58 @@ impl_runtime_apis! {
         fn blocks_per_era() -> BlockNumber {
             InflationCycleConfig::blocks_per_era()
         }
+
+        fn pending_staker_rewards(account: AccountId) -> Balance {
+            DappStaking::pending_staker_rewards(&account)
+        }
+
+        fn pending_bonus_rewards(account: AccountId) -> Vec<(SmartContract<AccountId>, Balance)> {
+            DappStaking::pending_bonus_rewards(&account)
+        }
+
+        fn pending_dapp_rewards(smart_contract: SmartContract<AccountId>) -> Vec<(pallet_dapp_staking_v3::EraNumber, Balance)> {
+            DappStaking::pending_dapp_rewards(&smart_contract)
+        }
+
+        fn dapp_tier(smart_contract: SmartContract<AccountId>) -> Option<pallet_dapp_staking_v3::TierId> {
+            DappStaking::dapp_tier(&smart_contract)
+        }
+
+        fn tier_thresholds() -> Vec<Balance> {
+            DappStaking::tier_thresholds()
+        }
+
+        fn dapp_metadata(smart_contract: SmartContract<AccountId>) -> Option<pallet_dapp_staking_v3::DAppMetadataInfo> {
+            DappStaking::dapp_metadata(&smart_contract)
+        }
+    }
+
+    impl inflation_runtime_api::InflationApi<Block> for Runtime {
+        fn inflation_configuration() -> pallet_inflation::InflationConfiguration {
+            pallet_inflation::ActiveInflationConfig::<Runtime>::get()
+        }
+
+        fn inflation_parameters() -> pallet_inflation::InflationParameters {
+            pallet_inflation::InflationParams::<Runtime>::get()
+        }
+
+        fn projected_inflation_configuration() -> pallet_inflation::InflationConfiguration {
+            Inflation::projected_inflation_config()
+        }
+
+        fn reward_pools(total_value_staked: Balance) -> pallet_inflation::RewardPoolsBreakdown {
+            Inflation::reward_pools(total_value_staked)
+        }
+
+        fn project_inflation(cycles: u32) -> Vec<pallet_inflation::CycleInflationProjection> {
+            Inflation::project_inflation(cycles)
+        }
+    }
+
+    impl dynamic_evm_base_fee_runtime_api::DynamicEvmBaseFeeApi<Block, BlockNumber> for Runtime {
+        fn base_fee_history(count: u32) -> Vec<pallet_dynamic_evm_base_fee::BaseFeeRecord<BlockNumber>> {
+            DynamicEvmBaseFee::base_fee_history(count)
+        }
     }
 
     #[cfg(feature = "runtime-benchmarks")]
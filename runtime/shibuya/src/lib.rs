@@ -421,13 +421,28 @@ impl pallet_dapps_staking::Config for Runtime {
     type ForcePalletDisabled = ConstBool<true>;
 }
 
-// Placeholder until we introduce a pallet for this.
-// Real solution will be an oracle.
-pub struct DummyPriceProvider;
-impl pallet_dapp_staking_v3::PriceProvider for DummyPriceProvider {
-    fn average_price() -> FixedU64 {
-        FixedU64::from_rational(1, 10)
-    }
+parameter_types! {
+    pub const PriceOracleTwapWindow: BlockNumber = HOURS;
+    // Enough to hold a price point for each block in the TWAP window.
+    pub const PriceOracleMaxHistoryLength: u32 = HOURS + 1;
+    pub const PriceOracleMaxDeviation: Perbill = Perbill::from_percent(10);
+    pub PriceOracleDefaultPrice: FixedU64 = FixedU64::from_rational(1, 10);
+}
+
+impl pallet_price_oracle::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type MaxFeeders = ConstU32<16>;
+    type TwapWindow = PriceOracleTwapWindow;
+    type MaxHistoryLength = PriceOracleMaxHistoryLength;
+    type MaxDeviation = PriceOracleMaxDeviation;
+    type DefaultPrice = PriceOracleDefaultPrice;
+    type WeightInfo = weights::pallet_price_oracle::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const DAppMetadataDepositBase: Balance = deposit(1, 0);
+    pub const DAppMetadataDepositPerByte: Balance = deposit(0, 1);
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -454,11 +469,13 @@ impl pallet_dapp_staking_v3::BenchmarkHelper<SmartContract<AccountId>, AccountId
 impl pallet_dapp_staking_v3::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeFreezeReason = RuntimeFreezeReason;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type SmartContract = SmartContract<AccountId>;
     type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
-    type NativePriceProvider = DummyPriceProvider;
+    type NativePriceProvider = PriceOracle;
     type StakingRewardHandler = Inflation;
+    type TierAssignment = pallet_dapp_staking_v3::StakeAmountTierAssignment;
     type CycleConfiguration = InflationCycleConfig;
     type EraRewardSpanLength = ConstU32<16>;
     type RewardRetentionInPeriods = ConstU32<2>; // Low enough value so we can get some expired rewards during testing
@@ -469,6 +486,12 @@ impl pallet_dapp_staking_v3::Config for Runtime {
     type MaxNumberOfStakedContracts = ConstU32<8>;
     type MinimumStakeAmount = MinimumStakingAmount;
     type NumberOfTiers = ConstU32<4>;
+    type MaxDAppRewardClaimEras = ConstU32<16>;
+    type MaxRewardSplits = ConstU32<8>;
+    type MaxDAppNameLength = ConstU32<64>;
+    type MaxDAppWebsiteLength = ConstU32<128>;
+    type MetadataDepositBase = DAppMetadataDepositBase;
+    type MetadataDepositPerByte = DAppMetadataDepositPerByte;
     type WeightInfo = weights::pallet_dapp_staking_v3::SubstrateWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<SmartContract<AccountId>, AccountId>;
@@ -483,6 +506,10 @@ impl pallet_inflation::PayoutPerBlock<NegativeImbalance> for InflationPayoutPerB
     fn collators(reward: NegativeImbalance) {
         ToStakingPot::on_unbalanced(reward);
     }
+    fn extra_beneficiary(id: &pallet_inflation::BeneficiaryId, reward: NegativeImbalance) {
+        // Each extra beneficiary gets its own pallet-like account, derived from its name.
+        Balances::resolve_creating(&PalletId(*id).into_account_truncating(), reward);
+    }
 }
 
 pub struct InflationCycleConfig;
@@ -509,6 +536,7 @@ impl pallet_inflation::Config for Runtime {
     type PayoutPerBlock = InflationPayoutPerBlock;
     type CycleConfiguration = InflationCycleConfig;
     type RuntimeEvent = RuntimeEvent;
+    type MaxScheduledInflationParams = ConstU32<16>;
     type WeightInfo = pallet_inflation::weights::SubstrateWeight<Runtime>;
 }
 
@@ -584,9 +612,30 @@ parameter_types! {
     pub const MinCandidates: u32 = 5;
     pub const MaxInvulnerables: u32 = 48;
     pub const SlashRatio: Perbill = Perbill::from_percent(1);
+    pub const RepeatedOffenceSlashRatio: Perbill = Perbill::from_percent(10);
+    pub const CollatorJailPeriod: BlockNumber = 7 * DAYS;
+    pub const MaxDelegatorsPerCandidate: u32 = 32;
+    pub const MinDelegation: Balance = 100 * SBY;
+    pub const DelegationUnbondingPeriod: BlockNumber = 7 * DAYS;
+    pub const MaxPerformanceHistory: u32 = 48;
     pub const KickThreshold: BlockNumber = 2 * HOURS; // 2 SessionPeriod
 }
 
+/// Aura slot of the block being executed, read from its pre-runtime digest.
+pub struct AuraSlot;
+impl Get<u64> for AuraSlot {
+    fn get() -> u64 {
+        System::digest()
+            .convert_first(|item| {
+                item.pre_runtime_try_to::<sp_consensus_aura::Slot>(
+                    &sp_consensus_aura::AURA_ENGINE_ID,
+                )
+            })
+            .map(u64::from)
+            .unwrap_or_default()
+    }
+}
+
 impl pallet_collator_selection::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -601,6 +650,13 @@ impl pallet_collator_selection::Config for Runtime {
     type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
     type ValidatorRegistration = Session;
     type SlashRatio = SlashRatio;
+    type RepeatedOffenceSlashRatio = RepeatedOffenceSlashRatio;
+    type JailPeriod = CollatorJailPeriod;
+    type MaxDelegatorsPerCandidate = MaxDelegatorsPerCandidate;
+    type MinDelegation = MinDelegation;
+    type UnbondingPeriod = DelegationUnbondingPeriod;
+    type MaxPerformanceHistory = MaxPerformanceHistory;
+    type CurrentSlot = AuraSlot;
     type WeightInfo = pallet_collator_selection::weights::SubstrateWeight<Runtime>;
 }
 
@@ -636,9 +692,9 @@ impl pallet_balances::Config for Runtime {
     type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = frame_system::Pallet<Runtime>;
     type WeightInfo = weights::pallet_balances::SubstrateWeight<Runtime>;
-    type HoldIdentifier = ();
+    type HoldIdentifier = RuntimeHoldReason;
     type FreezeIdentifier = RuntimeFreezeReason;
-    type MaxHolds = ConstU32<0>;
+    type MaxHolds = ConstU32<1>;
     type MaxFreezes = ConstU32<1>;
 }
 
@@ -831,6 +887,25 @@ impl Get<Multiplier> for AdjustmentFactorGetter {
     }
 }
 
+/// Simple wrapper for fetching the EVM gas usage of the current block.
+/// Relies on `pallet-ethereum` finalizing the block before `pallet-dynamic-evm-base-fee`.
+pub struct BlockGasUsageGetter;
+impl Get<pallet_dynamic_evm_base_fee::BlockGasUsage> for BlockGasUsageGetter {
+    fn get() -> pallet_dynamic_evm_base_fee::BlockGasUsage {
+        pallet_ethereum::CurrentBlock::<Runtime>::get()
+            .map(|block| pallet_dynamic_evm_base_fee::BlockGasUsage {
+                used: block.header.gas_used,
+                limit: block.header.gas_limit,
+            })
+            .unwrap_or_default()
+    }
+}
+
+parameter_types! {
+    pub const EvmBaseFeeAdjustmentMode: pallet_dynamic_evm_base_fee::BaseFeeAdjustmentMode =
+        pallet_dynamic_evm_base_fee::BaseFeeAdjustmentMode::FeeAlignment;
+}
+
 impl pallet_dynamic_evm_base_fee::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DefaultBaseFeePerGas = DefaultBaseFeePerGas;
@@ -839,6 +914,14 @@ impl pallet_dynamic_evm_base_fee::Config for Runtime {
     type AdjustmentFactor = AdjustmentFactorGetter;
     type WeightFactor = WeightFeeFactor;
     type StepLimitRatio = StepLimitRatio;
+    type AdjustmentMode = EvmBaseFeeAdjustmentMode;
+    type GasUsage = BlockGasUsageGetter;
+    type Elasticity = ConstU32<2>;
+    type BaseFeeHistoryLength = ConstU32<256>;
+    type UpdateOrigin = EitherOfDiverse<
+        EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+    >;
     type WeightInfo = pallet_dynamic_evm_base_fee::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1272,6 +1355,10 @@ impl pallet_xc_asset_config::Config for Runtime {
     type AssetId = AssetId;
     // Good enough for testnet since we lack pallet-assets hooks for now
     type ManagerOrigin = EnsureRoot<AccountId>;
+    type Balance = Balance;
+    type ForeignAssetManager =
+        astar_primitives::xcm::AssetsForeignAssetManager<Runtime, TreasuryAccountId>;
+    type MaxReserveLocations = ConstU32<8>;
     type WeightInfo = pallet_xc_asset_config::weights::SubstrateWeight<Self>;
 }
 
@@ -1343,6 +1430,7 @@ construct_runtime!(
         Inflation: pallet_inflation = 35,
         Assets: pallet_assets = 36,
         Uniques: pallet_uniques = 37,
+        PriceOracle: pallet_price_oracle = 38,
 
         Authorship: pallet_authorship = 40,
         CollatorSelection: pallet_collator_selection = 41,
@@ -1420,7 +1508,12 @@ pub type Executive = frame_executive::Executive<
 /// All migrations that will run on the next runtime upgrade.
 ///
 /// Once done, migrations should be removed from the tuple.
-pub type Migrations = ();
+pub type Migrations = (
+    pallet_dapp_staking_v3::AccountLedgerRestakeMigration<Runtime>,
+    pallet_dapp_staking_v3::DAppInfoRewardSplitsMigration<Runtime>,
+    pallet_inflation::InflationExtraBeneficiariesMigration<Runtime>,
+    pallet_xc_asset_config::migrations::MigrationReserveLocations<Runtime>,
+);
 
 type EventRecord = frame_system::EventRecord<
     <Runtime as frame_system::Config>::RuntimeEvent,
@@ -1500,10 +1593,13 @@ mod benches {
         [pallet_dapps_staking, DappsStaking]
         [pallet_dapp_staking_v3, DappStaking]
         [pallet_inflation, Inflation]
+        [pallet_price_oracle, PriceOracle]
         [pallet_dapp_staking_migration, DappStakingMigration]
         [pallet_xc_asset_config, XcAssetConfig]
         [pallet_collator_selection, CollatorSelection]
         [pallet_xcm, PolkadotXcm]
+        [astar_xcm_benchmarks::fungible, XcmFungible]
+        [pallet_xcm_benchmarks::generic, XcmGeneric]
         [pallet_ethereum_checked, EthereumChecked]
         [pallet_xvm, Xvm]
         [pallet_dynamic_evm_base_fee, DynamicEvmBaseFee]
@@ -1871,7 +1967,7 @@ impl_runtime_apis! {
         }
 
         fn elasticity() -> Option<Permill> {
-            Some(Permill::zero())
+            Some(DynamicEvmBaseFee::fee_change_rate())
         }
 
         fn gas_limit_multiplier_support() {}
@@ -1966,6 +2062,7 @@ impl_runtime_apis! {
         }
     }
 
+    #[api_version(2)]
     impl dapp_staking_v3_runtime_api::DappStakingApi<Block> for Runtime {
         fn eras_per_voting_subperiod() -> pallet_dapp_staking_v3::EraNumber {
             InflationCycleConfig::eras_per_voting_subperiod()
@@ -1978,6 +2075,78 @@ impl_runtime_apis! {
         fn blocks_per_era() -> BlockNumber {
             InflationCycleConfig::blocks_per_era()
         }
+
+        fn pending_staker_rewards(account: AccountId) -> Balance {
+            DappStaking::pending_staker_rewards(&account)
+        }
+
+        fn pending_bonus_rewards(account: AccountId) -> Vec<(SmartContract<AccountId>, Balance)> {
+            DappStaking::pending_bonus_rewards(&account)
+        }
+
+        fn pending_dapp_rewards(smart_contract: SmartContract<AccountId>) -> Vec<(pallet_dapp_staking_v3::EraNumber, Balance)> {
+            DappStaking::pending_dapp_rewards(&smart_contract)
+        }
+
+        fn dapp_tier(smart_contract: SmartContract<AccountId>) -> Option<pallet_dapp_staking_v3::TierId> {
+            DappStaking::dapp_tier(&smart_contract)
+        }
+
+        fn tier_thresholds() -> Vec<Balance> {
+            DappStaking::tier_thresholds()
+        }
+
+        fn dapp_metadata(smart_contract: SmartContract<AccountId>) -> Option<pallet_dapp_staking_v3::DAppMetadataInfo> {
+            DappStaking::dapp_metadata(&smart_contract)
+        }
+    }
+
+    impl inflation_runtime_api::InflationApi<Block> for Runtime {
+        fn inflation_configuration() -> pallet_inflation::InflationConfiguration {
+            pallet_inflation::ActiveInflationConfig::<Runtime>::get()
+        }
+
+        fn inflation_parameters() -> pallet_inflation::InflationParameters {
+            pallet_inflation::InflationParams::<Runtime>::get()
+        }
+
+        fn projected_inflation_configuration() -> pallet_inflation::InflationConfiguration {
+            Inflation::projected_inflation_config()
+        }
+
+        fn reward_pools(total_value_staked: Balance) -> pallet_inflation::RewardPoolsBreakdown {
+            Inflation::reward_pools(total_value_staked)
+        }
+
+        fn project_inflation(cycles: u32) -> Vec<pallet_inflation::CycleInflationProjection> {
+            Inflation::project_inflation(cycles)
+        }
+    }
+
+    impl collator_selection_runtime_api::CollatorSelectionApi<Block, AccountId, Balance> for Runtime {
+        fn current_stats(collator: AccountId) -> pallet_collator_selection::CollatorStats<Balance> {
+            CollatorSelection::current_stats(collator)
+        }
+
+        fn performance_history(collator: AccountId) -> Vec<pallet_collator_selection::SessionPerformance<Balance>> {
+            CollatorSelection::performance_history(collator).into_inner()
+        }
+    }
+
+    impl dynamic_evm_base_fee_runtime_api::DynamicEvmBaseFeeApi<Block, BlockNumber> for Runtime {
+        fn base_fee_history(count: u32) -> Vec<pallet_dynamic_evm_base_fee::BaseFeeRecord<BlockNumber>> {
+            DynamicEvmBaseFee::base_fee_history(count)
+        }
+    }
+
+    impl xcm_dry_run_runtime_api::XcmDryRunApi<Block> for Runtime {
+        fn dry_run_xcm(
+            origin: xcm::VersionedMultiLocation,
+            xcm: xcm::VersionedXcm<()>,
+        ) -> Result<xcm_dry_run_runtime_api::XcmDryRunEffects<Vec<u8>>, xcm_dry_run_runtime_api::XcmDryRunError> {
+            astar_primitives::xcm::dry_run::dry_run_xcm::<Runtime, xcm_config::XcmConfig>(origin, xcm)
+                .map(|effects| effects.encode_events())
+        }
     }
 
     #[cfg(feature = "runtime-benchmarks")]
@@ -1991,6 +2160,9 @@ impl_runtime_apis! {
             use frame_system_benchmarking::Pallet as SystemBench;
             use baseline::Pallet as BaselineBench;
 
+            type XcmFungible = astar_xcm_benchmarks::fungible::Pallet::<Runtime>;
+            type XcmGeneric = pallet_xcm_benchmarks::generic::Pallet::<Runtime>;
+
             let mut list = Vec::<BenchmarkList>::new();
             list_benchmarks!(list, extra);
 
@@ -2009,6 +2181,77 @@ impl_runtime_apis! {
             impl frame_system_benchmarking::Config for Runtime {}
             impl baseline::Config for Runtime {}
 
+            use frame_benchmarking::BenchmarkError;
+            use xcm::latest::prelude::*;
+            use xcm_config::{ShibuyaLocation, LocationToAccountId, XcmConfig};
+
+            impl pallet_xcm_benchmarks::Config for Runtime {
+                type XcmConfig = XcmConfig;
+                type AccountIdConverter = LocationToAccountId;
+
+                fn valid_destination() -> Result<MultiLocation, BenchmarkError> {
+                    Ok(MultiLocation::parent())
+                }
+
+                fn worst_case_holding(_depositable_count: u32) -> MultiAssets {
+                    // Only the native currency is benchmarked.
+                    vec![MultiAsset {
+                        id: Concrete(ShibuyaLocation::get()),
+                        fun: Fungible(u128::MAX),
+                    }]
+                    .into()
+                }
+            }
+
+            impl astar_xcm_benchmarks::fungible::Config for Runtime {}
+
+            impl pallet_xcm_benchmarks::generic::Config for Runtime {
+                type RuntimeCall = RuntimeCall;
+
+                fn worst_case_response() -> (u64, Response) {
+                    (0u64, Response::Version(Default::default()))
+                }
+
+                fn worst_case_asset_exchange() -> Result<(MultiAssets, MultiAssets), BenchmarkError> {
+                    // Asset exchange isn't supported.
+                    Err(BenchmarkError::Skip)
+                }
+
+                fn universal_alias() -> Result<(MultiLocation, Junction), BenchmarkError> {
+                    // Universal aliases aren't supported.
+                    Err(BenchmarkError::Skip)
+                }
+
+                fn transact_origin_and_runtime_call() -> Result<(MultiLocation, RuntimeCall), BenchmarkError> {
+                    Ok((MultiLocation::parent(), frame_system::Call::remark_with_event { remark: vec![] }.into()))
+                }
+
+                fn subscribe_origin() -> Result<MultiLocation, BenchmarkError> {
+                    Ok(MultiLocation::parent())
+                }
+
+                fn claimable_asset() -> Result<(MultiLocation, MultiLocation, MultiAssets), BenchmarkError> {
+                    let origin = MultiLocation::parent();
+                    let assets: MultiAssets = (Concrete(ShibuyaLocation::get()), 1_000 * SBY).into();
+                    let ticket = MultiLocation { parents: 0, interior: Here };
+                    Ok((origin, ticket, assets))
+                }
+
+                fn unlockable_asset() -> Result<(MultiLocation, MultiLocation, MultiAsset), BenchmarkError> {
+                    // Asset locking isn't supported.
+                    Err(BenchmarkError::Skip)
+                }
+
+                fn export_message_origin_and_destination(
+                ) -> Result<(MultiLocation, NetworkId, InteriorMultiLocation), BenchmarkError> {
+                    // Message exporting isn't supported.
+                    Err(BenchmarkError::Skip)
+                }
+            }
+
+            type XcmFungible = astar_xcm_benchmarks::fungible::Pallet::<Runtime>;
+            type XcmGeneric = pallet_xcm_benchmarks::generic::Pallet::<Runtime>;
+
             use frame_support::traits::WhitelistedStorageKeys;
             let whitelist: Vec<TrackedStorageKey> = AllPalletsWithSystem::whitelisted_storage_keys();
 
@@ -21,6 +21,8 @@ pub mod pallet_balances;
 pub mod pallet_dapp_staking_migration;
 pub mod pallet_dapp_staking_v3;
 pub mod pallet_inflation;
+pub mod pallet_price_oracle;
 pub mod pallet_uniques;
 pub mod pallet_uniques;
 pub mod pallet_xcm;
+pub mod xcm;
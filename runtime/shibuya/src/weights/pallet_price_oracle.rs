@@ -0,0 +1,80 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for pallet_price_oracle
+//!
+//! Storage accesses & proof sizes follow the benchmarks in `benchmarking.rs`, using the Shibuya runtime configuration
+//! (`MaxFeeders = 16`, `MaxHistoryLength = HOURS + 1`).
+//! Reference times are provisional until the benchmarks are run on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_price_oracle::WeightInfo;
+
+/// Weights for pallet_price_oracle using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: PriceOracle Feeders (r:1 w:0)
+	/// Proof: PriceOracle Feeders (max_values: Some(1), max_size: Some(513), added: 1008, mode: MaxEncodedLen)
+	/// Storage: PriceOracle CurrentValues (r:1 w:1)
+	/// Proof: PriceOracle CurrentValues (max_values: Some(1), max_size: Some(641), added: 1136, mode: MaxEncodedLen)
+	fn submit_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `2126`
+		Weight::from_parts(17_885_000, 2126)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PriceOracle Feeders (r:1 w:1)
+	/// Proof: PriceOracle Feeders (max_values: Some(1), max_size: Some(513), added: 1008, mode: MaxEncodedLen)
+	fn add_feeder() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `1998`
+		Weight::from_parts(13_611_000, 1998)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PriceOracle Feeders (r:1 w:1)
+	/// Proof: PriceOracle Feeders (max_values: Some(1), max_size: Some(513), added: 1008, mode: MaxEncodedLen)
+	/// Storage: PriceOracle CurrentValues (r:1 w:1)
+	/// Proof: PriceOracle CurrentValues (max_values: Some(1), max_size: Some(641), added: 1136, mode: MaxEncodedLen)
+	fn remove_feeder() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `2126`
+		Weight::from_parts(16_378_000, 2126)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PriceOracle CurrentValues (r:1 w:1)
+	/// Proof: PriceOracle CurrentValues (max_values: Some(1), max_size: Some(641), added: 1136, mode: MaxEncodedLen)
+	/// Storage: PriceOracle PriceHistory (r:1 w:1)
+	/// Proof: PriceOracle PriceHistory (max_values: Some(1), max_size: Some(3614), added: 4109, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn aggregate_price(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `5099`
+		Weight::from_parts(14_870_115, 5099)
+			.saturating_add(Weight::from_parts(1_152_403, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
@@ -0,0 +1,252 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! XCM weights, based on the `astar-xcm-benchmarks` fungible & the `pallet-xcm-benchmarks` generic results.
+
+mod astar_xcm_benchmarks_fungible;
+mod pallet_xcm_benchmarks_generic;
+
+use crate::Runtime;
+use astar_xcm_benchmarks_fungible::WeightInfo as XcmFungibleWeight;
+use frame_support::weights::Weight;
+use pallet_xcm_benchmarks_generic::WeightInfo as XcmGeneric;
+use sp_std::prelude::*;
+use xcm::{latest::prelude::*, DoubleEncoded};
+
+/// Max number of assets used to weigh the instructions operating on a wildcard asset filter.
+const MAX_ASSETS: u64 = 100;
+
+trait WeighMultiAssets {
+    fn weigh_multi_assets(&self, weight: Weight) -> Weight;
+}
+
+impl WeighMultiAssets for MultiAssetFilter {
+    fn weigh_multi_assets(&self, weight: Weight) -> Weight {
+        match self {
+            Self::Definite(assets) => weight.saturating_mul(assets.inner().len() as u64),
+            Self::Wild(asset) => match asset {
+                All => weight.saturating_mul(MAX_ASSETS),
+                AllOf { fun, .. } => match fun {
+                    WildFungibility::Fungible => weight,
+                    WildFungibility::NonFungible => weight.saturating_mul(MAX_ASSETS),
+                },
+                AllCounted(count) => weight.saturating_mul(MAX_ASSETS.min(*count as u64)),
+                AllOfCounted { count, .. } => weight.saturating_mul(MAX_ASSETS.min(*count as u64)),
+            },
+        }
+    }
+}
+
+impl WeighMultiAssets for MultiAssets {
+    fn weigh_multi_assets(&self, weight: Weight) -> Weight {
+        weight.saturating_mul(self.inner().len() as u64)
+    }
+}
+
+/// Benchmarked weights of the XCM instructions.
+///
+/// Instructions which aren't supported by the `XcmConfig` are weighed as `Weight::MAX`, so they can never be executed.
+pub struct XcmWeight<RuntimeCall>(core::marker::PhantomData<RuntimeCall>);
+impl<RuntimeCall> XcmWeightInfo<RuntimeCall> for XcmWeight<RuntimeCall> {
+    fn withdraw_asset(assets: &MultiAssets) -> Weight {
+        assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::withdraw_asset())
+    }
+    fn reserve_asset_deposited(assets: &MultiAssets) -> Weight {
+        assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::reserve_asset_deposited())
+    }
+    fn receive_teleported_asset(_assets: &MultiAssets) -> Weight {
+        // Teleports aren't supported.
+        Weight::MAX
+    }
+    fn query_response(
+        _query_id: &u64,
+        _response: &Response,
+        _max_weight: &Weight,
+        _querier: &Option<MultiLocation>,
+    ) -> Weight {
+        XcmGeneric::<Runtime>::query_response()
+    }
+    fn transfer_asset(assets: &MultiAssets, _dest: &MultiLocation) -> Weight {
+        assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::transfer_asset())
+    }
+    fn transfer_reserve_asset(
+        assets: &MultiAssets,
+        _dest: &MultiLocation,
+        _xcm: &Xcm<()>,
+    ) -> Weight {
+        assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::transfer_reserve_asset())
+    }
+    fn transact(
+        _origin_type: &OriginKind,
+        _require_weight_at_most: &Weight,
+        _call: &DoubleEncoded<RuntimeCall>,
+    ) -> Weight {
+        XcmGeneric::<Runtime>::transact()
+    }
+    fn hrmp_new_channel_open_request(
+        _sender: &u32,
+        _max_message_size: &u32,
+        _max_capacity: &u32,
+    ) -> Weight {
+        // Only relay chain sends this instruction.
+        Weight::MAX
+    }
+    fn hrmp_channel_accepted(_recipient: &u32) -> Weight {
+        // Only relay chain sends this instruction.
+        Weight::MAX
+    }
+    fn hrmp_channel_closing(_initiator: &u32, _sender: &u32, _recipient: &u32) -> Weight {
+        // Only relay chain sends this instruction.
+        Weight::MAX
+    }
+    fn clear_origin() -> Weight {
+        XcmGeneric::<Runtime>::clear_origin()
+    }
+    fn descend_origin(_who: &InteriorMultiLocation) -> Weight {
+        XcmGeneric::<Runtime>::descend_origin()
+    }
+    fn report_error(_query_response_info: &QueryResponseInfo) -> Weight {
+        XcmGeneric::<Runtime>::report_error()
+    }
+    fn deposit_asset(assets: &MultiAssetFilter, _dest: &MultiLocation) -> Weight {
+        assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::deposit_asset())
+    }
+    fn deposit_reserve_asset(
+        assets: &MultiAssetFilter,
+        _dest: &MultiLocation,
+        _xcm: &Xcm<()>,
+    ) -> Weight {
+        assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::deposit_reserve_asset())
+    }
+    fn exchange_asset(_give: &MultiAssetFilter, _receive: &MultiAssets, _maximal: &bool) -> Weight {
+        XcmGeneric::<Runtime>::exchange_asset()
+    }
+    fn initiate_reserve_withdraw(
+        assets: &MultiAssetFilter,
+        _reserve: &MultiLocation,
+        _xcm: &Xcm<()>,
+    ) -> Weight {
+        assets.weigh_multi_assets(XcmGeneric::<Runtime>::initiate_reserve_withdraw())
+    }
+    fn initiate_teleport(
+        _assets: &MultiAssetFilter,
+        _dest: &MultiLocation,
+        _xcm: &Xcm<()>,
+    ) -> Weight {
+        // Teleports aren't supported.
+        Weight::MAX
+    }
+    fn report_holding(_response_info: &QueryResponseInfo, _assets: &MultiAssetFilter) -> Weight {
+        XcmGeneric::<Runtime>::report_holding()
+    }
+    fn buy_execution(_fees: &MultiAsset, _weight_limit: &WeightLimit) -> Weight {
+        XcmGeneric::<Runtime>::buy_execution()
+    }
+    fn refund_surplus() -> Weight {
+        XcmGeneric::<Runtime>::refund_surplus()
+    }
+    fn set_error_handler(_xcm: &Xcm<RuntimeCall>) -> Weight {
+        XcmGeneric::<Runtime>::set_error_handler()
+    }
+    fn set_appendix(_xcm: &Xcm<RuntimeCall>) -> Weight {
+        XcmGeneric::<Runtime>::set_appendix()
+    }
+    fn clear_error() -> Weight {
+        XcmGeneric::<Runtime>::clear_error()
+    }
+    fn claim_asset(_assets: &MultiAssets, _ticket: &MultiLocation) -> Weight {
+        XcmGeneric::<Runtime>::claim_asset()
+    }
+    fn trap(_code: &u64) -> Weight {
+        XcmGeneric::<Runtime>::trap()
+    }
+    fn subscribe_version(_query_id: &QueryId, _max_response_weight: &Weight) -> Weight {
+        XcmGeneric::<Runtime>::subscribe_version()
+    }
+    fn unsubscribe_version() -> Weight {
+        XcmGeneric::<Runtime>::unsubscribe_version()
+    }
+    fn burn_asset(assets: &MultiAssetFilter) -> Weight {
+        assets.weigh_multi_assets(XcmGeneric::<Runtime>::burn_asset())
+    }
+    fn expect_asset(assets: &MultiAssetFilter) -> Weight {
+        assets.weigh_multi_assets(XcmGeneric::<Runtime>::expect_asset())
+    }
+    fn expect_origin(_origin: &Option<MultiLocation>) -> Weight {
+        XcmGeneric::<Runtime>::expect_origin()
+    }
+    fn expect_error(_error: &Option<(u32, XcmError)>) -> Weight {
+        XcmGeneric::<Runtime>::expect_error()
+    }
+    fn expect_transact_status(_transact_status: &MaybeErrorCode) -> Weight {
+        XcmGeneric::<Runtime>::expect_transact_status()
+    }
+    fn query_pallet(_module_name: &Vec<u8>, _response_info: &QueryResponseInfo) -> Weight {
+        XcmGeneric::<Runtime>::query_pallet()
+    }
+    fn expect_pallet(
+        _index: &u32,
+        _name: &Vec<u8>,
+        _module_name: &Vec<u8>,
+        _crate_major: &u32,
+        _min_crate_minor: &u32,
+    ) -> Weight {
+        XcmGeneric::<Runtime>::expect_pallet()
+    }
+    fn report_transact_status(_response_info: &QueryResponseInfo) -> Weight {
+        XcmGeneric::<Runtime>::report_transact_status()
+    }
+    fn clear_transact_status() -> Weight {
+        XcmGeneric::<Runtime>::clear_transact_status()
+    }
+    fn universal_origin(_: &Junction) -> Weight {
+        XcmGeneric::<Runtime>::universal_origin()
+    }
+    fn export_message(_: &NetworkId, _: &Junctions, _: &Xcm<()>) -> Weight {
+        // Message exporting isn't supported.
+        Weight::MAX
+    }
+    fn lock_asset(_: &MultiAsset, _: &MultiLocation) -> Weight {
+        XcmGeneric::<Runtime>::lock_asset()
+    }
+    fn unlock_asset(_: &MultiAsset, _: &MultiLocation) -> Weight {
+        XcmGeneric::<Runtime>::unlock_asset()
+    }
+    fn note_unlockable(_: &MultiAsset, _: &MultiLocation) -> Weight {
+        XcmGeneric::<Runtime>::note_unlockable()
+    }
+    fn request_unlock(_: &MultiAsset, _: &MultiLocation) -> Weight {
+        XcmGeneric::<Runtime>::request_unlock()
+    }
+    fn set_fees_mode(_: &bool) -> Weight {
+        XcmGeneric::<Runtime>::set_fees_mode()
+    }
+    fn set_topic(_topic: &[u8; 32]) -> Weight {
+        XcmGeneric::<Runtime>::set_topic()
+    }
+    fn clear_topic() -> Weight {
+        XcmGeneric::<Runtime>::clear_topic()
+    }
+    fn alias_origin(_: &MultiLocation) -> Weight {
+        // Origin aliasing isn't supported.
+        Weight::MAX
+    }
+    fn unpaid_execution(_: &WeightLimit, _: &Option<MultiLocation>) -> Weight {
+        XcmGeneric::<Runtime>::unpaid_execution()
+    }
+}
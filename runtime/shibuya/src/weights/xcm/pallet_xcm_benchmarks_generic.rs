@@ -0,0 +1,306 @@
+
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for `pallet_xcm_benchmarks::generic`
+//!
+//! Storage accesses & proof sizes follow the `pallet-xcm-benchmarks` generic benchmarks, using the Shibuya runtime configuration.
+//! Reference times are provisional until the benchmarks are run on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_xcm_benchmarks::generic`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo<T> {
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Proof Skipped: PolkadotXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	pub(crate) fn report_holding() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3599`
+		Weight::from_parts(30_664_000, 3599)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	pub(crate) fn buy_execution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(3_942_000, 0)
+	}
+	// Storage: PolkadotXcm Queries (r:1 w:0)
+	// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	pub(crate) fn query_response() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3534`
+		Weight::from_parts(10_926_000, 3534)
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	pub(crate) fn transact() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(11_693_000, 0)
+	}
+	pub(crate) fn refund_surplus() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(3_991_000, 0)
+	}
+	pub(crate) fn set_error_handler() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(2_840_000, 0)
+	}
+	pub(crate) fn set_appendix() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(2_791_000, 0)
+	}
+	pub(crate) fn clear_error() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(2_815_000, 0)
+	}
+	pub(crate) fn descend_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(3_506_000, 0)
+	}
+	pub(crate) fn clear_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(2_728_000, 0)
+	}
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Proof Skipped: PolkadotXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	pub(crate) fn report_error() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3599`
+		Weight::from_parts(26_460_000, 3599)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: PolkadotXcm AssetTraps (r:1 w:1)
+	// Proof Skipped: PolkadotXcm AssetTraps (max_values: None, max_size: None, mode: Measured)
+	pub(crate) fn claim_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3591`
+		Weight::from_parts(14_797_000, 3591)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	pub(crate) fn trap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(2_866_000, 0)
+	}
+	// Storage: PolkadotXcm VersionNotifyTargets (r:1 w:1)
+	// Proof Skipped: PolkadotXcm VersionNotifyTargets (max_values: None, max_size: None, mode: Measured)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Proof Skipped: PolkadotXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	pub(crate) fn subscribe_version() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3599`
+		Weight::from_parts(28_625_000, 3599)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: PolkadotXcm VersionNotifyTargets (r:0 w:1)
+	// Proof Skipped: PolkadotXcm VersionNotifyTargets (max_values: None, max_size: None, mode: Measured)
+	pub(crate) fn unsubscribe_version() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(5_033_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Proof Skipped: PolkadotXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	pub(crate) fn initiate_reserve_withdraw() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3599`
+		Weight::from_parts(31_264_000, 3599)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	pub(crate) fn burn_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(4_241_000, 0)
+	}
+	pub(crate) fn expect_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(2_913_000, 0)
+	}
+	pub(crate) fn expect_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(2_856_000, 0)
+	}
+	pub(crate) fn expect_error() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(2_823_000, 0)
+	}
+	pub(crate) fn expect_transact_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(2_956_000, 0)
+	}
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Proof Skipped: PolkadotXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	pub(crate) fn query_pallet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3599`
+		Weight::from_parts(28_923_000, 3599)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	pub(crate) fn expect_pallet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(5_372_000, 0)
+	}
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Proof Skipped: PolkadotXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	pub(crate) fn report_transact_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3599`
+		Weight::from_parts(25_985_000, 3599)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	pub(crate) fn clear_transact_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(2_756_000, 0)
+	}
+	pub(crate) fn set_topic() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(2_718_000, 0)
+	}
+	pub(crate) fn clear_topic() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(2_640_000, 0)
+	}
+	pub(crate) fn set_fees_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(2_675_000, 0)
+	}
+	pub(crate) fn unpaid_execution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(2_810_000, 0)
+	}
+	pub(crate) fn exchange_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(18_446_744_073_709_551_000, 0)
+	}
+	pub(crate) fn universal_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(18_446_744_073_709_551_000, 0)
+	}
+	pub(crate) fn lock_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(18_446_744_073_709_551_000, 0)
+	}
+	pub(crate) fn unlock_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(18_446_744_073_709_551_000, 0)
+	}
+	pub(crate) fn note_unlockable() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(18_446_744_073_709_551_000, 0)
+	}
+	pub(crate) fn request_unlock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(18_446_744_073_709_551_000, 0)
+	}
+}
@@ -431,9 +431,30 @@ parameter_types! {
     pub const MinCandidates: u32 = 5;
     pub const MaxInvulnerables: u32 = 48;
     pub const SlashRatio: Perbill = Perbill::from_percent(1);
+    pub const RepeatedOffenceSlashRatio: Perbill = Perbill::from_percent(10);
+    pub const CollatorJailPeriod: BlockNumber = 7 * DAYS;
+    pub const MaxDelegatorsPerCandidate: u32 = 32;
+    pub const MinDelegation: Balance = 100 * SDN;
+    pub const DelegationUnbondingPeriod: BlockNumber = 7 * DAYS;
+    pub const MaxPerformanceHistory: u32 = 48;
     pub const KickThreshold: BlockNumber = 2 * HOURS; // 2 SessionPeriod
 }
 
+/// Aura slot of the block being executed, read from its pre-runtime digest.
+pub struct AuraSlot;
+impl Get<u64> for AuraSlot {
+    fn get() -> u64 {
+        System::digest()
+            .convert_first(|item| {
+                item.pre_runtime_try_to::<sp_consensus_aura::Slot>(
+                    &sp_consensus_aura::AURA_ENGINE_ID,
+                )
+            })
+            .map(u64::from)
+            .unwrap_or_default()
+    }
+}
+
 impl pallet_collator_selection::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -448,6 +469,13 @@ impl pallet_collator_selection::Config for Runtime {
     type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
     type ValidatorRegistration = Session;
     type SlashRatio = SlashRatio;
+    type RepeatedOffenceSlashRatio = RepeatedOffenceSlashRatio;
+    type JailPeriod = CollatorJailPeriod;
+    type MaxDelegatorsPerCandidate = MaxDelegatorsPerCandidate;
+    type MinDelegation = MinDelegation;
+    type UnbondingPeriod = DelegationUnbondingPeriod;
+    type MaxPerformanceHistory = MaxPerformanceHistory;
+    type CurrentSlot = AuraSlot;
     type WeightInfo = pallet_collator_selection::weights::SubstrateWeight<Runtime>;
 }
 
@@ -735,6 +763,25 @@ impl Get<Multiplier> for AdjustmentFactorGetter {
     }
 }
 
+/// Simple wrapper for fetching the EVM gas usage of the current block.
+/// Relies on `pallet-ethereum` finalizing the block before `pallet-dynamic-evm-base-fee`.
+pub struct BlockGasUsageGetter;
+impl Get<pallet_dynamic_evm_base_fee::BlockGasUsage> for BlockGasUsageGetter {
+    fn get() -> pallet_dynamic_evm_base_fee::BlockGasUsage {
+        pallet_ethereum::CurrentBlock::<Runtime>::get()
+            .map(|block| pallet_dynamic_evm_base_fee::BlockGasUsage {
+                used: block.header.gas_used,
+                limit: block.header.gas_limit,
+            })
+            .unwrap_or_default()
+    }
+}
+
+parameter_types! {
+    pub const EvmBaseFeeAdjustmentMode: pallet_dynamic_evm_base_fee::BaseFeeAdjustmentMode =
+        pallet_dynamic_evm_base_fee::BaseFeeAdjustmentMode::FeeAlignment;
+}
+
 impl pallet_dynamic_evm_base_fee::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DefaultBaseFeePerGas = DefaultBaseFeePerGas;
@@ -743,6 +790,11 @@ impl pallet_dynamic_evm_base_fee::Config for Runtime {
     type AdjustmentFactor = AdjustmentFactorGetter;
     type WeightFactor = WeightFeeFactor;
     type StepLimitRatio = StepLimitRatio;
+    type AdjustmentMode = EvmBaseFeeAdjustmentMode;
+    type GasUsage = BlockGasUsageGetter;
+    type Elasticity = ConstU32<2>;
+    type BaseFeeHistoryLength = ConstU32<256>;
+    type UpdateOrigin = EnsureRoot<AccountId>;
     type WeightInfo = pallet_dynamic_evm_base_fee::weights::SubstrateWeight<Runtime>;
 }
 
@@ -837,6 +889,10 @@ impl pallet_xc_asset_config::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = AssetId;
     type ManagerOrigin = EnsureRoot<AccountId>;
+    type Balance = Balance;
+    type ForeignAssetManager =
+        astar_primitives::xcm::AssetsForeignAssetManager<Runtime, TreasuryAccountId>;
+    type MaxReserveLocations = ConstU32<8>;
     type WeightInfo = pallet_xc_asset_config::weights::SubstrateWeight<Self>;
 }
 
@@ -1096,7 +1152,8 @@ pub type Executive = frame_executive::Executive<
 /// All migrations that will run on the next runtime upgrade.
 ///
 /// Once done, migrations should be removed from the tuple.
-pub type Migrations = ();
+pub type Migrations =
+    (pallet_xc_asset_config::migrations::MigrationReserveLocations<Runtime>,);
 
 type EventRecord = frame_system::EventRecord<
     <Runtime as frame_system::Config>::RuntimeEvent,
@@ -1178,6 +1235,8 @@ mod benches {
         [pallet_xc_asset_config, XcAssetConfig]
         [pallet_collator_selection, CollatorSelection]
         [pallet_xcm, PolkadotXcm]
+        [astar_xcm_benchmarks::fungible, XcmFungible]
+        [pallet_xcm_benchmarks::generic, XcmGeneric]
         [pallet_dynamic_evm_base_fee, DynamicEvmBaseFee]
         [pallet_uniques, Uniques]
     );
@@ -1542,7 +1601,7 @@ impl_runtime_apis! {
         }
 
         fn elasticity() -> Option<Permill> {
-            Some(Permill::zero())
+            Some(DynamicEvmBaseFee::fee_change_rate())
         }
 
         fn gas_limit_multiplier_support() {}
@@ -1637,6 +1696,32 @@ impl_runtime_apis! {
         }
     }
 
+    impl collator_selection_runtime_api::CollatorSelectionApi<Block, AccountId, Balance> for Runtime {
+        fn current_stats(collator: AccountId) -> pallet_collator_selection::CollatorStats<Balance> {
+            CollatorSelection::current_stats(collator)
+        }
+
+        fn performance_history(collator: AccountId) -> Vec<pallet_collator_selection::SessionPerformance<Balance>> {
+            CollatorSelection::performance_history(collator).into_inner()
+        }
+    }
+
+    impl dynamic_evm_base_fee_runtime_api::DynamicEvmBaseFeeApi<Block, BlockNumber> for Runtime {
+        fn base_fee_history(count: u32) -> Vec<pallet_dynamic_evm_base_fee::BaseFeeRecord<BlockNumber>> {
+            DynamicEvmBaseFee::base_fee_history(count)
+        }
+    }
+
+    impl xcm_dry_run_runtime_api::XcmDryRunApi<Block> for Runtime {
+        fn dry_run_xcm(
+            origin: xcm::VersionedMultiLocation,
+            xcm: xcm::VersionedXcm<()>,
+        ) -> Result<xcm_dry_run_runtime_api::XcmDryRunEffects<Vec<u8>>, xcm_dry_run_runtime_api::XcmDryRunError> {
+            astar_primitives::xcm::dry_run::dry_run_xcm::<Runtime, xcm_config::XcmConfig>(origin, xcm)
+                .map(|effects| effects.encode_events())
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (
@@ -1648,6 +1733,9 @@ impl_runtime_apis! {
             use frame_system_benchmarking::Pallet as SystemBench;
             use baseline::Pallet as BaselineBench;
 
+            type XcmFungible = astar_xcm_benchmarks::fungible::Pallet::<Runtime>;
+            type XcmGeneric = pallet_xcm_benchmarks::generic::Pallet::<Runtime>;
+
             let mut list = Vec::<BenchmarkList>::new();
             list_benchmarks!(list, extra);
 
@@ -1666,6 +1754,77 @@ impl_runtime_apis! {
             impl frame_system_benchmarking::Config for Runtime {}
             impl baseline::Config for Runtime {}
 
+            use frame_benchmarking::BenchmarkError;
+            use xcm::latest::prelude::*;
+            use xcm_config::{ShidenLocation, LocationToAccountId, XcmConfig};
+
+            impl pallet_xcm_benchmarks::Config for Runtime {
+                type XcmConfig = XcmConfig;
+                type AccountIdConverter = LocationToAccountId;
+
+                fn valid_destination() -> Result<MultiLocation, BenchmarkError> {
+                    Ok(MultiLocation::parent())
+                }
+
+                fn worst_case_holding(_depositable_count: u32) -> MultiAssets {
+                    // Only the native currency is benchmarked.
+                    vec![MultiAsset {
+                        id: Concrete(ShidenLocation::get()),
+                        fun: Fungible(u128::MAX),
+                    }]
+                    .into()
+                }
+            }
+
+            impl astar_xcm_benchmarks::fungible::Config for Runtime {}
+
+            impl pallet_xcm_benchmarks::generic::Config for Runtime {
+                type RuntimeCall = RuntimeCall;
+
+                fn worst_case_response() -> (u64, Response) {
+                    (0u64, Response::Version(Default::default()))
+                }
+
+                fn worst_case_asset_exchange() -> Result<(MultiAssets, MultiAssets), BenchmarkError> {
+                    // Asset exchange isn't supported.
+                    Err(BenchmarkError::Skip)
+                }
+
+                fn universal_alias() -> Result<(MultiLocation, Junction), BenchmarkError> {
+                    // Universal aliases aren't supported.
+                    Err(BenchmarkError::Skip)
+                }
+
+                fn transact_origin_and_runtime_call() -> Result<(MultiLocation, RuntimeCall), BenchmarkError> {
+                    Ok((MultiLocation::parent(), frame_system::Call::remark_with_event { remark: vec![] }.into()))
+                }
+
+                fn subscribe_origin() -> Result<MultiLocation, BenchmarkError> {
+                    Ok(MultiLocation::parent())
+                }
+
+                fn claimable_asset() -> Result<(MultiLocation, MultiLocation, MultiAssets), BenchmarkError> {
+                    let origin = MultiLocation::parent();
+                    let assets: MultiAssets = (Concrete(ShidenLocation::get()), 1_000 * SDN).into();
+                    let ticket = MultiLocation { parents: 0, interior: Here };
+                    Ok((origin, ticket, assets))
+                }
+
+                fn unlockable_asset() -> Result<(MultiLocation, MultiLocation, MultiAsset), BenchmarkError> {
+                    // Asset locking isn't supported.
+                    Err(BenchmarkError::Skip)
+                }
+
+                fn export_message_origin_and_destination(
+                ) -> Result<(MultiLocation, NetworkId, InteriorMultiLocation), BenchmarkError> {
+                    // Message exporting isn't supported.
+                    Err(BenchmarkError::Skip)
+                }
+            }
+
+            type XcmFungible = astar_xcm_benchmarks::fungible::Pallet::<Runtime>;
+            type XcmGeneric = pallet_xcm_benchmarks::generic::Pallet::<Runtime>;
+
             use frame_support::traits::WhitelistedStorageKeys;
             let whitelist: Vec<TrackedStorageKey> = AllPalletsWithSystem::whitelisted_storage_keys();
 
@@ -19,3 +19,4 @@
 pub mod pallet_assets;
 pub mod pallet_xcm;
 pub mod pallet_uniques;
+pub mod xcm;
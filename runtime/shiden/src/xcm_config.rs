@@ -17,9 +17,10 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use super::{
-    AccountId, AllPalletsWithSystem, AssetId, Assets, Balance, Balances, DealWithFees,
+    AccountId, AllPalletsWithSystem, AssetId, Assets, Balance, Balances, BlockNumber, DealWithFees,
     ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
     ShidenAssetLocationIdConverter, TreasuryAccountId, XcAssetConfig, XcmWeightToFee, XcmpQueue,
+    DAYS,
 };
 use crate::weights;
 use frame_support::{
@@ -35,10 +36,11 @@ use xcm::latest::prelude::*;
 use xcm_builder::{
     AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
     AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, ConvertedConcreteId, CurrencyAdapter,
-    EnsureXcmOrigin, FixedWeightBounds, FungiblesAdapter, IsConcrete, NoChecking,
-    ParentAsSuperuser, ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative,
-    SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32,
-    SovereignSignedViaLocation, TakeWeightCredit, UsingComponents, WithComputedOrigin, SiblingSystemParachainAsSuperuser,
+    EnsureXcmOrigin, FungiblesAdapter, IsConcrete, NoChecking, ParentAsSuperuser, ParentIsPreset,
+    RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
+    SiblingSystemParachainAsSuperuser, SignedAccountId32AsNative, SignedToAccountId32,
+    SovereignSignedViaLocation, TakeWeightCredit, UsingComponents, WeightInfoBounds,
+    WithComputedOrigin,
 };
 use xcm_executor::{
     traits::{Convert as XcmConvert, JustTry, WithOriginFilter},
@@ -51,9 +53,10 @@ use orml_xcm_support::DisabledParachainFee;
 // Astar imports
 use astar_primitives::xcm::{
     AbsoluteAndRelativeReserveProvider, AccountIdToMultiLocation, DescribeAllTerminal,
-    DescribeFamily, FixedRateOfForeignAsset, HashedDescription, ReserveAssetFilter,
+    DescribeFamily, ExchangeRateOfForeignAsset, FixedRateOfForeignAsset, HashedDescription,
     XcmFungibleFeeHandler,
 };
+use pallet_xc_asset_config::ReserveLocationFilter;
 
 parameter_types! {
     pub RelayNetwork: Option<NetworkId> = Some(NetworkId::Kusama);
@@ -138,10 +141,12 @@ pub type XcmOriginToTransactDispatchOrigin = (
 );
 
 parameter_types! {
-    // One XCM operation is 1_000_000_000 weight - almost certainly a conservative estimate.
-    // For the PoV size, we estimate 4 kB per instruction. This will be changed when we benchmark the instructions.
+    // Base weight of an XCM message sent via `orml-xtokens`, on top of the weight of its instructions.
+    // Local XCM instructions are weighed using the benchmarked weights, see `Weigher`.
     pub UnitWeightCost: Weight = Weight::from_parts(1_000_000_000, 4 * 1024);
     pub const MaxInstructions: u32 = 100;
+    // Exchange rates of foreign assets which haven't been updated for this long aren't used for execution payment.
+    pub const MaxExchangeRateAge: BlockNumber = 7 * DAYS;
 }
 
 match_types! {
@@ -250,13 +255,20 @@ impl xcm_executor::Config for XcmConfig {
     type XcmSender = XcmRouter;
     type AssetTransactor = AssetTransactors;
     type OriginConverter = XcmOriginToTransactDispatchOrigin;
-    type IsReserve = ReserveAssetFilter;
+    type IsReserve = ReserveLocationFilter<Runtime>;
     type IsTeleporter = ();
     type UniversalLocation = UniversalLocation;
     type Barrier = XcmBarrier;
     type Weigher = Weigher;
     type Trader = (
         UsingComponents<XcmWeightToFee, ShidenLocation, AccountId, Balances, DealWithFees>,
+        // Assets with an exchange rate pay the native currency fee, converted into the asset.
+        ExchangeRateOfForeignAsset<
+            XcmWeightToFee,
+            XcAssetConfig,
+            MaxExchangeRateAge,
+            ShidenXcmFungibleFeeHandler,
+        >,
         FixedRateOfForeignAsset<XcAssetConfig, ShidenXcmFungibleFeeHandler>,
     );
     type ResponseHandler = PolkadotXcm;
@@ -292,7 +304,9 @@ parameter_types! {
     pub ReachableDest: Option<MultiLocation> = Some(Parent.into());
 }
 
-pub type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+/// Weigher of the XCM messages, based on the benchmarked weights of the XCM instructions.
+pub type Weigher =
+    WeightInfoBounds<weights::xcm::XcmWeight<RuntimeCall>, RuntimeCall, MaxInstructions>;
 
 impl pallet_xcm::Config for Runtime {
     const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
@@ -84,27 +84,29 @@ EXCLUDED_PALLETS=(
   # Pallets without automatic benchmarking
 )
 
-# Load all pallet names in an array.
-ALL_PALLETS=($(
-  $ASTAR_COLLATOR benchmark pallet --list --chain=$chain |\
-    tail -n+2 |\
-    cut -d',' -f1 |\
-    sort |\
-    uniq
-))
-
-# Filter out the excluded pallets by concatenating the arrays and discarding duplicates.
-if [ "$target_pallets" == "" ] || [ "$target_pallets" == "all" ]; then
-    PALLETS=($({ printf '%s\n' "${ALL_PALLETS[@]}" "${EXCLUDED_PALLETS[@]}"; } | sort | uniq -u))
-else
-    PALLETS=($({ printf '%s\n' "${target_pallets//,/ }"; } | sort | uniq -u))
-fi
-
-echo "[+] Benchmarking ${#PALLETS[@]} Astar collator pallets."
-
 ERR_RC=0
 ERR_FILES=""
 for chain in ${chains//,/ }; do
+    # Load all pallet names in an array.
+    # Runtimes don't share the same pallets (e.g. `dev` has no collator selection), so the list is per chain.
+    ALL_PALLETS=($(
+      $ASTAR_COLLATOR benchmark pallet --list --chain=$chain |\
+        tail -n+2 |\
+        cut -d',' -f1 |\
+        sort |\
+        uniq
+    ))
+
+    # Filter out the excluded pallets by concatenating the arrays and discarding duplicates.
+    if [ "$target_pallets" == "" ] || [ "$target_pallets" == "all" ]; then
+        PALLETS=($({ printf '%s\n' "${ALL_PALLETS[@]}" "${EXCLUDED_PALLETS[@]}"; } | sort | uniq -u))
+    else
+        # Only the requested pallets which are part of the chain's runtime.
+        PALLETS=($({ printf '%s\n' ${target_pallets//,/ }; } | sort | uniq | grep -Fx -f <(printf '%s\n' "${ALL_PALLETS[@]}")))
+    fi
+
+    echo "[+] Benchmarking ${#PALLETS[@]} pallets of $chain."
+
     mkdir $output_path/$chain
     # Define the error file.
     ERR_FILE="$output_path/$chain/bench_errors.txt"
@@ -119,6 +121,13 @@ for chain in ${chains//,/ }; do
       WEIGHT_FILE="$output_path/$chain/${NAME}_weights.rs"
       echo "[+] Benchmarking $PALLET with weight file $WEIGHT_FILE";
 
+      # XCM benchmarks use a dedicated template, since weights are used by the XCM weigher directly.
+      if [[ "$PALLET" == pallet_xcm_benchmarks::* ]]; then
+        TEMPLATE="./scripts/templates/xcm-weight-template.hbs"
+      else
+        TEMPLATE="./scripts/templates/weight-template.hbs"
+      fi
+
       OUTPUT=$(
         $ASTAR_COLLATOR benchmark pallet \
         --chain=$chain \
@@ -130,7 +139,7 @@ for chain in ${chains//,/ }; do
         --wasm-execution=compiled \
         --heap-pages=4096 \
         --output="$WEIGHT_FILE" \
-        --template=./scripts/templates/weight-template.hbs 2>&1
+        --template="$TEMPLATE" 2>&1
       )
       if [ $? -ne 0 ]; then
         echo "$OUTPUT" >> "$ERR_FILE"
@@ -207,8 +207,9 @@ fn issue_dapps_staking_rewards() -> (parachain::NegativeImbalance, parachain::Ne
 
 /// Register and configure the asset for use in XCM
 /// It first create the asset in `pallet_assets` and then register the asset multilocation
-/// mapping in `pallet_xc_asset_config`, and lastly set the asset per second for calculating
-/// XCM execution cost (only applicable if `is_sufficent` is true)
+/// mapping in `pallet_xc_asset_config`, its reserve location (relay chain or sibling parachain)
+/// and lastly set the asset per second for calculating XCM execution cost
+/// (only applicable if `is_sufficent` is true)
 pub fn register_and_setup_xcm_asset<Runtime, AssetId>(
     origin: Runtime::RuntimeOrigin,
     // AssetId for the new asset
@@ -243,7 +244,7 @@ where
     pallet_xc_asset_config::Pallet::<Runtime>::register_asset_location(
         origin.clone(),
         Box::new(asset_location.clone().into().into_versioned()),
-        asset_id.into(),
+        asset_id.clone().into(),
     )?;
 
     // set the units per second for XCM cost
@@ -66,8 +66,10 @@ use xcm_executor::{
 };
 
 use astar_primitives::xcm::{
-    AssetLocationIdConverter, FixedRateOfForeignAsset, ReserveAssetFilter, XcmFungibleFeeHandler,
+    AssetLocationIdConverter, AssetsForeignAssetManager, FixedRateOfForeignAsset,
+    XcmFungibleFeeHandler,
 };
+use pallet_xc_asset_config::ReserveLocationFilter;
 
 pub type AccountId = AccountId32;
 pub type Balance = u128;
@@ -395,6 +397,9 @@ impl pallet_xc_asset_config::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = AssetId;
     type ManagerOrigin = EnsureRoot<AccountId>;
+    type Balance = Balance;
+    type ForeignAssetManager = AssetsForeignAssetManager<Runtime, TreasuryAccountId>;
+    type MaxReserveLocations = ConstU32<8>;
     type WeightInfo = pallet_xc_asset_config::weights::SubstrateWeight<Runtime>;
 }
 
@@ -530,7 +535,7 @@ impl xcm_executor::Config for XcmConfig {
     type XcmSender = XcmRouter;
     type AssetTransactor = AssetTransactors;
     type OriginConverter = XcmOriginToTransactDispatchOrigin;
-    type IsReserve = ReserveAssetFilter;
+    type IsReserve = ReserveLocationFilter<Runtime>;
     type IsTeleporter = ();
     type UniversalLocation = UniversalLocation;
     type Barrier = XcmBarrier;
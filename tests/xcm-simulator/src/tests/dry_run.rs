@@ -0,0 +1,209 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mocks::{parachain, *};
+
+use astar_primitives::xcm::dry_run::{dry_run_xcm, XcmDryRunEffects, XcmDryRunError};
+use frame_support::{traits::Get, weights::Weight};
+use xcm::{prelude::*, VersionedMultiAssets, VersionedMultiLocation, VersionedXcm};
+use xcm_simulator::TestExt;
+
+/// Dry-runs the XCM program on the mock parachain.
+fn dry_run(
+    origin: impl Into<MultiLocation>,
+    xcm: Xcm<()>,
+) -> XcmDryRunEffects<parachain::RuntimeEvent> {
+    dry_run_xcm::<parachain::Runtime, parachain::XcmConfig>(
+        VersionedMultiLocation::V3(origin.into()),
+        VersionedXcm::V3(xcm),
+    )
+    .expect("Dry-run must be executed.")
+}
+
+#[test]
+fn dry_run_local_reserve_transfer_is_ok() {
+    MockNet::reset();
+
+    let amount = 1_000_000_000_000_u128;
+    let alice_location: MultiLocation = AccountId32 {
+        network: None,
+        id: ALICE.into(),
+    }
+    .into();
+    let xcm = Xcm(vec![
+        WithdrawAsset((Here, amount).into()),
+        DepositReserveAsset {
+            assets: All.into(),
+            dest: (Parent, Parachain(2)).into(),
+            xcm: Xcm(vec![DepositAsset {
+                assets: All.into(),
+                beneficiary: AccountId32 {
+                    network: None,
+                    id: BOB.into(),
+                }
+                .into(),
+            }]),
+        },
+    ]);
+
+    ParaA::execute_with(|| {
+        use parachain::{Balances, RuntimeEvent, System};
+
+        let events_before = System::events();
+        let effects = dry_run(alice_location, xcm);
+
+        // Local execution is paid with weight credit, same as when executed via `pallet-xcm`.
+        let expected_weight = parachain::UnitWeightCost::get().saturating_mul(2);
+        assert_eq!(effects.execution_result, Outcome::Complete(expected_weight));
+        assert_eq!(effects.weight_used, expected_weight);
+        assert_eq!(
+            effects.fees_charged,
+            VersionedMultiAssets::V3(MultiAssets::new())
+        );
+
+        // Withdrawal from Alice is reported, but not committed.
+        assert!(effects.emitted_events.iter().any(|event| matches!(
+            event,
+            RuntimeEvent::Balances(pallet_balances::Event::Withdraw { who, amount: withdrawn })
+                if *who == ALICE && *withdrawn == amount
+        )));
+        assert_eq!(Balances::free_balance(&ALICE), INITIAL_BALANCE);
+        assert_eq!(
+            Balances::free_balance(&sibling_para_account_id(2)),
+            INITIAL_BALANCE
+        );
+        assert_eq!(System::events(), events_before);
+
+        // Reserve transfer message is forwarded to the destination.
+        assert_eq!(effects.forwarded_xcms.len(), 1);
+        let (destination, message) = effects.forwarded_xcms[0].clone();
+        assert_eq!(
+            destination,
+            VersionedMultiLocation::V3((Parent, Parachain(2)).into())
+        );
+        let message = Xcm::<()>::try_from(message).expect("Must be latest version.");
+        assert!(matches!(
+            message.0.as_slice(),
+            [ReserveAssetDeposited(assets), ClearOrigin, DepositAsset { .. }]
+                if *assets == MultiAssets::from(MultiAsset::from(((Parent, Parachain(1)), amount)))
+        ));
+    });
+}
+
+#[test]
+fn dry_run_remote_paid_execution_is_ok() {
+    MockNet::reset();
+
+    let amount = 1_000_000_000_000_u128;
+    let xcm = Xcm(vec![
+        WithdrawAsset((Here, amount).into()),
+        BuyExecution {
+            fees: (Here, amount).into(),
+            weight_limit: Unlimited,
+        },
+        DepositAsset {
+            assets: All.into(),
+            beneficiary: AccountId32 {
+                network: None,
+                id: ALICE.into(),
+            }
+            .into(),
+        },
+    ]);
+
+    ParaA::execute_with(|| {
+        use parachain::{Balances, RuntimeEvent};
+
+        let effects = dry_run((Parent, Parachain(2)), xcm);
+
+        // Remote origin must buy execution, with native currency being priced at 1 unit per weight unit.
+        let expected_weight = parachain::UnitWeightCost::get().saturating_mul(3);
+        let expected_fee = expected_weight.ref_time() as u128;
+        assert_eq!(effects.execution_result, Outcome::Complete(expected_weight));
+        assert_eq!(
+            effects.fees_charged,
+            VersionedMultiAssets::V3((Here, expected_fee).into())
+        );
+        assert!(effects.forwarded_xcms.is_empty());
+
+        // Deposit to Alice is reported, but not committed.
+        assert!(effects.emitted_events.iter().any(|event| matches!(
+            event,
+            RuntimeEvent::Balances(pallet_balances::Event::Deposit { who, amount: deposited })
+                if *who == ALICE && *deposited == amount - expected_fee
+        )));
+        assert_eq!(Balances::free_balance(&ALICE), INITIAL_BALANCE);
+        assert_eq!(
+            Balances::free_balance(&sibling_para_account_id(2)),
+            INITIAL_BALANCE
+        );
+    });
+}
+
+#[test]
+fn dry_run_failed_execution_is_reported() {
+    MockNet::reset();
+
+    let alice_location: MultiLocation = AccountId32 {
+        network: None,
+        id: ALICE.into(),
+    }
+    .into();
+
+    ParaA::execute_with(|| {
+        // Alice doesn't have enough funds, so the withdrawal fails.
+        let effects = dry_run(
+            alice_location,
+            Xcm(vec![
+                WithdrawAsset((Here, INITIAL_BALANCE + 1).into()),
+                DepositReserveAsset {
+                    assets: All.into(),
+                    dest: (Parent, Parachain(2)).into(),
+                    xcm: Xcm(vec![]),
+                },
+            ]),
+        );
+
+        assert!(matches!(
+            effects.execution_result,
+            Outcome::Incomplete(_, XcmError::FailedToTransactAsset(_))
+        ));
+        assert!(effects.forwarded_xcms.is_empty());
+
+        // Barrier rejects unpaid execution from a remote origin.
+        let effects = dry_run((Parent, Parachain(2)), Xcm(vec![ClearOrigin]));
+        assert_eq!(effects.execution_result, Outcome::Error(XcmError::Barrier));
+        assert_eq!(effects.weight_used, Weight::zero());
+
+        // Origin which can't be converted into the latest version is rejected.
+        let named_origin = xcm::v2::MultiLocation::new(
+            0,
+            xcm::v2::Junctions::X1(xcm::v2::Junction::AccountId32 {
+                network: xcm::v2::NetworkId::Named(b"astar".to_vec().try_into().unwrap()),
+                id: ALICE.into(),
+            }),
+        );
+        assert_eq!(
+            dry_run_xcm::<parachain::Runtime, parachain::XcmConfig>(
+                VersionedMultiLocation::V2(named_origin),
+                VersionedXcm::V3(Xcm(vec![ClearOrigin])),
+            ),
+            Err(XcmDryRunError::UnsupportedOrigin)
+        );
+    });
+}
@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::mocks::{parachain, relay_chain, *};
+use crate::mocks::{msg_queue::mock_msg_queue, parachain, relay_chain, *};
 use frame_support::{assert_ok, weights::Weight};
 use xcm::prelude::*;
 use xcm_simulator::TestExt;
@@ -996,3 +996,191 @@ fn para_asset_trap_and_claim() {
         assert_eq!(parachain::Balances::free_balance(BOB), send_amount);
     });
 }
+
+#[test]
+fn reserve_asset_deposit_only_accepted_from_registered_reserves() {
+    MockNet::reset();
+
+    let relay_asset_id = 123 as u128;
+    let source_location: MultiLocation = (Parent,).into();
+    let deposit_amount = 1_000_000_000_000_u128;
+
+    // On parachain B register relay asset, relay chain is its only reserve location.
+    ParaB::execute_with(|| {
+        assert_ok!(register_and_setup_xcm_asset::<parachain::Runtime, _>(
+            parachain::RuntimeOrigin::root(),
+            relay_asset_id,
+            source_location,
+            parent_account_id(),
+            Some(true),
+            Some(1),
+            Some(1_000_000_000_000)
+        ));
+    });
+
+    // Parachain A claims to be the reserve of relay asset, and deposits it to Alice on parachain B.
+    let send_reserve_asset_deposit = || {
+        ParaA::execute_with(|| {
+            assert_ok!(ParachainPalletXcm::send_xcm(
+                Here,
+                (Parent, Parachain(2)),
+                Xcm(vec![
+                    ReserveAssetDeposited((Parent, deposit_amount).into()),
+                    ClearOrigin,
+                    BuyExecution {
+                        fees: (Parent, deposit_amount).into(),
+                        weight_limit: Unlimited
+                    },
+                    DepositAsset {
+                        assets: AllCounted(1).into(),
+                        beneficiary: AccountId32 {
+                            network: None,
+                            id: ALICE.into(),
+                        }
+                        .into(),
+                    },
+                ]),
+            ));
+        });
+    };
+
+    // Parachain A isn't a registered reserve so deposit must be rejected.
+    send_reserve_asset_deposit();
+    ParaB::execute_with(|| {
+        use parachain::{RuntimeEvent, System};
+        assert!(System::events().iter().any(|r| matches!(
+            r.event,
+            RuntimeEvent::MsgQueue(mock_msg_queue::Event::Fail(
+                _,
+                XcmError::UntrustedReserveLocation
+            ))
+        )));
+        assert_eq!(ParachainAssets::balance(relay_asset_id, &ALICE.into()), 0);
+
+        // Governance registers parachain A as an additional reserve location
+        assert_ok!(parachain::XcAssetConfig::add_reserve_location(
+            parachain::RuntimeOrigin::root(),
+            relay_asset_id,
+            Box::new(MultiLocation::new(1, X1(Parachain(1))).into_versioned()),
+        ));
+    });
+
+    // Deposit from registered reserve is accepted, minus the execution cost.
+    send_reserve_asset_deposit();
+    let four_instructions_execution_cost =
+        (parachain::UnitWeightCost::get() * 4).ref_time() as u128;
+    let received_amount = deposit_amount - four_instructions_execution_cost;
+    ParaB::execute_with(|| {
+        assert_eq!(
+            ParachainAssets::balance(relay_asset_id, &ALICE.into()),
+            received_amount
+        );
+
+        // Governance removes parachain A from the reserve locations again
+        assert_ok!(parachain::XcAssetConfig::remove_reserve_location(
+            parachain::RuntimeOrigin::root(),
+            relay_asset_id,
+            Box::new(MultiLocation::new(1, X1(Parachain(1))).into_versioned()),
+        ));
+        parachain::System::reset_events();
+    });
+
+    // Parachain A can no longer deposit relay asset.
+    send_reserve_asset_deposit();
+    ParaB::execute_with(|| {
+        use parachain::{RuntimeEvent, System};
+        assert!(System::events().iter().any(|r| matches!(
+            r.event,
+            RuntimeEvent::MsgQueue(mock_msg_queue::Event::Fail(
+                _,
+                XcmError::UntrustedReserveLocation
+            ))
+        )));
+        assert_eq!(
+            ParachainAssets::balance(relay_asset_id, &ALICE.into()),
+            received_amount
+        );
+    });
+}
+
+#[test]
+fn register_and_deregister_foreign_asset() {
+    MockNet::reset();
+
+    let asset_id = 123 as u128;
+    let asset_location = MultiLocation::new(1, X1(Parachain(1)));
+
+    ParaB::execute_with(|| {
+        use frame_support::traits::{
+            fungibles::{metadata::Inspect as _, Inspect},
+            Get,
+        };
+        use parachain::{RuntimeEvent, System};
+
+        // Governance registers parachain A native asset, together with its local asset,
+        // reserve location & execution price
+        assert_ok!(parachain::XcAssetConfig::register_foreign_asset(
+            parachain::RuntimeOrigin::root(),
+            Box::new(asset_location.into_versioned()),
+            asset_id,
+            pallet_xc_asset_config::ForeignAssetMetadata {
+                name: b"Parachain A".to_vec(),
+                symbol: b"PARA".to_vec(),
+                decimals: 18,
+                min_balance: 1,
+                is_sufficient: true,
+            },
+            vec![asset_location.into_versioned()].try_into().unwrap(),
+            Some(1_000_000_000_000),
+        ));
+
+        assert!(ParachainAssets::asset_exists(asset_id));
+        assert_eq!(ParachainAssets::name(asset_id), b"Parachain A".to_vec());
+        assert_eq!(ParachainAssets::symbol(asset_id), b"PARA".to_vec());
+        assert_eq!(ParachainAssets::decimals(asset_id), 18);
+        assert_eq!(ParachainAssets::minimum_balance(asset_id), 1);
+        assert_eq!(
+            parachain::XcAssetConfig::asset_location_to_id(asset_location.into_versioned()),
+            Some(asset_id)
+        );
+        assert!(parachain::XcAssetConfig::is_reserve_location(
+            asset_id,
+            asset_location
+        ));
+        assert_eq!(
+            parachain::XcAssetConfig::asset_location_units_per_second(
+                asset_location.into_versioned()
+            ),
+            Some(1_000_000_000_000)
+        );
+
+        // Sufficient asset can be deposited to a fresh account
+        let fresh_account = [7_u8; 32];
+        assert_ok!(ParachainAssets::mint(
+            parachain::RuntimeOrigin::signed(parachain::TreasuryAccountId::get()),
+            asset_id,
+            fresh_account.into(),
+            1_000
+        ));
+        assert_eq!(
+            ParachainAssets::balance(asset_id, &fresh_account.into()),
+            1_000
+        );
+
+        // Deregistering removes the location & starts the local asset destruction
+        assert_ok!(parachain::XcAssetConfig::deregister_foreign_asset(
+            parachain::RuntimeOrigin::root(),
+            asset_id,
+        ));
+
+        assert_eq!(
+            parachain::XcAssetConfig::asset_location_to_id(asset_location.into_versioned()),
+            None
+        );
+        assert!(System::events().iter().any(|r| matches!(
+            r.event,
+            RuntimeEvent::Assets(pallet_assets::Event::DestructionStarted { asset_id: id })
+                if id == asset_id
+        )));
+    });
+}